@@ -0,0 +1,457 @@
+use crate::ai::steering::*;
+use crate::game::rng::GameRng;
+use crate::game::system::GameEvent;
+use crate::game::types::*;
+use crate::navigation::{find_path, NavGrid};
+use crate::stealth::suspicion::DetectionThresholds;
+use glam::Vec2;
+use serde::{Deserialize, Serialize};
+
+/// Loudness at which a guard reacts to a sound
+pub const HEARING_THRESHOLD: f32 = 0.4;
+
+/// Time an alerted guard takes to react before giving chase (seconds)
+pub const REACTION_TIME: f32 = 0.5;
+
+/// Time without sight of the target before a hunting guard gives up the chase
+pub const LOSE_TARGET_TIME: f32 = 1.5;
+
+/// How long a suspicious guard investigates before returning to patrol
+pub const INVESTIGATE_TIMEOUT: f32 = 8.0;
+
+/// How long a guard searches after losing a target it was hunting
+pub const SEARCH_TIMEOUT: f32 = 12.0;
+
+/// Chasing guards move faster than patrolling ones
+pub const HUNT_SPEED_MULTIPLIER: f32 = 1.5;
+
+/// Distance the destination may drift before the path is re-planned
+pub const REPATH_DISTANCE: f32 = 2.0;
+
+/// Half-angle and period of the sweep while searching an area
+const SEARCH_SWEEP_ANGLE: f32 = std::f32::consts::FRAC_PI_2;
+const SEARCH_SWEEP_PERIOD: f32 = 4.0;
+
+/// What a guard perceived of a target this tick
+#[derive(Debug, Clone, Copy)]
+pub struct Sighting {
+    pub target_id: EntityId,
+    pub position: Position,
+    pub detection: f32, // This guard's suspicion of the target
+}
+
+/// The loudest sound a guard heard this tick
+#[derive(Debug, Clone, Copy)]
+pub struct HeardNoise {
+    pub source_id: EntityId,
+    pub position: Position,
+    pub loudness: f32,
+}
+
+/// Per-guard AI memory driving the ActorState machine
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GuardMind {
+    pub guard_id: EntityId,
+    pub target_id: Option<EntityId>,
+    pub last_known_position: Option<Position>,
+    pub state_time: f32,       // Seconds spent in the current state
+    pub lost_time: f32,        // Seconds since the hunted target was last seen
+    pub search_remaining: f32, // Seconds left before giving up a search
+    pub search_heading: f32,   // Heading the search sweep is centred on
+    #[serde(default)]
+    pub sweep_phase: f32,      // Random offset into the search sweep so guards don't move in unison
+    #[serde(default)]
+    pub path: Vec<Position>,   // Remaining waypoints to the current destination
+    #[serde(default)]
+    pub path_goal: Option<Position>,
+    #[serde(default)]
+    pub path_signature: Option<u64>, // Layout of the nav grid the path was planned on
+    #[serde(default)]
+    pub last_heard: Option<Position>, // Where the sound currently being tracked came from
+    #[serde(default)]
+    pub thresholds: DetectionThresholds,
+}
+
+impl GuardMind {
+    pub fn new(guard_id: EntityId) -> Self {
+        Self {
+            guard_id,
+            target_id: None,
+            last_known_position: None,
+            state_time: 0.0,
+            lost_time: 0.0,
+            search_remaining: 0.0,
+            search_heading: 0.0,
+            sweep_phase: 0.0,
+            path: Vec::new(),
+            path_goal: None,
+            path_signature: None,
+            last_heard: None,
+            thresholds: DetectionThresholds::default(),
+        }
+    }
+}
+
+/// Is the guard calm enough to be following its patrol route
+pub fn is_patrolling(state: ActorState) -> bool {
+    matches!(state, ActorState::Idle | ActorState::Walking)
+}
+
+/// Move towards a destination, routing around obstacles when a grid is available
+/// Returns true once the destination has been reached
+fn move_to(
+    guard: &mut Entity,
+    mind: &mut GuardMind,
+    destination: Position,
+    nav: Option<&NavGrid>,
+    speed: f32,
+    delta_time: f32,
+) -> bool {
+    if let Some(grid) = nav {
+        // Re-plan when the goal has moved on, or a door or obstacle has changed the layout
+        let stale = mind.path_signature != Some(grid.signature)
+            || mind.path_goal.is_none_or(|goal| goal.distance_2d(&destination) > REPATH_DISTANCE);
+        if stale {
+            mind.path = find_path(grid, &guard.position, &destination).unwrap_or_default();
+            mind.path_goal = Some(destination);
+            mind.path_signature = Some(grid.signature);
+        }
+    }
+
+    // Finished (or unreachable) paths fall back to walking straight at the destination
+    if mind.path.is_empty() {
+        return steer_towards(guard, &destination, speed, delta_time);
+    }
+    steer_along_path(guard, &mut mind.path, speed, delta_time);
+    false
+}
+
+fn clear_path(mind: &mut GuardMind) {
+    mind.path.clear();
+    mind.path_goal = None;
+    mind.path_signature = None;
+}
+
+fn enter_state(guard: &mut Entity, mind: &mut GuardMind, state: ActorState) {
+    guard.state = state;
+    mind.state_time = 0.0;
+}
+
+fn start_investigating(
+    guard: &mut Entity,
+    mind: &mut GuardMind,
+    position: Position,
+    timeout: f32,
+    rng: &mut GameRng,
+    events: &mut Vec<GameEvent>,
+) {
+    mind.last_known_position = Some(position);
+    mind.search_remaining = timeout;
+
+    if guard.state != ActorState::Investigating {
+        enter_state(guard, mind, ActorState::Investigating);
+        mind.sweep_phase = rng.next_f32();
+        events.push(GameEvent::GuardInvestigating {
+            guard_id: guard.id,
+            position,
+        });
+    }
+}
+
+fn become_alerted(
+    guard: &mut Entity,
+    mind: &mut GuardMind,
+    sighting: &Sighting,
+    events: &mut Vec<GameEvent>,
+) {
+    mind.target_id = Some(sighting.target_id);
+    mind.last_known_position = Some(sighting.position);
+    mind.lost_time = 0.0;
+    guard.velocity = Vec2::ZERO;
+    enter_state(guard, mind, ActorState::Alerted);
+    events.push(GameEvent::GuardAlerted {
+        guard_id: guard.id,
+        position: sighting.position,
+    });
+}
+
+fn return_to_patrol(guard: &mut Entity, mind: &mut GuardMind, events: &mut Vec<GameEvent>) {
+    mind.target_id = None;
+    mind.last_known_position = None;
+    mind.search_remaining = 0.0;
+    clear_path(mind);
+    guard.velocity = Vec2::ZERO;
+    enter_state(guard, mind, ActorState::Idle);
+    events.push(GameEvent::GuardReturnedToPatrol { guard_id: guard.id });
+}
+
+/// Send a guard to check out a position reported by the alarm system
+/// Guards already alerted or hunting keep doing what they are doing
+pub fn respond_to_report(
+    guard: &mut Entity,
+    mind: &mut GuardMind,
+    target_id: Option<EntityId>,
+    position: Position,
+    rng: &mut GameRng,
+    events: &mut Vec<GameEvent>,
+) {
+    if matches!(guard.state, ActorState::Alerted | ActorState::Hunting) {
+        return;
+    }
+    if target_id.is_some() {
+        mind.target_id = target_id;
+    }
+    start_investigating(guard, mind, position, INVESTIGATE_TIMEOUT, rng, events);
+}
+
+/// Run one tick of the guard behaviour state machine
+///
+/// Patrol (Idle/Walking) -> Investigating on suspicion,
+/// any calm state -> Alerted on full detection -> Hunting after reacting,
+/// Hunting -> Investigating once the target is lost -> patrol after the search times out.
+pub fn update_guard_mind(
+    guard: &mut Entity,
+    mind: &mut GuardMind,
+    sighting: Option<&Sighting>,
+    heard: Option<&HeardNoise>,
+    nav: Option<&NavGrid>,
+    rng: &mut GameRng,
+    delta_time: f32,
+) -> Vec<GameEvent> {
+    let mut events = Vec::new();
+    mind.state_time += delta_time;
+
+    // Only sounds above the hearing threshold register, and each new one is reported once
+    let heard = heard.filter(|noise| noise.loudness >= HEARING_THRESHOLD);
+    match heard {
+        Some(noise) => {
+            let is_new = mind.last_heard
+                .is_none_or(|p| p.distance_2d(&noise.position) > REPATH_DISTANCE);
+            if is_new {
+                mind.last_heard = Some(noise.position);
+                events.push(GameEvent::NoiseHeard {
+                    guard_id: guard.id,
+                    position: noise.position,
+                    loudness: noise.loudness,
+                });
+            }
+        }
+        None => mind.last_heard = None,
+    }
+
+    match guard.state {
+        ActorState::Alerted => {
+            // Freeze and turn towards the target before giving chase
+            if let Some(seen) = sighting {
+                mind.last_known_position = Some(seen.position);
+            }
+            guard.velocity = Vec2::ZERO;
+            if let Some(target) = mind.last_known_position {
+                face_towards(guard, &target, delta_time);
+            }
+
+            if mind.state_time >= REACTION_TIME {
+                enter_state(guard, mind, ActorState::Hunting);
+                if let Some(target_id) = mind.target_id {
+                    events.push(GameEvent::GuardHunting { guard_id: guard.id, target_id });
+                }
+            }
+        }
+        ActorState::Hunting => {
+            match sighting {
+                Some(seen) => {
+                    mind.target_id = Some(seen.target_id);
+                    mind.last_known_position = Some(seen.position);
+                    mind.lost_time = 0.0;
+                }
+                None => match heard.filter(|noise| Some(noise.source_id) == mind.target_id) {
+                    // Track the target by ear while it is out of sight
+                    Some(noise) => {
+                        mind.last_known_position = Some(noise.position);
+                        mind.lost_time = 0.0;
+                    }
+                    None => mind.lost_time += delta_time,
+                },
+            }
+
+            if mind.lost_time >= LOSE_TARGET_TIME {
+                let last_known = mind.last_known_position.unwrap_or(guard.position);
+                mind.search_heading = guard.rotation;
+                events.push(GameEvent::GuardLostTarget {
+                    guard_id: guard.id,
+                    last_known_position: last_known,
+                });
+                start_investigating(guard, mind, last_known, SEARCH_TIMEOUT, rng, &mut events);
+                return events;
+            }
+
+            // Chase the target, or run to where it was last seen
+            if let Some(target) = mind.last_known_position {
+                let speed = guard.speed * HUNT_SPEED_MULTIPLIER;
+                move_to(guard, mind, target, nav, speed, delta_time);
+            }
+        }
+        ActorState::Investigating => {
+            if let Some(seen) = sighting {
+                if seen.detection >= mind.thresholds.detected {
+                    become_alerted(guard, mind, seen, &mut events);
+                    return events;
+                }
+                if seen.detection >= mind.thresholds.suspicious {
+                    start_investigating(guard, mind, seen.position, INVESTIGATE_TIMEOUT, rng, &mut events);
+                }
+            } else if let Some(noise) = heard {
+                // Follow up on the new sound instead
+                mind.target_id = Some(noise.source_id);
+                start_investigating(guard, mind, noise.position, INVESTIGATE_TIMEOUT, rng, &mut events);
+            }
+
+            mind.search_remaining -= delta_time;
+            if mind.search_remaining <= 0.0 {
+                return_to_patrol(guard, mind, &mut events);
+                return events;
+            }
+
+            // Walk to the last known position, then look around it
+            let target = mind.last_known_position.unwrap_or(guard.position);
+            if move_to(guard, mind, target, nav, guard.speed, delta_time) {
+                let progress = ((mind.state_time % SEARCH_SWEEP_PERIOD) / SEARCH_SWEEP_PERIOD + mind.sweep_phase) % 1.0;
+                look_around(guard, mind.search_heading, SEARCH_SWEEP_ANGLE, progress, delta_time);
+            } else {
+                mind.search_heading = guard.rotation;
+            }
+        }
+        _ => {
+            // Patrolling or idle: react to anything suspicious
+            let Some(seen) = sighting else {
+                if let Some(noise) = heard {
+                    mind.target_id = Some(noise.source_id);
+                    start_investigating(guard, mind, noise.position, INVESTIGATE_TIMEOUT, rng, &mut events);
+                }
+                return events;
+            };
+
+            if seen.detection >= mind.thresholds.detected {
+                become_alerted(guard, mind, seen, &mut events);
+            } else if seen.detection >= mind.thresholds.suspicious {
+                mind.target_id = Some(seen.target_id);
+                start_investigating(guard, mind, seen.position, INVESTIGATE_TIMEOUT, rng, &mut events);
+            }
+        }
+    }
+
+    events
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sighting(detection: f32) -> Sighting {
+        Sighting {
+            target_id: 2,
+            position: Position::new(10.0, 0.0),
+            detection,
+        }
+    }
+
+    #[test]
+    fn test_path_is_replanned_when_the_layout_changes() {
+        let mut guard = Entity::new_guard(1, Position::new(2.0, 10.0));
+        let mut mind = GuardMind::new(1);
+        let destination = Position::new(18.0, 10.0);
+        let mut world = World::new(20.0, 20.0);
+        let open = NavGrid::bake(&world);
+        move_to(&mut guard, &mut mind, destination, Some(&open), 1.0, 0.0);
+        assert_eq!(mind.path_signature, Some(open.signature));
+
+        // Something now stands in the way, so the old straight path is dropped
+        world.obstacles.push(Obstacle {
+            position: Position::new(10.0, 10.0),
+            radius: 3.0,
+            blocks_vision: true,
+            blocks_sound: true,
+            provides_cover: true,
+        });
+        let blocked = NavGrid::bake(&world);
+        move_to(&mut guard, &mut mind, destination, Some(&blocked), 1.0, 0.0);
+        assert_eq!(mind.path_signature, Some(blocked.signature));
+        assert!(mind.path.iter().any(|p| (p.y - 10.0).abs() > 3.0), "the new path goes around");
+    }
+
+    #[test]
+    fn test_suspicion_starts_investigation() {
+        let mut guard = Entity::new_guard(1, Position::new(0.0, 0.0));
+        let mut mind = GuardMind::new(1);
+        let mut rng = GameRng::new(1);
+
+        let events = update_guard_mind(&mut guard, &mut mind, Some(&sighting(0.5)), None, None, &mut rng, 0.1);
+        assert_eq!(guard.state, ActorState::Investigating);
+        assert!(matches!(events[0], GameEvent::GuardInvestigating { guard_id: 1, .. }));
+    }
+
+    #[test]
+    fn test_detection_leads_to_hunt() {
+        let mut guard = Entity::new_guard(1, Position::new(0.0, 0.0));
+        let mut mind = GuardMind::new(1);
+        let mut rng = GameRng::new(1);
+
+        update_guard_mind(&mut guard, &mut mind, Some(&sighting(1.0)), None, None, &mut rng, 0.1);
+        assert_eq!(guard.state, ActorState::Alerted);
+
+        let events = update_guard_mind(&mut guard, &mut mind, Some(&sighting(1.0)), None, None, &mut rng, REACTION_TIME);
+        assert_eq!(guard.state, ActorState::Hunting);
+        assert!(matches!(events[0], GameEvent::GuardHunting { guard_id: 1, target_id: 2 }));
+
+        // Chasing moves towards the target
+        update_guard_mind(&mut guard, &mut mind, Some(&sighting(1.0)), None, None, &mut rng, 0.1);
+        assert!(guard.velocity.x > 0.0);
+    }
+
+    #[test]
+    fn test_noise_starts_investigation() {
+        let mut guard = Entity::new_guard(1, Position::new(0.0, 0.0));
+        let mut mind = GuardMind::new(1);
+        let mut rng = GameRng::new(1);
+        let noise = HeardNoise { source_id: 2, position: Position::new(-10.0, 0.0), loudness: 0.6 };
+
+        let events = update_guard_mind(&mut guard, &mut mind, None, Some(&noise), None, &mut rng, 0.1);
+        assert_eq!(guard.state, ActorState::Investigating);
+        assert_eq!(mind.target_id, Some(2));
+        assert!(matches!(events[0], GameEvent::NoiseHeard { guard_id: 1, .. }));
+
+        // The same sound is not reported twice, and the guard turns towards it
+        let events = update_guard_mind(&mut guard, &mut mind, None, Some(&noise), None, &mut rng, 0.1);
+        assert!(events.is_empty());
+        assert!(guard.velocity.x < 0.0);
+    }
+
+    #[test]
+    fn test_quiet_noise_is_ignored() {
+        let mut guard = Entity::new_guard(1, Position::new(0.0, 0.0));
+        let mut mind = GuardMind::new(1);
+        let mut rng = GameRng::new(1);
+        let noise = HeardNoise { source_id: 2, position: Position::new(-10.0, 0.0), loudness: 0.1 };
+
+        let events = update_guard_mind(&mut guard, &mut mind, None, Some(&noise), None, &mut rng, 0.1);
+        assert!(events.is_empty());
+        assert_eq!(guard.state, ActorState::Idle);
+    }
+
+    #[test]
+    fn test_lost_target_returns_to_patrol() {
+        let mut guard = Entity::new_guard(1, Position::new(0.0, 0.0));
+        let mut mind = GuardMind::new(1);
+        let mut rng = GameRng::new(1);
+        update_guard_mind(&mut guard, &mut mind, Some(&sighting(1.0)), None, None, &mut rng, 0.1);
+        update_guard_mind(&mut guard, &mut mind, None, None, None, &mut rng, REACTION_TIME);
+
+        let events = update_guard_mind(&mut guard, &mut mind, None, None, None, &mut rng, LOSE_TARGET_TIME);
+        assert_eq!(guard.state, ActorState::Investigating);
+        assert!(matches!(events[0], GameEvent::GuardLostTarget { guard_id: 1, .. }));
+
+        let events = update_guard_mind(&mut guard, &mut mind, None, None, None, &mut rng, SEARCH_TIMEOUT);
+        assert_eq!(guard.state, ActorState::Idle);
+        assert!(matches!(events[0], GameEvent::GuardReturnedToPatrol { guard_id: 1 }));
+    }
+}
@@ -0,0 +1,7 @@
+pub mod guard;
+pub mod patrol;
+pub mod steering;
+
+pub use guard::*;
+pub use patrol::*;
+pub use steering::*;
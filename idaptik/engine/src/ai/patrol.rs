@@ -0,0 +1,178 @@
+use crate::ai::steering::*;
+use crate::game::types::*;
+use glam::Vec2;
+use serde::{Deserialize, Serialize};
+
+/// How a patrol continues once the last waypoint is reached
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PatrolMode {
+    Loop,     // Last waypoint leads back to the first
+    PingPong, // Walk the route backwards, then forwards again
+}
+
+/// A single stop on a patrol route
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Waypoint {
+    pub position: Position,
+    pub dwell_time: f32,  // Seconds spent at the waypoint
+    pub look_around: f32, // Half-angle of the sweep while dwelling (radians)
+}
+
+/// Authored patrol route and its runtime progress for one guard
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PatrolRoute {
+    pub guard_id: EntityId,
+    pub mode: PatrolMode,
+    pub waypoints: Vec<Waypoint>,
+
+    // Runtime progress
+    pub current: usize,
+    pub forward: bool,
+    pub dwell_remaining: f32,
+    pub dwell_heading: f32,
+}
+
+impl PatrolRoute {
+    pub fn new(guard_id: EntityId, mode: PatrolMode) -> Self {
+        Self {
+            guard_id,
+            mode,
+            waypoints: Vec::new(),
+            current: 0,
+            forward: true,
+            dwell_remaining: 0.0,
+            dwell_heading: 0.0,
+        }
+    }
+
+    pub fn add_waypoint(&mut self, position: Position, dwell_time: f32, look_around: f32) {
+        self.waypoints.push(Waypoint {
+            position,
+            dwell_time: dwell_time.max(0.0),
+            look_around: look_around.abs(),
+        });
+    }
+
+    /// Waypoint the guard is currently heading to or dwelling at
+    pub fn current_waypoint(&self) -> Option<&Waypoint> {
+        self.waypoints.get(self.current)
+    }
+
+    pub fn is_dwelling(&self) -> bool {
+        self.dwell_remaining > 0.0
+    }
+
+    /// Move on to the next waypoint according to the patrol mode
+    pub fn advance(&mut self) {
+        let count = self.waypoints.len();
+        if count < 2 {
+            self.current = 0;
+            return;
+        }
+
+        match self.mode {
+            PatrolMode::Loop => {
+                self.current = (self.current + 1) % count;
+            }
+            PatrolMode::PingPong => {
+                if self.forward && self.current + 1 >= count {
+                    self.forward = false;
+                } else if !self.forward && self.current == 0 {
+                    self.forward = true;
+                }
+
+                if self.forward {
+                    self.current += 1;
+                } else {
+                    self.current -= 1;
+                }
+            }
+        }
+    }
+}
+
+/// Advance a guard along its patrol route
+pub fn step_patrol(guard: &mut Entity, route: &mut PatrolRoute, delta_time: f32) {
+    let Some(waypoint) = route.current_waypoint().cloned() else {
+        return;
+    };
+
+    // Dwelling: stand still and sweep around the arrival heading
+    if route.is_dwelling() {
+        guard.velocity = Vec2::ZERO;
+        guard.state = ActorState::Idle;
+
+        route.dwell_remaining -= delta_time;
+        if route.dwell_remaining <= 0.0 {
+            route.dwell_remaining = 0.0;
+            route.advance();
+            return;
+        }
+
+        if waypoint.look_around > 0.0 && waypoint.dwell_time > 0.0 {
+            let progress = 1.0 - route.dwell_remaining / waypoint.dwell_time;
+            look_around(guard, route.dwell_heading, waypoint.look_around, progress, delta_time);
+        }
+        return;
+    }
+
+    // Arrived: start dwelling, or head straight on if there is no dwell
+    if steer_towards(guard, &waypoint.position, guard.speed, delta_time) {
+        if waypoint.dwell_time > 0.0 {
+            route.dwell_remaining = waypoint.dwell_time;
+            route.dwell_heading = guard.rotation;
+            guard.state = ActorState::Idle;
+        } else {
+            route.advance();
+        }
+        return;
+    }
+
+    guard.state = ActorState::Walking;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn route(mode: PatrolMode, count: usize) -> PatrolRoute {
+        let mut route = PatrolRoute::new(1, mode);
+        for i in 0..count {
+            route.add_waypoint(Position::new(i as f32 * 10.0, 0.0), 0.0, 0.0);
+        }
+        route
+    }
+
+    #[test]
+    fn test_loop_wraps_around() {
+        let mut route = route(PatrolMode::Loop, 3);
+        let visited: Vec<usize> = (0..5).map(|_| { route.advance(); route.current }).collect();
+        assert_eq!(visited, vec![1, 2, 0, 1, 2]);
+    }
+
+    #[test]
+    fn test_ping_pong_reverses() {
+        let mut route = route(PatrolMode::PingPong, 3);
+        let visited: Vec<usize> = (0..6).map(|_| { route.advance(); route.current }).collect();
+        assert_eq!(visited, vec![1, 2, 1, 0, 1, 2]);
+    }
+
+    #[test]
+    fn test_guard_dwells_at_waypoint() {
+        let mut guard = Entity::new_guard(1, Position::new(0.0, 0.0));
+        let mut route = PatrolRoute::new(1, PatrolMode::Loop);
+        route.add_waypoint(Position::new(0.0, 0.0), 2.0, 0.5);
+        route.add_waypoint(Position::new(10.0, 0.0), 0.0, 0.0);
+
+        step_patrol(&mut guard, &mut route, 0.1);
+        assert!(route.is_dwelling());
+        assert_eq!(guard.velocity, Vec2::ZERO);
+
+        // Finish dwelling, then head to the next waypoint
+        step_patrol(&mut guard, &mut route, 2.0);
+        assert_eq!(route.current, 1);
+        step_patrol(&mut guard, &mut route, 0.1);
+        assert!(guard.velocity.x > 0.0);
+        assert_eq!(guard.state, ActorState::Walking);
+    }
+}
@@ -0,0 +1,104 @@
+use crate::game::math;
+use crate::game::types::*;
+use glam::Vec2;
+
+/// Distance at which an NPC counts as having reached a point
+pub const ARRIVAL_RADIUS: f32 = 0.5;
+
+/// How fast guards turn to follow their heading (radians per second)
+pub const GUARD_TURN_RATE: f32 = std::f32::consts::PI * 1.5;
+
+/// Wrap an angle into the range (-PI, PI]
+pub fn wrap_angle(angle: f32) -> f32 {
+    let two_pi = std::f32::consts::PI * 2.0;
+    let wrapped = (angle + std::f32::consts::PI).rem_euclid(two_pi) - std::f32::consts::PI;
+    if wrapped <= -std::f32::consts::PI {
+        wrapped + two_pi
+    } else {
+        wrapped
+    }
+}
+
+/// Turn from `current` towards `target` by at most `max_delta` radians
+pub fn rotate_towards(current: f32, target: f32, max_delta: f32) -> f32 {
+    let diff = wrap_angle(target - current);
+    if diff.abs() <= max_delta {
+        target
+    } else {
+        wrap_angle(current + max_delta * diff.signum())
+    }
+}
+
+/// Set velocity and rotation to walk towards a point
+/// Returns true once the entity has arrived (velocity is then zero)
+pub fn steer_towards(entity: &mut Entity, target: &Position, speed: f32, delta_time: f32) -> bool {
+    let to_target = target.to_vec2() - entity.position.to_vec2();
+    let distance = to_target.length();
+
+    if distance <= ARRIVAL_RADIUS {
+        entity.velocity = Vec2::ZERO;
+        return true;
+    }
+
+    // Don't overshoot the target this tick
+    let direction = to_target / distance;
+    let max_speed = if delta_time > 0.0 {
+        distance / delta_time
+    } else {
+        speed
+    };
+    entity.velocity = direction * speed.min(max_speed);
+
+    // Rotation follows the heading
+    let heading = math::atan2(direction.y, direction.x);
+    entity.rotation = rotate_towards(entity.rotation, heading, GUARD_TURN_RATE * delta_time);
+    false
+}
+
+/// Walk along a list of waypoints, dropping each one as it is reached
+/// Returns true once the final waypoint has been reached
+pub fn steer_along_path(entity: &mut Entity, path: &mut Vec<Position>, speed: f32, delta_time: f32) -> bool {
+    while let Some(next) = path.first().copied() {
+        if !steer_towards(entity, &next, speed, delta_time) {
+            return false;
+        }
+        path.remove(0);
+    }
+    true
+}
+
+/// Turn to face a point without moving
+pub fn face_towards(entity: &mut Entity, target: &Position, delta_time: f32) {
+    let to_target = target.to_vec2() - entity.position.to_vec2();
+    if to_target.length() < f32::EPSILON {
+        return;
+    }
+    let heading = math::atan2(to_target.y, to_target.x);
+    entity.rotation = rotate_towards(entity.rotation, heading, GUARD_TURN_RATE * delta_time);
+}
+
+/// Sweep left and right around a base heading
+/// `progress` runs from 0.0 to 1.0 over one full sweep
+pub fn look_around(entity: &mut Entity, base_heading: f32, half_angle: f32, progress: f32, delta_time: f32) {
+    let sweep = math::sin(progress * std::f32::consts::PI * 2.0) * half_angle;
+    entity.rotation = rotate_towards(entity.rotation, base_heading + sweep, GUARD_TURN_RATE * delta_time);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rotate_towards_takes_short_way() {
+        let rotated = rotate_towards(3.0, -3.0, 0.1);
+        assert!(rotated.abs() > 3.0);
+    }
+
+    #[test]
+    fn test_steer_does_not_overshoot() {
+        let mut entity = Entity::new_guard(1, Position::new(0.0, 0.0));
+        let arrived = steer_towards(&mut entity, &Position::new(1.0, 0.0), 4.0, 1.0);
+        assert!(!arrived);
+        assert!((entity.velocity.x - 1.0).abs() < 1e-5);
+    }
+}
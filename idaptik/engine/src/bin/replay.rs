@@ -0,0 +1,62 @@
+//! Load a recorded replay, re-run it and report what happened
+//!
+//! Usage: replay <file.json> [--verify] [--events] [--seek <tick>]
+
+use idaptik_engine::replay::{Replay, ReplayPlayer};
+use std::process::ExitCode;
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let Some(path) = args.first() else {
+        eprintln!("usage: replay <file.json> [--verify] [--events] [--seek <tick>]");
+        return ExitCode::FAILURE;
+    };
+    let verify = args.iter().any(|a| a == "--verify");
+    let print_events = args.iter().any(|a| a == "--events");
+    let seek = args.iter()
+        .position(|a| a == "--seek")
+        .and_then(|i| args.get(i + 1))
+        .and_then(|tick| tick.parse::<usize>().ok());
+
+    let replay = match std::fs::read_to_string(path).map_err(|e| e.to_string())
+        .and_then(|json| Replay::from_json(&json).map_err(|e| e.to_string()))
+    {
+        Ok(replay) => replay,
+        Err(err) => {
+            eprintln!("{}: {}", path, err);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let mut player = ReplayPlayer::new(replay);
+    println!("{}: {} ticks", path, player.len());
+
+    if verify {
+        match player.verify() {
+            Ok(()) => println!("verified: every tick matches"),
+            Err(divergence) => {
+                println!(
+                    "diverged at tick {} (expected checksum {:016x}, got {:016x})",
+                    divergence.tick, divergence.expected, divergence.actual
+                );
+                return ExitCode::FAILURE;
+            }
+        }
+        player.seek(0);
+    }
+
+    if let Some(tick) = seek {
+        player.seek(tick);
+        println!("state after tick {}:", player.position());
+        println!("{}", player.system().serialize_state().unwrap_or_default());
+        return ExitCode::SUCCESS;
+    }
+
+    if print_events {
+        for (tick, event) in player.run_to_end() {
+            println!("{:>6} {:?}", tick, event);
+        }
+    }
+
+    ExitCode::SUCCESS
+}
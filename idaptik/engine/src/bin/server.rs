@@ -0,0 +1,154 @@
+//! Run one authoritative room headless, speaking the Port protocol on stdin/stdout
+//!
+//! Usage: server <level.json> [--tick-rate <hz>] [--seed <n>] [--ticks <n>] [--protocol json|flatbuffers]
+//!
+//! Client messages arrive on stdin and server messages go out on stdout, framed as the
+//! core frames them: one JSON object per line by default, or with `--protocol flatbuffers`
+//! a 4-byte big-endian length prefix before each buffer (schemas/port_protocol.fbs).
+//! Each server message names its recipient in `to` (absent for broadcasts).
+//! Logs go to stderr. The server stops when stdin closes or after `--ticks`.
+
+use idaptik_engine::net::{self, ClientMessage, Protocol, Room, ServerMessage};
+use idaptik_engine::GameSystem;
+use std::io::Write;
+use std::process::ExitCode;
+use std::sync::mpsc::{self, TryRecvError};
+use std::time::{Duration, Instant};
+
+const DEFAULT_TICK_RATE: f32 = 20.0;
+
+fn flag<T: std::str::FromStr>(args: &[String], name: &str) -> Option<T> {
+    args.iter()
+        .position(|a| a == name)
+        .and_then(|i| args.get(i + 1))
+        .and_then(|value| value.parse().ok())
+}
+
+fn send(out: &mut impl Write, protocol: Protocol, messages: &[ServerMessage]) -> std::io::Result<()> {
+    for message in messages {
+        match net::encode_server_message(message, protocol) {
+            Ok(frame) => net::write_frame(out, protocol, &frame)?,
+            Err(e) => eprintln!("server: couldn't encode {}: {}", message.msg_type, e),
+        }
+    }
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let Some(path) = args.first() else {
+        eprintln!("usage: server <level.json> [--tick-rate <hz>] [--seed <n>] [--ticks <n>] [--protocol json|flatbuffers]");
+        return ExitCode::FAILURE;
+    };
+    let tick_rate = flag(&args, "--tick-rate").unwrap_or(DEFAULT_TICK_RATE);
+    let seed = flag(&args, "--seed").unwrap_or(0);
+    let max_ticks: Option<u64> = flag(&args, "--ticks");
+    let protocol = match flag::<String>(&args, "--protocol") {
+        None => Protocol::Json,
+        Some(name) => match Protocol::from_name(&name) {
+            Some(protocol) => protocol,
+            None => {
+                eprintln!("unknown protocol {:?}, expected json or flatbuffers", name);
+                return ExitCode::FAILURE;
+            }
+        },
+    };
+
+    // A level is a serialized World, as written by GameSystem::serialize_state
+    let mut system = GameSystem::with_seed(0.0, 0.0, seed);
+    if let Err(err) = std::fs::read_to_string(path).map_err(|e| e.to_string())
+        .and_then(|json| system.deserialize_state(&json).map_err(|e| e.to_string()))
+    {
+        eprintln!("{}: {}", path, err);
+        return ExitCode::FAILURE;
+    }
+
+    let mut room = Room::with_protocol(system, tick_rate, protocol);
+    eprintln!(
+        "server: {} loaded, {} entities, {} Hz, {}",
+        path, room.system.world.entities.len(), tick_rate, protocol.name()
+    );
+
+    // Stdin is read on its own thread so a quiet Port never stalls the tick loop
+    let (incoming, inbox) = mpsc::channel::<ClientMessage>();
+    std::thread::spawn(move || {
+        let mut stdin = std::io::stdin().lock();
+        loop {
+            let frame = match net::read_frame(&mut stdin, protocol) {
+                Ok(Some(frame)) => frame,
+                Ok(None) => break,
+                Err(e) => {
+                    eprintln!("server: stdin: {}", e);
+                    break;
+                }
+            };
+            if protocol == Protocol::Json && frame.trim_ascii().is_empty() {
+                continue;
+            }
+            match net::decode_client_message(&frame, protocol) {
+                Ok(message) => {
+                    if incoming.send(message).is_err() {
+                        break;
+                    }
+                }
+                Err(e) => eprintln!("server: unreadable message: {}", e),
+            }
+        }
+    });
+
+    let mut stdout = std::io::stdout().lock();
+    let timestep = Duration::from_secs_f32(room.timestep());
+    let mut next_tick = Instant::now();
+    let mut ticks = 0u64;
+    let mut busy = Duration::ZERO;
+    let mut worst = Duration::ZERO;
+
+    'run: loop {
+        let started = Instant::now();
+        let mut outgoing = Vec::new();
+
+        loop {
+            match inbox.try_recv() {
+                Ok(message) => match room.handle(message) {
+                    Ok(replies) => outgoing.extend(replies),
+                    Err(e) => eprintln!("server: {}", e),
+                },
+                Err(TryRecvError::Empty) => break,
+                Err(TryRecvError::Disconnected) => break 'run,
+            }
+        }
+
+        outgoing.extend(room.tick());
+        if let Err(e) = send(&mut stdout, protocol, &outgoing) {
+            eprintln!("server: stdout closed: {}", e);
+            break;
+        }
+
+        let spent = started.elapsed();
+        busy += spent;
+        worst = worst.max(spent);
+        ticks += 1;
+        if max_ticks.is_some_and(|max| ticks >= max) {
+            break;
+        }
+
+        next_tick += timestep;
+        let now = Instant::now();
+        if next_tick > now {
+            std::thread::sleep(next_tick - now);
+        } else {
+            next_tick = now; // Running behind: don't try to catch up in a burst
+        }
+    }
+
+    if ticks > 0 {
+        eprintln!(
+            "server: {} ticks, {} players, {:.0} us mean, {:.0} us worst",
+            ticks,
+            room.player_count(),
+            busy.as_secs_f64() * 1e6 / ticks as f64,
+            worst.as_secs_f64() * 1e6,
+        );
+    }
+    ExitCode::SUCCESS
+}
@@ -0,0 +1,26 @@
+//! Transcendental functions for simulation code
+//!
+//! `f32::sin` and friends call into the platform's libm, which differs between
+//! native targets and wasm32. These go through the `libm` crate instead so the
+//! simulation produces bit-identical results everywhere. Basic arithmetic and
+//! `sqrt` are exactly rounded by IEEE 754 and need no wrapper.
+
+#[inline]
+pub fn sin(x: f32) -> f32 {
+    libm::sinf(x)
+}
+
+#[inline]
+pub fn cos(x: f32) -> f32 {
+    libm::cosf(x)
+}
+
+#[inline]
+pub fn acos(x: f32) -> f32 {
+    libm::acosf(x)
+}
+
+#[inline]
+pub fn atan2(y: f32, x: f32) -> f32 {
+    libm::atan2f(y, x)
+}
@@ -1,5 +1,8 @@
+pub mod math;
+pub mod rng;
 pub mod types;
 pub mod system;
 
+pub use rng::*;
 pub use types::*;
 pub use system::*;
@@ -1,13 +1,55 @@
+use crate::ai::guard::*;
+use crate::ai::patrol::*;
+use crate::game::math;
+use crate::game::rng::GameRng;
 use crate::game::types::*;
+use crate::hacking::{
+    Ice, MarkerError, MarkerKind, Puzzle, PuzzleRun, TraceMeter, TraceStage, ICE_HOP_INTERVAL, MAX_ROUTE_POINTS,
+    TRACE_MAX,
+};
+use crate::mission::*;
+use crate::navigation::{find_path, NavCache};
+use crate::net::{hacker_always_knows, update_hacker_intel, update_role_visibility, WorldSnapshot};
+use crate::replay::{Replay, ReplayRecorder};
+use crate::security::alarm::*;
+use crate::security::camera::*;
+use crate::security::door::*;
+use crate::security::network::*;
 use crate::stealth::detection::*;
+use crate::stealth::suspicion::*;
 use glam::Vec2;
 use serde::{Deserialize, Serialize};
 
+/// Default fixed simulation step (60 ticks per second)
+pub const DEFAULT_TIMESTEP: f32 = 1.0 / 60.0;
+
+/// Most fixed ticks run for one frame; a long stall drops time instead of spiralling
+pub const MAX_TICKS_PER_UPDATE: u32 = 8;
+
 /// Game system that updates all game logic
 pub struct GameSystem {
     pub world: World,
     next_entity_id: EntityId,
     pub time: f32,
+    pub tick: u64,
+    fixed_timestep: Option<f32>,
+    accumulator: f32,
+    rng: GameRng,
+    navigation: NavCache,
+    noise_sources: Vec<NoiseSource>,
+    pending_events: Vec<GameEvent>,
+    recorder: Option<ReplayRecorder>,
+}
+
+/// Full simulation state, enough to resume a game bit-for-bit
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SimulationSnapshot {
+    pub world: World,
+    pub next_entity_id: EntityId,
+    pub time: f32,
+    pub tick: u64,
+    pub rng: GameRng,
+    pub pending_events: Vec<GameEvent>,
 }
 
 /// Player input commands
@@ -38,15 +80,51 @@ pub enum GameEvent {
     PlayerDetected { player_id: EntityId, guard_id: EntityId, level: f32 },
     PlayerHidden { player_id: EntityId },
     GuardAlerted { guard_id: EntityId, position: Position },
-    ObjectiveComplete { player_id: EntityId },
+    GuardInvestigating { guard_id: EntityId, position: Position },
+    GuardHunting { guard_id: EntityId, target_id: EntityId },
+    GuardLostTarget { guard_id: EntityId, last_known_position: Position },
+    GuardReturnedToPatrol { guard_id: EntityId },
+    NoiseHeard { guard_id: EntityId, position: Position, loudness: f32 },
+    CameraAlarm { camera_id: EntityId, player_id: EntityId, position: Position },
+    AlarmTriggered { trigger: AlarmTrigger, position: Position, level: AlarmLevel },
+    AlarmLevelChanged { previous: AlarmLevel, level: AlarmLevel },
+    DoorInteraction { actor_id: EntityId, door_id: EntityId, result: InteractionResult },
+    DoorStateChanged { door_id: EntityId, state: DoorState },
+    ObjectiveActivated { objective_id: u32 },
+    ObjectiveComplete { objective_id: u32, player_id: Option<EntityId> },
+    MissionComplete { bonus_completed: u32 },
+    MissionFailed { reason: FailureReason },
+    DeviceCompromised { device_id: EntityId, kind: DeviceKind },
+    FeedLooped { camera_id: EntityId, looped: bool },
+    LightsCut { light_id: EntityId, cut: bool },
+    MarkerPlaced { marker_id: u32, kind: MarkerKind },
+    MarkerRemoved { marker_id: u32 },
+    TraceRaised { hacker_id: EntityId, level: f32 },
+    TraceStageChanged { hacker_id: EntityId, previous: TraceStage, stage: TraceStage },
+    DevicesRelocked { device_ids: Vec<EntityId> },
+    IceMoved { hacker_id: EntityId, device_id: EntityId },
+    HackerTraced { hacker_id: EntityId },
 }
 
 impl GameSystem {
     pub fn new(width: f32, height: f32) -> Self {
+        Self::with_seed(width, height, 0)
+    }
+
+    /// Create a game whose AI randomness is drawn from `seed`
+    pub fn with_seed(width: f32, height: f32, seed: u64) -> Self {
         Self {
             world: World::new(width, height),
             next_entity_id: 1,
             time: 0.0,
+            tick: 0,
+            fixed_timestep: None,
+            accumulator: 0.0,
+            rng: GameRng::new(seed),
+            navigation: NavCache::new(),
+            noise_sources: Vec::new(),
+            pending_events: Vec::new(),
+            recorder: None,
         }
     }
 
@@ -68,6 +146,7 @@ pub fn spawn_player(&mut self, x: f32, y: f32) -> EntityId {
     pub fn spawn_guard(&mut self, x: f32, y: f32) -> EntityId {
         let id = self.next_id();
         let entity = Entity::new_guard(id, Position::new(x, y));
+        self.world.guard_minds.push(GuardMind::new(id));
         self.world.add_entity(entity)
     }
 
@@ -88,13 +167,518 @@ pub fn add_light(&mut self, x: f32, y: f32, radius: f32, intensity: f32) {
             position: Position::new(x, y),
             radius,
             intensity,
+            light_id: None,
+        });
+    }
+
+    /// Spawn a light on the device network, so the hacker can cut it
+    pub fn spawn_light(&mut self, x: f32, y: f32, radius: f32, intensity: f32) -> EntityId {
+        let id = self.next_id();
+        self.world.lights.push(LightSource {
+            position: Position::new(x, y),
+            radius,
+            intensity,
+            light_id: Some(id),
         });
+        self.world.network.add_node(id, DeviceKind::Light);
+        self.world.add_entity(Entity::new_static(id, EntityType::LightSource, Position::new(x, y)))
+    }
+
+    /// Spawn a security camera that sweeps across an arc centred on `heading`
+    pub fn spawn_camera(
+        &mut self,
+        x: f32,
+        y: f32,
+        heading: f32,
+        sweep_arc: f32,
+        sweep_speed: f32,
+        pause_time: f32,
+    ) -> EntityId {
+        let id = self.next_id();
+        let entity = Entity::new_camera(id, Position::new(x, y), heading);
+        self.world.cameras.push(CameraRig::new(id, heading, sweep_arc, sweep_speed, pause_time));
+        self.world.network.add_node(id, DeviceKind::Camera);
+        self.world.add_entity(entity)
+    }
+
+    /// Spawn a door; shut doors block movement, vision and sound
+    pub fn spawn_door(
+        &mut self,
+        x: f32,
+        y: f32,
+        radius: f32,
+        state: DoorState,
+        requirement: UnlockRequirement,
+    ) -> EntityId {
+        let id = self.next_id();
+        let entity = Entity::new_door(id, Position::new(x, y));
+        let mut door = Door::new(id, radius, state);
+        door.requirement = requirement;
+        self.world.doors.push(door);
+        self.world.network.add_node(id, DeviceKind::Door);
+        self.world.add_entity(entity)
+    }
+
+    /// Change what it takes to unlock a door
+    pub fn set_door_requirement(&mut self, door_id: EntityId, requirement: UnlockRequirement) -> bool {
+        let Some(door) = self.world.get_door_mut(door_id) else {
+            return false;
+        };
+        door.requirement = requirement;
+        true
+    }
+
+    /// Hand an entity a keycard
+    pub fn give_keycard(&mut self, entity_id: EntityId, keycard_id: u32) -> bool {
+        let Some(entity) = self.world.get_entity_mut(entity_id) else {
+            return false;
+        };
+        if !entity.keycards.contains(&keycard_id) {
+            entity.keycards.push(keycard_id);
+        }
+        true
+    }
+
+    /// Use the nearest door; None if the actor isn't a player or the level has no doors
+    pub fn interact(&mut self, actor_id: EntityId) -> Option<GameEvent> {
+        let actor = self.world.get_entity(actor_id).filter(|a| a.entity_type == EntityType::Player)?;
+        let actor_position = actor.position;
+
+        let nearest = self.world.doors.iter()
+            .filter_map(|door| {
+                let entity = self.world.get_entity(door.door_id)?;
+                Some((door.door_id, entity.position.distance_2d(&actor_position)))
+            })
+            .min_by(|a, b| a.1.total_cmp(&b.1));
+
+        let (door_id, _) = nearest?;
+        self.interact_with(actor_id, door_id)
+    }
+
+    /// Use a specific door, if the actor is close enough to reach it
+    /// Only players have hands; None for anyone else
+    pub fn interact_with(&mut self, actor_id: EntityId, door_id: EntityId) -> Option<GameEvent> {
+        let actor = self.world.get_entity(actor_id).filter(|a| a.entity_type == EntityType::Player)?;
+        let actor_position = actor.position;
+        let keycards = actor.keycards.clone();
+
+        let door_position = self.world.get_entity(door_id).map(|e| e.position);
+        let (Some(door), Some(door_position)) = (self.world.get_door_mut(door_id), door_position) else {
+            return Some(GameEvent::DoorInteraction {
+                actor_id,
+                door_id,
+                result: InteractionResult::NothingToInteract,
+            });
+        };
+
+        let previous = door.state;
+        let result = if actor_position.distance_2d(&door_position) > door.radius + INTERACT_RANGE {
+            InteractionResult::OutOfRange
+        } else {
+            door.interact(&keycards)
+        };
+
+        if door.state != previous {
+            self.pending_events.push(GameEvent::DoorStateChanged { door_id, state: door.state });
+        }
+        Some(GameEvent::DoorInteraction { actor_id, door_id, result })
+    }
+
+    /// Force a door open from the hacker's side; lockdowns leave it open
+    fn hack_door(&mut self, door_id: EntityId) -> bool {
+        let Some(door) = self.world.get_door_mut(door_id) else {
+            return false;
+        };
+        door.state = DoorState::Hacked;
+        door.locked_by_alarm = false;
+        self.pending_events.push(GameEvent::DoorStateChanged { door_id, state: DoorState::Hacked });
+        true
+    }
+
+    /// Add an access link between two devices on the network
+    pub fn connect_devices(&mut self, a: EntityId, b: EntityId) -> bool {
+        self.world.network.link(a, b)
+    }
+
+    /// Carry out a hacker command against the device network
+    /// Every command that goes through raises the hacker's trace
+    pub fn hack(&mut self, hacker_id: EntityId, command: HackCommand) -> Result<(), HackError> {
+        let is_hacker = self.world.get_entity(hacker_id).is_some_and(|e| e.entity_type == EntityType::Hacker);
+        if !is_hacker {
+            return Err(HackError::NotAHacker(hacker_id));
+        }
+        let (cost, device_id) = (command.trace_cost(), command.device_id());
+        self.run_hack(command)?;
+        let events = self.raise_trace(hacker_id, cost, device_id);
+        self.pending_events.extend(events);
+        Ok(())
+    }
+
+    fn run_hack(&mut self, command: HackCommand) -> Result<(), HackError> {
+        let network = &mut self.world.network;
+        match command {
+            HackCommand::Compromise { device_id } => {
+                if network.compromise(device_id)? {
+                    self.device_compromised(device_id);
+                }
+            }
+            HackCommand::Execute { device_id, instruction } => {
+                // Solving the puzzle is what compromises the device
+                if network.execute(device_id, &instruction)? && network.compromise(device_id)? {
+                    self.device_compromised(device_id);
+                }
+            }
+            HackCommand::Undo { device_id } => network.undo(device_id)?,
+            HackCommand::LoopFeed { camera_id } | HackCommand::RestoreFeed { camera_id } => {
+                let looped = matches!(command, HackCommand::LoopFeed { .. });
+                let node = network.controlled(camera_id, DeviceKind::Camera)?;
+                if node.tampered != looped {
+                    node.tampered = looped;
+                    self.pending_events.push(GameEvent::FeedLooped { camera_id, looped });
+                }
+            }
+            HackCommand::UnlockDoor { door_id } => {
+                let node = network.controlled(door_id, DeviceKind::Door)?;
+                node.tampered = true;
+                self.hack_door(door_id);
+            }
+            HackCommand::CutLights { light_id } | HackCommand::RestoreLights { light_id } => {
+                let cut = matches!(command, HackCommand::CutLights { .. });
+                let node = network.controlled(light_id, DeviceKind::Light)?;
+                if node.tampered != cut {
+                    node.tampered = cut;
+                    self.pending_events.push(GameEvent::LightsCut { light_id, cut });
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Place a hacker marker for the infiltrator; tags are for guards and drones only
+    pub fn place_marker(&mut self, kind: MarkerKind) -> Result<u32, MarkerError> {
+        match &kind {
+            MarkerKind::Ping { .. } => {}
+            MarkerKind::Tag { entity_id } => {
+                let entity = self.world.get_entity(*entity_id).ok_or(MarkerError::UnknownEntity(*entity_id))?;
+                if !matches!(entity.entity_type, EntityType::Guard | EntityType::Drone) {
+                    return Err(MarkerError::NotTaggable(*entity_id));
+                }
+            }
+            MarkerKind::Route { points } if points.is_empty() => return Err(MarkerError::EmptyRoute),
+            MarkerKind::Route { points } if points.len() > MAX_ROUTE_POINTS => {
+                return Err(MarkerError::RouteTooLong(points.len()));
+            }
+            MarkerKind::Route { .. } => {}
+        }
+        let (marker_id, replaced) = self.world.markers.place(kind.clone(), self.time);
+        if let Some(marker_id) = replaced {
+            self.pending_events.push(GameEvent::MarkerRemoved { marker_id });
+        }
+        self.pending_events.push(GameEvent::MarkerPlaced { marker_id, kind });
+        Ok(marker_id)
+    }
+
+    /// Take a marker down before it runs out
+    pub fn clear_marker(&mut self, marker_id: u32) -> bool {
+        let removed = self.world.markers.remove(marker_id);
+        if removed {
+            self.pending_events.push(GameEvent::MarkerRemoved { marker_id });
+        }
+        removed
+    }
+
+    /// Lock a device behind a puzzle the hacker has to solve to compromise it
+    pub fn set_device_puzzle(&mut self, device_id: EntityId, puzzle: Puzzle) -> bool {
+        let Some(node) = self.world.network.node_mut(device_id) else {
+            return false;
+        };
+        node.lock = Some(PuzzleRun::new(puzzle));
+        true
+    }
+
+    fn device_compromised(&mut self, device_id: EntityId) {
+        let Some(kind) = self.world.network.node(device_id).map(|n| n.kind) else {
+            return;
+        };
+        self.pending_events.push(GameEvent::DeviceCompromised { device_id, kind });
+        if kind == DeviceKind::Terminal {
+            self.hack_terminal(device_id);
+        }
+    }
+
+    /// The hacker's trace meter, if they have hacked anything yet
+    pub fn trace(&self, hacker_id: EntityId) -> Option<&TraceMeter> {
+        self.world.traces.iter().find(|t| t.hacker_id == hacker_id)
+    }
+
+    fn raise_trace(&mut self, hacker_id: EntityId, amount: f32, device_id: EntityId) -> Vec<GameEvent> {
+        let traces = &mut self.world.traces;
+        let index = match traces.iter().position(|t| t.hacker_id == hacker_id) {
+            Some(index) => index,
+            None => {
+                traces.push(TraceMeter::new(hacker_id));
+                traces.len() - 1
+            }
+        };
+        let meter = &mut traces[index];
+        let previous = meter.stage;
+        let crossed = meter.raise(amount, device_id);
+        let (level, stage) = (meter.level, meter.stage);
+
+        let mut events = vec![GameEvent::TraceRaised { hacker_id, level }];
+        if stage != previous {
+            events.push(GameEvent::TraceStageChanged { hacker_id, previous, stage });
+        }
+        for stage in crossed {
+            events.extend(self.counter_intrusion(index, stage));
+        }
+        events
+    }
+
+    /// Security's answer to a trace stage being reached
+    fn counter_intrusion(&mut self, index: usize, stage: TraceStage) -> Vec<GameEvent> {
+        let meter = &self.world.traces[index];
+        let (hacker_id, connection) = (meter.hacker_id, meter.connection);
+        let mut events = Vec::new();
+        match stage {
+            TraceStage::Clear => {}
+            TraceStage::Relock => {
+                // Tampering stops with the access that allowed it
+                let mut hacked_doors = Vec::new();
+                for node in self.world.network.nodes.iter().filter(|n| n.compromised && !n.entry_point && n.tampered) {
+                    match node.kind {
+                        DeviceKind::Camera => events.push(GameEvent::FeedLooped { camera_id: node.device_id, looped: false }),
+                        DeviceKind::Light => events.push(GameEvent::LightsCut { light_id: node.device_id, cut: false }),
+                        DeviceKind::Door => hacked_doors.push(node.device_id),
+                        _ => {}
+                    }
+                }
+                for door_id in hacked_doors {
+                    if let Some(door) = self.world.get_door_mut(door_id).filter(|d| d.state == DoorState::Hacked) {
+                        door.state = DoorState::Locked;
+                        events.push(GameEvent::DoorStateChanged { door_id, state: DoorState::Locked });
+                    }
+                }
+                let device_ids = self.world.network.relock();
+                if !device_ids.is_empty() {
+                    events.push(GameEvent::DevicesRelocked { device_ids });
+                }
+            }
+            TraceStage::Hunted => {
+                // The ICE comes in at an entry point away from the hacker, or anywhere it can
+                let network = &self.world.network;
+                let start = network.nodes.iter()
+                    .filter(|n| Some(n.device_id) != connection)
+                    .min_by_key(|n| !n.entry_point)
+                    .or_else(|| network.nodes.first())
+                    .map(|n| n.device_id);
+                if let Some(device_id) = start {
+                    self.world.traces[index].ice = Some(Ice { device_id, hop_timer: ICE_HOP_INTERVAL });
+                    events.push(GameEvent::IceMoved { hacker_id, device_id });
+                }
+            }
+            TraceStage::Traced => {
+                self.world.traces[index].ice = None;
+                // The alarm goes up where the hacker was plugged in
+                let device_id = connection.unwrap_or(hacker_id);
+                let position = self.world.get_entity(device_id)
+                    .map(|e| e.position)
+                    .unwrap_or(Position::new(0.0, 0.0));
+                events.push(GameEvent::HackerTraced { hacker_id });
+                events.extend(self.raise_alarm(AlarmTrigger::Intrusion { device_id }, position, None));
+            }
+        }
+        events
+    }
+
+    /// Wind trace meters down and move any ICE a link closer to its hacker
+    fn update_traces(&mut self, delta_time: f32) -> Vec<GameEvent> {
+        let mut events = Vec::new();
+        for index in 0..self.world.traces.len() {
+            let meter = &mut self.world.traces[index];
+            let (hacker_id, previous) = (meter.hacker_id, meter.stage);
+            meter.decay(delta_time);
+            if meter.stage != previous {
+                events.push(GameEvent::TraceStageChanged { hacker_id, previous, stage: meter.stage });
+            }
+
+            let (Some(ice), Some(connection)) = (&mut meter.ice, meter.connection) else {
+                continue;
+            };
+            ice.hop_timer -= delta_time;
+            if ice.hop_timer > 0.0 {
+                continue;
+            }
+            ice.hop_timer = ICE_HOP_INTERVAL;
+            let Some(device_id) = self.world.network.next_hop(ice.device_id, connection) else {
+                meter.ice = None; // No way through to the hacker, so the ICE loses the trail
+                continue;
+            };
+            ice.device_id = device_id;
+            events.push(GameEvent::IceMoved { hacker_id, device_id });
+
+            if device_id == connection {
+                // Caught: the meter fills and the alarm goes up
+                let previous = meter.stage;
+                let crossed = meter.set_level(TRACE_MAX);
+                if meter.stage != previous {
+                    events.push(GameEvent::TraceStageChanged { hacker_id, previous, stage: meter.stage });
+                }
+                for stage in crossed {
+                    events.extend(self.counter_intrusion(index, stage));
+                }
+            }
+        }
+        events
+    }
+
+    /// Spawn an item a player can pick up
+    pub fn spawn_item(&mut self, x: f32, y: f32) -> EntityId {
+        let id = self.next_id();
+        self.world.add_entity(Entity::new_static(id, EntityType::Item, Position::new(x, y)))
+    }
+
+    /// Spawn the hacker's presence in the world; it has no body, only a seat
+    pub fn spawn_hacker(&mut self, x: f32, y: f32) -> EntityId {
+        let id = self.next_id();
+        self.world.add_entity(Entity::new_static(id, EntityType::Hacker, Position::new(x, y)))
+    }
+
+    /// Spawn a terminal for the hacker to break into
+    pub fn spawn_terminal(&mut self, x: f32, y: f32) -> EntityId {
+        let id = self.next_id();
+        self.world.network.add_node(id, DeviceKind::Terminal);
+        self.world.add_entity(Entity::new_static(id, EntityType::Terminal, Position::new(x, y)))
+    }
+
+    /// Start a new mission, replacing any current one
+    pub fn start_mission(&mut self, order: MissionOrder, time_limit: Option<f32>, fail_on_caught: bool) {
+        self.world.mission = Some(Mission::new(order, time_limit, fail_on_caught));
+    }
+
+    /// Add an objective to the current mission and return its ID
+    pub fn add_objective(&mut self, kind: ObjectiveKind, optional: bool) -> Option<u32> {
+        let mission = self.world.mission.as_mut()?;
+        Some(mission.add_objective(kind, optional))
+    }
+
+    /// Record that the hacker broke into a terminal; objectives pick it up next update
+    fn hack_terminal(&mut self, terminal_id: EntityId) -> bool {
+        let is_terminal = self.world.get_entity(terminal_id)
+            .is_some_and(|e| e.entity_type == EntityType::Terminal);
+        let Some(mission) = self.world.mission.as_mut().filter(|_| is_terminal) else {
+            return false;
+        };
+        if !mission.hacked_terminals.contains(&terminal_id) {
+            mission.hacked_terminals.push(terminal_id);
+        }
+        true
+    }
+
+    /// Assign a patrol route to a guard, replacing any existing one
+    pub fn set_patrol_route(&mut self, guard_id: EntityId, mode: PatrolMode) -> bool {
+        let is_guard = self.world.get_entity(guard_id)
+            .is_some_and(|e| e.entity_type == EntityType::Guard);
+        if !is_guard {
+            return false;
+        }
+
+        self.world.patrol_routes.retain(|r| r.guard_id != guard_id);
+        self.world.patrol_routes.push(PatrolRoute::new(guard_id, mode));
+        true
+    }
+
+    /// Append a waypoint to a guard's patrol route
+    pub fn add_patrol_waypoint(
+        &mut self,
+        guard_id: EntityId,
+        x: f32,
+        y: f32,
+        dwell_time: f32,
+        look_around: f32,
+    ) -> bool {
+        let Some(route) = self.world.get_patrol_route_mut(guard_id) else {
+            return false;
+        };
+        route.add_waypoint(Position::new(x, y), dwell_time, look_around);
+        true
+    }
+
+    /// Remove a guard's patrol route so it stands still again
+    pub fn clear_patrol_route(&mut self, guard_id: EntityId) -> bool {
+        let before = self.world.patrol_routes.len();
+        self.world.patrol_routes.retain(|r| r.guard_id != guard_id);
+        self.world.patrol_routes.len() != before
+    }
+
+    /// Switch to fixed-timestep mode (`Some(step)`) or back to variable steps (`None`)
+    pub fn set_fixed_timestep(&mut self, timestep: Option<f32>) {
+        self.fixed_timestep = timestep.filter(|step| *step > 0.0);
+        self.accumulator = 0.0;
+    }
+
+    pub fn fixed_timestep(&self) -> Option<f32> {
+        self.fixed_timestep
+    }
+
+    /// How far between the last fixed tick and the next one we are (0.0 to 1.0), for rendering
+    pub fn interpolation_alpha(&self) -> f32 {
+        self.fixed_timestep.map_or(1.0, |step| self.accumulator / step)
+    }
+
+    /// Re-seed the simulation RNG
+    pub fn seed_rng(&mut self, seed: u64) {
+        self.rng = GameRng::new(seed);
+    }
+
+    /// Simulation RNG; any randomness in game logic must be drawn from here
+    pub fn rng(&mut self) -> &mut GameRng {
+        &mut self.rng
     }
 
     /// Main game update loop
+    /// In fixed-timestep mode the frame delta is banked and whole ticks are run from it;
+    /// otherwise the delta is simulated directly as a single tick
     pub fn update(&mut self, delta_time: f32) -> Vec<GameEvent> {
-        self.time += delta_time;
+        let Some(step) = self.fixed_timestep else {
+            return self.step(delta_time);
+        };
+
         let mut events = Vec::new();
+        for _ in 0..self.due_ticks(delta_time) {
+            events.extend(self.step(step));
+        }
+        events
+    }
+
+    /// Bank `delta_time` and take out the whole fixed ticks now due, without running them
+    /// For callers that feed each tick its own input; always 1 without a fixed timestep
+    pub fn due_ticks(&mut self, delta_time: f32) -> u32 {
+        let Some(step) = self.fixed_timestep else {
+            return 1;
+        };
+
+        self.accumulator += delta_time;
+        let mut ticks = 0;
+        while self.accumulator >= step && ticks < MAX_TICKS_PER_UPDATE {
+            self.accumulator -= step;
+            ticks += 1;
+        }
+
+        // Too far behind: drop the backlog rather than falling further behind
+        if ticks == MAX_TICKS_PER_UPDATE {
+            self.accumulator = self.accumulator.min(step);
+        }
+
+        ticks
+    }
+
+    /// Advance the simulation by exactly one tick of `delta_time`
+    /// Lockstep peers and servers call this directly with the fixed step
+    pub fn step(&mut self, delta_time: f32) -> Vec<GameEvent> {
+        self.tick += 1;
+        self.time += delta_time;
+        let mut events = std::mem::take(&mut self.pending_events);
 
         // Update light exposure for all entities
         self.update_lighting();
@@ -102,17 +686,90 @@ pub fn update(&mut self, delta_time: f32) -> Vec<GameEvent> {
         // Update noise levels
         self.update_noise();
 
+        // Move guards along their patrol routes
+        self.update_patrols(delta_time);
+
+        // Sweep security cameras
+        self.update_cameras(delta_time);
+
         // Update movement physics
         self.update_physics(delta_time);
 
         // Update detection and AI
         events.extend(self.update_detection_and_ai(delta_time));
 
+        // Escalate or wind down the level-wide alarm
+        let alarm_events = self.update_alarm(&events, delta_time);
+        events.extend(alarm_events);
+
+        // Progress objectives and check for mission success or failure
+        events.extend(self.update_mission(delta_time));
+
+        // Let hacker traces cool off while ICE closes in
+        events.extend(self.update_traces(delta_time));
+
+        // Take down markers that have run out
+        let world = &mut self.world;
+        let expired = world.markers.expire(self.time, |id| world.entities.iter().any(|e| e.id == id));
+        events.extend(expired.into_iter().map(|marker_id| GameEvent::MarkerRemoved { marker_id }));
+
+        // Work out who may know about what after everything has moved
+        update_role_visibility(&mut self.world);
+        update_hacker_intel(&mut self.world, self.time, |e| !hacker_always_knows(e.entity_type));
+
+        if let Some(recorder) = &mut self.recorder {
+            recorder.record_tick(delta_time, &self.world);
+        }
+
         events
     }
 
+    /// Capture everything needed to resume the simulation exactly
+    pub fn snapshot(&self) -> SimulationSnapshot {
+        SimulationSnapshot {
+            world: self.world.clone(),
+            next_entity_id: self.next_entity_id,
+            time: self.time,
+            tick: self.tick,
+            rng: self.rng.clone(),
+            pending_events: self.pending_events.clone(),
+        }
+    }
+
+    /// Resume from a snapshot; derived caches are rebuilt on the next tick
+    pub fn restore(&mut self, snapshot: SimulationSnapshot) {
+        self.world = snapshot.world;
+        self.next_entity_id = snapshot.next_entity_id;
+        self.time = snapshot.time;
+        self.tick = snapshot.tick;
+        self.rng = snapshot.rng;
+        self.pending_events = snapshot.pending_events;
+        self.accumulator = 0.0;
+        self.noise_sources.clear();
+        self.navigation.invalidate();
+    }
+
+    /// Start recording a replay from the current state
+    /// Only player inputs are captured, so set the level up before starting
+    pub fn start_recording(&mut self) {
+        self.recorder = Some(ReplayRecorder::new(self.snapshot()));
+    }
+
+    /// Stop recording and hand back the replay
+    pub fn stop_recording(&mut self) -> Option<Replay> {
+        self.recorder.take().map(ReplayRecorder::finish)
+    }
+
+    pub fn is_recording(&self) -> bool {
+        self.recorder.is_some()
+    }
+
     /// Apply player input to move a player entity
     pub fn apply_player_input(&mut self, player_id: EntityId, input: PlayerInput) {
+        if let Some(recorder) = &mut self.recorder {
+            recorder.record_input(player_id, input);
+        }
+
         let Some(player) = self.world.get_entity_mut(player_id) else {
             return;
         };
@@ -154,7 +811,7 @@ pub fn apply_player_input(&mut self, player_id: EntityId, input: PlayerInput) {
             player.velocity = move_vec * speed;
             
             // Update rotation to face movement direction
-            player.rotation = move_vec.y.atan2(move_vec.x);
+            player.rotation = math::atan2(move_vec.y, move_vec.x);
         } else {
             player.velocity = Vec2::ZERO;
         }
@@ -170,25 +827,83 @@ fn update_lighting(&mut self) {
     }
 
     fn update_noise(&mut self) {
-        // Calculate noise from all moving entities
-        let noise_sources: Vec<(Position, f32)> = self.world.entities.iter()
-            .map(|e| (e.position, calculate_movement_noise(e)))
+        // Calculate noise from all moving entities, remembering who made it
+        self.noise_sources = self.world.entities.iter()
+            .map(|e| NoiseSource {
+                emitter_id: e.id,
+                position: e.position,
+                level: calculate_movement_noise(e),
+            })
+            .collect();
+
+        let noise_sources: Vec<(Position, f32)> = self.noise_sources.iter()
+            .map(|s| (s.position, s.level))
             .collect();
 
         // Update noise awareness for all entities
+        self.navigation.refresh(&self.world);
         for i in 0..self.world.entities.len() {
             let entity_pos = self.world.entities[i].position;
             let noise_level = calculate_noise_at_position(
                 &entity_pos,
                 &noise_sources,
-                &self.world.obstacles
+                self.navigation.blockers()
             );
             self.world.entities[i].noise_level = noise_level;
         }
     }
 
+    /// Loudest sound a guard hears that wasn't made by another guard
+    fn noise_heard_by(&self, guard: &Entity, blockers: &[Obstacle]) -> Option<HeardNoise> {
+        let audible: Vec<NoiseSource> = self.noise_sources.iter()
+            .filter(|s| {
+                self.world.get_entity(s.emitter_id)
+                    .is_some_and(|e| e.entity_type != EntityType::Guard)
+            })
+            .copied()
+            .collect();
+
+        loudest_noise_at(guard, &audible, blockers).map(|(source, loudness)| HeardNoise {
+            source_id: source.emitter_id,
+            position: source.position,
+            loudness,
+        })
+    }
+
+    fn update_patrols(&mut self, delta_time: f32) {
+        for route in &mut self.world.patrol_routes {
+            let Some(guard) = self.world.entities.iter_mut().find(|e| e.id == route.guard_id) else {
+                continue;
+            };
+
+            // Only calm guards follow their route
+            if !is_patrolling(guard.state) {
+                continue;
+            }
+
+            step_patrol(guard, route, delta_time);
+        }
+    }
+
+    fn update_cameras(&mut self, delta_time: f32) {
+        for rig in &mut self.world.cameras {
+            let Some(camera) = self.world.entities.iter_mut().find(|e| e.id == rig.camera_id) else {
+                continue;
+            };
+            step_camera_sweep(camera, rig, delta_time);
+        }
+    }
+
     fn update_physics(&mut self, delta_time: f32) {
+        self.navigation.refresh(&self.world);
+        let blockers = self.navigation.blockers();
+
         for entity in &mut self.world.entities {
+            // Doors don't move (and would collide with themselves)
+            if entity.entity_type == EntityType::Door {
+                continue;
+            }
+
             // Update position based on velocity
             let delta_pos = entity.velocity * delta_time;
             entity.position.x += delta_pos.x;
@@ -198,8 +913,8 @@ fn update_physics(&mut self, delta_time: f32) {
             entity.position.x = entity.position.x.clamp(0.0, self.world.width);
             entity.position.y = entity.position.y.clamp(0.0, self.world.height);
 
-            // Simple collision with obstacles
-            for obstacle in &self.world.obstacles {
+            // Simple collision with obstacles and shut doors
+            for obstacle in blockers {
                 let dist = entity.position.distance_2d(&obstacle.position);
                 if dist < obstacle.radius + 0.5 {
                     // Push entity away from obstacle
@@ -226,48 +941,297 @@ fn update_detection_and_ai(&mut self, delta_time: f32) -> Vec<GameEvent> {
             .map(|e| e.id)
             .collect();
 
-        // Guards detect players
+        // Re-bake the navigation grid and blockers if the layout changed
+        self.navigation.refresh(&self.world);
+
+        // Guards loaded from older states may not have a mind yet
         for &guard_id in &guards {
-            for &player_id in &players {
-                let guard = self.world.get_entity(guard_id).unwrap().clone();
-                let player = self.world.get_entity(player_id).unwrap().clone();
+            if self.world.get_guard_mind(guard_id).is_none() {
+                self.world.guard_minds.push(GuardMind::new(guard_id));
+            }
+        }
 
-                let visibility = calculate_visibility(&guard, &player, &self.world);
+        // Guards detect players, each with its own suspicion meter
+        for &guard_id in &guards {
+            let mut sighting: Option<Sighting> = None;
+            let thresholds = self.world.get_guard_mind(guard_id).unwrap().thresholds;
+
+            for &player_id in &players {
+                let Some((visibility, player_position)) = self.sight(guard_id, player_id) else {
+                    continue;
+                };
+                let meter = self.world.suspicion_meter_mut(guard_id, player_id, visibility > HIDDEN_THRESHOLD);
+                let Some(meter) = meter else {
+                    continue;
+                };
 
-                // Update player detection level
-                let player_mut = self.world.get_entity_mut(player_id).unwrap();
-                let old_detection = player_mut.detection_level;
-                update_detection(player_mut, visibility, delta_time);
-                let new_detection = player_mut.detection_level;
+                let old_detection = meter.level;
+                update_suspicion(meter, &thresholds, visibility, player_position, self.time, delta_time);
+                let new_detection = meter.level;
 
                 // Generate detection events
-                if old_detection < 0.9 && new_detection >= 0.9 {
+                if old_detection < thresholds.detected && new_detection >= thresholds.detected {
                     events.push(GameEvent::PlayerDetected {
                         player_id,
                         guard_id,
                         level: new_detection,
                     });
+                }
 
-                    // Alert the guard
-                    let guard_mut = self.world.get_entity_mut(guard_id).unwrap();
-                    guard_mut.state = ActorState::Alerted;
-                } else if old_detection > 0.1 && new_detection <= 0.1 {
-                    events.push(GameEvent::PlayerHidden { player_id });
+                // Track the player this guard suspects most among those it can see
+                if visibility > HIDDEN_THRESHOLD && sighting.is_none_or(|s| new_detection > s.detection) {
+                    sighting = Some(Sighting {
+                        target_id: player_id,
+                        position: player_position,
+                        detection: new_detection,
+                    });
                 }
             }
+
+            let heard = self.world.get_entity(guard_id).and_then(|g| self.noise_heard_by(g, self.navigation.blockers()));
+            events.extend(self.update_guard_ai(guard_id, sighting.as_ref(), heard.as_ref(), delta_time));
+        }
+
+        // Cameras watch for players and call nearby guards over
+        events.extend(self.update_camera_detection(&players, delta_time));
+
+        // Forget meters that have fully decayed
+        self.world.suspicion_meters.retain(|m| m.level > 0.0);
+
+        // Players carry the aggregate level for the HUD
+        for &player_id in &players {
+            let aggregate = self.world.aggregate_detection(player_id);
+            let player = self.world.get_entity_mut(player_id).unwrap();
+            let old_detection = player.detection_level;
+            player.detection_level = aggregate;
+
+            if old_detection > HIDDEN_THRESHOLD && aggregate <= HIDDEN_THRESHOLD {
+                events.push(GameEvent::PlayerHidden { player_id });
+            }
+        }
+
+        events
+    }
+
+    /// How well an observer sees a target, and where the target is
+    fn sight(&self, observer_id: EntityId, target_id: EntityId) -> Option<(f32, Position)> {
+        let observer = self.world.get_entity(observer_id)?;
+        let target = self.world.get_entity(target_id)?;
+        Some((calculate_visibility(observer, target, &self.world), target.position))
+    }
+
+    fn update_camera_detection(&mut self, players: &[EntityId], delta_time: f32) -> Vec<GameEvent> {
+        let mut events = Vec::new();
+        let cameras: Vec<(EntityId, DetectionThresholds)> = self.world.cameras.iter()
+            .map(|c| (c.camera_id, c.thresholds))
+            .collect();
+
+        for (camera_id, thresholds) in cameras {
+            for &player_id in players {
+                let Some((visibility, player_position)) = self.sight(camera_id, player_id) else {
+                    continue;
+                };
+                let meter = self.world.suspicion_meter_mut(camera_id, player_id, visibility > HIDDEN_THRESHOLD);
+                let Some(meter) = meter else {
+                    continue;
+                };
+
+                let old_detection = meter.level;
+                update_suspicion(meter, &thresholds, visibility, player_position, self.time, delta_time);
+                if old_detection < thresholds.detected && meter.level >= thresholds.detected {
+                    events.push(GameEvent::CameraAlarm {
+                        camera_id,
+                        player_id,
+                        position: player_position,
+                    });
+                }
+            }
+        }
+
+        events
+    }
+
+    fn update_mission(&mut self, delta_time: f32) -> Vec<GameEvent> {
+        let Some(mut mission) = self.world.mission.take() else {
+            return Vec::new();
+        };
+        let events = step_mission(&mut mission, &self.world, delta_time);
+
+        // Stolen items leave the level with the player
+        for event in &events {
+            let GameEvent::ObjectiveComplete { objective_id, .. } = event else {
+                continue;
+            };
+            if let Some(ObjectiveKind::StealItem { item_id }) = mission.get_objective(*objective_id).map(|o| o.kind) {
+                self.world.remove_entity(item_id);
+            }
+        }
+
+        self.world.mission = Some(mission);
+        events
+    }
+
+    /// Raise the alarm from this tick's camera and guard alerts, then count it down
+    fn update_alarm(&mut self, events: &[GameEvent], delta_time: f32) -> Vec<GameEvent> {
+        let mut alarm_events = Vec::new();
+
+        let triggers: Vec<(AlarmTrigger, Position, Option<EntityId>)> = events.iter()
+            .filter_map(|e| match e {
+                GameEvent::CameraAlarm { camera_id, player_id, position } => {
+                    Some((AlarmTrigger::Camera { camera_id: *camera_id }, *position, Some(*player_id)))
+                }
+                GameEvent::GuardAlerted { guard_id, position } => {
+                    Some((AlarmTrigger::Guard { guard_id: *guard_id }, *position, None))
+                }
+                _ => None,
+            })
+            .collect();
+
+        for (trigger, position, target_id) in triggers {
+            alarm_events.extend(self.raise_alarm(trigger, position, target_id));
+        }
+
+        // Don't stand down while someone is still being chased
+        let hunting = self.world.entities.iter()
+            .any(|e| e.entity_type == EntityType::Guard && e.state == ActorState::Hunting);
+        let previous = self.world.alarm.level;
+        if let Some(level) = self.world.alarm.tick(delta_time, hunting) {
+            alarm_events.push(GameEvent::AlarmLevelChanged { previous, level });
+        }
+
+        // Lock or release doors as the alarm crosses the lockdown tier
+        let lockdown_changes: Vec<bool> = events.iter()
+            .chain(alarm_events.iter())
+            .filter_map(|e| match e {
+                GameEvent::AlarmLevelChanged { previous, level }
+                    if (*previous >= AlarmLevel::Lockdown) != (*level >= AlarmLevel::Lockdown) =>
+                {
+                    Some(*level >= AlarmLevel::Lockdown)
+                }
+                _ => None,
+            })
+            .collect();
+
+        for locked in lockdown_changes {
+            alarm_events.extend(self.apply_lockdown(locked));
+        }
+
+        alarm_events
+    }
+
+    /// Lock every lockdown door, or release the ones a lockdown locked
+    fn apply_lockdown(&mut self, locked: bool) -> Vec<GameEvent> {
+        self.world.doors.iter_mut()
+            .filter_map(|door| {
+                let changed = if locked { door.lock_down() } else { door.release_lockdown() };
+                changed.then_some(GameEvent::DoorStateChanged { door_id: door.door_id, state: door.state })
+            })
+            .collect()
+    }
+
+    /// Raise the alarm and send guards to the reported position
+    fn raise_alarm(
+        &mut self,
+        trigger: AlarmTrigger,
+        position: Position,
+        target_id: Option<EntityId>,
+    ) -> Vec<GameEvent> {
+        let mut events = Vec::new();
+        let previous = self.world.alarm.level;
+        let level = self.world.alarm.raise(trigger, position);
+
+        events.push(GameEvent::AlarmTriggered { trigger, position, level });
+        if level != previous {
+            events.push(GameEvent::AlarmLevelChanged { previous, level });
+        }
+
+        // Guards near whatever raised the alarm respond; everyone does from Alert up
+        let origin = match trigger {
+            AlarmTrigger::Camera { camera_id: id }
+            | AlarmTrigger::Guard { guard_id: id }
+            | AlarmTrigger::Tamper { entity_id: id }
+            | AlarmTrigger::Intrusion { device_id: id } => {
+                self.world.get_entity(id).map(|e| e.position).unwrap_or(position)
+            }
+        };
+        let everyone = self.world.alarm.all_guards_respond();
+
+        for mind in &mut self.world.guard_minds {
+            let Some(guard) = self.world.entities.iter_mut().find(|e| e.id == mind.guard_id) else {
+                continue;
+            };
+            if everyone || guard.position.distance_2d(&origin) <= ALARM_RESPONSE_RADIUS {
+                respond_to_report(guard, mind, target_id, position, &mut self.rng, &mut events);
+            }
         }
 
         events
     }
 
+    /// Report that an object was tampered with; the resulting alarm events
+    /// are delivered with the next update
+    pub fn report_tampering(&mut self, entity_id: EntityId) -> Option<AlarmLevel> {
+        let position = self.world.get_entity(entity_id)?.position;
+        let events = self.raise_alarm(AlarmTrigger::Tamper { entity_id }, position, None);
+        self.pending_events.extend(events);
+        Some(self.world.alarm.level)
+    }
+
+    /// Run the behaviour state machine for one guard
+    fn update_guard_ai(
+        &mut self,
+        guard_id: EntityId,
+        sighting: Option<&Sighting>,
+        heard: Option<&HeardNoise>,
+        delta_time: f32,
+    ) -> Vec<GameEvent> {
+        let Some(mind) = self.world.guard_minds.iter_mut().find(|m| m.guard_id == guard_id) else {
+            return Vec::new();
+        };
+        let Some(guard) = self.world.entities.iter_mut().find(|e| e.id == guard_id) else {
+            return Vec::new();
+        };
+
+        update_guard_mind(guard, mind, sighting, heard, self.navigation.grid(), &mut self.rng, delta_time)
+    }
+
+    /// Set how quickly a guard notices players and when it reacts
+    pub fn set_guard_thresholds(&mut self, guard_id: EntityId, thresholds: DetectionThresholds) -> bool {
+        let Some(mind) = self.world.guard_minds.iter_mut().find(|m| m.guard_id == guard_id) else {
+            return false;
+        };
+        mind.thresholds = thresholds;
+        true
+    }
+
+    /// Find a walking path around obstacles between two points
+    pub fn find_path(&mut self, from: Position, to: Position) -> Option<Vec<Position>> {
+        self.navigation.refresh(&self.world);
+        find_path(self.navigation.grid()?, &from, &to)
+    }
+
     /// Serialize game state for network sync
     pub fn serialize_state(&self) -> Result<String, serde_json::Error> {
         serde_json::to_string(&self.world)
     }
 
+    /// Quantized entity state at the current tick, for delta-compressed sync
+    pub fn world_snapshot(&self) -> WorldSnapshot {
+        WorldSnapshot::capture(&self.world, self.tick)
+    }
+
+    /// Snapshot filtered to what one player may know about
+    pub fn world_snapshot_for(&self, player_id: EntityId) -> WorldSnapshot {
+        WorldSnapshot::capture_for(&self.world, self.tick, player_id)
+    }
+
     /// Deserialize and apply game state from network
     pub fn deserialize_state(&mut self, state_json: &str) -> Result<(), serde_json::Error> {
         self.world = serde_json::from_str(state_json)?;
+        // Entities spawned afterwards must not reuse the loaded ids
+        let max_id = self.world.entities.iter().map(|e| e.id).max().unwrap_or(0);
+        self.next_entity_id = self.next_entity_id.max(max_id + 1);
+        self.navigation.invalidate();
         Ok(())
     }
 }
@@ -275,6 +1239,7 @@ pub fn deserialize_state(&mut self, state_json: &str) -> Result<(), serde_json::
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::hacking::VmError;
 
     #[test]
     fn test_spawn_entities() {
@@ -316,4 +1281,480 @@ fn test_update_loop() {
         let events = game.update(0.016); // ~60 FPS
         assert!(events.is_empty()); // No immediate detection
     }
+
+    #[test]
+    fn test_guard_follows_patrol() {
+        let mut game = GameSystem::new(100.0, 100.0);
+        let guard_id = game.spawn_guard(10.0, 10.0);
+
+        assert!(game.set_patrol_route(guard_id, PatrolMode::Loop));
+        assert!(game.add_patrol_waypoint(guard_id, 30.0, 10.0, 0.0, 0.0));
+        assert!(game.add_patrol_waypoint(guard_id, 10.0, 10.0, 0.0, 0.0));
+
+        for _ in 0..60 {
+            game.update(0.05);
+        }
+
+        let guard = game.world.get_entity(guard_id).unwrap();
+        assert!(guard.position.x > 10.0);
+        assert_eq!(guard.state, ActorState::Walking);
+
+        // Patrol progress round-trips through the serialized state
+        let json = game.serialize_state().unwrap();
+        let mut restored = GameSystem::new(100.0, 100.0);
+        restored.deserialize_state(&json).unwrap();
+        assert_eq!(restored.world.get_patrol_route(guard_id).unwrap().waypoints.len(), 2);
+    }
+
+    #[test]
+    fn test_guard_hunts_visible_player() {
+        let mut game = GameSystem::new(100.0, 100.0);
+        let guard_id = game.spawn_guard(10.0, 10.0);
+        let player_id = game.spawn_player(15.0, 10.0);
+        game.add_light(15.0, 10.0, 20.0, 1.0);
+
+        let mut events = Vec::new();
+        for _ in 0..100 {
+            events.extend(game.update(0.05));
+        }
+
+        assert!(events.iter().any(|e| matches!(e, GameEvent::GuardAlerted { .. })));
+        assert!(events.iter().any(|e| matches!(
+            e,
+            GameEvent::GuardHunting { guard_id: g, target_id: t } if *g == guard_id && *t == player_id
+        )));
+        assert_eq!(game.world.get_entity(guard_id).unwrap().state, ActorState::Hunting);
+    }
+
+    #[test]
+    fn test_detection_is_per_guard() {
+        let mut game = GameSystem::new(100.0, 100.0);
+        let watcher = game.spawn_guard(10.0, 10.0);
+        let other = game.spawn_guard(20.0, 10.0);
+        let player_id = game.spawn_player(15.0, 10.0);
+        game.add_light(15.0, 10.0, 20.0, 1.0);
+
+        // Only the first guard faces the player
+        game.world.get_entity_mut(other).unwrap().rotation = 0.0;
+        game.update(0.1);
+
+        assert!(game.world.get_suspicion(watcher, player_id).unwrap().level > 0.0);
+        assert!(game.world.get_suspicion(other, player_id).is_none());
+        assert_eq!(
+            game.world.get_entity(player_id).unwrap().detection_level,
+            game.world.aggregate_detection(player_id)
+        );
+    }
+
+    #[test]
+    fn test_camera_alerts_nearby_guard() {
+        let mut game = GameSystem::new(100.0, 100.0);
+        let camera_id = game.spawn_camera(10.0, 10.0, 0.0, 0.0, 0.0, 0.0);
+        let guard_id = game.spawn_guard(10.0, 40.0);
+        let player_id = game.spawn_player(20.0, 10.0);
+        game.add_light(20.0, 10.0, 20.0, 1.0);
+
+        let mut events = Vec::new();
+        for _ in 0..60 {
+            events.extend(game.update(0.1));
+        }
+
+        assert!(events.iter().any(|e| matches!(
+            e,
+            GameEvent::CameraAlarm { camera_id: c, player_id: p, .. } if *c == camera_id && *p == player_id
+        )));
+
+        // The camera stays put; the guard comes to look
+        let camera = game.world.get_entity(camera_id).unwrap();
+        assert_eq!(camera.position.x, 10.0);
+        assert_eq!(camera.state, ActorState::Idle);
+        assert_ne!(game.world.get_entity(guard_id).unwrap().state, ActorState::Idle);
+    }
+
+    #[test]
+    fn test_tampering_raises_caution_for_nearby_guards() {
+        let mut game = GameSystem::new(200.0, 200.0);
+        let near = game.spawn_guard(20.0, 10.0);
+        let far = game.spawn_guard(150.0, 150.0);
+        let camera_id = game.spawn_camera(10.0, 10.0, 0.0, 0.0, 0.0, 0.0);
+
+        assert_eq!(game.report_tampering(camera_id), Some(AlarmLevel::Caution));
+        let events = game.update(0.1);
+
+        assert!(events.iter().any(|e| matches!(
+            e,
+            GameEvent::AlarmLevelChanged { previous: AlarmLevel::Calm, level: AlarmLevel::Caution }
+        )));
+        assert_eq!(game.world.get_entity(near).unwrap().state, ActorState::Investigating);
+        assert_ne!(game.world.get_entity(far).unwrap().state, ActorState::Investigating);
+
+        // Alarm state travels with the world and winds back down
+        let json = game.serialize_state().unwrap();
+        assert!(json.contains("Caution"));
+        for _ in 0..250 {
+            game.update(0.1);
+        }
+        assert_eq!(game.world.alarm.level, AlarmLevel::Calm);
+    }
+
+    #[test]
+    fn test_guard_investigates_noise() {
+        let mut game = GameSystem::new(100.0, 100.0);
+        let guard_id = game.spawn_guard(50.0, 50.0);
+        let player_id = game.spawn_player(60.0, 50.0);
+
+        // Guard faces away, so only hearing can pick up the running player
+        game.world.get_entity_mut(guard_id).unwrap().rotation = std::f32::consts::PI;
+        let input = PlayerInput { move_x: 0.0, move_y: 1.0, sprint: true, ..Default::default() };
+        game.apply_player_input(player_id, input);
+
+        let events = game.update(0.05);
+        let heard = events.iter().find_map(|e| match e {
+            GameEvent::NoiseHeard { guard_id: g, position, loudness } if *g == guard_id => Some((*position, *loudness)),
+            _ => None,
+        });
+        let (position, loudness) = heard.expect("guard should hear the player");
+        assert!(loudness >= HEARING_THRESHOLD);
+        assert!(position.distance_2d(&Position::new(60.0, 50.0)) < 1.0);
+
+        let guard = game.world.get_entity(guard_id).unwrap();
+        assert_eq!(guard.state, ActorState::Investigating);
+        assert_eq!(game.world.get_guard_mind(guard_id).unwrap().target_id, Some(player_id));
+    }
+
+    #[test]
+    fn test_investigating_guard_walks_around_cover() {
+        let mut game = GameSystem::new(40.0, 40.0);
+        let guard_id = game.spawn_guard(5.0, 20.0);
+        game.add_obstacle(20.0, 20.0, 5.0);
+
+        // Send the guard to investigate a spot behind the cover
+        let guard = game.world.get_entity_mut(guard_id).unwrap();
+        guard.state = ActorState::Investigating;
+        let mind = game.world.guard_minds.iter_mut().find(|m| m.guard_id == guard_id).unwrap();
+        mind.last_known_position = Some(Position::new(35.0, 20.0));
+        mind.search_remaining = 30.0;
+
+        for _ in 0..200 {
+            game.update(0.05);
+        }
+
+        let guard = game.world.get_entity(guard_id).unwrap();
+        assert!(guard.position.distance_2d(&Position::new(35.0, 20.0)) < 1.0);
+    }
+
+    #[test]
+    fn test_closed_door_blocks_until_opened() {
+        let mut game = GameSystem::new(100.0, 100.0);
+        let guard_id = game.spawn_guard(10.0, 10.0);
+        let player_id = game.spawn_player(20.0, 10.0);
+        let door_id = game.spawn_door(15.0, 10.0, 1.0, DoorState::Locked, UnlockRequirement::Keycard { keycard_id: 4 });
+        game.add_light(20.0, 10.0, 20.0, 1.0);
+
+        let guard = game.world.get_entity(guard_id).unwrap();
+        let player = game.world.get_entity(player_id).unwrap();
+        assert_eq!(calculate_visibility(guard, player, &game.world), 0.0);
+
+        // Out of reach, then missing the card, then through
+        let mut empty = GameSystem::new(10.0, 10.0);
+        let alone = empty.spawn_player(5.0, 5.0);
+        assert!(empty.interact(alone).is_none(), "no doors to use");
+        let result = |event: Option<GameEvent>| match event {
+            Some(GameEvent::DoorInteraction { result, .. }) => result,
+            other => panic!("unexpected {:?}", other),
+        };
+        assert_eq!(result(game.interact(player_id)), InteractionResult::OutOfRange);
+        game.world.get_entity_mut(player_id).unwrap().position = Position::new(17.0, 10.0);
+        assert_eq!(result(game.interact(player_id)), InteractionResult::MissingKeycard { keycard_id: 4 });
+        assert!(game.give_keycard(player_id, 4));
+        assert!(game.pending_events.is_empty(), "a refused door doesn't change");
+        assert_eq!(result(game.interact_with(player_id, door_id)), InteractionResult::Unlocked);
+        assert!(matches!(
+            game.pending_events[..],
+            [GameEvent::DoorStateChanged { door_id: d, state: DoorState::Open }] if d == door_id
+        ));
+
+        let guard = game.world.get_entity(guard_id).unwrap();
+        let player = game.world.get_entity(player_id).unwrap();
+        assert!(calculate_visibility(guard, player, &game.world) > 0.0);
+    }
+
+    #[test]
+    fn test_hacked_devices_blind_cameras_and_darken_rooms() {
+        let mut game = GameSystem::new(100.0, 100.0);
+        let hacker_id = game.spawn_hacker(0.0, 0.0);
+        let terminal_id = game.spawn_terminal(90.0, 90.0);
+        let camera_id = game.spawn_camera(10.0, 10.0, 0.0, 0.0, 0.0, 0.0);
+        let light_id = game.spawn_light(20.0, 10.0, 20.0, 1.0);
+        let door_id = game.spawn_door(50.0, 50.0, 1.0, DoorState::Locked, UnlockRequirement::Hacker);
+        let player_id = game.spawn_player(20.0, 10.0);
+        game.connect_devices(terminal_id, camera_id);
+        game.connect_devices(camera_id, light_id);
+        game.connect_devices(camera_id, door_id);
+        game.update(0.1);
+        assert_eq!(game.hack(hacker_id, HackCommand::Compromise { device_id: terminal_id }), Err(HackError::NoPuzzle(terminal_id)));
+        game.world.network.open_access = true; // This level leaves unguarded devices open
+
+        let lit = game.world.get_entity(player_id).unwrap().light_exposure;
+        let camera = game.world.get_entity(camera_id).unwrap();
+        let player = game.world.get_entity(player_id).unwrap();
+        assert!(calculate_visibility(camera, player, &game.world) > 0.0);
+
+        // Only the hacker gets to run commands
+        let compromise = HackCommand::Compromise { device_id: terminal_id };
+        assert_eq!(game.hack(player_id, compromise.clone()), Err(HackError::NotAHacker(player_id)));
+        assert_eq!(game.hack(999, compromise), Err(HackError::NotAHacker(999)));
+        assert!(game.trace(player_id).is_none());
+
+        // The camera is one hop from the terminal, so it has to wait its turn
+        assert_eq!(game.hack(hacker_id, HackCommand::LoopFeed { camera_id }), Err(HackError::NotCompromised(camera_id)));
+        assert_eq!(game.hack(hacker_id, HackCommand::Compromise { device_id: camera_id }), Err(HackError::NotReachable(camera_id)));
+        for device_id in [terminal_id, camera_id, light_id, door_id] {
+            game.hack(hacker_id, HackCommand::Compromise { device_id }).unwrap();
+        }
+
+        game.hack(hacker_id, HackCommand::LoopFeed { camera_id }).unwrap();
+        game.hack(hacker_id, HackCommand::CutLights { light_id }).unwrap();
+        game.hack(hacker_id, HackCommand::UnlockDoor { door_id }).unwrap();
+        let events = game.update(0.1);
+        assert!(events.iter().any(|e| matches!(e, GameEvent::FeedLooped { looped: true, .. })));
+        assert!(events.iter().any(|e| matches!(e, GameEvent::DoorStateChanged { state: DoorState::Hacked, .. })));
+
+        let camera = game.world.get_entity(camera_id).unwrap();
+        let player = game.world.get_entity(player_id).unwrap();
+        assert_eq!(calculate_visibility(camera, player, &game.world), 0.0);
+        assert!(player.light_exposure < lit);
+        assert!(!game.world.get_door(door_id).unwrap().is_blocking());
+    }
+
+    #[test]
+    fn test_solving_a_puzzle_compromises_the_device() {
+        let mut game = GameSystem::new(100.0, 100.0);
+        let hacker_id = game.spawn_hacker(0.0, 0.0);
+        let terminal_id = game.spawn_terminal(90.0, 90.0);
+        let puzzle = Puzzle::from_json(
+            r#"{"name": "lock", "initialState": {"x": 5, "y": 3}, "goalState": {"x": 8, "y": 3}, "maxMoves": 1}"#,
+        )
+        .unwrap();
+        assert!(game.set_device_puzzle(terminal_id, puzzle));
+
+        let execute = |instruction: &str| HackCommand::Execute { device_id: terminal_id, instruction: instruction.into() };
+        assert_eq!(game.hack(hacker_id, HackCommand::Compromise { device_id: terminal_id }), Err(HackError::Locked(terminal_id)));
+        game.hack(hacker_id, execute("SUB x y")).unwrap();
+        assert!(matches!(game.hack(hacker_id, execute("ADD x y")), Err(HackError::Puzzle { error: VmError::OutOfMoves, .. })));
+        game.hack(hacker_id, HackCommand::Undo { device_id: terminal_id }).unwrap();
+        game.hack(hacker_id, execute("ADD x y")).unwrap();
+
+        let events = game.update(0.1);
+        assert!(events.iter().any(|e| matches!(e, GameEvent::DeviceCompromised { kind: DeviceKind::Terminal, .. })));
+        assert!(game.world.network.node(terminal_id).unwrap().compromised);
+    }
+
+    #[test]
+    fn test_trace_relocks_devices_and_ice_raises_the_alarm() {
+        let mut game = GameSystem::new(100.0, 100.0);
+        let hacker_id = game.spawn_hacker(0.0, 0.0);
+        let terminal_id = game.spawn_terminal(0.0, 0.0);
+        let camera_id = game.spawn_camera(10.0, 10.0, 0.0, 0.0, 0.0, 0.0);
+        let light_id = game.spawn_light(20.0, 10.0, 20.0, 1.0);
+        game.connect_devices(terminal_id, camera_id);
+        game.connect_devices(camera_id, light_id);
+        game.world.network.open_access = true;
+        for device_id in [terminal_id, camera_id, light_id] {
+            game.hack(hacker_id, HackCommand::Compromise { device_id }).unwrap();
+        }
+        game.hack(hacker_id, HackCommand::CutLights { light_id }).unwrap();
+
+        // Working the camera too hard gets everything past the terminal locked again
+        while game.trace(hacker_id).unwrap().stage == TraceStage::Clear {
+            game.hack(hacker_id, HackCommand::LoopFeed { camera_id }).unwrap();
+        }
+        let events = game.update(0.1);
+        assert!(events.iter().any(|e| matches!(e, GameEvent::LightsCut { cut: false, .. })));
+        assert!(events.iter().any(|e| matches!(
+            e,
+            GameEvent::DevicesRelocked { device_ids } if *device_ids == vec![camera_id, light_id]
+        )));
+        assert!(game.world.network.node(terminal_id).unwrap().compromised);
+        assert!(!game.world.network.light_cut(light_id));
+
+        // Breaking back in sends ICE after the hacker's connection
+        game.hack(hacker_id, HackCommand::Compromise { device_id: camera_id }).unwrap();
+        while game.trace(hacker_id).unwrap().stage == TraceStage::Relock {
+            game.hack(hacker_id, HackCommand::LoopFeed { camera_id }).unwrap();
+        }
+        let ice = game.trace(hacker_id).unwrap().ice.unwrap();
+        assert_eq!(ice.device_id, terminal_id);
+
+        let events: Vec<GameEvent> = (0..4).flat_map(|_| game.update(1.0)).collect();
+        assert!(events.iter().any(|e| matches!(e, GameEvent::IceMoved { device_id, .. } if *device_id == camera_id)));
+        assert!(events.iter().any(|e| matches!(e, GameEvent::HackerTraced { hacker_id: h } if *h == hacker_id)));
+        assert!(events.iter().any(|e| matches!(
+            e,
+            GameEvent::AlarmTriggered { trigger: AlarmTrigger::Intrusion { device_id }, .. } if *device_id == camera_id
+        )));
+        assert_eq!(game.world.alarm.level, AlarmLevel::Alert);
+        assert!(game.trace(hacker_id).unwrap().ice.is_none());
+    }
+
+    #[test]
+    fn test_relocking_shuts_hacked_doors() {
+        let mut game = GameSystem::new(100.0, 100.0);
+        let hacker_id = game.spawn_hacker(0.0, 0.0);
+        let terminal_id = game.spawn_terminal(0.0, 0.0);
+        let door_id = game.spawn_door(50.0, 50.0, 1.0, DoorState::Locked, UnlockRequirement::None);
+        game.connect_devices(terminal_id, door_id);
+        game.world.network.open_access = true;
+        for device_id in [terminal_id, door_id] {
+            game.hack(hacker_id, HackCommand::Compromise { device_id }).unwrap();
+        }
+        game.hack(hacker_id, HackCommand::UnlockDoor { door_id }).unwrap();
+        assert_eq!(game.world.get_door(door_id).unwrap().state, DoorState::Hacked);
+
+        while game.trace(hacker_id).unwrap().stage == TraceStage::Clear {
+            game.hack(hacker_id, HackCommand::UnlockDoor { door_id }).unwrap();
+        }
+        let events = game.update(0.1);
+        assert!(events.iter().any(|e| matches!(
+            e,
+            GameEvent::DoorStateChanged { door_id: d, state: DoorState::Locked } if *d == door_id
+        )));
+        let door = game.world.get_door(door_id).unwrap();
+        assert_eq!(door.state, DoorState::Locked);
+        assert!(door.is_blocking());
+        assert!(!game.world.network.node(door_id).unwrap().compromised);
+    }
+
+    #[test]
+    fn test_replacing_a_marker_reports_the_old_one_gone() {
+        let mut game = GameSystem::new(100.0, 100.0);
+        let guard_id = game.spawn_guard(50.0, 50.0);
+        let first = game.place_marker(MarkerKind::Tag { entity_id: guard_id }).unwrap();
+        let route = game.place_marker(MarkerKind::Route { points: vec![Position::new(1.0, 1.0)] }).unwrap();
+        game.update(0.1);
+
+        let tag = game.place_marker(MarkerKind::Tag { entity_id: guard_id }).unwrap();
+        game.place_marker(MarkerKind::Route { points: vec![Position::new(2.0, 2.0)] }).unwrap();
+        let events = game.update(0.1);
+        let removed: Vec<u32> = events.iter()
+            .filter_map(|e| match e {
+                GameEvent::MarkerRemoved { marker_id } => Some(*marker_id),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(removed, vec![first, route]);
+        assert!(events.iter().any(|e| matches!(e, GameEvent::MarkerPlaced { marker_id, .. } if *marker_id == tag)));
+        assert_eq!(game.world.markers.markers.len(), 2);
+    }
+
+    #[test]
+    fn test_simultaneous_sightings_escalate_the_alarm_once() {
+        let mut game = GameSystem::new(100.0, 100.0);
+        let player_id = game.spawn_player(50.0, 50.0);
+        let cameras = [game.spawn_camera(10.0, 10.0, 0.0, 0.0, 0.0, 0.0), game.spawn_camera(90.0, 90.0, 0.0, 0.0, 0.0, 0.0)];
+        let sightings: Vec<GameEvent> = cameras.iter()
+            .map(|&camera_id| GameEvent::CameraAlarm { camera_id, player_id, position: Position::new(50.0, 50.0) })
+            .collect();
+
+        let events = game.update_alarm(&sightings, 0.1);
+        assert_eq!(game.world.alarm.level, AlarmLevel::Alert);
+        assert!(!events.iter().any(|e| matches!(e, GameEvent::AlarmLevelChanged { level: AlarmLevel::Lockdown, .. })));
+    }
+
+    #[test]
+    fn test_lockdown_locks_doors() {
+        let mut game = GameSystem::new(100.0, 100.0);
+        let door_id = game.spawn_door(50.0, 50.0, 1.0, DoorState::Open, UnlockRequirement::None);
+        let camera_id = game.spawn_camera(80.0, 80.0, 0.0, 0.0, 0.0, 0.0);
+        let mut events = Vec::new();
+        for _ in 0..3 {
+            game.report_tampering(camera_id);
+            events = game.update(0.1);
+        }
+        assert_eq!(game.world.alarm.level, AlarmLevel::Lockdown);
+        assert!(events.iter().any(|e| matches!(
+            e,
+            GameEvent::DoorStateChanged { door_id: d, state: DoorState::Locked } if *d == door_id
+        )));
+        assert!(game.world.get_door(door_id).unwrap().is_blocking());
+    }
+
+    #[test]
+    fn test_mission_steal_and_extract() {
+        let mut game = GameSystem::new(100.0, 100.0);
+        let player_id = game.spawn_player(10.0, 10.0);
+        let item_id = game.spawn_item(30.0, 10.0);
+        game.start_mission(MissionOrder::Parallel, Some(60.0), true);
+        game.add_objective(ObjectiveKind::StealItem { item_id }, false).unwrap();
+        game.add_objective(ObjectiveKind::Extract { position: Position::new(10.0, 10.0), radius: 3.0 }, false).unwrap();
+
+        game.update(0.1);
+        assert_eq!(game.world.mission.as_ref().unwrap().status, MissionStatus::InProgress);
+
+        game.world.get_entity_mut(player_id).unwrap().position = Position::new(30.0, 10.0);
+        game.update(0.1);
+        assert!(game.world.get_entity(item_id).is_none());
+
+        game.world.get_entity_mut(player_id).unwrap().position = Position::new(10.0, 10.0);
+        let events = game.update(0.1);
+        assert!(events.iter().any(|e| matches!(e, GameEvent::MissionComplete { bonus_completed: 0 })));
+
+        // Mission progress is part of the serialized world
+        let json = game.serialize_state().unwrap();
+        let mut restored = GameSystem::new(100.0, 100.0);
+        restored.deserialize_state(&json).unwrap();
+        assert_eq!(restored.world.mission.unwrap().status, MissionStatus::Succeeded);
+    }
+
+    #[test]
+    fn test_fixed_timestep_accumulates() {
+        let mut game = GameSystem::new(100.0, 100.0);
+        game.set_fixed_timestep(Some(0.1));
+
+        game.update(0.25);
+        assert_eq!(game.tick, 2);
+        assert!((game.interpolation_alpha() - 0.5).abs() < 1e-4);
+
+        game.update(0.05);
+        assert_eq!(game.tick, 3);
+
+        // A long stall is capped instead of running hundreds of ticks
+        game.update(100.0);
+        assert_eq!(game.tick, 3 + MAX_TICKS_PER_UPDATE as u64);
+
+        // Ticks can also be taken out and run by hand, one input each
+        game.set_fixed_timestep(Some(0.25));
+        let tick = game.tick;
+        assert_eq!(game.due_ticks(0.375), 1);
+        assert_eq!(game.due_ticks(0.125), 1);
+        assert_eq!(game.due_ticks(0.125), 0);
+        assert_eq!(game.tick, tick, "nothing is simulated");
+    }
+
+    #[test]
+    fn test_same_seed_and_inputs_give_identical_worlds() {
+        let run = |frame_times: &[f32]| {
+            let mut game = GameSystem::with_seed(100.0, 100.0, 7);
+            game.set_fixed_timestep(Some(0.0625));
+            let player_id = game.spawn_player(15.0, 10.0);
+            game.spawn_guard(10.0, 10.0);
+            game.add_light(15.0, 10.0, 20.0, 1.0);
+            game.add_obstacle(30.0, 30.0, 4.0);
+
+            let input = PlayerInput { move_x: 1.0, move_y: 0.5, ..PlayerInput::default() };
+            for &frame in frame_times {
+                game.apply_player_input(player_id, input);
+                game.update(frame);
+            }
+            (game.tick, game.serialize_state().unwrap())
+        };
+
+        // Different frame pacing, same ticks: same world, bit for bit
+        // (frame times are exact in binary so both runs bank exactly 300 ticks)
+        let steady = run(&[0.125; 150]);
+        let jittery: Vec<f32> = (0..300).map(|i| if i % 2 == 0 { 0.03125 } else { 0.09375 }).collect();
+        let jittery = run(&jittery);
+        assert_eq!(steady.0, 300);
+        assert_eq!(steady, jittery);
+    }
 }
@@ -1,3 +1,15 @@
+use crate::ai::guard::GuardMind;
+use crate::ai::patrol::PatrolRoute;
+use crate::game::math;
+use crate::hacking::markers::Markers;
+use crate::hacking::trace::TraceMeter;
+use crate::net::intel::HackerIntel;
+use crate::mission::Mission;
+use crate::security::alarm::{AlarmState, ALARM_AMBIENT_LIGHT};
+use crate::security::camera::CameraRig;
+use crate::security::door::Door;
+use crate::security::network::DeviceNetwork;
+use crate::stealth::suspicion::{aggregate_suspicion, SuspicionMeter};
 use glam::{Vec2, Vec3};
 use serde::{Deserialize, Serialize};
 
@@ -5,10 +17,11 @@
 pub type EntityId = u32;
 
 /// Game position in 2D world (x, y) with optional height
-#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub struct Position {
     pub x: f32,
     pub y: f32,
+    #[serde(default)]
     pub height: f32, // For multi-level stealth
 }
 
@@ -46,13 +59,48 @@ pub fn to_vec3(&self) -> Vec3 {
 /// Entity type classification
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum EntityType {
-    Player,
+    #[serde(alias = "Infiltrator")]
+    Player, // The infiltrator on the ground
     Guard,
     Camera,
     Door,
     LightSource,
     SoundEmitter,
     CoverObject,
+    #[serde(alias = "Objective")]
+    Item,
+    Terminal,
+    Hacker, // Remote player's marker; not physically present, never detected
+    Drone,
+    Wall,
+}
+
+/// Which seat a human player occupies
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PlayerRole {
+    Hacker,      // Top-down view, works through devices
+    Infiltrator, // On the ground, moves through the level
+}
+
+impl PlayerRole {
+    /// Entity type representing a player in this role
+    pub fn entity_type(self) -> EntityType {
+        match self {
+            PlayerRole::Hacker => EntityType::Hacker,
+            PlayerRole::Infiltrator => EntityType::Player,
+        }
+    }
+}
+
+impl EntityType {
+    /// The player role this entity type stands for, if any
+    pub fn role(self) -> Option<PlayerRole> {
+        match self {
+            EntityType::Player => Some(PlayerRole::Infiltrator),
+            EntityType::Hacker => Some(PlayerRole::Hacker),
+            _ => None,
+        }
+    }
 }
 
 /// Movement stance affects visibility and noise
@@ -99,6 +147,20 @@ pub struct Entity {
     // Attributes
     pub speed: f32,
     pub health: f32,
+
+    // Inventory
+    #[serde(default)]
+    pub keycards: Vec<u32>,
+
+    // Which players are shown this entity (decided by the server)
+    #[serde(default = "shown_by_default")]
+    pub visible_to_hacker: bool,
+    #[serde(default = "shown_by_default")]
+    pub visible_to_infiltrator: bool,
+}
+
+fn shown_by_default() -> bool {
+    true
 }
 
 impl Entity {
@@ -119,6 +181,9 @@ pub fn new_player(id: EntityId, position: Position) -> Self {
             light_exposure: 0.5,
             speed: 5.0,
             health: 100.0,
+            keycards: Vec::new(),
+            visible_to_hacker: true,
+            visible_to_infiltrator: true,
         }
     }
 
@@ -139,25 +204,92 @@ pub fn new_guard(id: EntityId, position: Position) -> Self {
             light_exposure: 1.0,
             speed: 4.0,
             health: 100.0,
+            keycards: Vec::new(),
+            visible_to_hacker: true,
+            visible_to_infiltrator: true,
+        }
+    }
+
+    pub fn new_camera(id: EntityId, position: Position, rotation: f32) -> Self {
+        Self {
+            id,
+            entity_type: EntityType::Camera,
+            position,
+            rotation,
+            velocity: Vec2::ZERO,
+            stance: Stance::Standing,
+            state: ActorState::Idle,
+            visible: true,
+            visibility_radius: 25.0,
+            field_of_view: std::f32::consts::PI * 0.35, // ~63 degrees
+            noise_level: 0.0,
+            detection_level: 0.0,
+            light_exposure: 1.0,
+            speed: 0.0,
+            health: 100.0,
+            keycards: Vec::new(),
+            visible_to_hacker: true,
+            visible_to_infiltrator: true,
+        }
+    }
+
+    /// Flying sentry: perceives like a guard, moves faster and makes a constant hum
+    pub fn new_drone(id: EntityId, position: Position) -> Self {
+        Self {
+            entity_type: EntityType::Drone,
+            visibility_radius: 15.0,
+            field_of_view: std::f32::consts::PI * 0.5, // 90 degrees
+            noise_level: 0.3,
+            speed: 6.0,
+            health: 40.0,
+            ..Self::new_guard(id, position)
+        }
+    }
+
+    pub fn new_door(id: EntityId, position: Position) -> Self {
+        Self::new_static(id, EntityType::Door, position)
+    }
+
+    /// Non-moving, non-perceiving entity (doors, items, terminals)
+    pub fn new_static(id: EntityId, entity_type: EntityType, position: Position) -> Self {
+        Self {
+            id,
+            entity_type,
+            position,
+            rotation: 0.0,
+            velocity: Vec2::ZERO,
+            stance: Stance::Standing,
+            state: ActorState::Idle,
+            visible: true,
+            visibility_radius: 0.0,
+            field_of_view: 0.0,
+            noise_level: 0.0,
+            detection_level: 0.0,
+            light_exposure: 1.0,
+            speed: 0.0,
+            health: 100.0,
+            keycards: Vec::new(),
+            visible_to_hacker: true,
+            visible_to_infiltrator: true,
         }
     }
 
     /// Get the direction vector this entity is facing
     pub fn facing_direction(&self) -> Vec2 {
-        Vec2::new(self.rotation.cos(), self.rotation.sin())
+        Vec2::new(math::cos(self.rotation), math::sin(self.rotation))
     }
 
     /// Check if this entity is facing towards a position
     pub fn is_facing(&self, target: &Position) -> bool {
         let to_target = (target.to_vec2() - self.position.to_vec2()).normalize();
         let facing = self.facing_direction();
-        let angle = to_target.dot(facing).acos();
+        let angle = math::acos(to_target.dot(facing));
         angle < self.field_of_view / 2.0
     }
 }
 
 /// World obstacles and cover
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
 pub struct Obstacle {
     pub position: Position,
     pub radius: f32,
@@ -172,6 +304,8 @@ pub struct LightSource {
     pub position: Position,
     pub radius: f32,
     pub intensity: f32, // 0.0 to 1.0
+    #[serde(default)]
+    pub light_id: Option<EntityId>, // Set for lights on the device network
 }
 
 /// Game world state
@@ -183,6 +317,28 @@ pub struct World {
     pub width: f32,
     pub height: f32,
     pub ambient_light: f32,
+    #[serde(default)]
+    pub patrol_routes: Vec<PatrolRoute>,
+    #[serde(default)]
+    pub guard_minds: Vec<GuardMind>,
+    #[serde(default)]
+    pub suspicion_meters: Vec<SuspicionMeter>,
+    #[serde(default)]
+    pub cameras: Vec<CameraRig>,
+    #[serde(default)]
+    pub alarm: AlarmState,
+    #[serde(default)]
+    pub doors: Vec<Door>,
+    #[serde(default)]
+    pub mission: Option<Mission>,
+    #[serde(default)]
+    pub network: DeviceNetwork,
+    #[serde(default)]
+    pub markers: Markers, // Hacker's pings, tags and route for the infiltrator
+    #[serde(default)]
+    pub intel: HackerIntel,
+    #[serde(default)]
+    pub traces: Vec<TraceMeter>, // One per hacker who has hacked anything
 }
 
 impl World {
@@ -194,9 +350,37 @@ pub fn new(width: f32, height: f32) -> Self {
             width,
             height,
             ambient_light: 0.3,
+            patrol_routes: Vec::new(),
+            guard_minds: Vec::new(),
+            suspicion_meters: Vec::new(),
+            cameras: Vec::new(),
+            alarm: AlarmState::default(),
+            doors: Vec::new(),
+            mission: None,
+            network: DeviceNetwork::default(),
+            markers: Markers::default(),
+            intel: HackerIntel::default(),
+            traces: Vec::new(),
+        }
+    }
+
+    /// Ambient light, raised while the alarm has the lights on
+    pub fn ambient_light_level(&self) -> f32 {
+        if self.alarm.lights_on() {
+            self.ambient_light.max(ALARM_AMBIENT_LIGHT)
+        } else {
+            self.ambient_light
         }
     }
 
+    /// Everything that blocks movement, vision or sound: obstacles plus shut doors
+    pub fn blockers(&self) -> impl Iterator<Item = Obstacle> + '_ {
+        let doors = self.doors.iter()
+            .filter(|d| d.is_blocking())
+            .filter_map(|door| self.get_entity(door.door_id).map(|entity| door.as_obstacle(entity.position)));
+        self.obstacles.iter().copied().chain(doors)
+    }
+
     pub fn add_entity(&mut self, entity: Entity) -> EntityId {
         let id = entity.id;
         self.entities.push(entity);
@@ -214,9 +398,74 @@ pub fn get_entity_mut(&mut self, id: EntityId) -> Option<&mut Entity> {
     pub fn remove_entity(&mut self, id: EntityId) -> bool {
         if let Some(pos) = self.entities.iter().position(|e| e.id == id) {
             self.entities.remove(pos);
+            self.patrol_routes.retain(|r| r.guard_id != id);
+            self.guard_minds.retain(|m| m.guard_id != id);
+            self.suspicion_meters.retain(|m| m.observer_id != id && m.target_id != id);
+            self.cameras.retain(|c| c.camera_id != id);
+            self.doors.retain(|d| d.door_id != id);
             true
         } else {
             false
         }
     }
+
+    pub fn get_patrol_route(&self, guard_id: EntityId) -> Option<&PatrolRoute> {
+        self.patrol_routes.iter().find(|r| r.guard_id == guard_id)
+    }
+
+    pub fn get_patrol_route_mut(&mut self, guard_id: EntityId) -> Option<&mut PatrolRoute> {
+        self.patrol_routes.iter_mut().find(|r| r.guard_id == guard_id)
+    }
+
+    pub fn get_guard_mind(&self, guard_id: EntityId) -> Option<&GuardMind> {
+        self.guard_minds.iter().find(|m| m.guard_id == guard_id)
+    }
+
+    pub fn get_camera(&self, camera_id: EntityId) -> Option<&CameraRig> {
+        self.cameras.iter().find(|c| c.camera_id == camera_id)
+    }
+
+    pub fn get_door(&self, door_id: EntityId) -> Option<&Door> {
+        self.doors.iter().find(|d| d.door_id == door_id)
+    }
+
+    pub fn get_door_mut(&mut self, door_id: EntityId) -> Option<&mut Door> {
+        self.doors.iter_mut().find(|d| d.door_id == door_id)
+    }
+
+    /// Suspicion one observer holds for one target, if any
+    pub fn get_suspicion(&self, observer_id: EntityId, target_id: EntityId) -> Option<&SuspicionMeter> {
+        self.suspicion_meters.iter()
+            .find(|m| m.observer_id == observer_id && m.target_id == target_id)
+    }
+
+    /// Find (or start, if `create` is set) the meter for an observer/target pair
+    pub fn suspicion_meter_mut(
+        &mut self,
+        observer_id: EntityId,
+        target_id: EntityId,
+        create: bool,
+    ) -> Option<&mut SuspicionMeter> {
+        let index = self.suspicion_meters.iter()
+            .position(|m| m.observer_id == observer_id && m.target_id == target_id);
+
+        match index {
+            Some(index) => Some(&mut self.suspicion_meters[index]),
+            None if create => {
+                self.suspicion_meters.push(SuspicionMeter::new(observer_id, target_id));
+                self.suspicion_meters.last_mut()
+            }
+            None => None,
+        }
+    }
+
+    /// All observers' meters for a target
+    pub fn suspicion_of(&self, target_id: EntityId) -> Vec<&SuspicionMeter> {
+        self.suspicion_meters.iter().filter(|m| m.target_id == target_id).collect()
+    }
+
+    /// Highest suspicion across all observers of a target
+    pub fn aggregate_detection(&self, target_id: EntityId) -> f32 {
+        aggregate_suspicion(&self.suspicion_meters, target_id)
+    }
 }
@@ -0,0 +1,311 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt;
+
+/// Named 32-bit registers, as in the ReScript VM (JS bitwise ops are 32-bit)
+pub type Registers = BTreeMap<String, i32>;
+
+/// One reversible VM instruction
+/// Every instruction is a bijection on the registers, so `invert` always undoes `execute`.
+/// Ancilla forms (AND, OR, MUL, DIV) accumulate into their targets instead of overwriting
+/// them; starting from zero they give the same results as the ReScript VM.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "op", rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum Instruction {
+    Noop,
+    Add { a: String, b: String },                       // a += b
+    Sub { a: String, b: String },                       // a -= b
+    Swap { a: String, b: String },
+    Negate { a: String },
+    Flip { a: String },                                 // a = !a
+    Xor { a: String, b: String },                       // a ^= b
+    Rol { a: String, bits: u32 },
+    Ror { a: String, bits: u32 },
+    And { a: String, b: String, c: String },            // c ^= a & b
+    Or { a: String, b: String, c: String },             // c ^= a | b
+    Mul { a: String, b: String, c: String },            // c += a * b
+    Div { a: String, b: String, q: String, r: String }, // q += a / b, r += a % b
+}
+
+/// Why an instruction can't be built or run
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum VmError {
+    UnknownOp(String),
+    WrongOperands { op: String, expected: usize, found: usize },
+    BadShift(String),
+    UnknownRegister(String),
+    Aliased(String), // A target register is also read, which would lose information
+    NotAllowed(String),
+    OutOfMoves,
+    NothingToUndo,
+}
+
+impl fmt::Display for VmError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            VmError::UnknownOp(op) => write!(f, "unknown instruction {}", op),
+            VmError::WrongOperands { op, expected, found } => {
+                write!(f, "{} takes {} registers, got {}", op, expected, found)
+            }
+            VmError::BadShift(bits) => write!(f, "{} is not a rotation between 0 and 31", bits),
+            VmError::UnknownRegister(name) => write!(f, "no register named {}", name),
+            VmError::Aliased(name) => write!(f, "register {} is both read and written", name),
+            VmError::NotAllowed(op) => write!(f, "{} is not allowed in this puzzle", op),
+            VmError::OutOfMoves => write!(f, "no moves left"),
+            VmError::NothingToUndo => write!(f, "nothing to undo"),
+        }
+    }
+}
+
+impl std::error::Error for VmError {}
+
+fn get(registers: &Registers, name: &str) -> i32 {
+    registers.get(name).copied().unwrap_or(0)
+}
+
+fn set(registers: &mut Registers, name: &str, value: i32) {
+    registers.insert(name.to_string(), value);
+}
+
+impl Instruction {
+    /// Build an instruction from its mnemonic and register operands
+    /// ROL and ROR take an optional rotation count after the register (default 1)
+    pub fn from_parts(op: &str, operands: &[&str]) -> Result<Self, VmError> {
+        let op = op.to_ascii_uppercase();
+        let arity = |expected: usize| {
+            if operands.len() == expected {
+                Ok(())
+            } else {
+                Err(VmError::WrongOperands { op: op.clone(), expected, found: operands.len() })
+            }
+        };
+        let reg = |i: usize| operands[i].to_string();
+
+        let instruction = match op.as_str() {
+            "NOOP" => { arity(0)?; Instruction::Noop }
+            "ADD" => { arity(2)?; Instruction::Add { a: reg(0), b: reg(1) } }
+            "SUB" => { arity(2)?; Instruction::Sub { a: reg(0), b: reg(1) } }
+            "SWAP" => { arity(2)?; Instruction::Swap { a: reg(0), b: reg(1) } }
+            "NEGATE" => { arity(1)?; Instruction::Negate { a: reg(0) } }
+            "FLIP" => { arity(1)?; Instruction::Flip { a: reg(0) } }
+            "XOR" => { arity(2)?; Instruction::Xor { a: reg(0), b: reg(1) } }
+            "ROL" | "ROR" => {
+                if operands.len() != 1 {
+                    arity(2)?;
+                }
+                let bits = match operands.get(1) {
+                    Some(bits) => bits.parse::<u32>().ok()
+                        .filter(|b| *b < 32)
+                        .ok_or_else(|| VmError::BadShift(bits.to_string()))?,
+                    None => 1,
+                };
+                if op == "ROL" {
+                    Instruction::Rol { a: reg(0), bits }
+                } else {
+                    Instruction::Ror { a: reg(0), bits }
+                }
+            }
+            "AND" => { arity(3)?; Instruction::And { a: reg(0), b: reg(1), c: reg(2) } }
+            "OR" => { arity(3)?; Instruction::Or { a: reg(0), b: reg(1), c: reg(2) } }
+            "MUL" => { arity(3)?; Instruction::Mul { a: reg(0), b: reg(1), c: reg(2) } }
+            "DIV" => { arity(4)?; Instruction::Div { a: reg(0), b: reg(1), q: reg(2), r: reg(3) } }
+            _ => return Err(VmError::UnknownOp(op)),
+        };
+        Ok(instruction)
+    }
+
+    /// Parse assembly text such as "ADD x y" or "ROL key 3"
+    pub fn parse(text: &str) -> Result<Self, VmError> {
+        let mut words = text.split(|c: char| c.is_whitespace() || c == ',').filter(|w| !w.is_empty());
+        let op = words.next().unwrap_or("NOOP");
+        let operands: Vec<&str> = words.collect();
+        Self::from_parts(op, &operands)
+    }
+
+    /// Mnemonic, as used in a puzzle's allowedInstructions
+    pub fn op(&self) -> &'static str {
+        match self {
+            Instruction::Noop => "NOOP",
+            Instruction::Add { .. } => "ADD",
+            Instruction::Sub { .. } => "SUB",
+            Instruction::Swap { .. } => "SWAP",
+            Instruction::Negate { .. } => "NEGATE",
+            Instruction::Flip { .. } => "FLIP",
+            Instruction::Xor { .. } => "XOR",
+            Instruction::Rol { .. } => "ROL",
+            Instruction::Ror { .. } => "ROR",
+            Instruction::And { .. } => "AND",
+            Instruction::Or { .. } => "OR",
+            Instruction::Mul { .. } => "MUL",
+            Instruction::Div { .. } => "DIV",
+        }
+    }
+
+    /// Registers read as inputs and registers written
+    fn operands(&self) -> (Vec<&str>, Vec<&str>) {
+        match self {
+            Instruction::Noop => (vec![], vec![]),
+            Instruction::Add { a, b } | Instruction::Sub { a, b } | Instruction::Xor { a, b } => {
+                (vec![b], vec![a])
+            }
+            Instruction::Swap { a, b } => (vec![], vec![a, b]),
+            Instruction::Negate { a } | Instruction::Flip { a } => (vec![], vec![a]),
+            Instruction::Rol { a, .. } | Instruction::Ror { a, .. } => (vec![], vec![a]),
+            Instruction::And { a, b, c } | Instruction::Or { a, b, c } | Instruction::Mul { a, b, c } => {
+                (vec![a, b], vec![c])
+            }
+            Instruction::Div { a, b, q, r } => (vec![a, b], vec![q, r]),
+        }
+    }
+
+    /// Check the instruction can run on `registers` and is invertible there
+    pub fn validate(&self, registers: &Registers) -> Result<(), VmError> {
+        let (reads, writes) = self.operands();
+        if let Some(missing) = reads.iter().chain(&writes).find(|r| !registers.contains_key(**r)) {
+            return Err(VmError::UnknownRegister(missing.to_string()));
+        }
+        if let Some(aliased) = writes.iter().find(|w| reads.contains(w)) {
+            return Err(VmError::Aliased(aliased.to_string()));
+        }
+        // Two outputs in one register would only keep one of them
+        if let Instruction::Div { q, r, .. } = self {
+            if q == r {
+                return Err(VmError::Aliased(q.clone()));
+            }
+        }
+        Ok(())
+    }
+
+    pub fn execute(&self, registers: &mut Registers) {
+        self.apply(registers, false);
+    }
+
+    /// Undo `execute`, given the registers it left behind
+    pub fn invert(&self, registers: &mut Registers) {
+        self.apply(registers, true);
+    }
+
+    fn apply(&self, regs: &mut Registers, inverse: bool) {
+        // Adds or subtracts depending on direction
+        let accumulate = |current: i32, amount: i32| {
+            if inverse { current.wrapping_sub(amount) } else { current.wrapping_add(amount) }
+        };
+
+        match self {
+            Instruction::Noop => {}
+            Instruction::Add { a, b } | Instruction::Sub { a, b } => {
+                let amount = get(regs, b);
+                let amount = if matches!(self, Instruction::Sub { .. }) { amount.wrapping_neg() } else { amount };
+                let value = accumulate(get(regs, a), amount);
+                set(regs, a, value);
+            }
+            Instruction::Swap { a, b } => {
+                let (va, vb) = (get(regs, a), get(regs, b));
+                set(regs, a, vb);
+                set(regs, b, va);
+            }
+            Instruction::Negate { a } => {
+                let value = get(regs, a).wrapping_neg();
+                set(regs, a, value);
+            }
+            Instruction::Flip { a } => {
+                let value = !get(regs, a);
+                set(regs, a, value);
+            }
+            Instruction::Xor { a, b } => {
+                let value = get(regs, a) ^ get(regs, b);
+                set(regs, a, value);
+            }
+            Instruction::Rol { a, bits } | Instruction::Ror { a, bits } => {
+                let left = matches!(self, Instruction::Rol { .. }) != inverse;
+                let value = get(regs, a) as u32;
+                let rotated = if left { value.rotate_left(*bits) } else { value.rotate_right(*bits) };
+                set(regs, a, rotated as i32);
+            }
+            Instruction::And { a, b, c } => {
+                let value = get(regs, c) ^ (get(regs, a) & get(regs, b));
+                set(regs, c, value);
+            }
+            Instruction::Or { a, b, c } => {
+                let value = get(regs, c) ^ (get(regs, a) | get(regs, b));
+                set(regs, c, value);
+            }
+            Instruction::Mul { a, b, c } => {
+                let value = accumulate(get(regs, c), get(regs, a).wrapping_mul(get(regs, b)));
+                set(regs, c, value);
+            }
+            Instruction::Div { a, b, q, r } => {
+                let (va, vb) = (get(regs, a), get(regs, b));
+                // Dividing by zero leaves the whole dividend as the remainder
+                let (quotient, remainder) = if vb == 0 { (0, va) } else { (va.wrapping_div(vb), va.wrapping_rem(vb)) };
+                let new_q = accumulate(get(regs, q), quotient);
+                let new_r = accumulate(get(regs, r), remainder);
+                set(regs, q, new_q);
+                set(regs, r, new_r);
+            }
+        }
+    }
+}
+
+impl fmt::Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Instruction::Rol { a, bits } | Instruction::Ror { a, bits } => write!(f, "{} {} {}", self.op(), a, bits),
+            _ => {
+                let (reads, writes) = self.operands();
+                // Operands in the order they are written
+                let operands: Vec<&str> = match self {
+                    Instruction::Add { .. } | Instruction::Sub { .. } | Instruction::Xor { .. } => {
+                        writes.into_iter().chain(reads).collect()
+                    }
+                    _ => reads.into_iter().chain(writes).collect(),
+                };
+                write!(f, "{}", self.op())?;
+                for operand in operands {
+                    write!(f, " {}", operand)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_every_instruction_inverts() {
+        let programs = [
+            "ADD x y", "SUB x y", "SWAP x y", "NEGATE x", "FLIP x", "XOR x y", "ROL x 5", "ROR y",
+            "AND x y z", "OR x y z", "MUL x y z", "DIV x y q r", "DIV x zero q r", "NOOP",
+        ];
+        for value in [0, 1, -7, 170, i32::MAX, i32::MIN] {
+            let start: Registers = [("x", value), ("y", 3), ("z", 9), ("q", 1), ("r", -2), ("zero", 0)]
+                .into_iter()
+                .map(|(k, v)| (k.to_string(), v))
+                .collect();
+            for text in programs {
+                let instruction = Instruction::parse(text).unwrap();
+                assert_eq!(instruction.to_string(), text.replace("ROR y", "ROR y 1"));
+                instruction.validate(&start).unwrap();
+
+                let mut registers = start.clone();
+                instruction.execute(&mut registers);
+                instruction.invert(&mut registers);
+                assert_eq!(registers, start, "{} did not invert for x = {}", text, value);
+            }
+        }
+    }
+
+    #[test]
+    fn test_lossy_operands_are_refused() {
+        let registers: Registers = [("x".to_string(), 1), ("y".to_string(), 2)].into_iter().collect();
+        assert_eq!(Instruction::parse("ADD x x").unwrap().validate(&registers), Err(VmError::Aliased("x".into())));
+        assert_eq!(Instruction::parse("AND x y y").unwrap().validate(&registers), Err(VmError::Aliased("y".into())));
+        assert_eq!(Instruction::parse("XOR x w").unwrap().validate(&registers), Err(VmError::UnknownRegister("w".into())));
+        assert!(matches!(Instruction::parse("ADD x"), Err(VmError::WrongOperands { expected: 2, found: 1, .. })));
+        assert_eq!(Instruction::parse("JMP x"), Err(VmError::UnknownOp("JMP".into())));
+        assert!(Instruction::parse("SWAP x x").unwrap().validate(&registers).is_ok());
+    }
+}
@@ -0,0 +1,164 @@
+use crate::game::types::{EntityId, Position};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Seconds a ping stays on screen
+pub const PING_LIFETIME: f32 = 6.0;
+
+/// Seconds a tagged guard stays visible through walls
+pub const TAG_LIFETIME: f32 = 20.0;
+
+/// Seconds a shared route stays up
+pub const ROUTE_LIFETIME: f32 = 45.0;
+
+/// Most points a shared route may have
+pub const MAX_ROUTE_POINTS: usize = 32;
+
+/// What the hacker has placed for the infiltrator
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "marker", rename_all = "snake_case")]
+pub enum MarkerKind {
+    Ping { position: Position },
+    Tag { entity_id: EntityId }, // Guard shown to the infiltrator wherever it goes
+    Route { points: Vec<Position> },
+}
+
+impl MarkerKind {
+    pub fn lifetime(&self) -> f32 {
+        match self {
+            MarkerKind::Ping { .. } => PING_LIFETIME,
+            MarkerKind::Tag { .. } => TAG_LIFETIME,
+            MarkerKind::Route { .. } => ROUTE_LIFETIME,
+        }
+    }
+}
+
+/// One marker, gone once the game clock passes `expires_at`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Marker {
+    pub marker_id: u32,
+    #[serde(flatten)]
+    pub kind: MarkerKind,
+    pub expires_at: f32,
+}
+
+/// Every live marker in the level
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Markers {
+    pub markers: Vec<Marker>,
+    #[serde(default)]
+    next_id: u32,
+    #[serde(default)]
+    pub revision: u32, // Bumped on every change, so the server knows when to resend
+}
+
+/// Why a marker couldn't be placed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum MarkerError {
+    UnknownEntity(EntityId),
+    NotTaggable(EntityId), // Only guards and drones can be tagged
+    EmptyRoute,
+    RouteTooLong(usize),
+}
+
+impl fmt::Display for MarkerError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MarkerError::UnknownEntity(id) => write!(f, "no entity {}", id),
+            MarkerError::NotTaggable(id) => write!(f, "entity {} can't be tagged", id),
+            MarkerError::EmptyRoute => write!(f, "a route needs at least one point"),
+            MarkerError::RouteTooLong(len) => {
+                write!(f, "route has {} points, at most {} allowed", len, MAX_ROUTE_POINTS)
+            }
+        }
+    }
+}
+
+impl std::error::Error for MarkerError {}
+
+impl Markers {
+    /// Add a marker at game time `now`; returns its ID and the ID of the marker it replaced
+    /// There is one shared route, and tagging a guard again only refreshes its tag
+    pub fn place(&mut self, kind: MarkerKind, now: f32) -> (u32, Option<u32>) {
+        let replaced = self.markers.iter()
+            .position(|m| match (&m.kind, &kind) {
+                (MarkerKind::Route { .. }, MarkerKind::Route { .. }) => true,
+                (MarkerKind::Tag { entity_id: a }, MarkerKind::Tag { entity_id: b }) => a == b,
+                _ => false,
+            })
+            .map(|index| self.markers.remove(index).marker_id);
+        self.next_id += 1;
+        let expires_at = now + kind.lifetime();
+        self.markers.push(Marker { marker_id: self.next_id, kind, expires_at });
+        self.revision += 1;
+        (self.next_id, replaced)
+    }
+
+    pub fn remove(&mut self, marker_id: u32) -> bool {
+        let before = self.markers.len();
+        self.markers.retain(|m| m.marker_id != marker_id);
+        let removed = self.markers.len() != before;
+        if removed {
+            self.revision += 1;
+        }
+        removed
+    }
+
+    /// Drop markers that have run out, or whose tagged entity is gone; returns their IDs
+    pub fn expire(&mut self, now: f32, exists: impl Fn(EntityId) -> bool) -> Vec<u32> {
+        let (kept, expired): (Vec<Marker>, Vec<Marker>) = std::mem::take(&mut self.markers)
+            .into_iter()
+            .partition(|m| {
+                m.expires_at > now && !matches!(m.kind, MarkerKind::Tag { entity_id } if !exists(entity_id))
+            });
+        self.markers = kept;
+        if !expired.is_empty() {
+            self.revision += 1;
+        }
+        expired.into_iter().map(|m| m.marker_id).collect()
+    }
+
+    pub fn is_tagged(&self, entity_id: EntityId) -> bool {
+        self.markers.iter().any(|m| matches!(m.kind, MarkerKind::Tag { entity_id: id } if id == entity_id))
+    }
+
+    /// Points of the shared route, if there is one
+    pub fn route(&self) -> Option<&[Position]> {
+        self.markers.iter().find_map(|m| match &m.kind {
+            MarkerKind::Route { points } => Some(points.as_slice()),
+            _ => None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_markers_replace_and_expire() {
+        let mut markers = Markers::default();
+        let (ping, _) = markers.place(MarkerKind::Ping { position: Position::new(1.0, 2.0) }, 0.0);
+        let (tag, _) = markers.place(MarkerKind::Tag { entity_id: 4 }, 0.0);
+        let (first_route, replaced) = markers.place(MarkerKind::Route { points: vec![Position::new(0.0, 0.0)] }, 0.0);
+        assert_eq!(replaced, None);
+        let (route, replaced) = markers.place(MarkerKind::Route { points: vec![Position::new(5.0, 5.0)] }, 1.0);
+        assert_eq!(replaced, Some(first_route), "the second route replaces the first");
+        assert_eq!(markers.markers.len(), 3);
+        assert_eq!(markers.route().unwrap()[0].x, 5.0);
+        assert!(markers.is_tagged(4));
+
+        // Tagging the same guard again swaps the old tag for a fresh one
+        let (retag, replaced) = markers.place(MarkerKind::Tag { entity_id: 4 }, 0.0);
+        assert_eq!(replaced, Some(tag));
+        assert_ne!(retag, tag);
+        assert_eq!(markers.markers.len(), 3);
+
+        // The ping runs out first; the tag goes with its guard
+        assert_eq!(markers.expire(PING_LIFETIME + 0.1, |_| true), vec![ping]);
+        assert_eq!(markers.expire(PING_LIFETIME + 0.2, |id| id != 4).len(), 1);
+        assert!(!markers.is_tagged(4));
+        assert!(markers.remove(route));
+        assert!(markers.markers.is_empty());
+    }
+}
@@ -0,0 +1,9 @@
+pub mod instruction;
+pub mod markers;
+pub mod puzzle;
+pub mod trace;
+
+pub use instruction::*;
+pub use markers::*;
+pub use puzzle::*;
+pub use trace::*;
@@ -0,0 +1,258 @@
+use super::instruction::{Instruction, Registers, VmError};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt;
+
+/// Hint shown once the player has made `move_number` moves
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Hint {
+    pub move_number: u32,
+    pub text: String,
+}
+
+/// A register puzzle: reach `goal_state` from `initial_state`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Puzzle {
+    pub name: String,
+    pub description: String,
+    pub difficulty: Option<String>,
+    pub initial_state: Registers,
+    pub goal_state: Registers,
+    pub max_moves: Option<u32>,
+    pub optimal_moves: Option<u32>,
+    pub allowed_instructions: Option<Vec<String>>, // None: anything goes
+    pub hints: Vec<Hint>,
+}
+
+/// A step in a vault puzzle, e.g. {"op": "xor", "targets": ["a", "b"], "result": "c"}
+#[derive(Debug, Clone, Deserialize)]
+struct PuzzleStep {
+    op: String,
+    #[serde(default)]
+    target: Option<String>,
+    #[serde(default)]
+    targets: Vec<String>,
+    #[serde(default)]
+    result: Option<String>,
+}
+
+impl PuzzleStep {
+    fn instructions(&self) -> Result<Vec<Instruction>, VmError> {
+        let mut operands: Vec<&str> = self.target.iter().chain(&self.targets).map(String::as_str).collect();
+        if let Some(result) = &self.result {
+            // XOR of two registers into a third: fold each into the result in turn
+            if self.op.eq_ignore_ascii_case("xor") && operands.len() == 2 {
+                return Ok(operands.iter()
+                    .map(|operand| Instruction::Xor { a: result.clone(), b: operand.to_string() })
+                    .collect());
+            }
+            operands.push(result);
+        }
+        Ok(vec![Instruction::from_parts(&self.op, &operands)?])
+    }
+}
+
+/// Puzzle JSON as found under vm/data/puzzles
+/// Puzzles either give initialState and goalState, or (vaults) a `state` and the `steps`
+/// that scrambled it: the player starts from the scrambled state and has to restore `state`
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PuzzleFile {
+    name: String,
+    #[serde(default)]
+    description: String,
+    #[serde(default)]
+    difficulty: Option<String>,
+    #[serde(default)]
+    initial_state: Option<BTreeMap<String, i64>>,
+    #[serde(default)]
+    goal_state: Option<BTreeMap<String, i64>>,
+    #[serde(default)]
+    state: Option<BTreeMap<String, i64>>,
+    #[serde(default)]
+    steps: Vec<PuzzleStep>,
+    #[serde(default)]
+    max_moves: Option<u32>,
+    #[serde(default)]
+    optimal_moves: Option<u32>,
+    #[serde(default)]
+    allowed_instructions: Option<Vec<String>>,
+    #[serde(default)]
+    hints: Vec<Hint>,
+}
+
+/// Why a puzzle file couldn't be loaded
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PuzzleError {
+    Json(String),
+    MissingState,          // Neither initialState nor state
+    BadStep(usize, VmError),
+}
+
+impl fmt::Display for PuzzleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PuzzleError::Json(e) => write!(f, "invalid puzzle JSON: {}", e),
+            PuzzleError::MissingState => write!(f, "puzzle has no initialState or state"),
+            PuzzleError::BadStep(index, e) => write!(f, "step {}: {}", index, e),
+        }
+    }
+}
+
+impl std::error::Error for PuzzleError {}
+
+/// JSON numbers are truncated to 32 bits, as the ReScript VM's bitwise ops would
+fn registers(values: BTreeMap<String, i64>) -> Registers {
+    values.into_iter().map(|(name, value)| (name, value as i32)).collect()
+}
+
+impl Puzzle {
+    pub fn from_json(json: &str) -> Result<Self, PuzzleError> {
+        let file: PuzzleFile = serde_json::from_str(json).map_err(|e| PuzzleError::Json(e.to_string()))?;
+
+        let (initial_state, goal_state) = match (file.initial_state, file.state) {
+            (Some(initial), _) => {
+                let initial = registers(initial);
+                let goal = file.goal_state.map(registers).unwrap_or_else(|| initial.clone());
+                (initial, goal)
+            }
+            (None, Some(state)) => {
+                let goal = registers(state);
+                let mut scrambled = goal.clone();
+                for (index, step) in file.steps.iter().enumerate() {
+                    for instruction in step.instructions().map_err(|e| PuzzleError::BadStep(index, e))? {
+                        instruction.validate(&scrambled).map_err(|e| PuzzleError::BadStep(index, e))?;
+                        instruction.execute(&mut scrambled);
+                    }
+                }
+                (scrambled, goal)
+            }
+            (None, None) => return Err(PuzzleError::MissingState),
+        };
+
+        Ok(Self {
+            name: file.name,
+            description: file.description,
+            difficulty: file.difficulty,
+            initial_state,
+            goal_state,
+            max_moves: file.max_moves,
+            optimal_moves: file.optimal_moves,
+            allowed_instructions: file.allowed_instructions
+                .map(|ops| ops.into_iter().map(|op| op.to_ascii_uppercase()).collect()),
+            hints: file.hints,
+        })
+    }
+
+    pub fn allows(&self, instruction: &Instruction) -> bool {
+        self.allowed_instructions.as_ref().is_none_or(|ops| ops.iter().any(|op| op == instruction.op()))
+    }
+}
+
+/// A puzzle being worked on: the registers and every move made, so each can be undone
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PuzzleRun {
+    pub puzzle: Puzzle,
+    pub registers: Registers,
+    pub history: Vec<Instruction>,
+}
+
+impl PuzzleRun {
+    pub fn new(puzzle: Puzzle) -> Self {
+        let registers = puzzle.initial_state.clone();
+        Self { puzzle, registers, history: Vec::new() }
+    }
+
+    pub fn moves(&self) -> u32 {
+        self.history.len() as u32
+    }
+
+    pub fn moves_left(&self) -> Option<u32> {
+        self.puzzle.max_moves.map(|max| max.saturating_sub(self.moves()))
+    }
+
+    pub fn is_solved(&self) -> bool {
+        self.registers == self.puzzle.goal_state
+    }
+
+    /// Run one instruction if the puzzle allows it and there are moves left
+    pub fn execute(&mut self, instruction: Instruction) -> Result<(), VmError> {
+        if !self.puzzle.allows(&instruction) {
+            return Err(VmError::NotAllowed(instruction.op().to_string()));
+        }
+        if self.moves_left() == Some(0) {
+            return Err(VmError::OutOfMoves);
+        }
+        instruction.validate(&self.registers)?;
+        instruction.execute(&mut self.registers);
+        self.history.push(instruction);
+        Ok(())
+    }
+
+    /// Reverse the last move; it no longer counts against the limit
+    pub fn undo(&mut self) -> Result<Instruction, VmError> {
+        let instruction = self.history.pop().ok_or(VmError::NothingToUndo)?;
+        instruction.invert(&mut self.registers);
+        Ok(instruction)
+    }
+
+    /// Undo everything back to the puzzle's starting registers
+    pub fn reset(&mut self) {
+        while self.undo().is_ok() {}
+    }
+
+    /// Hints unlocked by the moves made so far
+    pub fn hints(&self) -> impl Iterator<Item = &Hint> {
+        let moves = self.moves();
+        self.puzzle.hints.iter().filter(move |h| h.move_number <= moves)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PUZZLE_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../../vm/data/puzzles");
+
+    #[test]
+    fn test_every_shipped_puzzle_loads() {
+        let mut loaded = 0;
+        for entry in std::fs::read_dir(PUZZLE_DIR).unwrap() {
+            let path = entry.unwrap().path();
+            let json = std::fs::read_to_string(&path).unwrap();
+            let puzzle = Puzzle::from_json(&json).unwrap_or_else(|e| panic!("{}: {}", path.display(), e));
+            assert!(!puzzle.initial_state.is_empty(), "{}", path.display());
+            loaded += 1;
+        }
+        assert!(loaded > 20);
+    }
+
+    #[test]
+    fn test_solve_and_undo() {
+        let json = std::fs::read_to_string(format!("{}/beginner_01_simple_add.json", PUZZLE_DIR)).unwrap();
+        let mut run = PuzzleRun::new(Puzzle::from_json(&json).unwrap());
+
+        assert_eq!(run.execute(Instruction::parse("XOR x y").unwrap()), Err(VmError::NotAllowed("XOR".into())));
+        run.execute(Instruction::parse("SUB x y").unwrap()).unwrap();
+        assert!(!run.is_solved());
+        run.undo().unwrap();
+        run.execute(Instruction::parse("add x y").unwrap()).unwrap();
+        assert!(run.is_solved());
+        assert_eq!(run.moves(), 1);
+    }
+
+    #[test]
+    fn test_vault_starts_scrambled() {
+        let json = std::fs::read_to_string(format!("{}/vault_7.json", PUZZLE_DIR)).unwrap();
+        let mut run = PuzzleRun::new(Puzzle::from_json(&json).unwrap());
+        assert!(!run.is_solved());
+
+        // Undo the vault's steps in reverse order
+        for text in ["SWAP bit0 bit1", "FLIP bit1", "FLIP bit0"] {
+            run.execute(Instruction::parse(text).unwrap()).unwrap();
+        }
+        assert!(run.is_solved());
+    }
+}
@@ -0,0 +1,124 @@
+use crate::game::types::EntityId;
+use crate::security::network::HackCommand;
+use serde::{Deserialize, Serialize};
+
+/// A full meter means the hacker has been traced
+pub const TRACE_MAX: f32 = 100.0;
+
+/// Trace lost per second while the hacker lies low
+pub const TRACE_DECAY: f32 = 2.0;
+
+/// From here up, compromised devices are locked again
+pub const RELOCK_THRESHOLD: f32 = 50.0;
+
+/// From here up, ICE hunts the hacker's connection
+pub const ICE_THRESHOLD: f32 = 80.0;
+
+/// Seconds the ICE takes to move one link along the network
+pub const ICE_HOP_INTERVAL: f32 = 3.0;
+
+/// How far the counter-intrusion response has gone, in escalating order
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum TraceStage {
+    Clear,
+    Relock, // Compromised devices are being locked again
+    Hunted, // ICE is moving through the network towards the hacker
+    Traced, // The alarm system has been told
+}
+
+impl TraceStage {
+    pub fn of(level: f32) -> Self {
+        if level >= TRACE_MAX {
+            TraceStage::Traced
+        } else if level >= ICE_THRESHOLD {
+            TraceStage::Hunted
+        } else if level >= RELOCK_THRESHOLD {
+            TraceStage::Relock
+        } else {
+            TraceStage::Clear
+        }
+    }
+}
+
+/// Counter-intrusion program sitting on one device of the network
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Ice {
+    pub device_id: EntityId,
+    pub hop_timer: f32, // Seconds until the next move
+}
+
+/// How close the security system is to tracing one hacker
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TraceMeter {
+    pub hacker_id: EntityId,
+    pub level: f32, // 0 to TRACE_MAX
+    pub stage: TraceStage,
+    pub connection: Option<EntityId>, // Device the hacker last worked through, which the ICE hunts
+    pub ice: Option<Ice>,
+}
+
+impl TraceMeter {
+    pub fn new(hacker_id: EntityId) -> Self {
+        Self { hacker_id, level: 0.0, stage: TraceStage::Clear, connection: None, ice: None }
+    }
+
+    /// Add trace for an action through `device_id`; returns the stages newly crossed, lowest first
+    pub fn raise(&mut self, amount: f32, device_id: EntityId) -> Vec<TraceStage> {
+        self.connection = Some(device_id);
+        self.set_level(self.level + amount)
+    }
+
+    /// Set the meter outright; returns the stages newly crossed on the way up
+    pub fn set_level(&mut self, level: f32) -> Vec<TraceStage> {
+        let previous = self.stage;
+        self.level = level.clamp(0.0, TRACE_MAX);
+        self.stage = TraceStage::of(self.level);
+        [TraceStage::Relock, TraceStage::Hunted, TraceStage::Traced]
+            .into_iter()
+            .filter(|stage| *stage > previous && *stage <= self.stage)
+            .collect()
+    }
+
+    /// Let the meter wind down; it holds while ICE is on the hacker's trail
+    pub fn decay(&mut self, delta_time: f32) {
+        if self.ice.is_none() {
+            self.set_level(self.level - TRACE_DECAY * delta_time);
+        }
+    }
+}
+
+impl HackCommand {
+    /// Trace the command adds; loud actions cost more than quiet ones
+    pub fn trace_cost(&self) -> f32 {
+        match self {
+            HackCommand::Execute { .. } | HackCommand::Undo { .. } => 1.0,
+            HackCommand::LoopFeed { .. } | HackCommand::RestoreFeed { .. } => 5.0,
+            HackCommand::CutLights { .. } | HackCommand::RestoreLights { .. } => 5.0,
+            HackCommand::Compromise { .. } => 6.0,
+            HackCommand::UnlockDoor { .. } => 10.0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stages_rise_and_decay() {
+        let mut meter = TraceMeter::new(1);
+        assert!(meter.raise(40.0, 5).is_empty());
+        assert_eq!(meter.raise(45.0, 6), vec![TraceStage::Relock, TraceStage::Hunted]);
+        assert_eq!(meter.connection, Some(6));
+        assert!(meter.raise(1.0, 6).is_empty(), "each stage fires once per crossing");
+
+        meter.ice = Some(Ice { device_id: 5, hop_timer: ICE_HOP_INTERVAL });
+        meter.decay(5.0);
+        assert_eq!(meter.level, 86.0, "no cooling off with ICE closing in");
+        meter.ice = None;
+        meter.decay(5.0);
+        assert_eq!(meter.stage, TraceStage::Relock);
+        assert_eq!(meter.set_level(TRACE_MAX + 10.0), vec![TraceStage::Hunted, TraceStage::Traced]);
+        assert_eq!(meter.level, TRACE_MAX);
+    }
+}
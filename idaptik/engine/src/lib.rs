@@ -1,8 +1,22 @@
+pub mod ai;
 pub mod game;
+pub mod hacking;
+pub mod mission;
+pub mod navigation;
+pub mod net;
+pub mod replay;
+pub mod security;
 pub mod stealth;
 pub mod wasm;
 
 // Re-export main types for convenience
-pub use game::{GameSystem, PlayerInput, Entity, World, Position};
+pub use game::{GameSystem, PlayerInput, Entity, EntityType, PlayerRole, World, Position};
+
+// Math types used in the public API, for crates on a different glam version
+pub use glam;
+pub use ai::*;
+pub use hacking::*;
+pub use mission::*;
+pub use security::*;
 pub use stealth::*;
 pub use wasm::WasmGame;
@@ -0,0 +1,5 @@
+pub mod objective;
+pub mod tracker;
+
+pub use objective::*;
+pub use tracker::*;
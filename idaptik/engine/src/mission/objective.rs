@@ -0,0 +1,73 @@
+use crate::game::types::*;
+use serde::{Deserialize, Serialize};
+
+/// How close a player must get to an item to pick it up
+pub const PICKUP_RANGE: f32 = 1.5;
+
+/// What has to happen for an objective to count as done
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub enum ObjectiveKind {
+    ReachZone { position: Position, radius: f32 },
+    StealItem { item_id: EntityId },
+    HackTerminal { terminal_id: EntityId },
+    Extract { position: Position, radius: f32 }, // Only counts once every other required goal is done
+}
+
+/// Progress of one objective
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ObjectiveState {
+    Pending, // Waiting on earlier objectives in an ordered mission
+    Active,
+    Complete,
+}
+
+/// One mission goal
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Objective {
+    pub objective_id: u32,
+    pub kind: ObjectiveKind,
+    pub optional: bool, // Bonus goal: never required for success
+    pub state: ObjectiveState,
+    pub completed_by: Option<EntityId>,
+}
+
+impl Objective {
+    pub fn new(objective_id: u32, kind: ObjectiveKind, optional: bool) -> Self {
+        Self {
+            objective_id,
+            kind,
+            optional,
+            state: ObjectiveState::Pending,
+            completed_by: None,
+        }
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.state == ObjectiveState::Complete
+    }
+
+    /// Check whether the objective's condition is met right now
+    /// Returns the player responsible (None for remote hacks) once it is
+    pub fn check(&self, world: &World, hacked_terminals: &[EntityId]) -> Option<Option<EntityId>> {
+        match self.kind {
+            ObjectiveKind::ReachZone { position, radius }
+            | ObjectiveKind::Extract { position, radius } => {
+                player_within(world, &position, radius).map(Some)
+            }
+            ObjectiveKind::StealItem { item_id } => {
+                let item = world.get_entity(item_id)?;
+                player_within(world, &item.position, PICKUP_RANGE).map(Some)
+            }
+            ObjectiveKind::HackTerminal { terminal_id } => {
+                hacked_terminals.contains(&terminal_id).then_some(None)
+            }
+        }
+    }
+}
+
+/// First player standing within `radius` of a point
+fn player_within(world: &World, position: &Position, radius: f32) -> Option<EntityId> {
+    world.entities.iter()
+        .find(|e| e.entity_type == EntityType::Player && e.position.distance_2d(position) <= radius)
+        .map(|e| e.id)
+}
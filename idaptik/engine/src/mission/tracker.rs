@@ -0,0 +1,227 @@
+use crate::game::system::GameEvent;
+use crate::game::types::*;
+use crate::mission::objective::*;
+use serde::{Deserialize, Serialize};
+
+/// A hunting guard this close to its target has caught them
+pub const CATCH_RANGE: f32 = 1.5;
+
+/// Whether required objectives unlock one after another or all at once
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum MissionOrder {
+    Ordered,
+    Parallel,
+}
+
+/// Why a mission was lost
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum FailureReason {
+    Caught { player_id: EntityId, guard_id: EntityId },
+    TimeExpired,
+}
+
+/// Overall mission outcome
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum MissionStatus {
+    InProgress,
+    Succeeded,
+    Failed { reason: FailureReason },
+}
+
+/// Objectives, failure conditions and progress for the current level
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Mission {
+    pub order: MissionOrder,
+    pub objectives: Vec<Objective>,
+    pub time_limit: Option<f32>, // Seconds
+    pub fail_on_caught: bool,
+    pub elapsed: f32,
+    pub status: MissionStatus,
+    pub hacked_terminals: Vec<EntityId>,
+    pub next_objective_id: u32,
+}
+
+impl Mission {
+    pub fn new(order: MissionOrder, time_limit: Option<f32>, fail_on_caught: bool) -> Self {
+        Self {
+            order,
+            objectives: Vec::new(),
+            time_limit,
+            fail_on_caught,
+            elapsed: 0.0,
+            status: MissionStatus::InProgress,
+            hacked_terminals: Vec::new(),
+            next_objective_id: 1,
+        }
+    }
+
+    /// Append an objective and return its ID
+    pub fn add_objective(&mut self, kind: ObjectiveKind, optional: bool) -> u32 {
+        let objective_id = self.next_objective_id;
+        self.next_objective_id += 1;
+        self.objectives.push(Objective::new(objective_id, kind, optional));
+        objective_id
+    }
+
+    pub fn get_objective(&self, objective_id: u32) -> Option<&Objective> {
+        self.objectives.iter().find(|o| o.objective_id == objective_id)
+    }
+
+    /// Every required objective is done (and there is at least one)
+    pub fn required_complete(&self) -> bool {
+        let mut required = self.objectives.iter().filter(|o| !o.optional).peekable();
+        required.peek().is_some() && required.all(|o| o.is_complete())
+    }
+
+    pub fn bonus_completed(&self) -> u32 {
+        self.objectives.iter().filter(|o| o.optional && o.is_complete()).count() as u32
+    }
+
+    pub fn is_over(&self) -> bool {
+        self.status != MissionStatus::InProgress
+    }
+
+    /// Activate pending objectives that are now available
+    fn activate(&mut self, events: &mut Vec<GameEvent>) {
+        let mut blocked = false;
+        for objective in &mut self.objectives {
+            if objective.is_complete() {
+                continue;
+            }
+
+            // In ordered missions only the first unfinished required goal is live
+            let available = objective.optional || self.order == MissionOrder::Parallel || !blocked;
+            if !objective.optional {
+                blocked = true;
+            }
+
+            if available && objective.state == ObjectiveState::Pending {
+                objective.state = ObjectiveState::Active;
+                events.push(GameEvent::ObjectiveActivated { objective_id: objective.objective_id });
+            }
+        }
+    }
+
+    /// Extraction waits on every other required objective
+    fn extraction_ready(&self, objective_id: u32) -> bool {
+        self.objectives.iter()
+            .filter(|o| o.objective_id != objective_id && !o.optional)
+            .filter(|o| !matches!(o.kind, ObjectiveKind::Extract { .. }))
+            .all(|o| o.is_complete())
+    }
+}
+
+/// Advance a mission: activate and complete objectives, then check for success or failure
+pub fn step_mission(mission: &mut Mission, world: &World, delta_time: f32) -> Vec<GameEvent> {
+    let mut events = Vec::new();
+    if mission.is_over() {
+        return events;
+    }
+
+    mission.elapsed += delta_time;
+
+    // Completing one ordered objective can unlock (and complete) the next in the same tick
+    loop {
+        mission.activate(&mut events);
+
+        let completed = mission.objectives.iter().enumerate()
+            .filter(|(_, o)| o.state == ObjectiveState::Active)
+            .filter(|(_, o)| {
+                !matches!(o.kind, ObjectiveKind::Extract { .. }) || mission.extraction_ready(o.objective_id)
+            })
+            .find_map(|(i, o)| o.check(world, &mission.hacked_terminals).map(|player| (i, player)));
+
+        let Some((index, player_id)) = completed else {
+            break;
+        };
+
+        let objective = &mut mission.objectives[index];
+        objective.state = ObjectiveState::Complete;
+        objective.completed_by = player_id;
+        events.push(GameEvent::ObjectiveComplete { objective_id: objective.objective_id, player_id });
+    }
+
+    if mission.fail_on_caught {
+        if let Some(reason) = caught(world) {
+            mission.status = MissionStatus::Failed { reason };
+            events.push(GameEvent::MissionFailed { reason });
+            return events;
+        }
+    }
+
+    if mission.required_complete() {
+        mission.status = MissionStatus::Succeeded;
+        events.push(GameEvent::MissionComplete { bonus_completed: mission.bonus_completed() });
+    } else if mission.time_limit.is_some_and(|limit| mission.elapsed >= limit) {
+        let reason = FailureReason::TimeExpired;
+        mission.status = MissionStatus::Failed { reason };
+        events.push(GameEvent::MissionFailed { reason });
+    }
+
+    events
+}
+
+/// A hunting guard within reach of the player it is chasing
+fn caught(world: &World) -> Option<FailureReason> {
+    world.guard_minds.iter().find_map(|mind| {
+        let guard = world.get_entity(mind.guard_id)?;
+        if guard.state != ActorState::Hunting {
+            return None;
+        }
+        let target = world.get_entity(mind.target_id?)?;
+        (target.entity_type == EntityType::Player
+            && guard.position.distance_2d(&target.position) <= CATCH_RANGE)
+            .then_some(FailureReason::Caught { player_id: target.id, guard_id: guard.id })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ordered_objectives_unlock_in_turn() {
+        let mut world = World::new(50.0, 50.0);
+        world.add_entity(Entity::new_player(1, Position::new(10.0, 10.0)));
+
+        let mut mission = Mission::new(MissionOrder::Ordered, None, true);
+        let hack = mission.add_objective(ObjectiveKind::HackTerminal { terminal_id: 5 }, false);
+        let extract = mission.add_objective(
+            ObjectiveKind::Extract { position: Position::new(10.0, 10.0), radius: 2.0 },
+            false,
+        );
+        let bonus = mission.add_objective(
+            ObjectiveKind::ReachZone { position: Position::new(10.0, 10.0), radius: 2.0 },
+            true,
+        );
+
+        // Bonus goals are live straight away; extraction waits for the hack
+        let events = step_mission(&mut mission, &world, 0.1);
+        assert!(events.iter().any(|e| matches!(
+            e,
+            GameEvent::ObjectiveComplete { objective_id, .. } if *objective_id == bonus
+        )));
+        assert_eq!(mission.get_objective(hack).unwrap().state, ObjectiveState::Active);
+        assert_eq!(mission.get_objective(extract).unwrap().state, ObjectiveState::Pending);
+
+        mission.hacked_terminals.push(5);
+        let events = step_mission(&mut mission, &world, 0.1);
+        assert!(mission.get_objective(hack).unwrap().is_complete());
+        assert!(mission.get_objective(extract).unwrap().is_complete());
+        assert!(events.iter().any(|e| matches!(e, GameEvent::MissionComplete { bonus_completed: 1 })));
+        assert_eq!(mission.status, MissionStatus::Succeeded);
+    }
+
+    #[test]
+    fn test_time_limit_fails_mission() {
+        let world = World::new(50.0, 50.0);
+        let mut mission = Mission::new(MissionOrder::Parallel, Some(1.0), false);
+        mission.add_objective(ObjectiveKind::HackTerminal { terminal_id: 5 }, false);
+
+        step_mission(&mut mission, &world, 0.5);
+        assert_eq!(mission.status, MissionStatus::InProgress);
+        let events = step_mission(&mut mission, &world, 0.5);
+        assert_eq!(mission.status, MissionStatus::Failed { reason: FailureReason::TimeExpired });
+        assert_eq!(events.len(), 1);
+    }
+}
@@ -0,0 +1,247 @@
+use crate::game::types::*;
+
+/// Clearance kept between NPC centres and obstacle edges (matches physics push-out)
+pub const AGENT_RADIUS: f32 = 0.5;
+
+/// Smallest grid cell size used when baking
+pub const MIN_CELL_SIZE: f32 = 1.0;
+
+/// Upper bound on cells along the longest world axis
+pub const MAX_CELLS_PER_AXIS: usize = 256;
+
+/// Grid cell coordinate (column, row)
+pub type Cell = (usize, usize);
+
+/// Walkability grid baked from world bounds, obstacles and shut doors
+#[derive(Debug, Clone)]
+pub struct NavGrid {
+    pub cell_size: f32,
+    pub cols: usize,
+    pub rows: usize,
+    pub signature: u64,
+    blocked: Vec<bool>,
+}
+
+impl NavGrid {
+    /// Bake a grid for the world, sizing cells to keep the grid bounded
+    pub fn bake(world: &World) -> Self {
+        let longest = world.width.max(world.height);
+        let cell_size = (longest / MAX_CELLS_PER_AXIS as f32).max(MIN_CELL_SIZE);
+        Self::bake_with_cell_size(world, cell_size)
+    }
+
+    pub fn bake_with_cell_size(world: &World, cell_size: f32) -> Self {
+        let cols = ((world.width / cell_size).ceil() as usize).max(1);
+        let rows = ((world.height / cell_size).ceil() as usize).max(1);
+        let mut grid = Self {
+            cell_size,
+            cols,
+            rows,
+            signature: nav_signature(world),
+            blocked: vec![false; cols * rows],
+        };
+
+        // Mark every cell whose centre is too close to an obstacle or shut door
+        for obstacle in world.blockers() {
+            let reach = obstacle.radius + AGENT_RADIUS;
+            let min = grid.clamp_cell(obstacle.position.x - reach, obstacle.position.y - reach);
+            let max = grid.clamp_cell(obstacle.position.x + reach, obstacle.position.y + reach);
+
+            for row in min.1..=max.1 {
+                for col in min.0..=max.0 {
+                    let centre = grid.cell_center((col, row));
+                    if centre.distance_2d(&obstacle.position) < reach {
+                        grid.blocked[row * cols + col] = true;
+                    }
+                }
+            }
+        }
+
+        grid
+    }
+
+    fn clamp_cell(&self, x: f32, y: f32) -> Cell {
+        let col = (x / self.cell_size).floor().clamp(0.0, (self.cols - 1) as f32) as usize;
+        let row = (y / self.cell_size).floor().clamp(0.0, (self.rows - 1) as f32) as usize;
+        (col, row)
+    }
+
+    /// Cell containing a position, if it lies within the world
+    pub fn cell_of(&self, position: &Position) -> Option<Cell> {
+        if position.x < 0.0 || position.y < 0.0 {
+            return None;
+        }
+        let col = (position.x / self.cell_size) as usize;
+        let row = (position.y / self.cell_size) as usize;
+        (col < self.cols && row < self.rows).then_some((col, row))
+    }
+
+    pub fn cell_center(&self, cell: Cell) -> Position {
+        Position::new(
+            (cell.0 as f32 + 0.5) * self.cell_size,
+            (cell.1 as f32 + 0.5) * self.cell_size,
+        )
+    }
+
+    pub fn is_walkable(&self, cell: Cell) -> bool {
+        cell.0 < self.cols && cell.1 < self.rows && !self.blocked[cell.1 * self.cols + cell.0]
+    }
+
+    /// Closest walkable cell to a position (searching outwards ring by ring)
+    pub fn nearest_walkable(&self, position: &Position) -> Option<Cell> {
+        let origin = self.clamp_cell(position.x, position.y);
+        if self.is_walkable(origin) {
+            return Some(origin);
+        }
+
+        let max_ring = self.cols.max(self.rows);
+        for ring in 1..max_ring {
+            let mut best: Option<(f32, Cell)> = None;
+            let min_col = origin.0.saturating_sub(ring);
+            let min_row = origin.1.saturating_sub(ring);
+            let max_col = (origin.0 + ring).min(self.cols - 1);
+            let max_row = (origin.1 + ring).min(self.rows - 1);
+
+            for row in min_row..=max_row {
+                for col in min_col..=max_col {
+                    let on_ring = col.abs_diff(origin.0) == ring || row.abs_diff(origin.1) == ring;
+                    if !on_ring || !self.is_walkable((col, row)) {
+                        continue;
+                    }
+                    let dist = self.cell_center((col, row)).distance_2d(position);
+                    if best.is_none_or(|(d, _)| dist < d) {
+                        best = Some((dist, (col, row)));
+                    }
+                }
+            }
+
+            if let Some((_, cell)) = best {
+                return Some(cell);
+            }
+        }
+
+        None
+    }
+
+    /// Whether an agent can walk in a straight line between two points
+    pub fn segment_clear(&self, from: &Position, to: &Position) -> bool {
+        let distance = from.distance_2d(to);
+        let steps = ((distance / (self.cell_size * 0.5)).ceil() as usize).max(1);
+
+        (0..=steps).all(|i| {
+            let t = i as f32 / steps as f32;
+            let point = Position::new(
+                from.x + (to.x - from.x) * t,
+                from.y + (to.y - from.y) * t,
+            );
+            self.cell_of(&point).is_some_and(|cell| self.is_walkable(cell))
+        })
+    }
+}
+
+/// Fingerprint of everything the grid is baked from
+pub fn nav_signature(world: &World) -> u64 {
+    // FNV-1a over the raw float bits
+    let mut hash: u64 = 0xcbf29ce484222325;
+    let mut mix = |value: f32| {
+        for byte in value.to_bits().to_le_bytes() {
+            hash ^= byte as u64;
+            hash = hash.wrapping_mul(0x100000001b3);
+        }
+    };
+
+    mix(world.width);
+    mix(world.height);
+    for obstacle in world.blockers() {
+        mix(obstacle.position.x);
+        mix(obstacle.position.y);
+        mix(obstacle.radius);
+        for flag in [obstacle.blocks_vision, obstacle.blocks_sound, obstacle.provides_cover] {
+            mix(f32::from(u8::from(flag)));
+        }
+    }
+
+    hash
+}
+
+/// Keeps a baked grid and the blockers it was baked from, rebuilding both only when the world layout changes
+#[derive(Debug, Clone, Default)]
+pub struct NavCache {
+    grid: Option<NavGrid>,
+    blockers: Vec<Obstacle>,
+}
+
+impl NavCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Re-bake if the obstacles, doors or bounds changed since the last bake
+    /// Returns true if a bake happened
+    pub fn refresh(&mut self, world: &World) -> bool {
+        let signature = nav_signature(world);
+        if self.grid.as_ref().is_some_and(|g| g.signature == signature) {
+            return false;
+        }
+        self.grid = Some(NavGrid::bake(world));
+        self.blockers.clear();
+        self.blockers.extend(world.blockers());
+        true
+    }
+
+    pub fn grid(&self) -> Option<&NavGrid> {
+        self.grid.as_ref()
+    }
+
+    /// Obstacles and shut doors as of the last refresh
+    pub fn blockers(&self) -> &[Obstacle] {
+        &self.blockers
+    }
+
+    /// Drop the baked grid so the next refresh re-bakes
+    pub fn invalidate(&mut self) {
+        self.grid = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn world_with_obstacle() -> World {
+        let mut world = World::new(20.0, 20.0);
+        world.obstacles.push(Obstacle {
+            position: Position::new(10.0, 10.0),
+            radius: 3.0,
+            blocks_vision: true,
+            blocks_sound: true,
+            provides_cover: true,
+        });
+        world
+    }
+
+    #[test]
+    fn test_bake_blocks_obstacle_cells() {
+        let grid = NavGrid::bake(&world_with_obstacle());
+        assert!(!grid.is_walkable(grid.cell_of(&Position::new(10.0, 10.0)).unwrap()));
+        assert!(grid.is_walkable(grid.cell_of(&Position::new(1.0, 1.0)).unwrap()));
+    }
+
+    #[test]
+    fn test_cache_rebakes_on_change() {
+        let mut world = world_with_obstacle();
+        let mut cache = NavCache::new();
+
+        assert!(cache.refresh(&world));
+        assert!(!cache.refresh(&world));
+
+        world.obstacles[0].position.x = 5.0;
+        assert!(cache.refresh(&world));
+        assert_eq!(cache.blockers()[0].position.x, 5.0);
+
+        // Flags matter to sight and sound even though the grid doesn't change
+        world.obstacles[0].blocks_vision = false;
+        assert!(cache.refresh(&world));
+        assert!(!cache.blockers()[0].blocks_vision);
+    }
+}
@@ -0,0 +1,5 @@
+pub mod grid;
+pub mod pathfinding;
+
+pub use grid::*;
+pub use pathfinding::*;
@@ -0,0 +1,196 @@
+use crate::game::types::*;
+use crate::navigation::grid::*;
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+/// Integer step costs keep the search exact and deterministic
+const STRAIGHT_COST: u32 = 10;
+const DIAGONAL_COST: u32 = 14;
+
+const NEIGHBOURS: [(isize, isize); 8] = [
+    (1, 0), (-1, 0), (0, 1), (0, -1),
+    (1, 1), (1, -1), (-1, 1), (-1, -1),
+];
+
+/// Octile distance heuristic between two cells
+fn heuristic(a: Cell, b: Cell) -> u32 {
+    let dx = a.0.abs_diff(b.0) as u32;
+    let dy = a.1.abs_diff(b.1) as u32;
+    STRAIGHT_COST * dx.max(dy) + (DIAGONAL_COST - STRAIGHT_COST) * dx.min(dy)
+}
+
+/// A* over the grid, returning the cells from start to goal (inclusive)
+pub fn find_cell_path(grid: &NavGrid, start: Cell, goal: Cell) -> Option<Vec<Cell>> {
+    if !grid.is_walkable(start) || !grid.is_walkable(goal) {
+        return None;
+    }
+
+    let index = |cell: Cell| cell.1 * grid.cols + cell.0;
+    let mut cost = vec![u32::MAX; grid.cols * grid.rows];
+    let mut came_from: Vec<Option<Cell>> = vec![None; grid.cols * grid.rows];
+    let mut open = BinaryHeap::new();
+
+    cost[index(start)] = 0;
+    open.push(Reverse((heuristic(start, goal), 0u32, start)));
+
+    while let Some(Reverse((_, g, cell))) = open.pop() {
+        if cell == goal {
+            let mut path = vec![goal];
+            let mut current = goal;
+            while let Some(prev) = came_from[index(current)] {
+                path.push(prev);
+                current = prev;
+            }
+            path.reverse();
+            return Some(path);
+        }
+
+        // Skip stale heap entries
+        if g > cost[index(cell)] {
+            continue;
+        }
+
+        for (dx, dy) in NEIGHBOURS {
+            let (Some(col), Some(row)) = (cell.0.checked_add_signed(dx), cell.1.checked_add_signed(dy)) else {
+                continue;
+            };
+            let next = (col, row);
+            if !grid.is_walkable(next) {
+                continue;
+            }
+
+            // No cutting corners past blocked cells
+            let diagonal = dx != 0 && dy != 0;
+            if diagonal && (!grid.is_walkable((col, cell.1)) || !grid.is_walkable((cell.0, row))) {
+                continue;
+            }
+
+            let step = if diagonal { DIAGONAL_COST } else { STRAIGHT_COST };
+            let next_cost = g + step;
+            if next_cost < cost[index(next)] {
+                cost[index(next)] = next_cost;
+                came_from[index(next)] = Some(cell);
+                open.push(Reverse((next_cost + heuristic(next, goal), next_cost, next)));
+            }
+        }
+    }
+
+    None
+}
+
+/// Drop intermediate points that can be skipped with a straight walk
+pub fn smooth_path(grid: &NavGrid, points: &[Position]) -> Vec<Position> {
+    if points.len() <= 2 {
+        return points.to_vec();
+    }
+
+    let mut smoothed = vec![points[0]];
+    let mut anchor = 0;
+
+    while anchor < points.len() - 1 {
+        // Furthest point visible from the anchor
+        let mut next = anchor + 1;
+        for candidate in (anchor + 2..points.len()).rev() {
+            if grid.segment_clear(&points[anchor], &points[candidate]) {
+                next = candidate;
+                break;
+            }
+        }
+        smoothed.push(points[next]);
+        anchor = next;
+    }
+
+    smoothed
+}
+
+/// Find a smoothed walking path between two world positions
+/// The returned waypoints exclude the start and end at (or near) the goal
+pub fn find_path(grid: &NavGrid, from: &Position, to: &Position) -> Option<Vec<Position>> {
+    let start = grid.nearest_walkable(from)?;
+    let goal = grid.nearest_walkable(to)?;
+
+    // Direct line needs no search
+    if grid.segment_clear(from, to) {
+        return Some(vec![*to]);
+    }
+
+    let cells = find_cell_path(grid, start, goal)?;
+
+    let goal_reachable = grid.cell_of(to) == Some(goal);
+    let mut points: Vec<Position> = Vec::with_capacity(cells.len() + 1);
+    points.push(*from);
+    points.extend(cells.iter().skip(1).map(|&c| grid.cell_center(c)));
+    if goal_reachable {
+        // Finish on the exact goal rather than its cell centre
+        points.pop();
+        points.push(*to);
+    }
+
+    let mut path = smooth_path(grid, &points);
+    path.remove(0);
+    if path.is_empty() {
+        path.push(grid.cell_center(goal));
+    }
+    Some(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn wall_world() -> World {
+        // A column of obstacles with a gap at the top
+        let mut world = World::new(30.0, 30.0);
+        for i in 0..5 {
+            world.obstacles.push(Obstacle {
+                position: Position::new(15.0, 2.0 + i as f32 * 4.0),
+                radius: 2.5,
+                blocks_vision: true,
+                blocks_sound: true,
+                provides_cover: true,
+            });
+        }
+        world
+    }
+
+    #[test]
+    fn test_direct_path_when_clear() {
+        let grid = NavGrid::bake(&World::new(30.0, 30.0));
+        let path = find_path(&grid, &Position::new(2.0, 2.0), &Position::new(20.0, 20.0)).unwrap();
+        assert_eq!(path.len(), 1);
+    }
+
+    #[test]
+    fn test_path_goes_around_obstacles() {
+        let world = wall_world();
+        let grid = NavGrid::bake(&world);
+        let from = Position::new(5.0, 5.0);
+        let to = Position::new(25.0, 5.0);
+
+        let path = find_path(&grid, &from, &to).unwrap();
+        assert!(path.len() > 1);
+        assert!(path.iter().any(|p| p.y > 20.0)); // Detours through the gap
+
+        // Every leg of the smoothed path is walkable
+        let mut prev = from;
+        for point in &path {
+            assert!(grid.segment_clear(&prev, point));
+            prev = *point;
+        }
+        assert!(prev.distance_2d(&to) < 0.01);
+    }
+
+    #[test]
+    fn test_no_path_when_sealed() {
+        let mut world = wall_world();
+        world.obstacles.push(Obstacle {
+            position: Position::new(15.0, 24.0),
+            radius: 8.0,
+            blocks_vision: true,
+            blocks_sound: true,
+            provides_cover: true,
+        });
+        let grid = NavGrid::bake(&world);
+        assert!(find_path(&grid, &Position::new(5.0, 5.0), &Position::new(25.0, 5.0)).is_none());
+    }
+}
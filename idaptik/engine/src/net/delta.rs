@@ -0,0 +1,187 @@
+use crate::net::snapshot::*;
+use std::collections::VecDeque;
+
+/// Snapshots kept on each side for use as delta baselines
+pub const SNAPSHOT_HISTORY: usize = 64;
+
+/// Server side: diffs each new snapshot against the last one the client acknowledged
+#[derive(Debug, Clone, Default)]
+pub struct DeltaEncoder {
+    history: VecDeque<WorldSnapshot>,
+    acked: Option<u64>,
+}
+
+impl DeltaEncoder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Delta for `snapshot`; a full snapshot until the client acknowledges one
+    /// (or once its acknowledged baseline has aged out of the history)
+    pub fn encode(&mut self, snapshot: WorldSnapshot) -> SnapshotDelta {
+        let baseline = self.acked.and_then(|tick| self.history.iter().find(|s| s.tick == tick));
+        let delta = snapshot.diff(baseline);
+
+        if self.history.back().is_some_and(|s| s.tick == snapshot.tick) {
+            self.history.pop_back();
+        }
+        self.history.push_back(snapshot);
+        while self.history.len() > SNAPSHOT_HISTORY {
+            self.history.pop_front();
+        }
+
+        delta
+    }
+
+    /// The client has applied the snapshot for `tick`
+    pub fn acknowledge(&mut self, tick: u64) {
+        if self.acked.is_some_and(|acked| acked >= tick) {
+            return;
+        }
+        if self.history.iter().any(|s| s.tick == tick) {
+            self.acked = Some(tick);
+        }
+    }
+
+    pub fn acked(&self) -> Option<u64> {
+        self.acked
+    }
+
+    /// Forget everything; the next delta is a full snapshot
+    pub fn reset(&mut self) {
+        self.history.clear();
+        self.acked = None;
+    }
+}
+
+/// Client side: rebuilds snapshots from deltas against recently received ones
+#[derive(Debug, Clone, Default)]
+pub struct DeltaDecoder {
+    history: VecDeque<WorldSnapshot>,
+}
+
+impl DeltaDecoder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Apply a delta, returning the snapshot it describes; acknowledge its tick afterwards
+    pub fn apply(&mut self, delta: &SnapshotDelta) -> Result<&WorldSnapshot, DeltaError> {
+        if self.latest().is_some_and(|latest| latest.tick >= delta.tick) {
+            return Err(DeltaError::Stale(delta.tick));
+        }
+
+        let baseline = match delta.baseline {
+            Some(tick) => Some(
+                self.history.iter()
+                    .find(|s| s.tick == tick)
+                    .ok_or(DeltaError::MissingBaseline(tick))?,
+            ),
+            None => None,
+        };
+        let snapshot = WorldSnapshot::from_delta(baseline, delta)?;
+
+        self.history.push_back(snapshot);
+        while self.history.len() > SNAPSHOT_HISTORY {
+            self.history.pop_front();
+        }
+        Ok(self.history.back().expect("just pushed"))
+    }
+
+    pub fn latest(&self) -> Option<&WorldSnapshot> {
+        self.history.back()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::system::GameSystem;
+
+    #[test]
+    fn test_deltas_round_trip_through_acks() {
+        let mut game = GameSystem::new(100.0, 100.0);
+        let player_id = game.spawn_player(10.0, 10.0);
+        game.spawn_guard(50.0, 50.0);
+
+        let mut encoder = DeltaEncoder::new();
+        let mut decoder = DeltaDecoder::new();
+
+        let full = encoder.encode(WorldSnapshot::capture(&game.world, 1));
+        assert_eq!(full.baseline, None);
+        decoder.apply(&full).unwrap();
+        encoder.acknowledge(1);
+
+        game.world.get_entity_mut(player_id).unwrap().position.x = 12.345;
+        let guard = game.spawn_guard(70.0, 70.0);
+        let delta = encoder.encode(WorldSnapshot::capture(&game.world, 2));
+        assert_eq!(delta.baseline, Some(1));
+        assert_eq!(delta.changed.len(), 2);
+        assert_eq!(delta.changed[0].x, Some(1235));
+        assert_eq!(delta.changed[0].y, None);
+
+        let rebuilt = decoder.apply(&delta).unwrap().clone();
+        assert_eq!(rebuilt, WorldSnapshot::capture(&game.world, 2));
+
+        // Applying onto a client world reproduces the quantized state
+        let mut client = GameSystem::new(100.0, 100.0);
+        rebuilt.apply_to_world(&mut client.world);
+        assert!((client.world.get_entity(player_id).unwrap().position.x - 12.35).abs() < 1e-4);
+        assert!(client.world.get_entity(guard).is_some());
+
+        assert_eq!(decoder.apply(&delta), Err(DeltaError::Stale(2)));
+        let mut fresh = DeltaDecoder::new();
+        assert_eq!(fresh.apply(&delta), Err(DeltaError::MissingBaseline(1)));
+    }
+
+    #[test]
+    fn test_role_visibility_survives_deltas() {
+        let mut game = GameSystem::new(100.0, 100.0);
+        let player_id = game.spawn_player(10.0, 10.0);
+        let mut encoder = DeltaEncoder::new();
+        let mut decoder = DeltaDecoder::new();
+        decoder.apply(&encoder.encode(WorldSnapshot::capture(&game.world, 1))).unwrap();
+        encoder.acknowledge(1);
+
+        game.world.get_entity_mut(player_id).unwrap().visible_to_hacker = false;
+        let delta = encoder.encode(WorldSnapshot::capture(&game.world, 2));
+        assert_eq!(delta.changed[0].vh, Some(false));
+        assert_eq!(delta.changed[0].vi, None);
+
+        let entity = decoder.apply(&delta).unwrap().get(player_id).unwrap().to_entity();
+        assert!(!entity.visible_to_hacker);
+        assert!(entity.visible_to_infiltrator);
+    }
+
+    #[test]
+    fn test_delta_is_an_order_of_magnitude_smaller() {
+        // A 100-entity level: a few patrolling guards, the rest props that don't move
+        let mut game = GameSystem::new(500.0, 500.0);
+        let mut guards = Vec::new();
+        for i in 0..100 {
+            let (x, y) = ((i % 10) as f32 * 40.0 + 20.0, (i / 10) as f32 * 40.0 + 20.0);
+            if i % 10 == 0 {
+                guards.push(game.spawn_guard(x, y));
+            } else {
+                game.spawn_camera(x, y, 0.0, 0.0, 0.0, 0.0);
+            }
+        }
+
+        let mut encoder = DeltaEncoder::new();
+        encoder.encode(WorldSnapshot::capture(&game.world, 1));
+        encoder.acknowledge(1);
+
+        for (i, guard) in guards.iter().enumerate() {
+            let entity = game.world.get_entity_mut(*guard).unwrap();
+            entity.position.x += 0.5 + i as f32 * 0.01;
+            entity.rotation += 0.1;
+        }
+        let full = WorldSnapshot::capture(&game.world, 2);
+        let delta = encoder.encode(full.clone());
+
+        // Measured against what would otherwise be sent: the full snapshot, not the whole World
+        let full_bytes = serde_json::to_string(&full).unwrap().len();
+        let delta_bytes = serde_json::to_string(&delta).unwrap().len();
+        assert!(delta_bytes * 10 <= full_bytes, "delta {} vs full {}", delta_bytes, full_bytes);
+    }
+}
@@ -0,0 +1,117 @@
+use crate::game::types::*;
+use crate::security::network::DeviceKind;
+use crate::stealth::detection::{calculate_visibility, has_line_of_sight};
+use serde::{Deserialize, Serialize};
+
+/// How far a compromised door's sensor picks up movement, all around
+pub const DOOR_SENSOR_RANGE: f32 = 40.0;
+
+/// Seconds a ghost lingers after its entity left coverage
+pub const GHOST_LIFETIME: f32 = 30.0;
+
+/// A device the hacker has compromised and can watch through
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Sensor {
+    pub sensor_id: EntityId,
+    pub kind: DeviceKind,
+    pub range: f32,
+    pub field_of_view: f32, // Radians, centred on the device's rotation
+}
+
+/// Where the hacker last saw an entity, and when
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Sighting {
+    pub entity_id: EntityId,
+    pub entity_type: EntityType,
+    pub position: Position,
+    pub seen_at: f32,
+}
+
+/// What the hacker's compromised sensors cover, and the ghosts of what left it
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct HackerIntel {
+    pub sensors: Vec<Sensor>,
+    pub ghosts: Vec<Sighting>, // Entities out of coverage, at their last known position
+    #[serde(default)]
+    sightings: Vec<Sighting>,  // Latest sighting of everything the sensors have picked up
+    #[serde(default)]
+    pub revision: u32, // Bumped when the sensors or ghosts change, so the server knows when to resend
+}
+
+/// Compromised cameras with a live feed, and compromised doors
+pub fn hacker_sensors(world: &World) -> Vec<&Entity> {
+    world.entities.iter()
+        .filter(|e| e.visible)
+        .filter(|e| match world.network.node(e.id) {
+            Some(node) if node.compromised => match node.kind {
+                DeviceKind::Camera => !node.tampered, // A looped feed shows nothing live
+                DeviceKind::Door => true,
+                _ => false,
+            },
+            _ => false,
+        })
+        .collect()
+}
+
+/// Whether a hacker sensor picks up `target`
+/// Cameras see as guards do; door sensors feel movement nearby, past anything but walls
+pub fn sensor_sees(sensor: &Entity, target: &Entity, world: &World) -> bool {
+    if sensor.entity_type == EntityType::Camera {
+        return calculate_visibility(sensor, target, world) > 0.0;
+    }
+    sensor.position.distance_2d(&target.position) <= DOOR_SENSOR_RANGE
+        && has_line_of_sight(&sensor.position, &target.position, &world.obstacles)
+}
+
+impl Sensor {
+    fn of(entity: &Entity) -> Self {
+        match entity.entity_type {
+            EntityType::Camera => Self {
+                sensor_id: entity.id,
+                kind: DeviceKind::Camera,
+                range: entity.visibility_radius,
+                field_of_view: entity.field_of_view,
+            },
+            _ => Self {
+                sensor_id: entity.id,
+                kind: DeviceKind::Door,
+                range: DOOR_SENSOR_RANGE,
+                field_of_view: std::f32::consts::TAU,
+            },
+        }
+    }
+}
+
+/// Record what the hacker sees at game time `now`, from the `visible_to_hacker` flags
+/// `tracked` says which entities can drop out of sight and leave a ghost
+pub fn update_hacker_intel(world: &mut World, now: f32, tracked: impl Fn(&Entity) -> bool) {
+    let sensors: Vec<Sensor> = hacker_sensors(world).into_iter().map(Sensor::of).collect();
+
+    let intel = &mut world.intel;
+    for entity in world.entities.iter().filter(|e| tracked(e) && e.visible_to_hacker) {
+        let sighting = Sighting {
+            entity_id: entity.id,
+            entity_type: entity.entity_type,
+            position: entity.position,
+            seen_at: now,
+        };
+        match intel.sightings.iter_mut().find(|s| s.entity_id == entity.id) {
+            Some(existing) => *existing = sighting,
+            None => intel.sightings.push(sighting),
+        }
+    }
+
+    // Forget what has been out of sight too long, or is gone altogether
+    let entities = &world.entities;
+    intel.sightings.retain(|s| now - s.seen_at <= GHOST_LIFETIME && entities.iter().any(|e| e.id == s.entity_id));
+    let ghosts: Vec<Sighting> = intel.sightings.iter()
+        .filter(|s| s.seen_at < now)
+        .copied()
+        .collect();
+
+    if ghosts != intel.ghosts || sensors != intel.sensors {
+        intel.ghosts = ghosts;
+        intel.sensors = sensors;
+        intel.revision += 1;
+    }
+}
@@ -0,0 +1,222 @@
+use crate::game::types::*;
+use crate::hacking::markers::Markers;
+use crate::stealth::detection::has_line_of_sight;
+use super::intel::{hacker_sensors, sensor_sees};
+use super::snapshot::{EntitySnapshot, WorldSnapshot};
+
+const FULL_CIRCLE: f32 = std::f32::consts::PI * 2.0;
+
+/// Map layout every player knows from the start
+fn is_layout(entity_type: EntityType) -> bool {
+    matches!(
+        entity_type,
+        EntityType::Door | EntityType::Wall | EntityType::CoverObject | EntityType::LightSource
+    )
+}
+
+/// Whether `observer` can see `target` with its own sight radius, field of view and LOS
+pub fn in_sight(observer: &Entity, target: &Entity, blockers: &[Obstacle]) -> bool {
+    let distance = observer.position.distance_2d(&target.position);
+    if distance > observer.visibility_radius {
+        return false;
+    }
+    if distance <= f32::EPSILON {
+        return true; // Standing on top of it
+    }
+    // Players look all around; is_facing misses targets directly behind at a full circle
+    if observer.field_of_view < FULL_CIRCLE && !observer.is_facing(&target.position) {
+        return false;
+    }
+    has_line_of_sight(&observer.position, &target.position, blockers)
+}
+
+/// What the hacker knows about without a sensor on it: the team, the map and the network devices
+pub fn hacker_always_knows(entity_type: EntityType) -> bool {
+    matches!(
+        entity_type,
+        EntityType::Player | EntityType::Hacker | EntityType::Camera | EntityType::Terminal
+    ) || is_layout(entity_type)
+}
+
+/// Whether the hacker may know about `target`
+fn hacker_knows(target: &Entity, sensors: &[&Entity], world: &World) -> bool {
+    // Everything else only while a compromised sensor picks it up
+    hacker_always_knows(target.entity_type) || sensors.iter().any(|sensor| sensor_sees(sensor, target, world))
+}
+
+/// Whether the infiltrator `viewer` may know about `target`
+fn infiltrator_knows(viewer: &Entity, target: &Entity, blockers: &[Obstacle], markers: &Markers) -> bool {
+    match target.entity_type {
+        EntityType::Player => true,
+        EntityType::Hacker => false, // Not physically in the building
+        entity_type if is_layout(entity_type) => true,
+        _ if markers.is_tagged(target.id) => true, // The hacker tagged it, so it shows through walls
+        _ => in_sight(viewer, target, blockers),
+    }
+}
+
+/// Entities the player `player_id` may know about, by role
+/// Empty if the player isn't in the world
+pub fn known_entities(world: &World, player_id: EntityId) -> Vec<EntityId> {
+    let Some(viewer) = world.get_entity(player_id) else {
+        return Vec::new();
+    };
+    let blockers: Vec<Obstacle> = world.blockers().collect();
+
+    match viewer.entity_type.role() {
+        Some(PlayerRole::Hacker) => {
+            let sensors = hacker_sensors(world);
+            world.entities.iter()
+                .filter(|e| hacker_knows(e, &sensors, world))
+                .map(|e| e.id)
+                .collect()
+        }
+        Some(PlayerRole::Infiltrator) => world.entities.iter()
+            .filter(|e| e.id == viewer.id || infiltrator_knows(viewer, e, &blockers, &world.markers))
+            .map(|e| e.id)
+            .collect(),
+        None => Vec::new(),
+    }
+}
+
+/// Set `visible_to_hacker` and `visible_to_infiltrator` from hacker sensors and infiltrator sight
+pub fn update_role_visibility(world: &mut World) {
+    let blockers: Vec<Obstacle> = world.blockers().collect();
+    let sensors = hacker_sensors(world);
+    let infiltrators: Vec<&Entity> = world.entities.iter()
+        .filter(|e| e.entity_type.role() == Some(PlayerRole::Infiltrator))
+        .collect();
+
+    let flags: Vec<(bool, bool)> = world.entities.iter()
+        .map(|target| (
+            hacker_knows(target, &sensors, world),
+            infiltrators.iter().any(|viewer| viewer.id == target.id || infiltrator_knows(viewer, target, &blockers, &world.markers)),
+        ))
+        .collect();
+
+    for (entity, (hacker, infiltrator)) in world.entities.iter_mut().zip(flags) {
+        entity.visible_to_hacker = hacker;
+        entity.visible_to_infiltrator = infiltrator;
+    }
+}
+
+impl WorldSnapshot {
+    /// Snapshot of only what `player_id` may know about, for that player's client
+    pub fn capture_for(world: &World, tick: u64, player_id: EntityId) -> Self {
+        let known = known_entities(world, player_id);
+        let mut entities: Vec<EntitySnapshot> = world.entities.iter()
+            .filter(|e| known.contains(&e.id))
+            .map(EntitySnapshot::capture)
+            .collect();
+        entities.sort_by_key(|e| e.id);
+        Self { tick, entities }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::system::GameSystem;
+    use crate::hacking::markers::MarkerKind;
+    use crate::security::network::HackCommand;
+    use crate::security::door::{DoorState, UnlockRequirement};
+
+    fn wall_at(x: f32, y: f32) -> Obstacle {
+        Obstacle {
+            position: Position::new(x, y),
+            radius: 2.0,
+            blocks_vision: true,
+            blocks_sound: true,
+            provides_cover: true,
+        }
+    }
+
+    #[test]
+    fn test_infiltrator_only_knows_what_it_can_see() {
+        let mut game = GameSystem::new(200.0, 200.0);
+        let player_id = game.spawn_player(10.0, 10.0);
+        let near = game.spawn_guard(20.0, 10.0);
+        let far = game.spawn_guard(150.0, 150.0);
+        let hidden = game.spawn_guard(10.0, 20.0);
+        let door = game.spawn_door(180.0, 10.0, 1.0, DoorState::Closed, UnlockRequirement::None);
+        game.world.obstacles.push(wall_at(10.0, 15.0));
+
+        let known = known_entities(&game.world, player_id);
+        assert!(known.contains(&player_id));
+        assert!(known.contains(&near));
+        assert!(!known.contains(&far), "out of sight range");
+        assert!(!known.contains(&hidden), "behind a wall");
+        assert!(known.contains(&door), "layout is always known");
+
+        let snapshot = WorldSnapshot::capture_for(&game.world, 1, player_id);
+        assert!(snapshot.get(far).is_none());
+        assert!(snapshot.get(near).is_some());
+
+        // A guard the hacker tagged shows through the wall
+        game.place_marker(MarkerKind::Tag { entity_id: hidden }).unwrap();
+        assert!(known_entities(&game.world, player_id).contains(&hidden));
+    }
+
+    #[test]
+    fn test_hacker_sees_through_camera_feeds() {
+        let mut game = GameSystem::new(200.0, 200.0);
+        let hacker_id = game.world.add_entity(Entity::new_static(100, EntityType::Hacker, Position::new(0.0, 0.0)));
+        let player_id = game.spawn_player(190.0, 190.0);
+        let camera = game.spawn_camera(50.0, 50.0, 0.0, 0.0, 0.0, 0.0);
+        let watched = game.spawn_guard(60.0, 50.0);
+        let unwatched = game.spawn_guard(40.0, 50.0); // Behind the camera
+        let terminal = game.spawn_terminal(0.0, 0.0);
+        game.connect_devices(terminal, camera);
+        game.world.network.open_access = true;
+
+        let known = known_entities(&game.world, hacker_id);
+        assert!(known.contains(&player_id), "the team is always known");
+        assert!(known.contains(&camera));
+        assert!(!known.contains(&watched), "the camera hasn't been hacked yet");
+
+        for device_id in [terminal, camera] {
+            game.hack(hacker_id, HackCommand::Compromise { device_id }).unwrap();
+        }
+        let known = known_entities(&game.world, hacker_id);
+        assert!(known.contains(&watched));
+        assert!(!known.contains(&unwatched));
+
+        update_role_visibility(&mut game.world);
+        let flags = |id| {
+            let e = game.world.get_entity(id).unwrap();
+            (e.visible_to_hacker, e.visible_to_infiltrator)
+        };
+        assert_eq!(flags(watched), (true, false));
+        assert_eq!(flags(unwatched), (false, false));
+        assert_eq!(flags(hacker_id), (true, false));
+    }
+
+    #[test]
+    fn test_hacker_keeps_ghosts_of_what_left_coverage() {
+        let mut game = GameSystem::new(200.0, 200.0);
+        let hacker_id = game.spawn_hacker(0.0, 0.0);
+        let terminal = game.spawn_terminal(0.0, 0.0);
+        let camera = game.spawn_camera(50.0, 50.0, 0.0, 0.0, 0.0, 0.0);
+        let guard = game.spawn_guard(60.0, 50.0);
+        game.connect_devices(terminal, camera);
+        game.world.network.open_access = true;
+        for device_id in [terminal, camera] {
+            game.hack(hacker_id, HackCommand::Compromise { device_id }).unwrap();
+        }
+        game.update(0.1);
+        assert_eq!(game.world.intel.sensors.len(), 1);
+        assert!(game.world.intel.ghosts.is_empty());
+
+        // Out of the camera's cone, the guard is only a ghost where it was last seen
+        game.world.get_entity_mut(guard).unwrap().position = Position::new(150.0, 150.0);
+        game.update(0.1);
+        assert!(!game.world.get_entity(guard).unwrap().visible_to_hacker);
+        let ghost = game.world.intel.ghosts[0];
+        assert_eq!((ghost.entity_id, ghost.position.x), (guard, 60.0));
+
+        // Looping the feed takes the camera's coverage away
+        game.hack(hacker_id, HackCommand::LoopFeed { camera_id: camera }).unwrap();
+        game.update(0.1);
+        assert!(game.world.intel.sensors.is_empty());
+    }
+}
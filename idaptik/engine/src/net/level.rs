@@ -0,0 +1,106 @@
+use crate::game::system::GameSystem;
+use crate::game::types::*;
+use crate::security::door::{Door, DoorState};
+use serde::{Deserialize, Serialize};
+
+/// A door as far as movement is concerned
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct DoorGeometry {
+    pub door_id: EntityId,
+    pub position: Position,
+    pub radius: f32,
+    pub state: DoorState, // Closed and locked doors block
+}
+
+/// What a client needs to predict movement the way the server simulates it:
+/// the tick length, the bounds and everything that blocks
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LevelGeometry {
+    pub timestep: f32,
+    pub width: f32,
+    pub height: f32,
+    pub obstacles: Vec<Obstacle>,
+    pub doors: Vec<DoorGeometry>,
+}
+
+impl LevelGeometry {
+    pub fn of(world: &World, timestep: f32) -> Self {
+        let doors = world.doors.iter()
+            .filter_map(|door| {
+                let entity = world.get_entity(door.door_id)?;
+                Some(DoorGeometry {
+                    door_id: door.door_id,
+                    position: entity.position,
+                    radius: door.radius,
+                    state: door.state,
+                })
+            })
+            .collect();
+
+        Self {
+            timestep,
+            width: world.width,
+            height: world.height,
+            obstacles: world.obstacles.clone(),
+            doors,
+        }
+    }
+
+    /// Replace the system's bounds, obstacles and doors with these, and tick it at the same rate
+    pub fn apply_to(&self, system: &mut GameSystem) {
+        let world = &mut system.world;
+        world.width = self.width;
+        world.height = self.height;
+        world.obstacles = self.obstacles.clone();
+
+        let old_doors: Vec<EntityId> = world.doors.drain(..).map(|d| d.door_id).collect();
+        for door_id in old_doors {
+            world.remove_entity(door_id);
+        }
+        for door in &self.doors {
+            world.add_entity(Entity::new_door(door.door_id, door.position));
+            world.doors.push(Door::new(door.door_id, door.radius, door.state));
+        }
+
+        system.set_fixed_timestep(Some(self.timestep));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::security::door::UnlockRequirement;
+
+    #[test]
+    fn test_geometry_blocks_the_same_way_on_both_sides() {
+        let mut server = GameSystem::new(200.0, 100.0);
+        server.add_obstacle(40.0, 10.0, 5.0);
+        let door_id = server.spawn_door(80.0, 10.0, 3.0, DoorState::Locked, UnlockRequirement::None);
+        server.spawn_door(120.0, 10.0, 3.0, DoorState::Open, UnlockRequirement::None);
+
+        let level = LevelGeometry::of(&server.world, 0.05);
+        assert_eq!(level.doors.len(), 2);
+        assert_eq!(level.doors[0], DoorGeometry {
+            door_id,
+            position: Position::new(80.0, 10.0),
+            radius: 3.0,
+            state: DoorState::Locked,
+        });
+
+        let mut client = GameSystem::new(10.0, 10.0);
+        level.apply_to(&mut client);
+        assert_eq!((client.world.width, client.world.height), (200.0, 100.0));
+        assert_eq!(client.fixed_timestep(), Some(0.05));
+
+        let blockers = |system: &GameSystem| -> Vec<(f32, f32)> {
+            system.world.blockers().map(|b| (b.position.x, b.radius)).collect()
+        };
+        assert_eq!(blockers(&client), blockers(&server));
+        assert_eq!(blockers(&client).len(), 2, "the obstacle and the locked door");
+
+        // Applying it again replaces rather than piles up
+        level.apply_to(&mut client);
+        assert_eq!(client.world.doors.len(), 2);
+        assert_eq!(client.world.entities.len(), 2);
+    }
+}
@@ -0,0 +1,18 @@
+pub mod delta;
+pub mod intel;
+pub mod interest;
+pub mod level;
+#[allow(clippy::all, unused_imports, dead_code, mismatched_lifetime_syntaxes)]
+#[rustfmt::skip]
+pub mod port_protocol_generated;
+pub mod room;
+pub mod snapshot;
+pub mod wire;
+
+pub use delta::*;
+pub use intel::*;
+pub use interest::*;
+pub use level::*;
+pub use room::*;
+pub use snapshot::*;
+pub use wire::*;
@@ -0,0 +1,8468 @@
+// automatically generated by the FlatBuffers compiler, do not modify
+
+
+// @generated
+
+use core::mem;
+use core::cmp::Ordering;
+
+extern crate flatbuffers;
+use self::flatbuffers::{EndianScalar, Follow};
+
+#[allow(unused_imports, dead_code)]
+pub mod idaptik {
+
+  use core::mem;
+  use core::cmp::Ordering;
+
+  extern crate flatbuffers;
+  use self::flatbuffers::{EndianScalar, Follow};
+#[allow(unused_imports, dead_code)]
+pub mod port {
+
+  use core::mem;
+  use core::cmp::Ordering;
+
+  extern crate flatbuffers;
+  use self::flatbuffers::{EndianScalar, Follow};
+
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MIN_ENTITY_TYPE: u8 = 0;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MAX_ENTITY_TYPE: u8 = 11;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+#[allow(non_camel_case_types)]
+pub const ENUM_VALUES_ENTITY_TYPE: [EntityType; 12] = [
+  EntityType::Player,
+  EntityType::Guard,
+  EntityType::Camera,
+  EntityType::Door,
+  EntityType::LightSource,
+  EntityType::SoundEmitter,
+  EntityType::CoverObject,
+  EntityType::Item,
+  EntityType::Terminal,
+  EntityType::Hacker,
+  EntityType::Drone,
+  EntityType::Wall,
+];
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct EntityType(pub u8);
+#[allow(non_upper_case_globals)]
+impl EntityType {
+  pub const Player: Self = Self(0);
+  pub const Guard: Self = Self(1);
+  pub const Camera: Self = Self(2);
+  pub const Door: Self = Self(3);
+  pub const LightSource: Self = Self(4);
+  pub const SoundEmitter: Self = Self(5);
+  pub const CoverObject: Self = Self(6);
+  pub const Item: Self = Self(7);
+  pub const Terminal: Self = Self(8);
+  pub const Hacker: Self = Self(9);
+  pub const Drone: Self = Self(10);
+  pub const Wall: Self = Self(11);
+
+  pub const ENUM_MIN: u8 = 0;
+  pub const ENUM_MAX: u8 = 11;
+  pub const ENUM_VALUES: &'static [Self] = &[
+    Self::Player,
+    Self::Guard,
+    Self::Camera,
+    Self::Door,
+    Self::LightSource,
+    Self::SoundEmitter,
+    Self::CoverObject,
+    Self::Item,
+    Self::Terminal,
+    Self::Hacker,
+    Self::Drone,
+    Self::Wall,
+  ];
+  /// Returns the variant's name or "" if unknown.
+  pub fn variant_name(self) -> Option<&'static str> {
+    match self {
+      Self::Player => Some("Player"),
+      Self::Guard => Some("Guard"),
+      Self::Camera => Some("Camera"),
+      Self::Door => Some("Door"),
+      Self::LightSource => Some("LightSource"),
+      Self::SoundEmitter => Some("SoundEmitter"),
+      Self::CoverObject => Some("CoverObject"),
+      Self::Item => Some("Item"),
+      Self::Terminal => Some("Terminal"),
+      Self::Hacker => Some("Hacker"),
+      Self::Drone => Some("Drone"),
+      Self::Wall => Some("Wall"),
+      _ => None,
+    }
+  }
+}
+impl core::fmt::Debug for EntityType {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    if let Some(name) = self.variant_name() {
+      f.write_str(name)
+    } else {
+      f.write_fmt(format_args!("<UNKNOWN {:?}>", self.0))
+    }
+  }
+}
+impl<'a> flatbuffers::Follow<'a> for EntityType {
+  type Inner = Self;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    let b = unsafe { flatbuffers::read_scalar_at::<u8>(buf, loc) };
+    Self(b)
+  }
+}
+
+impl flatbuffers::Push for EntityType {
+    type Output = EntityType;
+    #[inline]
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        unsafe { flatbuffers::emplace_scalar::<u8>(dst, self.0) };
+    }
+}
+
+impl flatbuffers::EndianScalar for EntityType {
+  type Scalar = u8;
+  #[inline]
+  fn to_little_endian(self) -> u8 {
+    self.0.to_le()
+  }
+  #[inline]
+  #[allow(clippy::wrong_self_convention)]
+  fn from_little_endian(v: u8) -> Self {
+    let b = u8::from_le(v);
+    Self(b)
+  }
+}
+
+impl<'a> flatbuffers::Verifiable for EntityType {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    u8::run_verifier(v, pos)
+  }
+}
+
+impl flatbuffers::SimpleToVerifyInSlice for EntityType {}
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MIN_STANCE: u8 = 0;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MAX_STANCE: u8 = 2;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+#[allow(non_camel_case_types)]
+pub const ENUM_VALUES_STANCE: [Stance; 3] = [
+  Stance::Standing,
+  Stance::Crouching,
+  Stance::Prone,
+];
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct Stance(pub u8);
+#[allow(non_upper_case_globals)]
+impl Stance {
+  pub const Standing: Self = Self(0);
+  pub const Crouching: Self = Self(1);
+  pub const Prone: Self = Self(2);
+
+  pub const ENUM_MIN: u8 = 0;
+  pub const ENUM_MAX: u8 = 2;
+  pub const ENUM_VALUES: &'static [Self] = &[
+    Self::Standing,
+    Self::Crouching,
+    Self::Prone,
+  ];
+  /// Returns the variant's name or "" if unknown.
+  pub fn variant_name(self) -> Option<&'static str> {
+    match self {
+      Self::Standing => Some("Standing"),
+      Self::Crouching => Some("Crouching"),
+      Self::Prone => Some("Prone"),
+      _ => None,
+    }
+  }
+}
+impl core::fmt::Debug for Stance {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    if let Some(name) = self.variant_name() {
+      f.write_str(name)
+    } else {
+      f.write_fmt(format_args!("<UNKNOWN {:?}>", self.0))
+    }
+  }
+}
+impl<'a> flatbuffers::Follow<'a> for Stance {
+  type Inner = Self;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    let b = unsafe { flatbuffers::read_scalar_at::<u8>(buf, loc) };
+    Self(b)
+  }
+}
+
+impl flatbuffers::Push for Stance {
+    type Output = Stance;
+    #[inline]
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        unsafe { flatbuffers::emplace_scalar::<u8>(dst, self.0) };
+    }
+}
+
+impl flatbuffers::EndianScalar for Stance {
+  type Scalar = u8;
+  #[inline]
+  fn to_little_endian(self) -> u8 {
+    self.0.to_le()
+  }
+  #[inline]
+  #[allow(clippy::wrong_self_convention)]
+  fn from_little_endian(v: u8) -> Self {
+    let b = u8::from_le(v);
+    Self(b)
+  }
+}
+
+impl<'a> flatbuffers::Verifiable for Stance {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    u8::run_verifier(v, pos)
+  }
+}
+
+impl flatbuffers::SimpleToVerifyInSlice for Stance {}
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MIN_ACTOR_STATE: u8 = 0;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MAX_ACTOR_STATE: u8 = 6;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+#[allow(non_camel_case_types)]
+pub const ENUM_VALUES_ACTOR_STATE: [ActorState; 7] = [
+  ActorState::Idle,
+  ActorState::Walking,
+  ActorState::Running,
+  ActorState::Hiding,
+  ActorState::Investigating,
+  ActorState::Alerted,
+  ActorState::Hunting,
+];
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct ActorState(pub u8);
+#[allow(non_upper_case_globals)]
+impl ActorState {
+  pub const Idle: Self = Self(0);
+  pub const Walking: Self = Self(1);
+  pub const Running: Self = Self(2);
+  pub const Hiding: Self = Self(3);
+  pub const Investigating: Self = Self(4);
+  pub const Alerted: Self = Self(5);
+  pub const Hunting: Self = Self(6);
+
+  pub const ENUM_MIN: u8 = 0;
+  pub const ENUM_MAX: u8 = 6;
+  pub const ENUM_VALUES: &'static [Self] = &[
+    Self::Idle,
+    Self::Walking,
+    Self::Running,
+    Self::Hiding,
+    Self::Investigating,
+    Self::Alerted,
+    Self::Hunting,
+  ];
+  /// Returns the variant's name or "" if unknown.
+  pub fn variant_name(self) -> Option<&'static str> {
+    match self {
+      Self::Idle => Some("Idle"),
+      Self::Walking => Some("Walking"),
+      Self::Running => Some("Running"),
+      Self::Hiding => Some("Hiding"),
+      Self::Investigating => Some("Investigating"),
+      Self::Alerted => Some("Alerted"),
+      Self::Hunting => Some("Hunting"),
+      _ => None,
+    }
+  }
+}
+impl core::fmt::Debug for ActorState {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    if let Some(name) = self.variant_name() {
+      f.write_str(name)
+    } else {
+      f.write_fmt(format_args!("<UNKNOWN {:?}>", self.0))
+    }
+  }
+}
+impl<'a> flatbuffers::Follow<'a> for ActorState {
+  type Inner = Self;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    let b = unsafe { flatbuffers::read_scalar_at::<u8>(buf, loc) };
+    Self(b)
+  }
+}
+
+impl flatbuffers::Push for ActorState {
+    type Output = ActorState;
+    #[inline]
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        unsafe { flatbuffers::emplace_scalar::<u8>(dst, self.0) };
+    }
+}
+
+impl flatbuffers::EndianScalar for ActorState {
+  type Scalar = u8;
+  #[inline]
+  fn to_little_endian(self) -> u8 {
+    self.0.to_le()
+  }
+  #[inline]
+  #[allow(clippy::wrong_self_convention)]
+  fn from_little_endian(v: u8) -> Self {
+    let b = u8::from_le(v);
+    Self(b)
+  }
+}
+
+impl<'a> flatbuffers::Verifiable for ActorState {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    u8::run_verifier(v, pos)
+  }
+}
+
+impl flatbuffers::SimpleToVerifyInSlice for ActorState {}
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MIN_PLAYER_ROLE: u8 = 0;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MAX_PLAYER_ROLE: u8 = 1;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+#[allow(non_camel_case_types)]
+pub const ENUM_VALUES_PLAYER_ROLE: [PlayerRole; 2] = [
+  PlayerRole::Hacker,
+  PlayerRole::Infiltrator,
+];
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct PlayerRole(pub u8);
+#[allow(non_upper_case_globals)]
+impl PlayerRole {
+  pub const Hacker: Self = Self(0);
+  pub const Infiltrator: Self = Self(1);
+
+  pub const ENUM_MIN: u8 = 0;
+  pub const ENUM_MAX: u8 = 1;
+  pub const ENUM_VALUES: &'static [Self] = &[
+    Self::Hacker,
+    Self::Infiltrator,
+  ];
+  /// Returns the variant's name or "" if unknown.
+  pub fn variant_name(self) -> Option<&'static str> {
+    match self {
+      Self::Hacker => Some("Hacker"),
+      Self::Infiltrator => Some("Infiltrator"),
+      _ => None,
+    }
+  }
+}
+impl core::fmt::Debug for PlayerRole {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    if let Some(name) = self.variant_name() {
+      f.write_str(name)
+    } else {
+      f.write_fmt(format_args!("<UNKNOWN {:?}>", self.0))
+    }
+  }
+}
+impl<'a> flatbuffers::Follow<'a> for PlayerRole {
+  type Inner = Self;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    let b = unsafe { flatbuffers::read_scalar_at::<u8>(buf, loc) };
+    Self(b)
+  }
+}
+
+impl flatbuffers::Push for PlayerRole {
+    type Output = PlayerRole;
+    #[inline]
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        unsafe { flatbuffers::emplace_scalar::<u8>(dst, self.0) };
+    }
+}
+
+impl flatbuffers::EndianScalar for PlayerRole {
+  type Scalar = u8;
+  #[inline]
+  fn to_little_endian(self) -> u8 {
+    self.0.to_le()
+  }
+  #[inline]
+  #[allow(clippy::wrong_self_convention)]
+  fn from_little_endian(v: u8) -> Self {
+    let b = u8::from_le(v);
+    Self(b)
+  }
+}
+
+impl<'a> flatbuffers::Verifiable for PlayerRole {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    u8::run_verifier(v, pos)
+  }
+}
+
+impl flatbuffers::SimpleToVerifyInSlice for PlayerRole {}
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MIN_DEVICE_KIND: u8 = 0;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MAX_DEVICE_KIND: u8 = 3;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+#[allow(non_camel_case_types)]
+pub const ENUM_VALUES_DEVICE_KIND: [DeviceKind; 4] = [
+  DeviceKind::Camera,
+  DeviceKind::Door,
+  DeviceKind::Light,
+  DeviceKind::Terminal,
+];
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct DeviceKind(pub u8);
+#[allow(non_upper_case_globals)]
+impl DeviceKind {
+  pub const Camera: Self = Self(0);
+  pub const Door: Self = Self(1);
+  pub const Light: Self = Self(2);
+  pub const Terminal: Self = Self(3);
+
+  pub const ENUM_MIN: u8 = 0;
+  pub const ENUM_MAX: u8 = 3;
+  pub const ENUM_VALUES: &'static [Self] = &[
+    Self::Camera,
+    Self::Door,
+    Self::Light,
+    Self::Terminal,
+  ];
+  /// Returns the variant's name or "" if unknown.
+  pub fn variant_name(self) -> Option<&'static str> {
+    match self {
+      Self::Camera => Some("Camera"),
+      Self::Door => Some("Door"),
+      Self::Light => Some("Light"),
+      Self::Terminal => Some("Terminal"),
+      _ => None,
+    }
+  }
+}
+impl core::fmt::Debug for DeviceKind {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    if let Some(name) = self.variant_name() {
+      f.write_str(name)
+    } else {
+      f.write_fmt(format_args!("<UNKNOWN {:?}>", self.0))
+    }
+  }
+}
+impl<'a> flatbuffers::Follow<'a> for DeviceKind {
+  type Inner = Self;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    let b = unsafe { flatbuffers::read_scalar_at::<u8>(buf, loc) };
+    Self(b)
+  }
+}
+
+impl flatbuffers::Push for DeviceKind {
+    type Output = DeviceKind;
+    #[inline]
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        unsafe { flatbuffers::emplace_scalar::<u8>(dst, self.0) };
+    }
+}
+
+impl flatbuffers::EndianScalar for DeviceKind {
+  type Scalar = u8;
+  #[inline]
+  fn to_little_endian(self) -> u8 {
+    self.0.to_le()
+  }
+  #[inline]
+  #[allow(clippy::wrong_self_convention)]
+  fn from_little_endian(v: u8) -> Self {
+    let b = u8::from_le(v);
+    Self(b)
+  }
+}
+
+impl<'a> flatbuffers::Verifiable for DeviceKind {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    u8::run_verifier(v, pos)
+  }
+}
+
+impl flatbuffers::SimpleToVerifyInSlice for DeviceKind {}
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MIN_TRACE_STAGE: u8 = 0;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MAX_TRACE_STAGE: u8 = 3;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+#[allow(non_camel_case_types)]
+pub const ENUM_VALUES_TRACE_STAGE: [TraceStage; 4] = [
+  TraceStage::Clear,
+  TraceStage::Relock,
+  TraceStage::Hunted,
+  TraceStage::Traced,
+];
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct TraceStage(pub u8);
+#[allow(non_upper_case_globals)]
+impl TraceStage {
+  pub const Clear: Self = Self(0);
+  pub const Relock: Self = Self(1);
+  pub const Hunted: Self = Self(2);
+  pub const Traced: Self = Self(3);
+
+  pub const ENUM_MIN: u8 = 0;
+  pub const ENUM_MAX: u8 = 3;
+  pub const ENUM_VALUES: &'static [Self] = &[
+    Self::Clear,
+    Self::Relock,
+    Self::Hunted,
+    Self::Traced,
+  ];
+  /// Returns the variant's name or "" if unknown.
+  pub fn variant_name(self) -> Option<&'static str> {
+    match self {
+      Self::Clear => Some("Clear"),
+      Self::Relock => Some("Relock"),
+      Self::Hunted => Some("Hunted"),
+      Self::Traced => Some("Traced"),
+      _ => None,
+    }
+  }
+}
+impl core::fmt::Debug for TraceStage {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    if let Some(name) = self.variant_name() {
+      f.write_str(name)
+    } else {
+      f.write_fmt(format_args!("<UNKNOWN {:?}>", self.0))
+    }
+  }
+}
+impl<'a> flatbuffers::Follow<'a> for TraceStage {
+  type Inner = Self;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    let b = unsafe { flatbuffers::read_scalar_at::<u8>(buf, loc) };
+    Self(b)
+  }
+}
+
+impl flatbuffers::Push for TraceStage {
+    type Output = TraceStage;
+    #[inline]
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        unsafe { flatbuffers::emplace_scalar::<u8>(dst, self.0) };
+    }
+}
+
+impl flatbuffers::EndianScalar for TraceStage {
+  type Scalar = u8;
+  #[inline]
+  fn to_little_endian(self) -> u8 {
+    self.0.to_le()
+  }
+  #[inline]
+  #[allow(clippy::wrong_self_convention)]
+  fn from_little_endian(v: u8) -> Self {
+    let b = u8::from_le(v);
+    Self(b)
+  }
+}
+
+impl<'a> flatbuffers::Verifiable for TraceStage {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    u8::run_verifier(v, pos)
+  }
+}
+
+impl flatbuffers::SimpleToVerifyInSlice for TraceStage {}
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MIN_DOOR_STATE: u8 = 0;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MAX_DOOR_STATE: u8 = 3;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+#[allow(non_camel_case_types)]
+pub const ENUM_VALUES_DOOR_STATE: [DoorState; 4] = [
+  DoorState::Open,
+  DoorState::Closed,
+  DoorState::Locked,
+  DoorState::Hacked,
+];
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct DoorState(pub u8);
+#[allow(non_upper_case_globals)]
+impl DoorState {
+  pub const Open: Self = Self(0);
+  pub const Closed: Self = Self(1);
+  pub const Locked: Self = Self(2);
+  pub const Hacked: Self = Self(3);
+
+  pub const ENUM_MIN: u8 = 0;
+  pub const ENUM_MAX: u8 = 3;
+  pub const ENUM_VALUES: &'static [Self] = &[
+    Self::Open,
+    Self::Closed,
+    Self::Locked,
+    Self::Hacked,
+  ];
+  /// Returns the variant's name or "" if unknown.
+  pub fn variant_name(self) -> Option<&'static str> {
+    match self {
+      Self::Open => Some("Open"),
+      Self::Closed => Some("Closed"),
+      Self::Locked => Some("Locked"),
+      Self::Hacked => Some("Hacked"),
+      _ => None,
+    }
+  }
+}
+impl core::fmt::Debug for DoorState {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    if let Some(name) = self.variant_name() {
+      f.write_str(name)
+    } else {
+      f.write_fmt(format_args!("<UNKNOWN {:?}>", self.0))
+    }
+  }
+}
+impl<'a> flatbuffers::Follow<'a> for DoorState {
+  type Inner = Self;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    let b = unsafe { flatbuffers::read_scalar_at::<u8>(buf, loc) };
+    Self(b)
+  }
+}
+
+impl flatbuffers::Push for DoorState {
+    type Output = DoorState;
+    #[inline]
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        unsafe { flatbuffers::emplace_scalar::<u8>(dst, self.0) };
+    }
+}
+
+impl flatbuffers::EndianScalar for DoorState {
+  type Scalar = u8;
+  #[inline]
+  fn to_little_endian(self) -> u8 {
+    self.0.to_le()
+  }
+  #[inline]
+  #[allow(clippy::wrong_self_convention)]
+  fn from_little_endian(v: u8) -> Self {
+    let b = u8::from_le(v);
+    Self(b)
+  }
+}
+
+impl<'a> flatbuffers::Verifiable for DoorState {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    u8::run_verifier(v, pos)
+  }
+}
+
+impl flatbuffers::SimpleToVerifyInSlice for DoorState {}
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MIN_INTERACTION_RESULT: u8 = 0;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MAX_INTERACTION_RESULT: u8 = 7;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+#[allow(non_camel_case_types)]
+pub const ENUM_VALUES_INTERACTION_RESULT: [InteractionResult; 8] = [
+  InteractionResult::Opened,
+  InteractionResult::Closed,
+  InteractionResult::Unlocked,
+  InteractionResult::MissingKeycard,
+  InteractionResult::RequiresHacker,
+  InteractionResult::AlreadyOpen,
+  InteractionResult::OutOfRange,
+  InteractionResult::NothingToInteract,
+];
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct InteractionResult(pub u8);
+#[allow(non_upper_case_globals)]
+impl InteractionResult {
+  pub const Opened: Self = Self(0);
+  pub const Closed: Self = Self(1);
+  pub const Unlocked: Self = Self(2);
+  pub const MissingKeycard: Self = Self(3);
+  pub const RequiresHacker: Self = Self(4);
+  pub const AlreadyOpen: Self = Self(5);
+  pub const OutOfRange: Self = Self(6);
+  pub const NothingToInteract: Self = Self(7);
+
+  pub const ENUM_MIN: u8 = 0;
+  pub const ENUM_MAX: u8 = 7;
+  pub const ENUM_VALUES: &'static [Self] = &[
+    Self::Opened,
+    Self::Closed,
+    Self::Unlocked,
+    Self::MissingKeycard,
+    Self::RequiresHacker,
+    Self::AlreadyOpen,
+    Self::OutOfRange,
+    Self::NothingToInteract,
+  ];
+  /// Returns the variant's name or "" if unknown.
+  pub fn variant_name(self) -> Option<&'static str> {
+    match self {
+      Self::Opened => Some("Opened"),
+      Self::Closed => Some("Closed"),
+      Self::Unlocked => Some("Unlocked"),
+      Self::MissingKeycard => Some("MissingKeycard"),
+      Self::RequiresHacker => Some("RequiresHacker"),
+      Self::AlreadyOpen => Some("AlreadyOpen"),
+      Self::OutOfRange => Some("OutOfRange"),
+      Self::NothingToInteract => Some("NothingToInteract"),
+      _ => None,
+    }
+  }
+}
+impl core::fmt::Debug for InteractionResult {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    if let Some(name) = self.variant_name() {
+      f.write_str(name)
+    } else {
+      f.write_fmt(format_args!("<UNKNOWN {:?}>", self.0))
+    }
+  }
+}
+impl<'a> flatbuffers::Follow<'a> for InteractionResult {
+  type Inner = Self;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    let b = unsafe { flatbuffers::read_scalar_at::<u8>(buf, loc) };
+    Self(b)
+  }
+}
+
+impl flatbuffers::Push for InteractionResult {
+    type Output = InteractionResult;
+    #[inline]
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        unsafe { flatbuffers::emplace_scalar::<u8>(dst, self.0) };
+    }
+}
+
+impl flatbuffers::EndianScalar for InteractionResult {
+  type Scalar = u8;
+  #[inline]
+  fn to_little_endian(self) -> u8 {
+    self.0.to_le()
+  }
+  #[inline]
+  #[allow(clippy::wrong_self_convention)]
+  fn from_little_endian(v: u8) -> Self {
+    let b = u8::from_le(v);
+    Self(b)
+  }
+}
+
+impl<'a> flatbuffers::Verifiable for InteractionResult {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    u8::run_verifier(v, pos)
+  }
+}
+
+impl flatbuffers::SimpleToVerifyInSlice for InteractionResult {}
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MIN_MISSION_STATUS: u8 = 0;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MAX_MISSION_STATUS: u8 = 2;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+#[allow(non_camel_case_types)]
+pub const ENUM_VALUES_MISSION_STATUS: [MissionStatus; 3] = [
+  MissionStatus::InProgress,
+  MissionStatus::Succeeded,
+  MissionStatus::Failed,
+];
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct MissionStatus(pub u8);
+#[allow(non_upper_case_globals)]
+impl MissionStatus {
+  pub const InProgress: Self = Self(0);
+  pub const Succeeded: Self = Self(1);
+  pub const Failed: Self = Self(2);
+
+  pub const ENUM_MIN: u8 = 0;
+  pub const ENUM_MAX: u8 = 2;
+  pub const ENUM_VALUES: &'static [Self] = &[
+    Self::InProgress,
+    Self::Succeeded,
+    Self::Failed,
+  ];
+  /// Returns the variant's name or "" if unknown.
+  pub fn variant_name(self) -> Option<&'static str> {
+    match self {
+      Self::InProgress => Some("InProgress"),
+      Self::Succeeded => Some("Succeeded"),
+      Self::Failed => Some("Failed"),
+      _ => None,
+    }
+  }
+}
+impl core::fmt::Debug for MissionStatus {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    if let Some(name) = self.variant_name() {
+      f.write_str(name)
+    } else {
+      f.write_fmt(format_args!("<UNKNOWN {:?}>", self.0))
+    }
+  }
+}
+impl<'a> flatbuffers::Follow<'a> for MissionStatus {
+  type Inner = Self;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    let b = unsafe { flatbuffers::read_scalar_at::<u8>(buf, loc) };
+    Self(b)
+  }
+}
+
+impl flatbuffers::Push for MissionStatus {
+    type Output = MissionStatus;
+    #[inline]
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        unsafe { flatbuffers::emplace_scalar::<u8>(dst, self.0) };
+    }
+}
+
+impl flatbuffers::EndianScalar for MissionStatus {
+  type Scalar = u8;
+  #[inline]
+  fn to_little_endian(self) -> u8 {
+    self.0.to_le()
+  }
+  #[inline]
+  #[allow(clippy::wrong_self_convention)]
+  fn from_little_endian(v: u8) -> Self {
+    let b = u8::from_le(v);
+    Self(b)
+  }
+}
+
+impl<'a> flatbuffers::Verifiable for MissionStatus {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    u8::run_verifier(v, pos)
+  }
+}
+
+impl flatbuffers::SimpleToVerifyInSlice for MissionStatus {}
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MIN_HACK_COMMAND_KIND: u8 = 0;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MAX_HACK_COMMAND_KIND: u8 = 7;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+#[allow(non_camel_case_types)]
+pub const ENUM_VALUES_HACK_COMMAND_KIND: [HackCommandKind; 8] = [
+  HackCommandKind::Compromise,
+  HackCommandKind::Execute,
+  HackCommandKind::Undo,
+  HackCommandKind::LoopFeed,
+  HackCommandKind::RestoreFeed,
+  HackCommandKind::UnlockDoor,
+  HackCommandKind::CutLights,
+  HackCommandKind::RestoreLights,
+];
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct HackCommandKind(pub u8);
+#[allow(non_upper_case_globals)]
+impl HackCommandKind {
+  pub const Compromise: Self = Self(0);
+  pub const Execute: Self = Self(1);
+  pub const Undo: Self = Self(2);
+  pub const LoopFeed: Self = Self(3);
+  pub const RestoreFeed: Self = Self(4);
+  pub const UnlockDoor: Self = Self(5);
+  pub const CutLights: Self = Self(6);
+  pub const RestoreLights: Self = Self(7);
+
+  pub const ENUM_MIN: u8 = 0;
+  pub const ENUM_MAX: u8 = 7;
+  pub const ENUM_VALUES: &'static [Self] = &[
+    Self::Compromise,
+    Self::Execute,
+    Self::Undo,
+    Self::LoopFeed,
+    Self::RestoreFeed,
+    Self::UnlockDoor,
+    Self::CutLights,
+    Self::RestoreLights,
+  ];
+  /// Returns the variant's name or "" if unknown.
+  pub fn variant_name(self) -> Option<&'static str> {
+    match self {
+      Self::Compromise => Some("Compromise"),
+      Self::Execute => Some("Execute"),
+      Self::Undo => Some("Undo"),
+      Self::LoopFeed => Some("LoopFeed"),
+      Self::RestoreFeed => Some("RestoreFeed"),
+      Self::UnlockDoor => Some("UnlockDoor"),
+      Self::CutLights => Some("CutLights"),
+      Self::RestoreLights => Some("RestoreLights"),
+      _ => None,
+    }
+  }
+}
+impl core::fmt::Debug for HackCommandKind {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    if let Some(name) = self.variant_name() {
+      f.write_str(name)
+    } else {
+      f.write_fmt(format_args!("<UNKNOWN {:?}>", self.0))
+    }
+  }
+}
+impl<'a> flatbuffers::Follow<'a> for HackCommandKind {
+  type Inner = Self;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    let b = unsafe { flatbuffers::read_scalar_at::<u8>(buf, loc) };
+    Self(b)
+  }
+}
+
+impl flatbuffers::Push for HackCommandKind {
+    type Output = HackCommandKind;
+    #[inline]
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        unsafe { flatbuffers::emplace_scalar::<u8>(dst, self.0) };
+    }
+}
+
+impl flatbuffers::EndianScalar for HackCommandKind {
+  type Scalar = u8;
+  #[inline]
+  fn to_little_endian(self) -> u8 {
+    self.0.to_le()
+  }
+  #[inline]
+  #[allow(clippy::wrong_self_convention)]
+  fn from_little_endian(v: u8) -> Self {
+    let b = u8::from_le(v);
+    Self(b)
+  }
+}
+
+impl<'a> flatbuffers::Verifiable for HackCommandKind {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    u8::run_verifier(v, pos)
+  }
+}
+
+impl flatbuffers::SimpleToVerifyInSlice for HackCommandKind {}
+// struct Vec2, aligned to 4
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq)]
+pub struct Vec2(pub [u8; 8]);
+impl Default for Vec2 { 
+  fn default() -> Self { 
+    Self([0; 8])
+  }
+}
+impl core::fmt::Debug for Vec2 {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    f.debug_struct("Vec2")
+      .field("x", &self.x())
+      .field("y", &self.y())
+      .finish()
+  }
+}
+
+impl flatbuffers::SimpleToVerifyInSlice for Vec2 {}
+impl<'a> flatbuffers::Follow<'a> for Vec2 {
+  type Inner = &'a Vec2;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    unsafe { <&'a Vec2>::follow(buf, loc) }
+  }
+}
+impl<'a> flatbuffers::Follow<'a> for &'a Vec2 {
+  type Inner = &'a Vec2;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    unsafe { flatbuffers::follow_cast_ref::<Vec2>(buf, loc) }
+  }
+}
+impl<'b> flatbuffers::Push for Vec2 {
+    type Output = Vec2;
+    #[inline]
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        let src = unsafe { ::core::slice::from_raw_parts(self as *const Vec2 as *const u8, <Self as flatbuffers::Push>::size()) };
+        dst.copy_from_slice(src);
+    }
+    #[inline]
+    fn alignment() -> flatbuffers::PushAlignment {
+        flatbuffers::PushAlignment::new(4)
+    }
+}
+
+impl<'a> flatbuffers::Verifiable for Vec2 {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.in_buffer::<Self>(pos)
+  }
+}
+
+impl<'a> Vec2 {
+  #[allow(clippy::too_many_arguments)]
+  pub fn new(
+    x: f32,
+    y: f32,
+  ) -> Self {
+    let mut s = Self([0; 8]);
+    s.set_x(x);
+    s.set_y(y);
+    s
+  }
+
+  pub fn x(&self) -> f32 {
+    let mut mem = core::mem::MaybeUninit::<<f32 as EndianScalar>::Scalar>::uninit();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    EndianScalar::from_little_endian(unsafe {
+      core::ptr::copy_nonoverlapping(
+        self.0[0..].as_ptr(),
+        mem.as_mut_ptr() as *mut u8,
+        core::mem::size_of::<<f32 as EndianScalar>::Scalar>(),
+      );
+      mem.assume_init()
+    })
+  }
+
+  pub fn set_x(&mut self, x: f32) {
+    let x_le = x.to_little_endian();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    unsafe {
+      core::ptr::copy_nonoverlapping(
+        &x_le as *const _ as *const u8,
+        self.0[0..].as_mut_ptr(),
+        core::mem::size_of::<<f32 as EndianScalar>::Scalar>(),
+      );
+    }
+  }
+
+  pub fn y(&self) -> f32 {
+    let mut mem = core::mem::MaybeUninit::<<f32 as EndianScalar>::Scalar>::uninit();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    EndianScalar::from_little_endian(unsafe {
+      core::ptr::copy_nonoverlapping(
+        self.0[4..].as_ptr(),
+        mem.as_mut_ptr() as *mut u8,
+        core::mem::size_of::<<f32 as EndianScalar>::Scalar>(),
+      );
+      mem.assume_init()
+    })
+  }
+
+  pub fn set_y(&mut self, x: f32) {
+    let x_le = x.to_little_endian();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    unsafe {
+      core::ptr::copy_nonoverlapping(
+        &x_le as *const _ as *const u8,
+        self.0[4..].as_mut_ptr(),
+        core::mem::size_of::<<f32 as EndianScalar>::Scalar>(),
+      );
+    }
+  }
+}
+
+// struct Position, aligned to 4
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq)]
+pub struct Position(pub [u8; 12]);
+impl Default for Position { 
+  fn default() -> Self { 
+    Self([0; 12])
+  }
+}
+impl core::fmt::Debug for Position {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    f.debug_struct("Position")
+      .field("x", &self.x())
+      .field("y", &self.y())
+      .field("height", &self.height())
+      .finish()
+  }
+}
+
+impl flatbuffers::SimpleToVerifyInSlice for Position {}
+impl<'a> flatbuffers::Follow<'a> for Position {
+  type Inner = &'a Position;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    unsafe { <&'a Position>::follow(buf, loc) }
+  }
+}
+impl<'a> flatbuffers::Follow<'a> for &'a Position {
+  type Inner = &'a Position;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    unsafe { flatbuffers::follow_cast_ref::<Position>(buf, loc) }
+  }
+}
+impl<'b> flatbuffers::Push for Position {
+    type Output = Position;
+    #[inline]
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        let src = unsafe { ::core::slice::from_raw_parts(self as *const Position as *const u8, <Self as flatbuffers::Push>::size()) };
+        dst.copy_from_slice(src);
+    }
+    #[inline]
+    fn alignment() -> flatbuffers::PushAlignment {
+        flatbuffers::PushAlignment::new(4)
+    }
+}
+
+impl<'a> flatbuffers::Verifiable for Position {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.in_buffer::<Self>(pos)
+  }
+}
+
+impl<'a> Position {
+  #[allow(clippy::too_many_arguments)]
+  pub fn new(
+    x: f32,
+    y: f32,
+    height: f32,
+  ) -> Self {
+    let mut s = Self([0; 12]);
+    s.set_x(x);
+    s.set_y(y);
+    s.set_height(height);
+    s
+  }
+
+  pub fn x(&self) -> f32 {
+    let mut mem = core::mem::MaybeUninit::<<f32 as EndianScalar>::Scalar>::uninit();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    EndianScalar::from_little_endian(unsafe {
+      core::ptr::copy_nonoverlapping(
+        self.0[0..].as_ptr(),
+        mem.as_mut_ptr() as *mut u8,
+        core::mem::size_of::<<f32 as EndianScalar>::Scalar>(),
+      );
+      mem.assume_init()
+    })
+  }
+
+  pub fn set_x(&mut self, x: f32) {
+    let x_le = x.to_little_endian();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    unsafe {
+      core::ptr::copy_nonoverlapping(
+        &x_le as *const _ as *const u8,
+        self.0[0..].as_mut_ptr(),
+        core::mem::size_of::<<f32 as EndianScalar>::Scalar>(),
+      );
+    }
+  }
+
+  pub fn y(&self) -> f32 {
+    let mut mem = core::mem::MaybeUninit::<<f32 as EndianScalar>::Scalar>::uninit();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    EndianScalar::from_little_endian(unsafe {
+      core::ptr::copy_nonoverlapping(
+        self.0[4..].as_ptr(),
+        mem.as_mut_ptr() as *mut u8,
+        core::mem::size_of::<<f32 as EndianScalar>::Scalar>(),
+      );
+      mem.assume_init()
+    })
+  }
+
+  pub fn set_y(&mut self, x: f32) {
+    let x_le = x.to_little_endian();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    unsafe {
+      core::ptr::copy_nonoverlapping(
+        &x_le as *const _ as *const u8,
+        self.0[4..].as_mut_ptr(),
+        core::mem::size_of::<<f32 as EndianScalar>::Scalar>(),
+      );
+    }
+  }
+
+  pub fn height(&self) -> f32 {
+    let mut mem = core::mem::MaybeUninit::<<f32 as EndianScalar>::Scalar>::uninit();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    EndianScalar::from_little_endian(unsafe {
+      core::ptr::copy_nonoverlapping(
+        self.0[8..].as_ptr(),
+        mem.as_mut_ptr() as *mut u8,
+        core::mem::size_of::<<f32 as EndianScalar>::Scalar>(),
+      );
+      mem.assume_init()
+    })
+  }
+
+  pub fn set_height(&mut self, x: f32) {
+    let x_le = x.to_little_endian();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    unsafe {
+      core::ptr::copy_nonoverlapping(
+        &x_le as *const _ as *const u8,
+        self.0[8..].as_mut_ptr(),
+        core::mem::size_of::<<f32 as EndianScalar>::Scalar>(),
+      );
+    }
+  }
+}
+
+pub enum GameEntityOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct GameEntity<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for GameEntity<'a> {
+  type Inner = GameEntity<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> GameEntity<'a> {
+  pub const VT_ID: flatbuffers::VOffsetT = 4;
+  pub const VT_ENTITY_TYPE: flatbuffers::VOffsetT = 6;
+  pub const VT_X: flatbuffers::VOffsetT = 8;
+  pub const VT_Y: flatbuffers::VOffsetT = 10;
+  pub const VT_HEIGHT: flatbuffers::VOffsetT = 12;
+  pub const VT_ROTATION: flatbuffers::VOffsetT = 14;
+  pub const VT_VX: flatbuffers::VOffsetT = 16;
+  pub const VT_VY: flatbuffers::VOffsetT = 18;
+  pub const VT_STANCE: flatbuffers::VOffsetT = 20;
+  pub const VT_STATE: flatbuffers::VOffsetT = 22;
+  pub const VT_VISIBLE: flatbuffers::VOffsetT = 24;
+  pub const VT_VISIBILITY_RADIUS: flatbuffers::VOffsetT = 26;
+  pub const VT_FIELD_OF_VIEW: flatbuffers::VOffsetT = 28;
+  pub const VT_NOISE_LEVEL: flatbuffers::VOffsetT = 30;
+  pub const VT_DETECTION_LEVEL: flatbuffers::VOffsetT = 32;
+  pub const VT_LIGHT_EXPOSURE: flatbuffers::VOffsetT = 34;
+  pub const VT_SPEED: flatbuffers::VOffsetT = 36;
+  pub const VT_HEALTH: flatbuffers::VOffsetT = 38;
+  pub const VT_KEYCARDS: flatbuffers::VOffsetT = 40;
+  pub const VT_VISIBLE_TO_HACKER: flatbuffers::VOffsetT = 42;
+  pub const VT_VISIBLE_TO_INFILTRATOR: flatbuffers::VOffsetT = 44;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    GameEntity { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args GameEntityArgs<'args>
+  ) -> flatbuffers::WIPOffset<GameEntity<'bldr>> {
+    let mut builder = GameEntityBuilder::new(_fbb);
+    if let Some(x) = args.keycards { builder.add_keycards(x); }
+    builder.add_health(args.health);
+    builder.add_speed(args.speed);
+    builder.add_light_exposure(args.light_exposure);
+    builder.add_detection_level(args.detection_level);
+    builder.add_noise_level(args.noise_level);
+    builder.add_field_of_view(args.field_of_view);
+    builder.add_visibility_radius(args.visibility_radius);
+    builder.add_vy(args.vy);
+    builder.add_vx(args.vx);
+    builder.add_rotation(args.rotation);
+    builder.add_height(args.height);
+    builder.add_y(args.y);
+    builder.add_x(args.x);
+    builder.add_id(args.id);
+    builder.add_visible_to_infiltrator(args.visible_to_infiltrator);
+    builder.add_visible_to_hacker(args.visible_to_hacker);
+    builder.add_visible(args.visible);
+    builder.add_state(args.state);
+    builder.add_stance(args.stance);
+    builder.add_entity_type(args.entity_type);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn id(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(GameEntity::VT_ID, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn entity_type(&self) -> EntityType {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<EntityType>(GameEntity::VT_ENTITY_TYPE, Some(EntityType::Player)).unwrap()}
+  }
+  #[inline]
+  pub fn x(&self) -> f32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f32>(GameEntity::VT_X, Some(0.0)).unwrap()}
+  }
+  #[inline]
+  pub fn y(&self) -> f32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f32>(GameEntity::VT_Y, Some(0.0)).unwrap()}
+  }
+  #[inline]
+  pub fn height(&self) -> f32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f32>(GameEntity::VT_HEIGHT, Some(0.0)).unwrap()}
+  }
+  #[inline]
+  pub fn rotation(&self) -> f32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f32>(GameEntity::VT_ROTATION, Some(0.0)).unwrap()}
+  }
+  #[inline]
+  pub fn vx(&self) -> f32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f32>(GameEntity::VT_VX, Some(0.0)).unwrap()}
+  }
+  #[inline]
+  pub fn vy(&self) -> f32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f32>(GameEntity::VT_VY, Some(0.0)).unwrap()}
+  }
+  #[inline]
+  pub fn stance(&self) -> Stance {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<Stance>(GameEntity::VT_STANCE, Some(Stance::Standing)).unwrap()}
+  }
+  #[inline]
+  pub fn state(&self) -> ActorState {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<ActorState>(GameEntity::VT_STATE, Some(ActorState::Idle)).unwrap()}
+  }
+  #[inline]
+  pub fn visible(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(GameEntity::VT_VISIBLE, Some(true)).unwrap()}
+  }
+  #[inline]
+  pub fn visibility_radius(&self) -> f32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f32>(GameEntity::VT_VISIBILITY_RADIUS, Some(0.0)).unwrap()}
+  }
+  #[inline]
+  pub fn field_of_view(&self) -> f32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f32>(GameEntity::VT_FIELD_OF_VIEW, Some(0.0)).unwrap()}
+  }
+  #[inline]
+  pub fn noise_level(&self) -> f32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f32>(GameEntity::VT_NOISE_LEVEL, Some(0.0)).unwrap()}
+  }
+  #[inline]
+  pub fn detection_level(&self) -> f32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f32>(GameEntity::VT_DETECTION_LEVEL, Some(0.0)).unwrap()}
+  }
+  #[inline]
+  pub fn light_exposure(&self) -> f32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f32>(GameEntity::VT_LIGHT_EXPOSURE, Some(0.0)).unwrap()}
+  }
+  #[inline]
+  pub fn speed(&self) -> f32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f32>(GameEntity::VT_SPEED, Some(0.0)).unwrap()}
+  }
+  #[inline]
+  pub fn health(&self) -> f32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f32>(GameEntity::VT_HEALTH, Some(0.0)).unwrap()}
+  }
+  #[inline]
+  pub fn keycards(&self) -> Option<flatbuffers::Vector<'a, u32>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(GameEntity::VT_KEYCARDS, None)}
+  }
+  #[inline]
+  pub fn visible_to_hacker(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(GameEntity::VT_VISIBLE_TO_HACKER, Some(true)).unwrap()}
+  }
+  #[inline]
+  pub fn visible_to_infiltrator(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(GameEntity::VT_VISIBLE_TO_INFILTRATOR, Some(true)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for GameEntity<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<u32>("id", Self::VT_ID, false)?
+     .visit_field::<EntityType>("entity_type", Self::VT_ENTITY_TYPE, false)?
+     .visit_field::<f32>("x", Self::VT_X, false)?
+     .visit_field::<f32>("y", Self::VT_Y, false)?
+     .visit_field::<f32>("height", Self::VT_HEIGHT, false)?
+     .visit_field::<f32>("rotation", Self::VT_ROTATION, false)?
+     .visit_field::<f32>("vx", Self::VT_VX, false)?
+     .visit_field::<f32>("vy", Self::VT_VY, false)?
+     .visit_field::<Stance>("stance", Self::VT_STANCE, false)?
+     .visit_field::<ActorState>("state", Self::VT_STATE, false)?
+     .visit_field::<bool>("visible", Self::VT_VISIBLE, false)?
+     .visit_field::<f32>("visibility_radius", Self::VT_VISIBILITY_RADIUS, false)?
+     .visit_field::<f32>("field_of_view", Self::VT_FIELD_OF_VIEW, false)?
+     .visit_field::<f32>("noise_level", Self::VT_NOISE_LEVEL, false)?
+     .visit_field::<f32>("detection_level", Self::VT_DETECTION_LEVEL, false)?
+     .visit_field::<f32>("light_exposure", Self::VT_LIGHT_EXPOSURE, false)?
+     .visit_field::<f32>("speed", Self::VT_SPEED, false)?
+     .visit_field::<f32>("health", Self::VT_HEALTH, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>("keycards", Self::VT_KEYCARDS, false)?
+     .visit_field::<bool>("visible_to_hacker", Self::VT_VISIBLE_TO_HACKER, false)?
+     .visit_field::<bool>("visible_to_infiltrator", Self::VT_VISIBLE_TO_INFILTRATOR, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct GameEntityArgs<'a> {
+    pub id: u32,
+    pub entity_type: EntityType,
+    pub x: f32,
+    pub y: f32,
+    pub height: f32,
+    pub rotation: f32,
+    pub vx: f32,
+    pub vy: f32,
+    pub stance: Stance,
+    pub state: ActorState,
+    pub visible: bool,
+    pub visibility_radius: f32,
+    pub field_of_view: f32,
+    pub noise_level: f32,
+    pub detection_level: f32,
+    pub light_exposure: f32,
+    pub speed: f32,
+    pub health: f32,
+    pub keycards: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
+    pub visible_to_hacker: bool,
+    pub visible_to_infiltrator: bool,
+}
+impl<'a> Default for GameEntityArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    GameEntityArgs {
+      id: 0,
+      entity_type: EntityType::Player,
+      x: 0.0,
+      y: 0.0,
+      height: 0.0,
+      rotation: 0.0,
+      vx: 0.0,
+      vy: 0.0,
+      stance: Stance::Standing,
+      state: ActorState::Idle,
+      visible: true,
+      visibility_radius: 0.0,
+      field_of_view: 0.0,
+      noise_level: 0.0,
+      detection_level: 0.0,
+      light_exposure: 0.0,
+      speed: 0.0,
+      health: 0.0,
+      keycards: None,
+      visible_to_hacker: true,
+      visible_to_infiltrator: true,
+    }
+  }
+}
+
+pub struct GameEntityBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> GameEntityBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_id(&mut self, id: u32) {
+    self.fbb_.push_slot::<u32>(GameEntity::VT_ID, id, 0);
+  }
+  #[inline]
+  pub fn add_entity_type(&mut self, entity_type: EntityType) {
+    self.fbb_.push_slot::<EntityType>(GameEntity::VT_ENTITY_TYPE, entity_type, EntityType::Player);
+  }
+  #[inline]
+  pub fn add_x(&mut self, x: f32) {
+    self.fbb_.push_slot::<f32>(GameEntity::VT_X, x, 0.0);
+  }
+  #[inline]
+  pub fn add_y(&mut self, y: f32) {
+    self.fbb_.push_slot::<f32>(GameEntity::VT_Y, y, 0.0);
+  }
+  #[inline]
+  pub fn add_height(&mut self, height: f32) {
+    self.fbb_.push_slot::<f32>(GameEntity::VT_HEIGHT, height, 0.0);
+  }
+  #[inline]
+  pub fn add_rotation(&mut self, rotation: f32) {
+    self.fbb_.push_slot::<f32>(GameEntity::VT_ROTATION, rotation, 0.0);
+  }
+  #[inline]
+  pub fn add_vx(&mut self, vx: f32) {
+    self.fbb_.push_slot::<f32>(GameEntity::VT_VX, vx, 0.0);
+  }
+  #[inline]
+  pub fn add_vy(&mut self, vy: f32) {
+    self.fbb_.push_slot::<f32>(GameEntity::VT_VY, vy, 0.0);
+  }
+  #[inline]
+  pub fn add_stance(&mut self, stance: Stance) {
+    self.fbb_.push_slot::<Stance>(GameEntity::VT_STANCE, stance, Stance::Standing);
+  }
+  #[inline]
+  pub fn add_state(&mut self, state: ActorState) {
+    self.fbb_.push_slot::<ActorState>(GameEntity::VT_STATE, state, ActorState::Idle);
+  }
+  #[inline]
+  pub fn add_visible(&mut self, visible: bool) {
+    self.fbb_.push_slot::<bool>(GameEntity::VT_VISIBLE, visible, true);
+  }
+  #[inline]
+  pub fn add_visibility_radius(&mut self, visibility_radius: f32) {
+    self.fbb_.push_slot::<f32>(GameEntity::VT_VISIBILITY_RADIUS, visibility_radius, 0.0);
+  }
+  #[inline]
+  pub fn add_field_of_view(&mut self, field_of_view: f32) {
+    self.fbb_.push_slot::<f32>(GameEntity::VT_FIELD_OF_VIEW, field_of_view, 0.0);
+  }
+  #[inline]
+  pub fn add_noise_level(&mut self, noise_level: f32) {
+    self.fbb_.push_slot::<f32>(GameEntity::VT_NOISE_LEVEL, noise_level, 0.0);
+  }
+  #[inline]
+  pub fn add_detection_level(&mut self, detection_level: f32) {
+    self.fbb_.push_slot::<f32>(GameEntity::VT_DETECTION_LEVEL, detection_level, 0.0);
+  }
+  #[inline]
+  pub fn add_light_exposure(&mut self, light_exposure: f32) {
+    self.fbb_.push_slot::<f32>(GameEntity::VT_LIGHT_EXPOSURE, light_exposure, 0.0);
+  }
+  #[inline]
+  pub fn add_speed(&mut self, speed: f32) {
+    self.fbb_.push_slot::<f32>(GameEntity::VT_SPEED, speed, 0.0);
+  }
+  #[inline]
+  pub fn add_health(&mut self, health: f32) {
+    self.fbb_.push_slot::<f32>(GameEntity::VT_HEALTH, health, 0.0);
+  }
+  #[inline]
+  pub fn add_keycards(&mut self, keycards: flatbuffers::WIPOffset<flatbuffers::Vector<'b , u32>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(GameEntity::VT_KEYCARDS, keycards);
+  }
+  #[inline]
+  pub fn add_visible_to_hacker(&mut self, visible_to_hacker: bool) {
+    self.fbb_.push_slot::<bool>(GameEntity::VT_VISIBLE_TO_HACKER, visible_to_hacker, true);
+  }
+  #[inline]
+  pub fn add_visible_to_infiltrator(&mut self, visible_to_infiltrator: bool) {
+    self.fbb_.push_slot::<bool>(GameEntity::VT_VISIBLE_TO_INFILTRATOR, visible_to_infiltrator, true);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> GameEntityBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    GameEntityBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<GameEntity<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for GameEntity<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("GameEntity");
+      ds.field("id", &self.id());
+      ds.field("entity_type", &self.entity_type());
+      ds.field("x", &self.x());
+      ds.field("y", &self.y());
+      ds.field("height", &self.height());
+      ds.field("rotation", &self.rotation());
+      ds.field("vx", &self.vx());
+      ds.field("vy", &self.vy());
+      ds.field("stance", &self.stance());
+      ds.field("state", &self.state());
+      ds.field("visible", &self.visible());
+      ds.field("visibility_radius", &self.visibility_radius());
+      ds.field("field_of_view", &self.field_of_view());
+      ds.field("noise_level", &self.noise_level());
+      ds.field("detection_level", &self.detection_level());
+      ds.field("light_exposure", &self.light_exposure());
+      ds.field("speed", &self.speed());
+      ds.field("health", &self.health());
+      ds.field("keycards", &self.keycards());
+      ds.field("visible_to_hacker", &self.visible_to_hacker());
+      ds.field("visible_to_infiltrator", &self.visible_to_infiltrator());
+      ds.finish()
+  }
+}
+pub enum EntityDeltaOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct EntityDelta<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for EntityDelta<'a> {
+  type Inner = EntityDelta<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> EntityDelta<'a> {
+  pub const VT_ID: flatbuffers::VOffsetT = 4;
+  pub const VT_KIND: flatbuffers::VOffsetT = 6;
+  pub const VT_X: flatbuffers::VOffsetT = 8;
+  pub const VT_Y: flatbuffers::VOffsetT = 10;
+  pub const VT_ROT: flatbuffers::VOffsetT = 12;
+  pub const VT_VX: flatbuffers::VOffsetT = 14;
+  pub const VT_VY: flatbuffers::VOffsetT = 16;
+  pub const VT_STANCE: flatbuffers::VOffsetT = 18;
+  pub const VT_STATE: flatbuffers::VOffsetT = 20;
+  pub const VT_DET: flatbuffers::VOffsetT = 22;
+  pub const VT_HP: flatbuffers::VOffsetT = 24;
+  pub const VT_VIS: flatbuffers::VOffsetT = 26;
+  pub const VT_VH: flatbuffers::VOffsetT = 28;
+  pub const VT_VI: flatbuffers::VOffsetT = 30;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    EntityDelta { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args EntityDeltaArgs
+  ) -> flatbuffers::WIPOffset<EntityDelta<'bldr>> {
+    let mut builder = EntityDeltaBuilder::new(_fbb);
+    if let Some(x) = args.vy { builder.add_vy(x); }
+    if let Some(x) = args.vx { builder.add_vx(x); }
+    if let Some(x) = args.y { builder.add_y(x); }
+    if let Some(x) = args.x { builder.add_x(x); }
+    builder.add_id(args.id);
+    if let Some(x) = args.rot { builder.add_rot(x); }
+    if let Some(x) = args.vi { builder.add_vi(x); }
+    if let Some(x) = args.vh { builder.add_vh(x); }
+    if let Some(x) = args.vis { builder.add_vis(x); }
+    if let Some(x) = args.hp { builder.add_hp(x); }
+    if let Some(x) = args.det { builder.add_det(x); }
+    if let Some(x) = args.state { builder.add_state(x); }
+    if let Some(x) = args.stance { builder.add_stance(x); }
+    if let Some(x) = args.kind { builder.add_kind(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn id(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(EntityDelta::VT_ID, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn kind(&self) -> Option<EntityType> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<EntityType>(EntityDelta::VT_KIND, None)}
+  }
+  #[inline]
+  pub fn x(&self) -> Option<i32> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<i32>(EntityDelta::VT_X, None)}
+  }
+  #[inline]
+  pub fn y(&self) -> Option<i32> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<i32>(EntityDelta::VT_Y, None)}
+  }
+  #[inline]
+  pub fn rot(&self) -> Option<u16> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u16>(EntityDelta::VT_ROT, None)}
+  }
+  #[inline]
+  pub fn vx(&self) -> Option<i32> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<i32>(EntityDelta::VT_VX, None)}
+  }
+  #[inline]
+  pub fn vy(&self) -> Option<i32> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<i32>(EntityDelta::VT_VY, None)}
+  }
+  #[inline]
+  pub fn stance(&self) -> Option<Stance> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<Stance>(EntityDelta::VT_STANCE, None)}
+  }
+  #[inline]
+  pub fn state(&self) -> Option<ActorState> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<ActorState>(EntityDelta::VT_STATE, None)}
+  }
+  #[inline]
+  pub fn det(&self) -> Option<u8> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u8>(EntityDelta::VT_DET, None)}
+  }
+  #[inline]
+  pub fn hp(&self) -> Option<u8> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u8>(EntityDelta::VT_HP, None)}
+  }
+  #[inline]
+  pub fn vis(&self) -> Option<bool> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(EntityDelta::VT_VIS, None)}
+  }
+  #[inline]
+  pub fn vh(&self) -> Option<bool> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(EntityDelta::VT_VH, None)}
+  }
+  #[inline]
+  pub fn vi(&self) -> Option<bool> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(EntityDelta::VT_VI, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for EntityDelta<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<u32>("id", Self::VT_ID, false)?
+     .visit_field::<EntityType>("kind", Self::VT_KIND, false)?
+     .visit_field::<i32>("x", Self::VT_X, false)?
+     .visit_field::<i32>("y", Self::VT_Y, false)?
+     .visit_field::<u16>("rot", Self::VT_ROT, false)?
+     .visit_field::<i32>("vx", Self::VT_VX, false)?
+     .visit_field::<i32>("vy", Self::VT_VY, false)?
+     .visit_field::<Stance>("stance", Self::VT_STANCE, false)?
+     .visit_field::<ActorState>("state", Self::VT_STATE, false)?
+     .visit_field::<u8>("det", Self::VT_DET, false)?
+     .visit_field::<u8>("hp", Self::VT_HP, false)?
+     .visit_field::<bool>("vis", Self::VT_VIS, false)?
+     .visit_field::<bool>("vh", Self::VT_VH, false)?
+     .visit_field::<bool>("vi", Self::VT_VI, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct EntityDeltaArgs {
+    pub id: u32,
+    pub kind: Option<EntityType>,
+    pub x: Option<i32>,
+    pub y: Option<i32>,
+    pub rot: Option<u16>,
+    pub vx: Option<i32>,
+    pub vy: Option<i32>,
+    pub stance: Option<Stance>,
+    pub state: Option<ActorState>,
+    pub det: Option<u8>,
+    pub hp: Option<u8>,
+    pub vis: Option<bool>,
+    pub vh: Option<bool>,
+    pub vi: Option<bool>,
+}
+impl Default for EntityDeltaArgs {
+  #[inline]
+  fn default() -> Self {
+    EntityDeltaArgs {
+      id: 0,
+      kind: None,
+      x: None,
+      y: None,
+      rot: None,
+      vx: None,
+      vy: None,
+      stance: None,
+      state: None,
+      det: None,
+      hp: None,
+      vis: None,
+      vh: None,
+      vi: None,
+    }
+  }
+}
+
+pub struct EntityDeltaBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> EntityDeltaBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_id(&mut self, id: u32) {
+    self.fbb_.push_slot::<u32>(EntityDelta::VT_ID, id, 0);
+  }
+  #[inline]
+  pub fn add_kind(&mut self, kind: EntityType) {
+    self.fbb_.push_slot_always::<EntityType>(EntityDelta::VT_KIND, kind);
+  }
+  #[inline]
+  pub fn add_x(&mut self, x: i32) {
+    self.fbb_.push_slot_always::<i32>(EntityDelta::VT_X, x);
+  }
+  #[inline]
+  pub fn add_y(&mut self, y: i32) {
+    self.fbb_.push_slot_always::<i32>(EntityDelta::VT_Y, y);
+  }
+  #[inline]
+  pub fn add_rot(&mut self, rot: u16) {
+    self.fbb_.push_slot_always::<u16>(EntityDelta::VT_ROT, rot);
+  }
+  #[inline]
+  pub fn add_vx(&mut self, vx: i32) {
+    self.fbb_.push_slot_always::<i32>(EntityDelta::VT_VX, vx);
+  }
+  #[inline]
+  pub fn add_vy(&mut self, vy: i32) {
+    self.fbb_.push_slot_always::<i32>(EntityDelta::VT_VY, vy);
+  }
+  #[inline]
+  pub fn add_stance(&mut self, stance: Stance) {
+    self.fbb_.push_slot_always::<Stance>(EntityDelta::VT_STANCE, stance);
+  }
+  #[inline]
+  pub fn add_state(&mut self, state: ActorState) {
+    self.fbb_.push_slot_always::<ActorState>(EntityDelta::VT_STATE, state);
+  }
+  #[inline]
+  pub fn add_det(&mut self, det: u8) {
+    self.fbb_.push_slot_always::<u8>(EntityDelta::VT_DET, det);
+  }
+  #[inline]
+  pub fn add_hp(&mut self, hp: u8) {
+    self.fbb_.push_slot_always::<u8>(EntityDelta::VT_HP, hp);
+  }
+  #[inline]
+  pub fn add_vis(&mut self, vis: bool) {
+    self.fbb_.push_slot_always::<bool>(EntityDelta::VT_VIS, vis);
+  }
+  #[inline]
+  pub fn add_vh(&mut self, vh: bool) {
+    self.fbb_.push_slot_always::<bool>(EntityDelta::VT_VH, vh);
+  }
+  #[inline]
+  pub fn add_vi(&mut self, vi: bool) {
+    self.fbb_.push_slot_always::<bool>(EntityDelta::VT_VI, vi);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> EntityDeltaBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    EntityDeltaBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<EntityDelta<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for EntityDelta<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("EntityDelta");
+      ds.field("id", &self.id());
+      ds.field("kind", &self.kind());
+      ds.field("x", &self.x());
+      ds.field("y", &self.y());
+      ds.field("rot", &self.rot());
+      ds.field("vx", &self.vx());
+      ds.field("vy", &self.vy());
+      ds.field("stance", &self.stance());
+      ds.field("state", &self.state());
+      ds.field("det", &self.det());
+      ds.field("hp", &self.hp());
+      ds.field("vis", &self.vis());
+      ds.field("vh", &self.vh());
+      ds.field("vi", &self.vi());
+      ds.finish()
+  }
+}
+pub enum PingMarkerOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct PingMarker<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for PingMarker<'a> {
+  type Inner = PingMarker<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> PingMarker<'a> {
+  pub const VT_POSITION: flatbuffers::VOffsetT = 4;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    PingMarker { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args PingMarkerArgs<'args>
+  ) -> flatbuffers::WIPOffset<PingMarker<'bldr>> {
+    let mut builder = PingMarkerBuilder::new(_fbb);
+    if let Some(x) = args.position { builder.add_position(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn position(&self) -> Option<&'a Position> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<Position>(PingMarker::VT_POSITION, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for PingMarker<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<Position>("position", Self::VT_POSITION, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct PingMarkerArgs<'a> {
+    pub position: Option<&'a Position>,
+}
+impl<'a> Default for PingMarkerArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    PingMarkerArgs {
+      position: None,
+    }
+  }
+}
+
+pub struct PingMarkerBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> PingMarkerBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_position(&mut self, position: &Position) {
+    self.fbb_.push_slot_always::<&Position>(PingMarker::VT_POSITION, position);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> PingMarkerBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    PingMarkerBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<PingMarker<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for PingMarker<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("PingMarker");
+      ds.field("position", &self.position());
+      ds.finish()
+  }
+}
+pub enum TagMarkerOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct TagMarker<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for TagMarker<'a> {
+  type Inner = TagMarker<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> TagMarker<'a> {
+  pub const VT_ENTITY_ID: flatbuffers::VOffsetT = 4;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    TagMarker { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args TagMarkerArgs
+  ) -> flatbuffers::WIPOffset<TagMarker<'bldr>> {
+    let mut builder = TagMarkerBuilder::new(_fbb);
+    builder.add_entity_id(args.entity_id);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn entity_id(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(TagMarker::VT_ENTITY_ID, Some(0)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for TagMarker<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<u32>("entity_id", Self::VT_ENTITY_ID, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct TagMarkerArgs {
+    pub entity_id: u32,
+}
+impl Default for TagMarkerArgs {
+  #[inline]
+  fn default() -> Self {
+    TagMarkerArgs {
+      entity_id: 0,
+    }
+  }
+}
+
+pub struct TagMarkerBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> TagMarkerBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_entity_id(&mut self, entity_id: u32) {
+    self.fbb_.push_slot::<u32>(TagMarker::VT_ENTITY_ID, entity_id, 0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> TagMarkerBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    TagMarkerBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<TagMarker<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for TagMarker<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("TagMarker");
+      ds.field("entity_id", &self.entity_id());
+      ds.finish()
+  }
+}
+pub enum RouteMarkerOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct RouteMarker<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for RouteMarker<'a> {
+  type Inner = RouteMarker<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> RouteMarker<'a> {
+  pub const VT_POINTS: flatbuffers::VOffsetT = 4;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    RouteMarker { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args RouteMarkerArgs<'args>
+  ) -> flatbuffers::WIPOffset<RouteMarker<'bldr>> {
+    let mut builder = RouteMarkerBuilder::new(_fbb);
+    if let Some(x) = args.points { builder.add_points(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn points(&self) -> Option<flatbuffers::Vector<'a, Position>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, Position>>>(RouteMarker::VT_POINTS, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for RouteMarker<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, Position>>>("points", Self::VT_POINTS, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct RouteMarkerArgs<'a> {
+    pub points: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, Position>>>,
+}
+impl<'a> Default for RouteMarkerArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    RouteMarkerArgs {
+      points: None,
+    }
+  }
+}
+
+pub struct RouteMarkerBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> RouteMarkerBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_points(&mut self, points: flatbuffers::WIPOffset<flatbuffers::Vector<'b , Position>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(RouteMarker::VT_POINTS, points);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> RouteMarkerBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    RouteMarkerBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<RouteMarker<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for RouteMarker<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("RouteMarker");
+      ds.field("points", &self.points());
+      ds.finish()
+  }
+}
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MIN_MARKER_KIND: u8 = 0;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MAX_MARKER_KIND: u8 = 3;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+#[allow(non_camel_case_types)]
+pub const ENUM_VALUES_MARKER_KIND: [MarkerKind; 4] = [
+  MarkerKind::NONE,
+  MarkerKind::PingMarker,
+  MarkerKind::TagMarker,
+  MarkerKind::RouteMarker,
+];
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct MarkerKind(pub u8);
+#[allow(non_upper_case_globals)]
+impl MarkerKind {
+  pub const NONE: Self = Self(0);
+  pub const PingMarker: Self = Self(1);
+  pub const TagMarker: Self = Self(2);
+  pub const RouteMarker: Self = Self(3);
+
+  pub const ENUM_MIN: u8 = 0;
+  pub const ENUM_MAX: u8 = 3;
+  pub const ENUM_VALUES: &'static [Self] = &[
+    Self::NONE,
+    Self::PingMarker,
+    Self::TagMarker,
+    Self::RouteMarker,
+  ];
+  /// Returns the variant's name or "" if unknown.
+  pub fn variant_name(self) -> Option<&'static str> {
+    match self {
+      Self::NONE => Some("NONE"),
+      Self::PingMarker => Some("PingMarker"),
+      Self::TagMarker => Some("TagMarker"),
+      Self::RouteMarker => Some("RouteMarker"),
+      _ => None,
+    }
+  }
+}
+impl core::fmt::Debug for MarkerKind {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    if let Some(name) = self.variant_name() {
+      f.write_str(name)
+    } else {
+      f.write_fmt(format_args!("<UNKNOWN {:?}>", self.0))
+    }
+  }
+}
+impl<'a> flatbuffers::Follow<'a> for MarkerKind {
+  type Inner = Self;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    let b = unsafe { flatbuffers::read_scalar_at::<u8>(buf, loc) };
+    Self(b)
+  }
+}
+
+impl flatbuffers::Push for MarkerKind {
+    type Output = MarkerKind;
+    #[inline]
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        unsafe { flatbuffers::emplace_scalar::<u8>(dst, self.0) };
+    }
+}
+
+impl flatbuffers::EndianScalar for MarkerKind {
+  type Scalar = u8;
+  #[inline]
+  fn to_little_endian(self) -> u8 {
+    self.0.to_le()
+  }
+  #[inline]
+  #[allow(clippy::wrong_self_convention)]
+  fn from_little_endian(v: u8) -> Self {
+    let b = u8::from_le(v);
+    Self(b)
+  }
+}
+
+impl<'a> flatbuffers::Verifiable for MarkerKind {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    u8::run_verifier(v, pos)
+  }
+}
+
+impl flatbuffers::SimpleToVerifyInSlice for MarkerKind {}
+pub struct MarkerKindUnionTableOffset {}
+
+pub enum MarkerOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct Marker<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Marker<'a> {
+  type Inner = Marker<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> Marker<'a> {
+  pub const VT_MARKER_ID: flatbuffers::VOffsetT = 4;
+  pub const VT_KIND_TYPE: flatbuffers::VOffsetT = 6;
+  pub const VT_KIND: flatbuffers::VOffsetT = 8;
+  pub const VT_EXPIRES_AT: flatbuffers::VOffsetT = 10;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    Marker { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args MarkerArgs
+  ) -> flatbuffers::WIPOffset<Marker<'bldr>> {
+    let mut builder = MarkerBuilder::new(_fbb);
+    builder.add_expires_at(args.expires_at);
+    if let Some(x) = args.kind { builder.add_kind(x); }
+    builder.add_marker_id(args.marker_id);
+    builder.add_kind_type(args.kind_type);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn marker_id(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(Marker::VT_MARKER_ID, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn kind_type(&self) -> MarkerKind {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<MarkerKind>(Marker::VT_KIND_TYPE, Some(MarkerKind::NONE)).unwrap()}
+  }
+  #[inline]
+  pub fn kind(&self) -> Option<flatbuffers::Table<'a>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Table<'a>>>(Marker::VT_KIND, None)}
+  }
+  #[inline]
+  pub fn expires_at(&self) -> f32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f32>(Marker::VT_EXPIRES_AT, Some(0.0)).unwrap()}
+  }
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn kind_as_ping_marker(&self) -> Option<PingMarker<'a>> {
+    if self.kind_type() == MarkerKind::PingMarker {
+      self.kind().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { PingMarker::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn kind_as_tag_marker(&self) -> Option<TagMarker<'a>> {
+    if self.kind_type() == MarkerKind::TagMarker {
+      self.kind().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { TagMarker::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn kind_as_route_marker(&self) -> Option<RouteMarker<'a>> {
+    if self.kind_type() == MarkerKind::RouteMarker {
+      self.kind().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { RouteMarker::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+}
+
+impl flatbuffers::Verifiable for Marker<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<u32>("marker_id", Self::VT_MARKER_ID, false)?
+     .visit_union::<MarkerKind, _>("kind_type", Self::VT_KIND_TYPE, "kind", Self::VT_KIND, false, |key, v, pos| {
+        match key {
+          MarkerKind::PingMarker => v.verify_union_variant::<flatbuffers::ForwardsUOffset<PingMarker>>("MarkerKind::PingMarker", pos),
+          MarkerKind::TagMarker => v.verify_union_variant::<flatbuffers::ForwardsUOffset<TagMarker>>("MarkerKind::TagMarker", pos),
+          MarkerKind::RouteMarker => v.verify_union_variant::<flatbuffers::ForwardsUOffset<RouteMarker>>("MarkerKind::RouteMarker", pos),
+          _ => Ok(()),
+        }
+     })?
+     .visit_field::<f32>("expires_at", Self::VT_EXPIRES_AT, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct MarkerArgs {
+    pub marker_id: u32,
+    pub kind_type: MarkerKind,
+    pub kind: Option<flatbuffers::WIPOffset<flatbuffers::UnionWIPOffset>>,
+    pub expires_at: f32,
+}
+impl Default for MarkerArgs {
+  #[inline]
+  fn default() -> Self {
+    MarkerArgs {
+      marker_id: 0,
+      kind_type: MarkerKind::NONE,
+      kind: None,
+      expires_at: 0.0,
+    }
+  }
+}
+
+pub struct MarkerBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> MarkerBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_marker_id(&mut self, marker_id: u32) {
+    self.fbb_.push_slot::<u32>(Marker::VT_MARKER_ID, marker_id, 0);
+  }
+  #[inline]
+  pub fn add_kind_type(&mut self, kind_type: MarkerKind) {
+    self.fbb_.push_slot::<MarkerKind>(Marker::VT_KIND_TYPE, kind_type, MarkerKind::NONE);
+  }
+  #[inline]
+  pub fn add_kind(&mut self, kind: flatbuffers::WIPOffset<flatbuffers::UnionWIPOffset>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Marker::VT_KIND, kind);
+  }
+  #[inline]
+  pub fn add_expires_at(&mut self, expires_at: f32) {
+    self.fbb_.push_slot::<f32>(Marker::VT_EXPIRES_AT, expires_at, 0.0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> MarkerBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    MarkerBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Marker<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for Marker<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("Marker");
+      ds.field("marker_id", &self.marker_id());
+      ds.field("kind_type", &self.kind_type());
+      match self.kind_type() {
+        MarkerKind::PingMarker => {
+          if let Some(x) = self.kind_as_ping_marker() {
+            ds.field("kind", &x)
+          } else {
+            ds.field("kind", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        MarkerKind::TagMarker => {
+          if let Some(x) = self.kind_as_tag_marker() {
+            ds.field("kind", &x)
+          } else {
+            ds.field("kind", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        MarkerKind::RouteMarker => {
+          if let Some(x) = self.kind_as_route_marker() {
+            ds.field("kind", &x)
+          } else {
+            ds.field("kind", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        _ => {
+          let x: Option<()> = None;
+          ds.field("kind", &x)
+        },
+      };
+      ds.field("expires_at", &self.expires_at());
+      ds.finish()
+  }
+}
+pub enum SensorOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct Sensor<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Sensor<'a> {
+  type Inner = Sensor<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> Sensor<'a> {
+  pub const VT_SENSOR_ID: flatbuffers::VOffsetT = 4;
+  pub const VT_KIND: flatbuffers::VOffsetT = 6;
+  pub const VT_RANGE: flatbuffers::VOffsetT = 8;
+  pub const VT_FIELD_OF_VIEW: flatbuffers::VOffsetT = 10;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    Sensor { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args SensorArgs
+  ) -> flatbuffers::WIPOffset<Sensor<'bldr>> {
+    let mut builder = SensorBuilder::new(_fbb);
+    builder.add_field_of_view(args.field_of_view);
+    builder.add_range(args.range);
+    builder.add_sensor_id(args.sensor_id);
+    builder.add_kind(args.kind);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn sensor_id(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(Sensor::VT_SENSOR_ID, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn kind(&self) -> DeviceKind {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<DeviceKind>(Sensor::VT_KIND, Some(DeviceKind::Camera)).unwrap()}
+  }
+  #[inline]
+  pub fn range(&self) -> f32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f32>(Sensor::VT_RANGE, Some(0.0)).unwrap()}
+  }
+  #[inline]
+  pub fn field_of_view(&self) -> f32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f32>(Sensor::VT_FIELD_OF_VIEW, Some(0.0)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for Sensor<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<u32>("sensor_id", Self::VT_SENSOR_ID, false)?
+     .visit_field::<DeviceKind>("kind", Self::VT_KIND, false)?
+     .visit_field::<f32>("range", Self::VT_RANGE, false)?
+     .visit_field::<f32>("field_of_view", Self::VT_FIELD_OF_VIEW, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct SensorArgs {
+    pub sensor_id: u32,
+    pub kind: DeviceKind,
+    pub range: f32,
+    pub field_of_view: f32,
+}
+impl Default for SensorArgs {
+  #[inline]
+  fn default() -> Self {
+    SensorArgs {
+      sensor_id: 0,
+      kind: DeviceKind::Camera,
+      range: 0.0,
+      field_of_view: 0.0,
+    }
+  }
+}
+
+pub struct SensorBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> SensorBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_sensor_id(&mut self, sensor_id: u32) {
+    self.fbb_.push_slot::<u32>(Sensor::VT_SENSOR_ID, sensor_id, 0);
+  }
+  #[inline]
+  pub fn add_kind(&mut self, kind: DeviceKind) {
+    self.fbb_.push_slot::<DeviceKind>(Sensor::VT_KIND, kind, DeviceKind::Camera);
+  }
+  #[inline]
+  pub fn add_range(&mut self, range: f32) {
+    self.fbb_.push_slot::<f32>(Sensor::VT_RANGE, range, 0.0);
+  }
+  #[inline]
+  pub fn add_field_of_view(&mut self, field_of_view: f32) {
+    self.fbb_.push_slot::<f32>(Sensor::VT_FIELD_OF_VIEW, field_of_view, 0.0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> SensorBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    SensorBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Sensor<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for Sensor<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("Sensor");
+      ds.field("sensor_id", &self.sensor_id());
+      ds.field("kind", &self.kind());
+      ds.field("range", &self.range());
+      ds.field("field_of_view", &self.field_of_view());
+      ds.finish()
+  }
+}
+pub enum SightingOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct Sighting<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Sighting<'a> {
+  type Inner = Sighting<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> Sighting<'a> {
+  pub const VT_ENTITY_ID: flatbuffers::VOffsetT = 4;
+  pub const VT_ENTITY_TYPE: flatbuffers::VOffsetT = 6;
+  pub const VT_POSITION: flatbuffers::VOffsetT = 8;
+  pub const VT_SEEN_AT: flatbuffers::VOffsetT = 10;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    Sighting { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args SightingArgs<'args>
+  ) -> flatbuffers::WIPOffset<Sighting<'bldr>> {
+    let mut builder = SightingBuilder::new(_fbb);
+    builder.add_seen_at(args.seen_at);
+    if let Some(x) = args.position { builder.add_position(x); }
+    builder.add_entity_id(args.entity_id);
+    builder.add_entity_type(args.entity_type);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn entity_id(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(Sighting::VT_ENTITY_ID, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn entity_type(&self) -> EntityType {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<EntityType>(Sighting::VT_ENTITY_TYPE, Some(EntityType::Player)).unwrap()}
+  }
+  #[inline]
+  pub fn position(&self) -> Option<&'a Position> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<Position>(Sighting::VT_POSITION, None)}
+  }
+  #[inline]
+  pub fn seen_at(&self) -> f32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f32>(Sighting::VT_SEEN_AT, Some(0.0)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for Sighting<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<u32>("entity_id", Self::VT_ENTITY_ID, false)?
+     .visit_field::<EntityType>("entity_type", Self::VT_ENTITY_TYPE, false)?
+     .visit_field::<Position>("position", Self::VT_POSITION, false)?
+     .visit_field::<f32>("seen_at", Self::VT_SEEN_AT, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct SightingArgs<'a> {
+    pub entity_id: u32,
+    pub entity_type: EntityType,
+    pub position: Option<&'a Position>,
+    pub seen_at: f32,
+}
+impl<'a> Default for SightingArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    SightingArgs {
+      entity_id: 0,
+      entity_type: EntityType::Player,
+      position: None,
+      seen_at: 0.0,
+    }
+  }
+}
+
+pub struct SightingBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> SightingBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_entity_id(&mut self, entity_id: u32) {
+    self.fbb_.push_slot::<u32>(Sighting::VT_ENTITY_ID, entity_id, 0);
+  }
+  #[inline]
+  pub fn add_entity_type(&mut self, entity_type: EntityType) {
+    self.fbb_.push_slot::<EntityType>(Sighting::VT_ENTITY_TYPE, entity_type, EntityType::Player);
+  }
+  #[inline]
+  pub fn add_position(&mut self, position: &Position) {
+    self.fbb_.push_slot_always::<&Position>(Sighting::VT_POSITION, position);
+  }
+  #[inline]
+  pub fn add_seen_at(&mut self, seen_at: f32) {
+    self.fbb_.push_slot::<f32>(Sighting::VT_SEEN_AT, seen_at, 0.0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> SightingBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    SightingBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Sighting<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for Sighting<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("Sighting");
+      ds.field("entity_id", &self.entity_id());
+      ds.field("entity_type", &self.entity_type());
+      ds.field("position", &self.position());
+      ds.field("seen_at", &self.seen_at());
+      ds.finish()
+  }
+}
+pub enum ObjectiveStatusOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct ObjectiveStatus<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for ObjectiveStatus<'a> {
+  type Inner = ObjectiveStatus<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> ObjectiveStatus<'a> {
+  pub const VT_OBJECTIVE_ID: flatbuffers::VOffsetT = 4;
+  pub const VT_DESCRIPTION: flatbuffers::VOffsetT = 6;
+  pub const VT_COMPLETE: flatbuffers::VOffsetT = 8;
+  pub const VT_OPTIONAL: flatbuffers::VOffsetT = 10;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    ObjectiveStatus { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args ObjectiveStatusArgs<'args>
+  ) -> flatbuffers::WIPOffset<ObjectiveStatus<'bldr>> {
+    let mut builder = ObjectiveStatusBuilder::new(_fbb);
+    if let Some(x) = args.description { builder.add_description(x); }
+    builder.add_objective_id(args.objective_id);
+    builder.add_optional(args.optional);
+    builder.add_complete(args.complete);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn objective_id(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(ObjectiveStatus::VT_OBJECTIVE_ID, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn description(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(ObjectiveStatus::VT_DESCRIPTION, None)}
+  }
+  #[inline]
+  pub fn complete(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(ObjectiveStatus::VT_COMPLETE, Some(false)).unwrap()}
+  }
+  #[inline]
+  pub fn optional(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(ObjectiveStatus::VT_OPTIONAL, Some(false)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for ObjectiveStatus<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<u32>("objective_id", Self::VT_OBJECTIVE_ID, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("description", Self::VT_DESCRIPTION, false)?
+     .visit_field::<bool>("complete", Self::VT_COMPLETE, false)?
+     .visit_field::<bool>("optional", Self::VT_OPTIONAL, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct ObjectiveStatusArgs<'a> {
+    pub objective_id: u32,
+    pub description: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub complete: bool,
+    pub optional: bool,
+}
+impl<'a> Default for ObjectiveStatusArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    ObjectiveStatusArgs {
+      objective_id: 0,
+      description: None,
+      complete: false,
+      optional: false,
+    }
+  }
+}
+
+pub struct ObjectiveStatusBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ObjectiveStatusBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_objective_id(&mut self, objective_id: u32) {
+    self.fbb_.push_slot::<u32>(ObjectiveStatus::VT_OBJECTIVE_ID, objective_id, 0);
+  }
+  #[inline]
+  pub fn add_description(&mut self, description: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(ObjectiveStatus::VT_DESCRIPTION, description);
+  }
+  #[inline]
+  pub fn add_complete(&mut self, complete: bool) {
+    self.fbb_.push_slot::<bool>(ObjectiveStatus::VT_COMPLETE, complete, false);
+  }
+  #[inline]
+  pub fn add_optional(&mut self, optional: bool) {
+    self.fbb_.push_slot::<bool>(ObjectiveStatus::VT_OPTIONAL, optional, false);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> ObjectiveStatusBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    ObjectiveStatusBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<ObjectiveStatus<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for ObjectiveStatus<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("ObjectiveStatus");
+      ds.field("objective_id", &self.objective_id());
+      ds.field("description", &self.description());
+      ds.field("complete", &self.complete());
+      ds.field("optional", &self.optional());
+      ds.finish()
+  }
+}
+pub enum WelcomeOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct Welcome<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Welcome<'a> {
+  type Inner = Welcome<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> Welcome<'a> {
+  pub const VT_VERSION: flatbuffers::VOffsetT = 4;
+  pub const VT_ENCODING: flatbuffers::VOffsetT = 6;
+  pub const VT_CAPABILITIES: flatbuffers::VOffsetT = 8;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    Welcome { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args WelcomeArgs<'args>
+  ) -> flatbuffers::WIPOffset<Welcome<'bldr>> {
+    let mut builder = WelcomeBuilder::new(_fbb);
+    if let Some(x) = args.capabilities { builder.add_capabilities(x); }
+    if let Some(x) = args.encoding { builder.add_encoding(x); }
+    builder.add_version(args.version);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn version(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(Welcome::VT_VERSION, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn encoding(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(Welcome::VT_ENCODING, None)}
+  }
+  #[inline]
+  pub fn capabilities(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>>(Welcome::VT_CAPABILITIES, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for Welcome<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<u32>("version", Self::VT_VERSION, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("encoding", Self::VT_ENCODING, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<&'_ str>>>>("capabilities", Self::VT_CAPABILITIES, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct WelcomeArgs<'a> {
+    pub version: u32,
+    pub encoding: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub capabilities: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>>,
+}
+impl<'a> Default for WelcomeArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    WelcomeArgs {
+      version: 0,
+      encoding: None,
+      capabilities: None,
+    }
+  }
+}
+
+pub struct WelcomeBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> WelcomeBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_version(&mut self, version: u32) {
+    self.fbb_.push_slot::<u32>(Welcome::VT_VERSION, version, 0);
+  }
+  #[inline]
+  pub fn add_encoding(&mut self, encoding: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Welcome::VT_ENCODING, encoding);
+  }
+  #[inline]
+  pub fn add_capabilities(&mut self, capabilities: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<&'b  str>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Welcome::VT_CAPABILITIES, capabilities);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> WelcomeBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    WelcomeBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Welcome<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for Welcome<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("Welcome");
+      ds.field("version", &self.version());
+      ds.field("encoding", &self.encoding());
+      ds.field("capabilities", &self.capabilities());
+      ds.finish()
+  }
+}
+pub enum StateUpdateOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct StateUpdate<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for StateUpdate<'a> {
+  type Inner = StateUpdate<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> StateUpdate<'a> {
+  pub const VT_ENTITIES: flatbuffers::VOffsetT = 4;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    StateUpdate { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args StateUpdateArgs<'args>
+  ) -> flatbuffers::WIPOffset<StateUpdate<'bldr>> {
+    let mut builder = StateUpdateBuilder::new(_fbb);
+    if let Some(x) = args.entities { builder.add_entities(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn entities(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<GameEntity<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<GameEntity<'a>>>>>(StateUpdate::VT_ENTITIES, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for StateUpdate<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<GameEntity>>>>("entities", Self::VT_ENTITIES, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct StateUpdateArgs<'a> {
+    pub entities: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<GameEntity<'a>>>>>,
+}
+impl<'a> Default for StateUpdateArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    StateUpdateArgs {
+      entities: None,
+    }
+  }
+}
+
+pub struct StateUpdateBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> StateUpdateBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_entities(&mut self, entities: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<GameEntity<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(StateUpdate::VT_ENTITIES, entities);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> StateUpdateBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    StateUpdateBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<StateUpdate<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for StateUpdate<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("StateUpdate");
+      ds.field("entities", &self.entities());
+      ds.finish()
+  }
+}
+pub enum SnapshotDeltaOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct SnapshotDelta<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for SnapshotDelta<'a> {
+  type Inner = SnapshotDelta<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> SnapshotDelta<'a> {
+  pub const VT_TICK: flatbuffers::VOffsetT = 4;
+  pub const VT_BASELINE: flatbuffers::VOffsetT = 6;
+  pub const VT_CHANGED: flatbuffers::VOffsetT = 8;
+  pub const VT_REMOVED: flatbuffers::VOffsetT = 10;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    SnapshotDelta { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args SnapshotDeltaArgs<'args>
+  ) -> flatbuffers::WIPOffset<SnapshotDelta<'bldr>> {
+    let mut builder = SnapshotDeltaBuilder::new(_fbb);
+    if let Some(x) = args.baseline { builder.add_baseline(x); }
+    builder.add_tick(args.tick);
+    if let Some(x) = args.removed { builder.add_removed(x); }
+    if let Some(x) = args.changed { builder.add_changed(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn tick(&self) -> u64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u64>(SnapshotDelta::VT_TICK, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn baseline(&self) -> Option<u64> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u64>(SnapshotDelta::VT_BASELINE, None)}
+  }
+  #[inline]
+  pub fn changed(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<EntityDelta<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<EntityDelta<'a>>>>>(SnapshotDelta::VT_CHANGED, None)}
+  }
+  #[inline]
+  pub fn removed(&self) -> Option<flatbuffers::Vector<'a, u32>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(SnapshotDelta::VT_REMOVED, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for SnapshotDelta<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<u64>("tick", Self::VT_TICK, false)?
+     .visit_field::<u64>("baseline", Self::VT_BASELINE, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<EntityDelta>>>>("changed", Self::VT_CHANGED, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>("removed", Self::VT_REMOVED, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct SnapshotDeltaArgs<'a> {
+    pub tick: u64,
+    pub baseline: Option<u64>,
+    pub changed: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<EntityDelta<'a>>>>>,
+    pub removed: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
+}
+impl<'a> Default for SnapshotDeltaArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    SnapshotDeltaArgs {
+      tick: 0,
+      baseline: None,
+      changed: None,
+      removed: None,
+    }
+  }
+}
+
+pub struct SnapshotDeltaBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> SnapshotDeltaBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_tick(&mut self, tick: u64) {
+    self.fbb_.push_slot::<u64>(SnapshotDelta::VT_TICK, tick, 0);
+  }
+  #[inline]
+  pub fn add_baseline(&mut self, baseline: u64) {
+    self.fbb_.push_slot_always::<u64>(SnapshotDelta::VT_BASELINE, baseline);
+  }
+  #[inline]
+  pub fn add_changed(&mut self, changed: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<EntityDelta<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(SnapshotDelta::VT_CHANGED, changed);
+  }
+  #[inline]
+  pub fn add_removed(&mut self, removed: flatbuffers::WIPOffset<flatbuffers::Vector<'b , u32>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(SnapshotDelta::VT_REMOVED, removed);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> SnapshotDeltaBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    SnapshotDeltaBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<SnapshotDelta<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for SnapshotDelta<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("SnapshotDelta");
+      ds.field("tick", &self.tick());
+      ds.field("baseline", &self.baseline());
+      ds.field("changed", &self.changed());
+      ds.field("removed", &self.removed());
+      ds.finish()
+  }
+}
+pub enum PlayerJoinedOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct PlayerJoined<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for PlayerJoined<'a> {
+  type Inner = PlayerJoined<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> PlayerJoined<'a> {
+  pub const VT_PLAYER_ID: flatbuffers::VOffsetT = 4;
+  pub const VT_ROLE: flatbuffers::VOffsetT = 6;
+  pub const VT_NAME: flatbuffers::VOffsetT = 8;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    PlayerJoined { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args PlayerJoinedArgs<'args>
+  ) -> flatbuffers::WIPOffset<PlayerJoined<'bldr>> {
+    let mut builder = PlayerJoinedBuilder::new(_fbb);
+    if let Some(x) = args.name { builder.add_name(x); }
+    builder.add_player_id(args.player_id);
+    builder.add_role(args.role);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn player_id(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(PlayerJoined::VT_PLAYER_ID, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn role(&self) -> PlayerRole {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<PlayerRole>(PlayerJoined::VT_ROLE, Some(PlayerRole::Hacker)).unwrap()}
+  }
+  #[inline]
+  pub fn name(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(PlayerJoined::VT_NAME, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for PlayerJoined<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<u32>("player_id", Self::VT_PLAYER_ID, false)?
+     .visit_field::<PlayerRole>("role", Self::VT_ROLE, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("name", Self::VT_NAME, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct PlayerJoinedArgs<'a> {
+    pub player_id: u32,
+    pub role: PlayerRole,
+    pub name: Option<flatbuffers::WIPOffset<&'a str>>,
+}
+impl<'a> Default for PlayerJoinedArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    PlayerJoinedArgs {
+      player_id: 0,
+      role: PlayerRole::Hacker,
+      name: None,
+    }
+  }
+}
+
+pub struct PlayerJoinedBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> PlayerJoinedBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_player_id(&mut self, player_id: u32) {
+    self.fbb_.push_slot::<u32>(PlayerJoined::VT_PLAYER_ID, player_id, 0);
+  }
+  #[inline]
+  pub fn add_role(&mut self, role: PlayerRole) {
+    self.fbb_.push_slot::<PlayerRole>(PlayerJoined::VT_ROLE, role, PlayerRole::Hacker);
+  }
+  #[inline]
+  pub fn add_name(&mut self, name: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(PlayerJoined::VT_NAME, name);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> PlayerJoinedBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    PlayerJoinedBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<PlayerJoined<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for PlayerJoined<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("PlayerJoined");
+      ds.field("player_id", &self.player_id());
+      ds.field("role", &self.role());
+      ds.field("name", &self.name());
+      ds.finish()
+  }
+}
+pub enum PlayerLeftOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct PlayerLeft<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for PlayerLeft<'a> {
+  type Inner = PlayerLeft<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> PlayerLeft<'a> {
+  pub const VT_PLAYER_ID: flatbuffers::VOffsetT = 4;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    PlayerLeft { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args PlayerLeftArgs
+  ) -> flatbuffers::WIPOffset<PlayerLeft<'bldr>> {
+    let mut builder = PlayerLeftBuilder::new(_fbb);
+    builder.add_player_id(args.player_id);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn player_id(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(PlayerLeft::VT_PLAYER_ID, Some(0)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for PlayerLeft<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<u32>("player_id", Self::VT_PLAYER_ID, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct PlayerLeftArgs {
+    pub player_id: u32,
+}
+impl Default for PlayerLeftArgs {
+  #[inline]
+  fn default() -> Self {
+    PlayerLeftArgs {
+      player_id: 0,
+    }
+  }
+}
+
+pub struct PlayerLeftBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> PlayerLeftBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_player_id(&mut self, player_id: u32) {
+    self.fbb_.push_slot::<u32>(PlayerLeft::VT_PLAYER_ID, player_id, 0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> PlayerLeftBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    PlayerLeftBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<PlayerLeft<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for PlayerLeft<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("PlayerLeft");
+      ds.field("player_id", &self.player_id());
+      ds.finish()
+  }
+}
+pub enum EntitySpawnedOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct EntitySpawned<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for EntitySpawned<'a> {
+  type Inner = EntitySpawned<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> EntitySpawned<'a> {
+  pub const VT_ENTITY: flatbuffers::VOffsetT = 4;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    EntitySpawned { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args EntitySpawnedArgs<'args>
+  ) -> flatbuffers::WIPOffset<EntitySpawned<'bldr>> {
+    let mut builder = EntitySpawnedBuilder::new(_fbb);
+    if let Some(x) = args.entity { builder.add_entity(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn entity(&self) -> Option<GameEntity<'a>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<GameEntity>>(EntitySpawned::VT_ENTITY, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for EntitySpawned<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<GameEntity>>("entity", Self::VT_ENTITY, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct EntitySpawnedArgs<'a> {
+    pub entity: Option<flatbuffers::WIPOffset<GameEntity<'a>>>,
+}
+impl<'a> Default for EntitySpawnedArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    EntitySpawnedArgs {
+      entity: None,
+    }
+  }
+}
+
+pub struct EntitySpawnedBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> EntitySpawnedBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_entity(&mut self, entity: flatbuffers::WIPOffset<GameEntity<'b >>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<GameEntity>>(EntitySpawned::VT_ENTITY, entity);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> EntitySpawnedBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    EntitySpawnedBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<EntitySpawned<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for EntitySpawned<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("EntitySpawned");
+      ds.field("entity", &self.entity());
+      ds.finish()
+  }
+}
+pub enum EntityRemovedOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct EntityRemoved<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for EntityRemoved<'a> {
+  type Inner = EntityRemoved<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> EntityRemoved<'a> {
+  pub const VT_ENTITY_ID: flatbuffers::VOffsetT = 4;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    EntityRemoved { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args EntityRemovedArgs
+  ) -> flatbuffers::WIPOffset<EntityRemoved<'bldr>> {
+    let mut builder = EntityRemovedBuilder::new(_fbb);
+    builder.add_entity_id(args.entity_id);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn entity_id(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(EntityRemoved::VT_ENTITY_ID, Some(0)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for EntityRemoved<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<u32>("entity_id", Self::VT_ENTITY_ID, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct EntityRemovedArgs {
+    pub entity_id: u32,
+}
+impl Default for EntityRemovedArgs {
+  #[inline]
+  fn default() -> Self {
+    EntityRemovedArgs {
+      entity_id: 0,
+    }
+  }
+}
+
+pub struct EntityRemovedBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> EntityRemovedBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_entity_id(&mut self, entity_id: u32) {
+    self.fbb_.push_slot::<u32>(EntityRemoved::VT_ENTITY_ID, entity_id, 0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> EntityRemovedBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    EntityRemovedBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<EntityRemoved<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for EntityRemoved<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("EntityRemoved");
+      ds.field("entity_id", &self.entity_id());
+      ds.finish()
+  }
+}
+pub enum EntityUpdatedOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct EntityUpdated<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for EntityUpdated<'a> {
+  type Inner = EntityUpdated<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> EntityUpdated<'a> {
+  pub const VT_ID: flatbuffers::VOffsetT = 4;
+  pub const VT_POSITION: flatbuffers::VOffsetT = 6;
+  pub const VT_VELOCITY: flatbuffers::VOffsetT = 8;
+  pub const VT_ROTATION: flatbuffers::VOffsetT = 10;
+  pub const VT_STANCE: flatbuffers::VOffsetT = 12;
+  pub const VT_STATE: flatbuffers::VOffsetT = 14;
+  pub const VT_DETECTION_LEVEL: flatbuffers::VOffsetT = 16;
+  pub const VT_VISIBLE_TO_HACKER: flatbuffers::VOffsetT = 18;
+  pub const VT_VISIBLE_TO_INFILTRATOR: flatbuffers::VOffsetT = 20;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    EntityUpdated { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args EntityUpdatedArgs<'args>
+  ) -> flatbuffers::WIPOffset<EntityUpdated<'bldr>> {
+    let mut builder = EntityUpdatedBuilder::new(_fbb);
+    if let Some(x) = args.detection_level { builder.add_detection_level(x); }
+    if let Some(x) = args.rotation { builder.add_rotation(x); }
+    if let Some(x) = args.velocity { builder.add_velocity(x); }
+    if let Some(x) = args.position { builder.add_position(x); }
+    builder.add_id(args.id);
+    if let Some(x) = args.visible_to_infiltrator { builder.add_visible_to_infiltrator(x); }
+    if let Some(x) = args.visible_to_hacker { builder.add_visible_to_hacker(x); }
+    if let Some(x) = args.state { builder.add_state(x); }
+    if let Some(x) = args.stance { builder.add_stance(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn id(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(EntityUpdated::VT_ID, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn position(&self) -> Option<&'a Position> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<Position>(EntityUpdated::VT_POSITION, None)}
+  }
+  #[inline]
+  pub fn velocity(&self) -> Option<&'a Vec2> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<Vec2>(EntityUpdated::VT_VELOCITY, None)}
+  }
+  #[inline]
+  pub fn rotation(&self) -> Option<f32> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f32>(EntityUpdated::VT_ROTATION, None)}
+  }
+  #[inline]
+  pub fn stance(&self) -> Option<Stance> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<Stance>(EntityUpdated::VT_STANCE, None)}
+  }
+  #[inline]
+  pub fn state(&self) -> Option<ActorState> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<ActorState>(EntityUpdated::VT_STATE, None)}
+  }
+  #[inline]
+  pub fn detection_level(&self) -> Option<f32> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f32>(EntityUpdated::VT_DETECTION_LEVEL, None)}
+  }
+  #[inline]
+  pub fn visible_to_hacker(&self) -> Option<bool> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(EntityUpdated::VT_VISIBLE_TO_HACKER, None)}
+  }
+  #[inline]
+  pub fn visible_to_infiltrator(&self) -> Option<bool> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(EntityUpdated::VT_VISIBLE_TO_INFILTRATOR, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for EntityUpdated<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<u32>("id", Self::VT_ID, false)?
+     .visit_field::<Position>("position", Self::VT_POSITION, false)?
+     .visit_field::<Vec2>("velocity", Self::VT_VELOCITY, false)?
+     .visit_field::<f32>("rotation", Self::VT_ROTATION, false)?
+     .visit_field::<Stance>("stance", Self::VT_STANCE, false)?
+     .visit_field::<ActorState>("state", Self::VT_STATE, false)?
+     .visit_field::<f32>("detection_level", Self::VT_DETECTION_LEVEL, false)?
+     .visit_field::<bool>("visible_to_hacker", Self::VT_VISIBLE_TO_HACKER, false)?
+     .visit_field::<bool>("visible_to_infiltrator", Self::VT_VISIBLE_TO_INFILTRATOR, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct EntityUpdatedArgs<'a> {
+    pub id: u32,
+    pub position: Option<&'a Position>,
+    pub velocity: Option<&'a Vec2>,
+    pub rotation: Option<f32>,
+    pub stance: Option<Stance>,
+    pub state: Option<ActorState>,
+    pub detection_level: Option<f32>,
+    pub visible_to_hacker: Option<bool>,
+    pub visible_to_infiltrator: Option<bool>,
+}
+impl<'a> Default for EntityUpdatedArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    EntityUpdatedArgs {
+      id: 0,
+      position: None,
+      velocity: None,
+      rotation: None,
+      stance: None,
+      state: None,
+      detection_level: None,
+      visible_to_hacker: None,
+      visible_to_infiltrator: None,
+    }
+  }
+}
+
+pub struct EntityUpdatedBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> EntityUpdatedBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_id(&mut self, id: u32) {
+    self.fbb_.push_slot::<u32>(EntityUpdated::VT_ID, id, 0);
+  }
+  #[inline]
+  pub fn add_position(&mut self, position: &Position) {
+    self.fbb_.push_slot_always::<&Position>(EntityUpdated::VT_POSITION, position);
+  }
+  #[inline]
+  pub fn add_velocity(&mut self, velocity: &Vec2) {
+    self.fbb_.push_slot_always::<&Vec2>(EntityUpdated::VT_VELOCITY, velocity);
+  }
+  #[inline]
+  pub fn add_rotation(&mut self, rotation: f32) {
+    self.fbb_.push_slot_always::<f32>(EntityUpdated::VT_ROTATION, rotation);
+  }
+  #[inline]
+  pub fn add_stance(&mut self, stance: Stance) {
+    self.fbb_.push_slot_always::<Stance>(EntityUpdated::VT_STANCE, stance);
+  }
+  #[inline]
+  pub fn add_state(&mut self, state: ActorState) {
+    self.fbb_.push_slot_always::<ActorState>(EntityUpdated::VT_STATE, state);
+  }
+  #[inline]
+  pub fn add_detection_level(&mut self, detection_level: f32) {
+    self.fbb_.push_slot_always::<f32>(EntityUpdated::VT_DETECTION_LEVEL, detection_level);
+  }
+  #[inline]
+  pub fn add_visible_to_hacker(&mut self, visible_to_hacker: bool) {
+    self.fbb_.push_slot_always::<bool>(EntityUpdated::VT_VISIBLE_TO_HACKER, visible_to_hacker);
+  }
+  #[inline]
+  pub fn add_visible_to_infiltrator(&mut self, visible_to_infiltrator: bool) {
+    self.fbb_.push_slot_always::<bool>(EntityUpdated::VT_VISIBLE_TO_INFILTRATOR, visible_to_infiltrator);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> EntityUpdatedBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    EntityUpdatedBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<EntityUpdated<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for EntityUpdated<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("EntityUpdated");
+      ds.field("id", &self.id());
+      ds.field("position", &self.position());
+      ds.field("velocity", &self.velocity());
+      ds.field("rotation", &self.rotation());
+      ds.field("stance", &self.stance());
+      ds.field("state", &self.state());
+      ds.field("detection_level", &self.detection_level());
+      ds.field("visible_to_hacker", &self.visible_to_hacker());
+      ds.field("visible_to_infiltrator", &self.visible_to_infiltrator());
+      ds.finish()
+  }
+}
+pub enum RoleChangedOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct RoleChanged<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for RoleChanged<'a> {
+  type Inner = RoleChanged<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> RoleChanged<'a> {
+  pub const VT_PLAYER_ID: flatbuffers::VOffsetT = 4;
+  pub const VT_ROLE: flatbuffers::VOffsetT = 6;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    RoleChanged { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args RoleChangedArgs
+  ) -> flatbuffers::WIPOffset<RoleChanged<'bldr>> {
+    let mut builder = RoleChangedBuilder::new(_fbb);
+    builder.add_player_id(args.player_id);
+    builder.add_role(args.role);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn player_id(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(RoleChanged::VT_PLAYER_ID, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn role(&self) -> PlayerRole {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<PlayerRole>(RoleChanged::VT_ROLE, Some(PlayerRole::Hacker)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for RoleChanged<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<u32>("player_id", Self::VT_PLAYER_ID, false)?
+     .visit_field::<PlayerRole>("role", Self::VT_ROLE, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct RoleChangedArgs {
+    pub player_id: u32,
+    pub role: PlayerRole,
+}
+impl Default for RoleChangedArgs {
+  #[inline]
+  fn default() -> Self {
+    RoleChangedArgs {
+      player_id: 0,
+      role: PlayerRole::Hacker,
+    }
+  }
+}
+
+pub struct RoleChangedBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> RoleChangedBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_player_id(&mut self, player_id: u32) {
+    self.fbb_.push_slot::<u32>(RoleChanged::VT_PLAYER_ID, player_id, 0);
+  }
+  #[inline]
+  pub fn add_role(&mut self, role: PlayerRole) {
+    self.fbb_.push_slot::<PlayerRole>(RoleChanged::VT_ROLE, role, PlayerRole::Hacker);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> RoleChangedBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    RoleChangedBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<RoleChanged<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for RoleChanged<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("RoleChanged");
+      ds.field("player_id", &self.player_id());
+      ds.field("role", &self.role());
+      ds.finish()
+  }
+}
+pub enum MissionStateOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct MissionState<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for MissionState<'a> {
+  type Inner = MissionState<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> MissionState<'a> {
+  pub const VT_STATUS: flatbuffers::VOffsetT = 4;
+  pub const VT_OBJECTIVES: flatbuffers::VOffsetT = 6;
+  pub const VT_TIME_REMAINING: flatbuffers::VOffsetT = 8;
+  pub const VT_FAILURE_REASON: flatbuffers::VOffsetT = 10;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    MissionState { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args MissionStateArgs<'args>
+  ) -> flatbuffers::WIPOffset<MissionState<'bldr>> {
+    let mut builder = MissionStateBuilder::new(_fbb);
+    if let Some(x) = args.failure_reason { builder.add_failure_reason(x); }
+    if let Some(x) = args.time_remaining { builder.add_time_remaining(x); }
+    if let Some(x) = args.objectives { builder.add_objectives(x); }
+    builder.add_status(args.status);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn status(&self) -> MissionStatus {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<MissionStatus>(MissionState::VT_STATUS, Some(MissionStatus::InProgress)).unwrap()}
+  }
+  #[inline]
+  pub fn objectives(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<ObjectiveStatus<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<ObjectiveStatus<'a>>>>>(MissionState::VT_OBJECTIVES, None)}
+  }
+  #[inline]
+  pub fn time_remaining(&self) -> Option<f32> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f32>(MissionState::VT_TIME_REMAINING, None)}
+  }
+  #[inline]
+  pub fn failure_reason(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(MissionState::VT_FAILURE_REASON, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for MissionState<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<MissionStatus>("status", Self::VT_STATUS, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<ObjectiveStatus>>>>("objectives", Self::VT_OBJECTIVES, false)?
+     .visit_field::<f32>("time_remaining", Self::VT_TIME_REMAINING, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("failure_reason", Self::VT_FAILURE_REASON, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct MissionStateArgs<'a> {
+    pub status: MissionStatus,
+    pub objectives: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<ObjectiveStatus<'a>>>>>,
+    pub time_remaining: Option<f32>,
+    pub failure_reason: Option<flatbuffers::WIPOffset<&'a str>>,
+}
+impl<'a> Default for MissionStateArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    MissionStateArgs {
+      status: MissionStatus::InProgress,
+      objectives: None,
+      time_remaining: None,
+      failure_reason: None,
+    }
+  }
+}
+
+pub struct MissionStateBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> MissionStateBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_status(&mut self, status: MissionStatus) {
+    self.fbb_.push_slot::<MissionStatus>(MissionState::VT_STATUS, status, MissionStatus::InProgress);
+  }
+  #[inline]
+  pub fn add_objectives(&mut self, objectives: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<ObjectiveStatus<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(MissionState::VT_OBJECTIVES, objectives);
+  }
+  #[inline]
+  pub fn add_time_remaining(&mut self, time_remaining: f32) {
+    self.fbb_.push_slot_always::<f32>(MissionState::VT_TIME_REMAINING, time_remaining);
+  }
+  #[inline]
+  pub fn add_failure_reason(&mut self, failure_reason: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(MissionState::VT_FAILURE_REASON, failure_reason);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> MissionStateBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    MissionStateBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<MissionState<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for MissionState<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("MissionState");
+      ds.field("status", &self.status());
+      ds.field("objectives", &self.objectives());
+      ds.field("time_remaining", &self.time_remaining());
+      ds.field("failure_reason", &self.failure_reason());
+      ds.finish()
+  }
+}
+pub enum ChatOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct Chat<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Chat<'a> {
+  type Inner = Chat<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> Chat<'a> {
+  pub const VT_PLAYER_ID: flatbuffers::VOffsetT = 4;
+  pub const VT_TEXT: flatbuffers::VOffsetT = 6;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    Chat { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args ChatArgs<'args>
+  ) -> flatbuffers::WIPOffset<Chat<'bldr>> {
+    let mut builder = ChatBuilder::new(_fbb);
+    if let Some(x) = args.text { builder.add_text(x); }
+    builder.add_player_id(args.player_id);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn player_id(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(Chat::VT_PLAYER_ID, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn text(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(Chat::VT_TEXT, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for Chat<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<u32>("player_id", Self::VT_PLAYER_ID, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("text", Self::VT_TEXT, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct ChatArgs<'a> {
+    pub player_id: u32,
+    pub text: Option<flatbuffers::WIPOffset<&'a str>>,
+}
+impl<'a> Default for ChatArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    ChatArgs {
+      player_id: 0,
+      text: None,
+    }
+  }
+}
+
+pub struct ChatBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ChatBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_player_id(&mut self, player_id: u32) {
+    self.fbb_.push_slot::<u32>(Chat::VT_PLAYER_ID, player_id, 0);
+  }
+  #[inline]
+  pub fn add_text(&mut self, text: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Chat::VT_TEXT, text);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> ChatBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    ChatBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Chat<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for Chat<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("Chat");
+      ds.field("player_id", &self.player_id());
+      ds.field("text", &self.text());
+      ds.finish()
+  }
+}
+pub enum PingOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct Ping<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Ping<'a> {
+  type Inner = Ping<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> Ping<'a> {
+  pub const VT_NONCE: flatbuffers::VOffsetT = 4;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    Ping { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args PingArgs
+  ) -> flatbuffers::WIPOffset<Ping<'bldr>> {
+    let mut builder = PingBuilder::new(_fbb);
+    builder.add_nonce(args.nonce);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn nonce(&self) -> u64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u64>(Ping::VT_NONCE, Some(0)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for Ping<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<u64>("nonce", Self::VT_NONCE, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct PingArgs {
+    pub nonce: u64,
+}
+impl Default for PingArgs {
+  #[inline]
+  fn default() -> Self {
+    PingArgs {
+      nonce: 0,
+    }
+  }
+}
+
+pub struct PingBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> PingBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_nonce(&mut self, nonce: u64) {
+    self.fbb_.push_slot::<u64>(Ping::VT_NONCE, nonce, 0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> PingBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    PingBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Ping<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for Ping<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("Ping");
+      ds.field("nonce", &self.nonce());
+      ds.finish()
+  }
+}
+pub enum InputAckOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct InputAck<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for InputAck<'a> {
+  type Inner = InputAck<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> InputAck<'a> {
+  pub const VT_SEQUENCE: flatbuffers::VOffsetT = 4;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    InputAck { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args InputAckArgs
+  ) -> flatbuffers::WIPOffset<InputAck<'bldr>> {
+    let mut builder = InputAckBuilder::new(_fbb);
+    builder.add_sequence(args.sequence);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn sequence(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(InputAck::VT_SEQUENCE, Some(0)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for InputAck<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<u32>("sequence", Self::VT_SEQUENCE, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct InputAckArgs {
+    pub sequence: u32,
+}
+impl Default for InputAckArgs {
+  #[inline]
+  fn default() -> Self {
+    InputAckArgs {
+      sequence: 0,
+    }
+  }
+}
+
+pub struct InputAckBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> InputAckBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_sequence(&mut self, sequence: u32) {
+    self.fbb_.push_slot::<u32>(InputAck::VT_SEQUENCE, sequence, 0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> InputAckBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    InputAckBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<InputAck<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for InputAck<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("InputAck");
+      ds.field("sequence", &self.sequence());
+      ds.finish()
+  }
+}
+pub enum MarkersOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct Markers<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Markers<'a> {
+  type Inner = Markers<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> Markers<'a> {
+  pub const VT_MARKERS: flatbuffers::VOffsetT = 4;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    Markers { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args MarkersArgs<'args>
+  ) -> flatbuffers::WIPOffset<Markers<'bldr>> {
+    let mut builder = MarkersBuilder::new(_fbb);
+    if let Some(x) = args.markers { builder.add_markers(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn markers(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Marker<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Marker<'a>>>>>(Markers::VT_MARKERS, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for Markers<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<Marker>>>>("markers", Self::VT_MARKERS, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct MarkersArgs<'a> {
+    pub markers: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Marker<'a>>>>>,
+}
+impl<'a> Default for MarkersArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    MarkersArgs {
+      markers: None,
+    }
+  }
+}
+
+pub struct MarkersBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> MarkersBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_markers(&mut self, markers: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<Marker<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Markers::VT_MARKERS, markers);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> MarkersBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    MarkersBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Markers<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for Markers<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("Markers");
+      ds.field("markers", &self.markers());
+      ds.finish()
+  }
+}
+pub enum HackerIntelOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct HackerIntel<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for HackerIntel<'a> {
+  type Inner = HackerIntel<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> HackerIntel<'a> {
+  pub const VT_SENSORS: flatbuffers::VOffsetT = 4;
+  pub const VT_GHOSTS: flatbuffers::VOffsetT = 6;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    HackerIntel { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args HackerIntelArgs<'args>
+  ) -> flatbuffers::WIPOffset<HackerIntel<'bldr>> {
+    let mut builder = HackerIntelBuilder::new(_fbb);
+    if let Some(x) = args.ghosts { builder.add_ghosts(x); }
+    if let Some(x) = args.sensors { builder.add_sensors(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn sensors(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Sensor<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Sensor<'a>>>>>(HackerIntel::VT_SENSORS, None)}
+  }
+  #[inline]
+  pub fn ghosts(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Sighting<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Sighting<'a>>>>>(HackerIntel::VT_GHOSTS, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for HackerIntel<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<Sensor>>>>("sensors", Self::VT_SENSORS, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<Sighting>>>>("ghosts", Self::VT_GHOSTS, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct HackerIntelArgs<'a> {
+    pub sensors: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Sensor<'a>>>>>,
+    pub ghosts: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Sighting<'a>>>>>,
+}
+impl<'a> Default for HackerIntelArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    HackerIntelArgs {
+      sensors: None,
+      ghosts: None,
+    }
+  }
+}
+
+pub struct HackerIntelBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> HackerIntelBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_sensors(&mut self, sensors: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<Sensor<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(HackerIntel::VT_SENSORS, sensors);
+  }
+  #[inline]
+  pub fn add_ghosts(&mut self, ghosts: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<Sighting<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(HackerIntel::VT_GHOSTS, ghosts);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> HackerIntelBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    HackerIntelBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<HackerIntel<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for HackerIntel<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("HackerIntel");
+      ds.field("sensors", &self.sensors());
+      ds.field("ghosts", &self.ghosts());
+      ds.finish()
+  }
+}
+pub enum TraceOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct Trace<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Trace<'a> {
+  type Inner = Trace<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> Trace<'a> {
+  pub const VT_LEVEL: flatbuffers::VOffsetT = 4;
+  pub const VT_STAGE: flatbuffers::VOffsetT = 6;
+  pub const VT_ICE: flatbuffers::VOffsetT = 8;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    Trace { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args TraceArgs
+  ) -> flatbuffers::WIPOffset<Trace<'bldr>> {
+    let mut builder = TraceBuilder::new(_fbb);
+    if let Some(x) = args.ice { builder.add_ice(x); }
+    builder.add_level(args.level);
+    builder.add_stage(args.stage);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn level(&self) -> f32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f32>(Trace::VT_LEVEL, Some(0.0)).unwrap()}
+  }
+  #[inline]
+  pub fn stage(&self) -> TraceStage {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<TraceStage>(Trace::VT_STAGE, Some(TraceStage::Clear)).unwrap()}
+  }
+  #[inline]
+  pub fn ice(&self) -> Option<u32> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(Trace::VT_ICE, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for Trace<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<f32>("level", Self::VT_LEVEL, false)?
+     .visit_field::<TraceStage>("stage", Self::VT_STAGE, false)?
+     .visit_field::<u32>("ice", Self::VT_ICE, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct TraceArgs {
+    pub level: f32,
+    pub stage: TraceStage,
+    pub ice: Option<u32>,
+}
+impl Default for TraceArgs {
+  #[inline]
+  fn default() -> Self {
+    TraceArgs {
+      level: 0.0,
+      stage: TraceStage::Clear,
+      ice: None,
+    }
+  }
+}
+
+pub struct TraceBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> TraceBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_level(&mut self, level: f32) {
+    self.fbb_.push_slot::<f32>(Trace::VT_LEVEL, level, 0.0);
+  }
+  #[inline]
+  pub fn add_stage(&mut self, stage: TraceStage) {
+    self.fbb_.push_slot::<TraceStage>(Trace::VT_STAGE, stage, TraceStage::Clear);
+  }
+  #[inline]
+  pub fn add_ice(&mut self, ice: u32) {
+    self.fbb_.push_slot_always::<u32>(Trace::VT_ICE, ice);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> TraceBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    TraceBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Trace<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for Trace<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("Trace");
+      ds.field("level", &self.level());
+      ds.field("stage", &self.stage());
+      ds.field("ice", &self.ice());
+      ds.finish()
+  }
+}
+pub enum InteractionOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct Interaction<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Interaction<'a> {
+  type Inner = Interaction<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> Interaction<'a> {
+  pub const VT_DOOR_ID: flatbuffers::VOffsetT = 4;
+  pub const VT_RESULT: flatbuffers::VOffsetT = 6;
+  pub const VT_KEYCARD_ID: flatbuffers::VOffsetT = 8;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    Interaction { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args InteractionArgs
+  ) -> flatbuffers::WIPOffset<Interaction<'bldr>> {
+    let mut builder = InteractionBuilder::new(_fbb);
+    builder.add_keycard_id(args.keycard_id);
+    if let Some(x) = args.door_id { builder.add_door_id(x); }
+    builder.add_result(args.result);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn door_id(&self) -> Option<u32> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(Interaction::VT_DOOR_ID, None)}
+  }
+  #[inline]
+  pub fn result(&self) -> InteractionResult {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<InteractionResult>(Interaction::VT_RESULT, Some(InteractionResult::Opened)).unwrap()}
+  }
+  #[inline]
+  pub fn keycard_id(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(Interaction::VT_KEYCARD_ID, Some(0)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for Interaction<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<u32>("door_id", Self::VT_DOOR_ID, false)?
+     .visit_field::<InteractionResult>("result", Self::VT_RESULT, false)?
+     .visit_field::<u32>("keycard_id", Self::VT_KEYCARD_ID, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct InteractionArgs {
+    pub door_id: Option<u32>,
+    pub result: InteractionResult,
+    pub keycard_id: u32,
+}
+impl Default for InteractionArgs {
+  #[inline]
+  fn default() -> Self {
+    InteractionArgs {
+      door_id: None,
+      result: InteractionResult::Opened,
+      keycard_id: 0,
+    }
+  }
+}
+
+pub struct InteractionBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> InteractionBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_door_id(&mut self, door_id: u32) {
+    self.fbb_.push_slot_always::<u32>(Interaction::VT_DOOR_ID, door_id);
+  }
+  #[inline]
+  pub fn add_result(&mut self, result: InteractionResult) {
+    self.fbb_.push_slot::<InteractionResult>(Interaction::VT_RESULT, result, InteractionResult::Opened);
+  }
+  #[inline]
+  pub fn add_keycard_id(&mut self, keycard_id: u32) {
+    self.fbb_.push_slot::<u32>(Interaction::VT_KEYCARD_ID, keycard_id, 0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> InteractionBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    InteractionBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Interaction<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for Interaction<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("Interaction");
+      ds.field("door_id", &self.door_id());
+      ds.field("result", &self.result());
+      ds.field("keycard_id", &self.keycard_id());
+      ds.finish()
+  }
+}
+pub enum DoorStateChangedOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct DoorStateChanged<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for DoorStateChanged<'a> {
+  type Inner = DoorStateChanged<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> DoorStateChanged<'a> {
+  pub const VT_DOOR_ID: flatbuffers::VOffsetT = 4;
+  pub const VT_STATE: flatbuffers::VOffsetT = 6;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    DoorStateChanged { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args DoorStateChangedArgs
+  ) -> flatbuffers::WIPOffset<DoorStateChanged<'bldr>> {
+    let mut builder = DoorStateChangedBuilder::new(_fbb);
+    builder.add_door_id(args.door_id);
+    builder.add_state(args.state);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn door_id(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(DoorStateChanged::VT_DOOR_ID, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn state(&self) -> DoorState {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<DoorState>(DoorStateChanged::VT_STATE, Some(DoorState::Open)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for DoorStateChanged<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<u32>("door_id", Self::VT_DOOR_ID, false)?
+     .visit_field::<DoorState>("state", Self::VT_STATE, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct DoorStateChangedArgs {
+    pub door_id: u32,
+    pub state: DoorState,
+}
+impl Default for DoorStateChangedArgs {
+  #[inline]
+  fn default() -> Self {
+    DoorStateChangedArgs {
+      door_id: 0,
+      state: DoorState::Open,
+    }
+  }
+}
+
+pub struct DoorStateChangedBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> DoorStateChangedBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_door_id(&mut self, door_id: u32) {
+    self.fbb_.push_slot::<u32>(DoorStateChanged::VT_DOOR_ID, door_id, 0);
+  }
+  #[inline]
+  pub fn add_state(&mut self, state: DoorState) {
+    self.fbb_.push_slot::<DoorState>(DoorStateChanged::VT_STATE, state, DoorState::Open);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> DoorStateChangedBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    DoorStateChangedBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<DoorStateChanged<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for DoorStateChanged<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("DoorStateChanged");
+      ds.field("door_id", &self.door_id());
+      ds.field("state", &self.state());
+      ds.finish()
+  }
+}
+pub enum ObstacleOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct Obstacle<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Obstacle<'a> {
+  type Inner = Obstacle<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> Obstacle<'a> {
+  pub const VT_POSITION: flatbuffers::VOffsetT = 4;
+  pub const VT_RADIUS: flatbuffers::VOffsetT = 6;
+  pub const VT_BLOCKS_VISION: flatbuffers::VOffsetT = 8;
+  pub const VT_BLOCKS_SOUND: flatbuffers::VOffsetT = 10;
+  pub const VT_PROVIDES_COVER: flatbuffers::VOffsetT = 12;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    Obstacle { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args ObstacleArgs<'args>
+  ) -> flatbuffers::WIPOffset<Obstacle<'bldr>> {
+    let mut builder = ObstacleBuilder::new(_fbb);
+    builder.add_radius(args.radius);
+    if let Some(x) = args.position { builder.add_position(x); }
+    builder.add_provides_cover(args.provides_cover);
+    builder.add_blocks_sound(args.blocks_sound);
+    builder.add_blocks_vision(args.blocks_vision);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn position(&self) -> Option<&'a Position> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<Position>(Obstacle::VT_POSITION, None)}
+  }
+  #[inline]
+  pub fn radius(&self) -> f32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f32>(Obstacle::VT_RADIUS, Some(0.0)).unwrap()}
+  }
+  #[inline]
+  pub fn blocks_vision(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(Obstacle::VT_BLOCKS_VISION, Some(false)).unwrap()}
+  }
+  #[inline]
+  pub fn blocks_sound(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(Obstacle::VT_BLOCKS_SOUND, Some(false)).unwrap()}
+  }
+  #[inline]
+  pub fn provides_cover(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(Obstacle::VT_PROVIDES_COVER, Some(false)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for Obstacle<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<Position>("position", Self::VT_POSITION, false)?
+     .visit_field::<f32>("radius", Self::VT_RADIUS, false)?
+     .visit_field::<bool>("blocks_vision", Self::VT_BLOCKS_VISION, false)?
+     .visit_field::<bool>("blocks_sound", Self::VT_BLOCKS_SOUND, false)?
+     .visit_field::<bool>("provides_cover", Self::VT_PROVIDES_COVER, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct ObstacleArgs<'a> {
+    pub position: Option<&'a Position>,
+    pub radius: f32,
+    pub blocks_vision: bool,
+    pub blocks_sound: bool,
+    pub provides_cover: bool,
+}
+impl<'a> Default for ObstacleArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    ObstacleArgs {
+      position: None,
+      radius: 0.0,
+      blocks_vision: false,
+      blocks_sound: false,
+      provides_cover: false,
+    }
+  }
+}
+
+pub struct ObstacleBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ObstacleBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_position(&mut self, position: &Position) {
+    self.fbb_.push_slot_always::<&Position>(Obstacle::VT_POSITION, position);
+  }
+  #[inline]
+  pub fn add_radius(&mut self, radius: f32) {
+    self.fbb_.push_slot::<f32>(Obstacle::VT_RADIUS, radius, 0.0);
+  }
+  #[inline]
+  pub fn add_blocks_vision(&mut self, blocks_vision: bool) {
+    self.fbb_.push_slot::<bool>(Obstacle::VT_BLOCKS_VISION, blocks_vision, false);
+  }
+  #[inline]
+  pub fn add_blocks_sound(&mut self, blocks_sound: bool) {
+    self.fbb_.push_slot::<bool>(Obstacle::VT_BLOCKS_SOUND, blocks_sound, false);
+  }
+  #[inline]
+  pub fn add_provides_cover(&mut self, provides_cover: bool) {
+    self.fbb_.push_slot::<bool>(Obstacle::VT_PROVIDES_COVER, provides_cover, false);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> ObstacleBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    ObstacleBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Obstacle<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for Obstacle<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("Obstacle");
+      ds.field("position", &self.position());
+      ds.field("radius", &self.radius());
+      ds.field("blocks_vision", &self.blocks_vision());
+      ds.field("blocks_sound", &self.blocks_sound());
+      ds.field("provides_cover", &self.provides_cover());
+      ds.finish()
+  }
+}
+pub enum DoorGeometryOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct DoorGeometry<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for DoorGeometry<'a> {
+  type Inner = DoorGeometry<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> DoorGeometry<'a> {
+  pub const VT_DOOR_ID: flatbuffers::VOffsetT = 4;
+  pub const VT_POSITION: flatbuffers::VOffsetT = 6;
+  pub const VT_RADIUS: flatbuffers::VOffsetT = 8;
+  pub const VT_STATE: flatbuffers::VOffsetT = 10;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    DoorGeometry { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args DoorGeometryArgs<'args>
+  ) -> flatbuffers::WIPOffset<DoorGeometry<'bldr>> {
+    let mut builder = DoorGeometryBuilder::new(_fbb);
+    builder.add_radius(args.radius);
+    if let Some(x) = args.position { builder.add_position(x); }
+    builder.add_door_id(args.door_id);
+    builder.add_state(args.state);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn door_id(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(DoorGeometry::VT_DOOR_ID, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn position(&self) -> Option<&'a Position> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<Position>(DoorGeometry::VT_POSITION, None)}
+  }
+  #[inline]
+  pub fn radius(&self) -> f32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f32>(DoorGeometry::VT_RADIUS, Some(0.0)).unwrap()}
+  }
+  #[inline]
+  pub fn state(&self) -> DoorState {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<DoorState>(DoorGeometry::VT_STATE, Some(DoorState::Open)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for DoorGeometry<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<u32>("door_id", Self::VT_DOOR_ID, false)?
+     .visit_field::<Position>("position", Self::VT_POSITION, false)?
+     .visit_field::<f32>("radius", Self::VT_RADIUS, false)?
+     .visit_field::<DoorState>("state", Self::VT_STATE, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct DoorGeometryArgs<'a> {
+    pub door_id: u32,
+    pub position: Option<&'a Position>,
+    pub radius: f32,
+    pub state: DoorState,
+}
+impl<'a> Default for DoorGeometryArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    DoorGeometryArgs {
+      door_id: 0,
+      position: None,
+      radius: 0.0,
+      state: DoorState::Open,
+    }
+  }
+}
+
+pub struct DoorGeometryBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> DoorGeometryBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_door_id(&mut self, door_id: u32) {
+    self.fbb_.push_slot::<u32>(DoorGeometry::VT_DOOR_ID, door_id, 0);
+  }
+  #[inline]
+  pub fn add_position(&mut self, position: &Position) {
+    self.fbb_.push_slot_always::<&Position>(DoorGeometry::VT_POSITION, position);
+  }
+  #[inline]
+  pub fn add_radius(&mut self, radius: f32) {
+    self.fbb_.push_slot::<f32>(DoorGeometry::VT_RADIUS, radius, 0.0);
+  }
+  #[inline]
+  pub fn add_state(&mut self, state: DoorState) {
+    self.fbb_.push_slot::<DoorState>(DoorGeometry::VT_STATE, state, DoorState::Open);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> DoorGeometryBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    DoorGeometryBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<DoorGeometry<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for DoorGeometry<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("DoorGeometry");
+      ds.field("door_id", &self.door_id());
+      ds.field("position", &self.position());
+      ds.field("radius", &self.radius());
+      ds.field("state", &self.state());
+      ds.finish()
+  }
+}
+pub enum LevelOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct Level<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Level<'a> {
+  type Inner = Level<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> Level<'a> {
+  pub const VT_TIMESTEP: flatbuffers::VOffsetT = 4;
+  pub const VT_WIDTH: flatbuffers::VOffsetT = 6;
+  pub const VT_HEIGHT: flatbuffers::VOffsetT = 8;
+  pub const VT_OBSTACLES: flatbuffers::VOffsetT = 10;
+  pub const VT_DOORS: flatbuffers::VOffsetT = 12;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    Level { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args LevelArgs<'args>
+  ) -> flatbuffers::WIPOffset<Level<'bldr>> {
+    let mut builder = LevelBuilder::new(_fbb);
+    if let Some(x) = args.doors { builder.add_doors(x); }
+    if let Some(x) = args.obstacles { builder.add_obstacles(x); }
+    builder.add_height(args.height);
+    builder.add_width(args.width);
+    builder.add_timestep(args.timestep);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn timestep(&self) -> f32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f32>(Level::VT_TIMESTEP, Some(0.0)).unwrap()}
+  }
+  #[inline]
+  pub fn width(&self) -> f32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f32>(Level::VT_WIDTH, Some(0.0)).unwrap()}
+  }
+  #[inline]
+  pub fn height(&self) -> f32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f32>(Level::VT_HEIGHT, Some(0.0)).unwrap()}
+  }
+  #[inline]
+  pub fn obstacles(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Obstacle<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Obstacle<'a>>>>>(Level::VT_OBSTACLES, None)}
+  }
+  #[inline]
+  pub fn doors(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<DoorGeometry<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<DoorGeometry<'a>>>>>(Level::VT_DOORS, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for Level<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<f32>("timestep", Self::VT_TIMESTEP, false)?
+     .visit_field::<f32>("width", Self::VT_WIDTH, false)?
+     .visit_field::<f32>("height", Self::VT_HEIGHT, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<Obstacle>>>>("obstacles", Self::VT_OBSTACLES, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<DoorGeometry>>>>("doors", Self::VT_DOORS, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct LevelArgs<'a> {
+    pub timestep: f32,
+    pub width: f32,
+    pub height: f32,
+    pub obstacles: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Obstacle<'a>>>>>,
+    pub doors: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<DoorGeometry<'a>>>>>,
+}
+impl<'a> Default for LevelArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    LevelArgs {
+      timestep: 0.0,
+      width: 0.0,
+      height: 0.0,
+      obstacles: None,
+      doors: None,
+    }
+  }
+}
+
+pub struct LevelBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> LevelBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_timestep(&mut self, timestep: f32) {
+    self.fbb_.push_slot::<f32>(Level::VT_TIMESTEP, timestep, 0.0);
+  }
+  #[inline]
+  pub fn add_width(&mut self, width: f32) {
+    self.fbb_.push_slot::<f32>(Level::VT_WIDTH, width, 0.0);
+  }
+  #[inline]
+  pub fn add_height(&mut self, height: f32) {
+    self.fbb_.push_slot::<f32>(Level::VT_HEIGHT, height, 0.0);
+  }
+  #[inline]
+  pub fn add_obstacles(&mut self, obstacles: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<Obstacle<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Level::VT_OBSTACLES, obstacles);
+  }
+  #[inline]
+  pub fn add_doors(&mut self, doors: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<DoorGeometry<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Level::VT_DOORS, doors);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> LevelBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    LevelBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Level<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for Level<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("Level");
+      ds.field("timestep", &self.timestep());
+      ds.field("width", &self.width());
+      ds.field("height", &self.height());
+      ds.field("obstacles", &self.obstacles());
+      ds.field("doors", &self.doors());
+      ds.finish()
+  }
+}
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MIN_SERVER_PAYLOAD: u8 = 0;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MAX_SERVER_PAYLOAD: u8 = 19;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+#[allow(non_camel_case_types)]
+pub const ENUM_VALUES_SERVER_PAYLOAD: [ServerPayload; 20] = [
+  ServerPayload::NONE,
+  ServerPayload::Welcome,
+  ServerPayload::StateUpdate,
+  ServerPayload::SnapshotDelta,
+  ServerPayload::PlayerJoined,
+  ServerPayload::PlayerLeft,
+  ServerPayload::EntitySpawned,
+  ServerPayload::EntityRemoved,
+  ServerPayload::EntityUpdated,
+  ServerPayload::RoleChanged,
+  ServerPayload::MissionState,
+  ServerPayload::Chat,
+  ServerPayload::Ping,
+  ServerPayload::InputAck,
+  ServerPayload::Markers,
+  ServerPayload::HackerIntel,
+  ServerPayload::Trace,
+  ServerPayload::Interaction,
+  ServerPayload::DoorStateChanged,
+  ServerPayload::Level,
+];
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct ServerPayload(pub u8);
+#[allow(non_upper_case_globals)]
+impl ServerPayload {
+  pub const NONE: Self = Self(0);
+  pub const Welcome: Self = Self(1);
+  pub const StateUpdate: Self = Self(2);
+  pub const SnapshotDelta: Self = Self(3);
+  pub const PlayerJoined: Self = Self(4);
+  pub const PlayerLeft: Self = Self(5);
+  pub const EntitySpawned: Self = Self(6);
+  pub const EntityRemoved: Self = Self(7);
+  pub const EntityUpdated: Self = Self(8);
+  pub const RoleChanged: Self = Self(9);
+  pub const MissionState: Self = Self(10);
+  pub const Chat: Self = Self(11);
+  pub const Ping: Self = Self(12);
+  pub const InputAck: Self = Self(13);
+  pub const Markers: Self = Self(14);
+  pub const HackerIntel: Self = Self(15);
+  pub const Trace: Self = Self(16);
+  pub const Interaction: Self = Self(17);
+  pub const DoorStateChanged: Self = Self(18);
+  pub const Level: Self = Self(19);
+
+  pub const ENUM_MIN: u8 = 0;
+  pub const ENUM_MAX: u8 = 19;
+  pub const ENUM_VALUES: &'static [Self] = &[
+    Self::NONE,
+    Self::Welcome,
+    Self::StateUpdate,
+    Self::SnapshotDelta,
+    Self::PlayerJoined,
+    Self::PlayerLeft,
+    Self::EntitySpawned,
+    Self::EntityRemoved,
+    Self::EntityUpdated,
+    Self::RoleChanged,
+    Self::MissionState,
+    Self::Chat,
+    Self::Ping,
+    Self::InputAck,
+    Self::Markers,
+    Self::HackerIntel,
+    Self::Trace,
+    Self::Interaction,
+    Self::DoorStateChanged,
+    Self::Level,
+  ];
+  /// Returns the variant's name or "" if unknown.
+  pub fn variant_name(self) -> Option<&'static str> {
+    match self {
+      Self::NONE => Some("NONE"),
+      Self::Welcome => Some("Welcome"),
+      Self::StateUpdate => Some("StateUpdate"),
+      Self::SnapshotDelta => Some("SnapshotDelta"),
+      Self::PlayerJoined => Some("PlayerJoined"),
+      Self::PlayerLeft => Some("PlayerLeft"),
+      Self::EntitySpawned => Some("EntitySpawned"),
+      Self::EntityRemoved => Some("EntityRemoved"),
+      Self::EntityUpdated => Some("EntityUpdated"),
+      Self::RoleChanged => Some("RoleChanged"),
+      Self::MissionState => Some("MissionState"),
+      Self::Chat => Some("Chat"),
+      Self::Ping => Some("Ping"),
+      Self::InputAck => Some("InputAck"),
+      Self::Markers => Some("Markers"),
+      Self::HackerIntel => Some("HackerIntel"),
+      Self::Trace => Some("Trace"),
+      Self::Interaction => Some("Interaction"),
+      Self::DoorStateChanged => Some("DoorStateChanged"),
+      Self::Level => Some("Level"),
+      _ => None,
+    }
+  }
+}
+impl core::fmt::Debug for ServerPayload {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    if let Some(name) = self.variant_name() {
+      f.write_str(name)
+    } else {
+      f.write_fmt(format_args!("<UNKNOWN {:?}>", self.0))
+    }
+  }
+}
+impl<'a> flatbuffers::Follow<'a> for ServerPayload {
+  type Inner = Self;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    let b = unsafe { flatbuffers::read_scalar_at::<u8>(buf, loc) };
+    Self(b)
+  }
+}
+
+impl flatbuffers::Push for ServerPayload {
+    type Output = ServerPayload;
+    #[inline]
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        unsafe { flatbuffers::emplace_scalar::<u8>(dst, self.0) };
+    }
+}
+
+impl flatbuffers::EndianScalar for ServerPayload {
+  type Scalar = u8;
+  #[inline]
+  fn to_little_endian(self) -> u8 {
+    self.0.to_le()
+  }
+  #[inline]
+  #[allow(clippy::wrong_self_convention)]
+  fn from_little_endian(v: u8) -> Self {
+    let b = u8::from_le(v);
+    Self(b)
+  }
+}
+
+impl<'a> flatbuffers::Verifiable for ServerPayload {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    u8::run_verifier(v, pos)
+  }
+}
+
+impl flatbuffers::SimpleToVerifyInSlice for ServerPayload {}
+pub struct ServerPayloadUnionTableOffset {}
+
+pub enum ServerMessageOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct ServerMessage<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for ServerMessage<'a> {
+  type Inner = ServerMessage<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> ServerMessage<'a> {
+  pub const VT_TO: flatbuffers::VOffsetT = 4;
+  pub const VT_PAYLOAD_TYPE: flatbuffers::VOffsetT = 6;
+  pub const VT_PAYLOAD: flatbuffers::VOffsetT = 8;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    ServerMessage { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args ServerMessageArgs
+  ) -> flatbuffers::WIPOffset<ServerMessage<'bldr>> {
+    let mut builder = ServerMessageBuilder::new(_fbb);
+    if let Some(x) = args.payload { builder.add_payload(x); }
+    if let Some(x) = args.to { builder.add_to(x); }
+    builder.add_payload_type(args.payload_type);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn to(&self) -> Option<u32> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(ServerMessage::VT_TO, None)}
+  }
+  #[inline]
+  pub fn payload_type(&self) -> ServerPayload {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<ServerPayload>(ServerMessage::VT_PAYLOAD_TYPE, Some(ServerPayload::NONE)).unwrap()}
+  }
+  #[inline]
+  pub fn payload(&self) -> Option<flatbuffers::Table<'a>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Table<'a>>>(ServerMessage::VT_PAYLOAD, None)}
+  }
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn payload_as_welcome(&self) -> Option<Welcome<'a>> {
+    if self.payload_type() == ServerPayload::Welcome {
+      self.payload().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { Welcome::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn payload_as_state_update(&self) -> Option<StateUpdate<'a>> {
+    if self.payload_type() == ServerPayload::StateUpdate {
+      self.payload().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { StateUpdate::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn payload_as_snapshot_delta(&self) -> Option<SnapshotDelta<'a>> {
+    if self.payload_type() == ServerPayload::SnapshotDelta {
+      self.payload().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { SnapshotDelta::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn payload_as_player_joined(&self) -> Option<PlayerJoined<'a>> {
+    if self.payload_type() == ServerPayload::PlayerJoined {
+      self.payload().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { PlayerJoined::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn payload_as_player_left(&self) -> Option<PlayerLeft<'a>> {
+    if self.payload_type() == ServerPayload::PlayerLeft {
+      self.payload().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { PlayerLeft::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn payload_as_entity_spawned(&self) -> Option<EntitySpawned<'a>> {
+    if self.payload_type() == ServerPayload::EntitySpawned {
+      self.payload().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { EntitySpawned::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn payload_as_entity_removed(&self) -> Option<EntityRemoved<'a>> {
+    if self.payload_type() == ServerPayload::EntityRemoved {
+      self.payload().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { EntityRemoved::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn payload_as_entity_updated(&self) -> Option<EntityUpdated<'a>> {
+    if self.payload_type() == ServerPayload::EntityUpdated {
+      self.payload().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { EntityUpdated::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn payload_as_role_changed(&self) -> Option<RoleChanged<'a>> {
+    if self.payload_type() == ServerPayload::RoleChanged {
+      self.payload().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { RoleChanged::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn payload_as_mission_state(&self) -> Option<MissionState<'a>> {
+    if self.payload_type() == ServerPayload::MissionState {
+      self.payload().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { MissionState::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn payload_as_chat(&self) -> Option<Chat<'a>> {
+    if self.payload_type() == ServerPayload::Chat {
+      self.payload().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { Chat::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn payload_as_ping(&self) -> Option<Ping<'a>> {
+    if self.payload_type() == ServerPayload::Ping {
+      self.payload().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { Ping::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn payload_as_input_ack(&self) -> Option<InputAck<'a>> {
+    if self.payload_type() == ServerPayload::InputAck {
+      self.payload().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { InputAck::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn payload_as_markers(&self) -> Option<Markers<'a>> {
+    if self.payload_type() == ServerPayload::Markers {
+      self.payload().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { Markers::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn payload_as_hacker_intel(&self) -> Option<HackerIntel<'a>> {
+    if self.payload_type() == ServerPayload::HackerIntel {
+      self.payload().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { HackerIntel::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn payload_as_trace(&self) -> Option<Trace<'a>> {
+    if self.payload_type() == ServerPayload::Trace {
+      self.payload().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { Trace::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn payload_as_interaction(&self) -> Option<Interaction<'a>> {
+    if self.payload_type() == ServerPayload::Interaction {
+      self.payload().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { Interaction::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn payload_as_door_state_changed(&self) -> Option<DoorStateChanged<'a>> {
+    if self.payload_type() == ServerPayload::DoorStateChanged {
+      self.payload().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { DoorStateChanged::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn payload_as_level(&self) -> Option<Level<'a>> {
+    if self.payload_type() == ServerPayload::Level {
+      self.payload().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { Level::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+}
+
+impl flatbuffers::Verifiable for ServerMessage<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<u32>("to", Self::VT_TO, false)?
+     .visit_union::<ServerPayload, _>("payload_type", Self::VT_PAYLOAD_TYPE, "payload", Self::VT_PAYLOAD, false, |key, v, pos| {
+        match key {
+          ServerPayload::Welcome => v.verify_union_variant::<flatbuffers::ForwardsUOffset<Welcome>>("ServerPayload::Welcome", pos),
+          ServerPayload::StateUpdate => v.verify_union_variant::<flatbuffers::ForwardsUOffset<StateUpdate>>("ServerPayload::StateUpdate", pos),
+          ServerPayload::SnapshotDelta => v.verify_union_variant::<flatbuffers::ForwardsUOffset<SnapshotDelta>>("ServerPayload::SnapshotDelta", pos),
+          ServerPayload::PlayerJoined => v.verify_union_variant::<flatbuffers::ForwardsUOffset<PlayerJoined>>("ServerPayload::PlayerJoined", pos),
+          ServerPayload::PlayerLeft => v.verify_union_variant::<flatbuffers::ForwardsUOffset<PlayerLeft>>("ServerPayload::PlayerLeft", pos),
+          ServerPayload::EntitySpawned => v.verify_union_variant::<flatbuffers::ForwardsUOffset<EntitySpawned>>("ServerPayload::EntitySpawned", pos),
+          ServerPayload::EntityRemoved => v.verify_union_variant::<flatbuffers::ForwardsUOffset<EntityRemoved>>("ServerPayload::EntityRemoved", pos),
+          ServerPayload::EntityUpdated => v.verify_union_variant::<flatbuffers::ForwardsUOffset<EntityUpdated>>("ServerPayload::EntityUpdated", pos),
+          ServerPayload::RoleChanged => v.verify_union_variant::<flatbuffers::ForwardsUOffset<RoleChanged>>("ServerPayload::RoleChanged", pos),
+          ServerPayload::MissionState => v.verify_union_variant::<flatbuffers::ForwardsUOffset<MissionState>>("ServerPayload::MissionState", pos),
+          ServerPayload::Chat => v.verify_union_variant::<flatbuffers::ForwardsUOffset<Chat>>("ServerPayload::Chat", pos),
+          ServerPayload::Ping => v.verify_union_variant::<flatbuffers::ForwardsUOffset<Ping>>("ServerPayload::Ping", pos),
+          ServerPayload::InputAck => v.verify_union_variant::<flatbuffers::ForwardsUOffset<InputAck>>("ServerPayload::InputAck", pos),
+          ServerPayload::Markers => v.verify_union_variant::<flatbuffers::ForwardsUOffset<Markers>>("ServerPayload::Markers", pos),
+          ServerPayload::HackerIntel => v.verify_union_variant::<flatbuffers::ForwardsUOffset<HackerIntel>>("ServerPayload::HackerIntel", pos),
+          ServerPayload::Trace => v.verify_union_variant::<flatbuffers::ForwardsUOffset<Trace>>("ServerPayload::Trace", pos),
+          ServerPayload::Interaction => v.verify_union_variant::<flatbuffers::ForwardsUOffset<Interaction>>("ServerPayload::Interaction", pos),
+          ServerPayload::DoorStateChanged => v.verify_union_variant::<flatbuffers::ForwardsUOffset<DoorStateChanged>>("ServerPayload::DoorStateChanged", pos),
+          ServerPayload::Level => v.verify_union_variant::<flatbuffers::ForwardsUOffset<Level>>("ServerPayload::Level", pos),
+          _ => Ok(()),
+        }
+     })?
+     .finish();
+    Ok(())
+  }
+}
+pub struct ServerMessageArgs {
+    pub to: Option<u32>,
+    pub payload_type: ServerPayload,
+    pub payload: Option<flatbuffers::WIPOffset<flatbuffers::UnionWIPOffset>>,
+}
+impl Default for ServerMessageArgs {
+  #[inline]
+  fn default() -> Self {
+    ServerMessageArgs {
+      to: None,
+      payload_type: ServerPayload::NONE,
+      payload: None,
+    }
+  }
+}
+
+pub struct ServerMessageBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ServerMessageBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_to(&mut self, to: u32) {
+    self.fbb_.push_slot_always::<u32>(ServerMessage::VT_TO, to);
+  }
+  #[inline]
+  pub fn add_payload_type(&mut self, payload_type: ServerPayload) {
+    self.fbb_.push_slot::<ServerPayload>(ServerMessage::VT_PAYLOAD_TYPE, payload_type, ServerPayload::NONE);
+  }
+  #[inline]
+  pub fn add_payload(&mut self, payload: flatbuffers::WIPOffset<flatbuffers::UnionWIPOffset>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(ServerMessage::VT_PAYLOAD, payload);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> ServerMessageBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    ServerMessageBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<ServerMessage<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for ServerMessage<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("ServerMessage");
+      ds.field("to", &self.to());
+      ds.field("payload_type", &self.payload_type());
+      match self.payload_type() {
+        ServerPayload::Welcome => {
+          if let Some(x) = self.payload_as_welcome() {
+            ds.field("payload", &x)
+          } else {
+            ds.field("payload", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        ServerPayload::StateUpdate => {
+          if let Some(x) = self.payload_as_state_update() {
+            ds.field("payload", &x)
+          } else {
+            ds.field("payload", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        ServerPayload::SnapshotDelta => {
+          if let Some(x) = self.payload_as_snapshot_delta() {
+            ds.field("payload", &x)
+          } else {
+            ds.field("payload", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        ServerPayload::PlayerJoined => {
+          if let Some(x) = self.payload_as_player_joined() {
+            ds.field("payload", &x)
+          } else {
+            ds.field("payload", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        ServerPayload::PlayerLeft => {
+          if let Some(x) = self.payload_as_player_left() {
+            ds.field("payload", &x)
+          } else {
+            ds.field("payload", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        ServerPayload::EntitySpawned => {
+          if let Some(x) = self.payload_as_entity_spawned() {
+            ds.field("payload", &x)
+          } else {
+            ds.field("payload", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        ServerPayload::EntityRemoved => {
+          if let Some(x) = self.payload_as_entity_removed() {
+            ds.field("payload", &x)
+          } else {
+            ds.field("payload", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        ServerPayload::EntityUpdated => {
+          if let Some(x) = self.payload_as_entity_updated() {
+            ds.field("payload", &x)
+          } else {
+            ds.field("payload", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        ServerPayload::RoleChanged => {
+          if let Some(x) = self.payload_as_role_changed() {
+            ds.field("payload", &x)
+          } else {
+            ds.field("payload", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        ServerPayload::MissionState => {
+          if let Some(x) = self.payload_as_mission_state() {
+            ds.field("payload", &x)
+          } else {
+            ds.field("payload", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        ServerPayload::Chat => {
+          if let Some(x) = self.payload_as_chat() {
+            ds.field("payload", &x)
+          } else {
+            ds.field("payload", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        ServerPayload::Ping => {
+          if let Some(x) = self.payload_as_ping() {
+            ds.field("payload", &x)
+          } else {
+            ds.field("payload", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        ServerPayload::InputAck => {
+          if let Some(x) = self.payload_as_input_ack() {
+            ds.field("payload", &x)
+          } else {
+            ds.field("payload", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        ServerPayload::Markers => {
+          if let Some(x) = self.payload_as_markers() {
+            ds.field("payload", &x)
+          } else {
+            ds.field("payload", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        ServerPayload::HackerIntel => {
+          if let Some(x) = self.payload_as_hacker_intel() {
+            ds.field("payload", &x)
+          } else {
+            ds.field("payload", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        ServerPayload::Trace => {
+          if let Some(x) = self.payload_as_trace() {
+            ds.field("payload", &x)
+          } else {
+            ds.field("payload", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        ServerPayload::Interaction => {
+          if let Some(x) = self.payload_as_interaction() {
+            ds.field("payload", &x)
+          } else {
+            ds.field("payload", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        ServerPayload::DoorStateChanged => {
+          if let Some(x) = self.payload_as_door_state_changed() {
+            ds.field("payload", &x)
+          } else {
+            ds.field("payload", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        ServerPayload::Level => {
+          if let Some(x) = self.payload_as_level() {
+            ds.field("payload", &x)
+          } else {
+            ds.field("payload", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        _ => {
+          let x: Option<()> = None;
+          ds.field("payload", &x)
+        },
+      };
+      ds.finish()
+  }
+}
+pub enum HelloOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct Hello<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Hello<'a> {
+  type Inner = Hello<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> Hello<'a> {
+  pub const VT_VERSION: flatbuffers::VOffsetT = 4;
+  pub const VT_MIN_VERSION: flatbuffers::VOffsetT = 6;
+  pub const VT_ENCODING: flatbuffers::VOffsetT = 8;
+  pub const VT_ENCODINGS: flatbuffers::VOffsetT = 10;
+  pub const VT_ROLE: flatbuffers::VOffsetT = 12;
+  pub const VT_PLAYER_ID: flatbuffers::VOffsetT = 14;
+  pub const VT_CAPABILITIES: flatbuffers::VOffsetT = 16;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    Hello { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args HelloArgs<'args>
+  ) -> flatbuffers::WIPOffset<Hello<'bldr>> {
+    let mut builder = HelloBuilder::new(_fbb);
+    if let Some(x) = args.capabilities { builder.add_capabilities(x); }
+    builder.add_player_id(args.player_id);
+    if let Some(x) = args.encodings { builder.add_encodings(x); }
+    if let Some(x) = args.encoding { builder.add_encoding(x); }
+    if let Some(x) = args.min_version { builder.add_min_version(x); }
+    builder.add_version(args.version);
+    builder.add_role(args.role);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn version(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(Hello::VT_VERSION, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn min_version(&self) -> Option<u32> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(Hello::VT_MIN_VERSION, None)}
+  }
+  #[inline]
+  pub fn encoding(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(Hello::VT_ENCODING, None)}
+  }
+  #[inline]
+  pub fn encodings(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>>(Hello::VT_ENCODINGS, None)}
+  }
+  #[inline]
+  pub fn role(&self) -> PlayerRole {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<PlayerRole>(Hello::VT_ROLE, Some(PlayerRole::Hacker)).unwrap()}
+  }
+  #[inline]
+  pub fn player_id(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(Hello::VT_PLAYER_ID, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn capabilities(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>>(Hello::VT_CAPABILITIES, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for Hello<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<u32>("version", Self::VT_VERSION, false)?
+     .visit_field::<u32>("min_version", Self::VT_MIN_VERSION, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("encoding", Self::VT_ENCODING, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<&'_ str>>>>("encodings", Self::VT_ENCODINGS, false)?
+     .visit_field::<PlayerRole>("role", Self::VT_ROLE, false)?
+     .visit_field::<u32>("player_id", Self::VT_PLAYER_ID, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<&'_ str>>>>("capabilities", Self::VT_CAPABILITIES, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct HelloArgs<'a> {
+    pub version: u32,
+    pub min_version: Option<u32>,
+    pub encoding: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub encodings: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>>,
+    pub role: PlayerRole,
+    pub player_id: u32,
+    pub capabilities: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>>,
+}
+impl<'a> Default for HelloArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    HelloArgs {
+      version: 0,
+      min_version: None,
+      encoding: None,
+      encodings: None,
+      role: PlayerRole::Hacker,
+      player_id: 0,
+      capabilities: None,
+    }
+  }
+}
+
+pub struct HelloBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> HelloBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_version(&mut self, version: u32) {
+    self.fbb_.push_slot::<u32>(Hello::VT_VERSION, version, 0);
+  }
+  #[inline]
+  pub fn add_min_version(&mut self, min_version: u32) {
+    self.fbb_.push_slot_always::<u32>(Hello::VT_MIN_VERSION, min_version);
+  }
+  #[inline]
+  pub fn add_encoding(&mut self, encoding: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Hello::VT_ENCODING, encoding);
+  }
+  #[inline]
+  pub fn add_encodings(&mut self, encodings: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<&'b  str>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Hello::VT_ENCODINGS, encodings);
+  }
+  #[inline]
+  pub fn add_role(&mut self, role: PlayerRole) {
+    self.fbb_.push_slot::<PlayerRole>(Hello::VT_ROLE, role, PlayerRole::Hacker);
+  }
+  #[inline]
+  pub fn add_player_id(&mut self, player_id: u32) {
+    self.fbb_.push_slot::<u32>(Hello::VT_PLAYER_ID, player_id, 0);
+  }
+  #[inline]
+  pub fn add_capabilities(&mut self, capabilities: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<&'b  str>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Hello::VT_CAPABILITIES, capabilities);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> HelloBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    HelloBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Hello<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for Hello<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("Hello");
+      ds.field("version", &self.version());
+      ds.field("min_version", &self.min_version());
+      ds.field("encoding", &self.encoding());
+      ds.field("encodings", &self.encodings());
+      ds.field("role", &self.role());
+      ds.field("player_id", &self.player_id());
+      ds.field("capabilities", &self.capabilities());
+      ds.finish()
+  }
+}
+pub enum PlayerInputOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct PlayerInput<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for PlayerInput<'a> {
+  type Inner = PlayerInput<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> PlayerInput<'a> {
+  pub const VT_MOVEMENT: flatbuffers::VOffsetT = 4;
+  pub const VT_SEQUENCE: flatbuffers::VOffsetT = 6;
+  pub const VT_SPRINT: flatbuffers::VOffsetT = 8;
+  pub const VT_CROUCH: flatbuffers::VOffsetT = 10;
+  pub const VT_PRONE: flatbuffers::VOffsetT = 12;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    PlayerInput { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args PlayerInputArgs<'args>
+  ) -> flatbuffers::WIPOffset<PlayerInput<'bldr>> {
+    let mut builder = PlayerInputBuilder::new(_fbb);
+    if let Some(x) = args.sequence { builder.add_sequence(x); }
+    if let Some(x) = args.movement { builder.add_movement(x); }
+    builder.add_prone(args.prone);
+    builder.add_crouch(args.crouch);
+    builder.add_sprint(args.sprint);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn movement(&self) -> Option<&'a Vec2> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<Vec2>(PlayerInput::VT_MOVEMENT, None)}
+  }
+  #[inline]
+  pub fn sequence(&self) -> Option<u32> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(PlayerInput::VT_SEQUENCE, None)}
+  }
+  #[inline]
+  pub fn sprint(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(PlayerInput::VT_SPRINT, Some(false)).unwrap()}
+  }
+  #[inline]
+  pub fn crouch(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(PlayerInput::VT_CROUCH, Some(false)).unwrap()}
+  }
+  #[inline]
+  pub fn prone(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(PlayerInput::VT_PRONE, Some(false)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for PlayerInput<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<Vec2>("movement", Self::VT_MOVEMENT, false)?
+     .visit_field::<u32>("sequence", Self::VT_SEQUENCE, false)?
+     .visit_field::<bool>("sprint", Self::VT_SPRINT, false)?
+     .visit_field::<bool>("crouch", Self::VT_CROUCH, false)?
+     .visit_field::<bool>("prone", Self::VT_PRONE, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct PlayerInputArgs<'a> {
+    pub movement: Option<&'a Vec2>,
+    pub sequence: Option<u32>,
+    pub sprint: bool,
+    pub crouch: bool,
+    pub prone: bool,
+}
+impl<'a> Default for PlayerInputArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    PlayerInputArgs {
+      movement: None,
+      sequence: None,
+      sprint: false,
+      crouch: false,
+      prone: false,
+    }
+  }
+}
+
+pub struct PlayerInputBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> PlayerInputBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_movement(&mut self, movement: &Vec2) {
+    self.fbb_.push_slot_always::<&Vec2>(PlayerInput::VT_MOVEMENT, movement);
+  }
+  #[inline]
+  pub fn add_sequence(&mut self, sequence: u32) {
+    self.fbb_.push_slot_always::<u32>(PlayerInput::VT_SEQUENCE, sequence);
+  }
+  #[inline]
+  pub fn add_sprint(&mut self, sprint: bool) {
+    self.fbb_.push_slot::<bool>(PlayerInput::VT_SPRINT, sprint, false);
+  }
+  #[inline]
+  pub fn add_crouch(&mut self, crouch: bool) {
+    self.fbb_.push_slot::<bool>(PlayerInput::VT_CROUCH, crouch, false);
+  }
+  #[inline]
+  pub fn add_prone(&mut self, prone: bool) {
+    self.fbb_.push_slot::<bool>(PlayerInput::VT_PRONE, prone, false);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> PlayerInputBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    PlayerInputBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<PlayerInput<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for PlayerInput<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("PlayerInput");
+      ds.field("movement", &self.movement());
+      ds.field("sequence", &self.sequence());
+      ds.field("sprint", &self.sprint());
+      ds.field("crouch", &self.crouch());
+      ds.field("prone", &self.prone());
+      ds.finish()
+  }
+}
+pub enum PlayerActionOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct PlayerAction<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for PlayerAction<'a> {
+  type Inner = PlayerAction<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> PlayerAction<'a> {
+  pub const VT_ACTION: flatbuffers::VOffsetT = 4;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    PlayerAction { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args PlayerActionArgs<'args>
+  ) -> flatbuffers::WIPOffset<PlayerAction<'bldr>> {
+    let mut builder = PlayerActionBuilder::new(_fbb);
+    if let Some(x) = args.action { builder.add_action(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn action(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(PlayerAction::VT_ACTION, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for PlayerAction<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("action", Self::VT_ACTION, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct PlayerActionArgs<'a> {
+    pub action: Option<flatbuffers::WIPOffset<&'a str>>,
+}
+impl<'a> Default for PlayerActionArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    PlayerActionArgs {
+      action: None,
+    }
+  }
+}
+
+pub struct PlayerActionBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> PlayerActionBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_action(&mut self, action: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(PlayerAction::VT_ACTION, action);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> PlayerActionBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    PlayerActionBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<PlayerAction<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for PlayerAction<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("PlayerAction");
+      ds.field("action", &self.action());
+      ds.finish()
+  }
+}
+pub enum HackOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct Hack<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Hack<'a> {
+  type Inner = Hack<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> Hack<'a> {
+  pub const VT_COMMAND: flatbuffers::VOffsetT = 4;
+  pub const VT_DEVICE_ID: flatbuffers::VOffsetT = 6;
+  pub const VT_INSTRUCTION: flatbuffers::VOffsetT = 8;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    Hack { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args HackArgs<'args>
+  ) -> flatbuffers::WIPOffset<Hack<'bldr>> {
+    let mut builder = HackBuilder::new(_fbb);
+    if let Some(x) = args.instruction { builder.add_instruction(x); }
+    builder.add_device_id(args.device_id);
+    builder.add_command(args.command);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn command(&self) -> HackCommandKind {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<HackCommandKind>(Hack::VT_COMMAND, Some(HackCommandKind::Compromise)).unwrap()}
+  }
+  #[inline]
+  pub fn device_id(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(Hack::VT_DEVICE_ID, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn instruction(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(Hack::VT_INSTRUCTION, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for Hack<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<HackCommandKind>("command", Self::VT_COMMAND, false)?
+     .visit_field::<u32>("device_id", Self::VT_DEVICE_ID, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("instruction", Self::VT_INSTRUCTION, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct HackArgs<'a> {
+    pub command: HackCommandKind,
+    pub device_id: u32,
+    pub instruction: Option<flatbuffers::WIPOffset<&'a str>>,
+}
+impl<'a> Default for HackArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    HackArgs {
+      command: HackCommandKind::Compromise,
+      device_id: 0,
+      instruction: None,
+    }
+  }
+}
+
+pub struct HackBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> HackBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_command(&mut self, command: HackCommandKind) {
+    self.fbb_.push_slot::<HackCommandKind>(Hack::VT_COMMAND, command, HackCommandKind::Compromise);
+  }
+  #[inline]
+  pub fn add_device_id(&mut self, device_id: u32) {
+    self.fbb_.push_slot::<u32>(Hack::VT_DEVICE_ID, device_id, 0);
+  }
+  #[inline]
+  pub fn add_instruction(&mut self, instruction: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Hack::VT_INSTRUCTION, instruction);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> HackBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    HackBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Hack<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for Hack<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("Hack");
+      ds.field("command", &self.command());
+      ds.field("device_id", &self.device_id());
+      ds.field("instruction", &self.instruction());
+      ds.finish()
+  }
+}
+pub enum MarkOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct Mark<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Mark<'a> {
+  type Inner = Mark<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> Mark<'a> {
+  pub const VT_MARKER_TYPE: flatbuffers::VOffsetT = 4;
+  pub const VT_MARKER: flatbuffers::VOffsetT = 6;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    Mark { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args MarkArgs
+  ) -> flatbuffers::WIPOffset<Mark<'bldr>> {
+    let mut builder = MarkBuilder::new(_fbb);
+    if let Some(x) = args.marker { builder.add_marker(x); }
+    builder.add_marker_type(args.marker_type);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn marker_type(&self) -> MarkerKind {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<MarkerKind>(Mark::VT_MARKER_TYPE, Some(MarkerKind::NONE)).unwrap()}
+  }
+  #[inline]
+  pub fn marker(&self) -> Option<flatbuffers::Table<'a>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Table<'a>>>(Mark::VT_MARKER, None)}
+  }
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn marker_as_ping_marker(&self) -> Option<PingMarker<'a>> {
+    if self.marker_type() == MarkerKind::PingMarker {
+      self.marker().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { PingMarker::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn marker_as_tag_marker(&self) -> Option<TagMarker<'a>> {
+    if self.marker_type() == MarkerKind::TagMarker {
+      self.marker().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { TagMarker::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn marker_as_route_marker(&self) -> Option<RouteMarker<'a>> {
+    if self.marker_type() == MarkerKind::RouteMarker {
+      self.marker().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { RouteMarker::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+}
+
+impl flatbuffers::Verifiable for Mark<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_union::<MarkerKind, _>("marker_type", Self::VT_MARKER_TYPE, "marker", Self::VT_MARKER, false, |key, v, pos| {
+        match key {
+          MarkerKind::PingMarker => v.verify_union_variant::<flatbuffers::ForwardsUOffset<PingMarker>>("MarkerKind::PingMarker", pos),
+          MarkerKind::TagMarker => v.verify_union_variant::<flatbuffers::ForwardsUOffset<TagMarker>>("MarkerKind::TagMarker", pos),
+          MarkerKind::RouteMarker => v.verify_union_variant::<flatbuffers::ForwardsUOffset<RouteMarker>>("MarkerKind::RouteMarker", pos),
+          _ => Ok(()),
+        }
+     })?
+     .finish();
+    Ok(())
+  }
+}
+pub struct MarkArgs {
+    pub marker_type: MarkerKind,
+    pub marker: Option<flatbuffers::WIPOffset<flatbuffers::UnionWIPOffset>>,
+}
+impl Default for MarkArgs {
+  #[inline]
+  fn default() -> Self {
+    MarkArgs {
+      marker_type: MarkerKind::NONE,
+      marker: None,
+    }
+  }
+}
+
+pub struct MarkBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> MarkBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_marker_type(&mut self, marker_type: MarkerKind) {
+    self.fbb_.push_slot::<MarkerKind>(Mark::VT_MARKER_TYPE, marker_type, MarkerKind::NONE);
+  }
+  #[inline]
+  pub fn add_marker(&mut self, marker: flatbuffers::WIPOffset<flatbuffers::UnionWIPOffset>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Mark::VT_MARKER, marker);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> MarkBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    MarkBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Mark<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for Mark<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("Mark");
+      ds.field("marker_type", &self.marker_type());
+      match self.marker_type() {
+        MarkerKind::PingMarker => {
+          if let Some(x) = self.marker_as_ping_marker() {
+            ds.field("marker", &x)
+          } else {
+            ds.field("marker", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        MarkerKind::TagMarker => {
+          if let Some(x) = self.marker_as_tag_marker() {
+            ds.field("marker", &x)
+          } else {
+            ds.field("marker", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        MarkerKind::RouteMarker => {
+          if let Some(x) = self.marker_as_route_marker() {
+            ds.field("marker", &x)
+          } else {
+            ds.field("marker", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        _ => {
+          let x: Option<()> = None;
+          ds.field("marker", &x)
+        },
+      };
+      ds.finish()
+  }
+}
+pub enum UnmarkOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct Unmark<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Unmark<'a> {
+  type Inner = Unmark<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> Unmark<'a> {
+  pub const VT_MARKER_ID: flatbuffers::VOffsetT = 4;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    Unmark { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args UnmarkArgs
+  ) -> flatbuffers::WIPOffset<Unmark<'bldr>> {
+    let mut builder = UnmarkBuilder::new(_fbb);
+    builder.add_marker_id(args.marker_id);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn marker_id(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(Unmark::VT_MARKER_ID, Some(0)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for Unmark<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<u32>("marker_id", Self::VT_MARKER_ID, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct UnmarkArgs {
+    pub marker_id: u32,
+}
+impl Default for UnmarkArgs {
+  #[inline]
+  fn default() -> Self {
+    UnmarkArgs {
+      marker_id: 0,
+    }
+  }
+}
+
+pub struct UnmarkBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> UnmarkBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_marker_id(&mut self, marker_id: u32) {
+    self.fbb_.push_slot::<u32>(Unmark::VT_MARKER_ID, marker_id, 0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> UnmarkBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    UnmarkBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Unmark<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for Unmark<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("Unmark");
+      ds.field("marker_id", &self.marker_id());
+      ds.finish()
+  }
+}
+pub enum StateAckOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct StateAck<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for StateAck<'a> {
+  type Inner = StateAck<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> StateAck<'a> {
+  pub const VT_TICK: flatbuffers::VOffsetT = 4;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    StateAck { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args StateAckArgs
+  ) -> flatbuffers::WIPOffset<StateAck<'bldr>> {
+    let mut builder = StateAckBuilder::new(_fbb);
+    builder.add_tick(args.tick);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn tick(&self) -> u64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u64>(StateAck::VT_TICK, Some(0)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for StateAck<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<u64>("tick", Self::VT_TICK, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct StateAckArgs {
+    pub tick: u64,
+}
+impl Default for StateAckArgs {
+  #[inline]
+  fn default() -> Self {
+    StateAckArgs {
+      tick: 0,
+    }
+  }
+}
+
+pub struct StateAckBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> StateAckBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_tick(&mut self, tick: u64) {
+    self.fbb_.push_slot::<u64>(StateAck::VT_TICK, tick, 0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> StateAckBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    StateAckBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<StateAck<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for StateAck<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("StateAck");
+      ds.field("tick", &self.tick());
+      ds.finish()
+  }
+}
+pub enum SendChatOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct SendChat<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for SendChat<'a> {
+  type Inner = SendChat<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> SendChat<'a> {
+  pub const VT_TEXT: flatbuffers::VOffsetT = 4;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    SendChat { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args SendChatArgs<'args>
+  ) -> flatbuffers::WIPOffset<SendChat<'bldr>> {
+    let mut builder = SendChatBuilder::new(_fbb);
+    if let Some(x) = args.text { builder.add_text(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn text(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(SendChat::VT_TEXT, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for SendChat<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("text", Self::VT_TEXT, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct SendChatArgs<'a> {
+    pub text: Option<flatbuffers::WIPOffset<&'a str>>,
+}
+impl<'a> Default for SendChatArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    SendChatArgs {
+      text: None,
+    }
+  }
+}
+
+pub struct SendChatBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> SendChatBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_text(&mut self, text: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(SendChat::VT_TEXT, text);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> SendChatBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    SendChatBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<SendChat<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for SendChat<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("SendChat");
+      ds.field("text", &self.text());
+      ds.finish()
+  }
+}
+pub enum LeaveOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct Leave<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Leave<'a> {
+  type Inner = Leave<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> Leave<'a> {
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    Leave { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    _args: &'args LeaveArgs
+  ) -> flatbuffers::WIPOffset<Leave<'bldr>> {
+    let mut builder = LeaveBuilder::new(_fbb);
+    builder.finish()
+  }
+
+}
+
+impl flatbuffers::Verifiable for Leave<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct LeaveArgs {
+}
+impl Default for LeaveArgs {
+  #[inline]
+  fn default() -> Self {
+    LeaveArgs {
+    }
+  }
+}
+
+pub struct LeaveBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> LeaveBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> LeaveBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    LeaveBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Leave<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for Leave<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("Leave");
+      ds.finish()
+  }
+}
+pub enum PongOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct Pong<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Pong<'a> {
+  type Inner = Pong<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> Pong<'a> {
+  pub const VT_NONCE: flatbuffers::VOffsetT = 4;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    Pong { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args PongArgs
+  ) -> flatbuffers::WIPOffset<Pong<'bldr>> {
+    let mut builder = PongBuilder::new(_fbb);
+    builder.add_nonce(args.nonce);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn nonce(&self) -> u64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u64>(Pong::VT_NONCE, Some(0)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for Pong<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<u64>("nonce", Self::VT_NONCE, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct PongArgs {
+    pub nonce: u64,
+}
+impl Default for PongArgs {
+  #[inline]
+  fn default() -> Self {
+    PongArgs {
+      nonce: 0,
+    }
+  }
+}
+
+pub struct PongBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> PongBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_nonce(&mut self, nonce: u64) {
+    self.fbb_.push_slot::<u64>(Pong::VT_NONCE, nonce, 0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> PongBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    PongBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Pong<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for Pong<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("Pong");
+      ds.field("nonce", &self.nonce());
+      ds.finish()
+  }
+}
+pub enum ErrorOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct Error<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Error<'a> {
+  type Inner = Error<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> Error<'a> {
+  pub const VT_MSG_TYPE: flatbuffers::VOffsetT = 4;
+  pub const VT_REASON: flatbuffers::VOffsetT = 6;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    Error { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args ErrorArgs<'args>
+  ) -> flatbuffers::WIPOffset<Error<'bldr>> {
+    let mut builder = ErrorBuilder::new(_fbb);
+    if let Some(x) = args.reason { builder.add_reason(x); }
+    if let Some(x) = args.msg_type { builder.add_msg_type(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn msg_type(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(Error::VT_MSG_TYPE, None)}
+  }
+  #[inline]
+  pub fn reason(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(Error::VT_REASON, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for Error<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("msg_type", Self::VT_MSG_TYPE, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("reason", Self::VT_REASON, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct ErrorArgs<'a> {
+    pub msg_type: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub reason: Option<flatbuffers::WIPOffset<&'a str>>,
+}
+impl<'a> Default for ErrorArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    ErrorArgs {
+      msg_type: None,
+      reason: None,
+    }
+  }
+}
+
+pub struct ErrorBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ErrorBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_msg_type(&mut self, msg_type: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Error::VT_MSG_TYPE, msg_type);
+  }
+  #[inline]
+  pub fn add_reason(&mut self, reason: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Error::VT_REASON, reason);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> ErrorBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    ErrorBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Error<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for Error<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("Error");
+      ds.field("msg_type", &self.msg_type());
+      ds.field("reason", &self.reason());
+      ds.finish()
+  }
+}
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MIN_CLIENT_PAYLOAD: u8 = 0;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MAX_CLIENT_PAYLOAD: u8 = 11;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+#[allow(non_camel_case_types)]
+pub const ENUM_VALUES_CLIENT_PAYLOAD: [ClientPayload; 12] = [
+  ClientPayload::NONE,
+  ClientPayload::Hello,
+  ClientPayload::PlayerInput,
+  ClientPayload::PlayerAction,
+  ClientPayload::Hack,
+  ClientPayload::Mark,
+  ClientPayload::Unmark,
+  ClientPayload::StateAck,
+  ClientPayload::SendChat,
+  ClientPayload::Leave,
+  ClientPayload::Pong,
+  ClientPayload::Error,
+];
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct ClientPayload(pub u8);
+#[allow(non_upper_case_globals)]
+impl ClientPayload {
+  pub const NONE: Self = Self(0);
+  pub const Hello: Self = Self(1);
+  pub const PlayerInput: Self = Self(2);
+  pub const PlayerAction: Self = Self(3);
+  pub const Hack: Self = Self(4);
+  pub const Mark: Self = Self(5);
+  pub const Unmark: Self = Self(6);
+  pub const StateAck: Self = Self(7);
+  pub const SendChat: Self = Self(8);
+  pub const Leave: Self = Self(9);
+  pub const Pong: Self = Self(10);
+  pub const Error: Self = Self(11);
+
+  pub const ENUM_MIN: u8 = 0;
+  pub const ENUM_MAX: u8 = 11;
+  pub const ENUM_VALUES: &'static [Self] = &[
+    Self::NONE,
+    Self::Hello,
+    Self::PlayerInput,
+    Self::PlayerAction,
+    Self::Hack,
+    Self::Mark,
+    Self::Unmark,
+    Self::StateAck,
+    Self::SendChat,
+    Self::Leave,
+    Self::Pong,
+    Self::Error,
+  ];
+  /// Returns the variant's name or "" if unknown.
+  pub fn variant_name(self) -> Option<&'static str> {
+    match self {
+      Self::NONE => Some("NONE"),
+      Self::Hello => Some("Hello"),
+      Self::PlayerInput => Some("PlayerInput"),
+      Self::PlayerAction => Some("PlayerAction"),
+      Self::Hack => Some("Hack"),
+      Self::Mark => Some("Mark"),
+      Self::Unmark => Some("Unmark"),
+      Self::StateAck => Some("StateAck"),
+      Self::SendChat => Some("SendChat"),
+      Self::Leave => Some("Leave"),
+      Self::Pong => Some("Pong"),
+      Self::Error => Some("Error"),
+      _ => None,
+    }
+  }
+}
+impl core::fmt::Debug for ClientPayload {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    if let Some(name) = self.variant_name() {
+      f.write_str(name)
+    } else {
+      f.write_fmt(format_args!("<UNKNOWN {:?}>", self.0))
+    }
+  }
+}
+impl<'a> flatbuffers::Follow<'a> for ClientPayload {
+  type Inner = Self;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    let b = unsafe { flatbuffers::read_scalar_at::<u8>(buf, loc) };
+    Self(b)
+  }
+}
+
+impl flatbuffers::Push for ClientPayload {
+    type Output = ClientPayload;
+    #[inline]
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        unsafe { flatbuffers::emplace_scalar::<u8>(dst, self.0) };
+    }
+}
+
+impl flatbuffers::EndianScalar for ClientPayload {
+  type Scalar = u8;
+  #[inline]
+  fn to_little_endian(self) -> u8 {
+    self.0.to_le()
+  }
+  #[inline]
+  #[allow(clippy::wrong_self_convention)]
+  fn from_little_endian(v: u8) -> Self {
+    let b = u8::from_le(v);
+    Self(b)
+  }
+}
+
+impl<'a> flatbuffers::Verifiable for ClientPayload {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    u8::run_verifier(v, pos)
+  }
+}
+
+impl flatbuffers::SimpleToVerifyInSlice for ClientPayload {}
+pub struct ClientPayloadUnionTableOffset {}
+
+pub enum ClientMessageOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct ClientMessage<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for ClientMessage<'a> {
+  type Inner = ClientMessage<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> ClientMessage<'a> {
+  pub const VT_PLAYER_ID: flatbuffers::VOffsetT = 4;
+  pub const VT_PAYLOAD_TYPE: flatbuffers::VOffsetT = 6;
+  pub const VT_PAYLOAD: flatbuffers::VOffsetT = 8;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    ClientMessage { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args ClientMessageArgs
+  ) -> flatbuffers::WIPOffset<ClientMessage<'bldr>> {
+    let mut builder = ClientMessageBuilder::new(_fbb);
+    if let Some(x) = args.payload { builder.add_payload(x); }
+    builder.add_player_id(args.player_id);
+    builder.add_payload_type(args.payload_type);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn player_id(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(ClientMessage::VT_PLAYER_ID, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn payload_type(&self) -> ClientPayload {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<ClientPayload>(ClientMessage::VT_PAYLOAD_TYPE, Some(ClientPayload::NONE)).unwrap()}
+  }
+  #[inline]
+  pub fn payload(&self) -> Option<flatbuffers::Table<'a>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Table<'a>>>(ClientMessage::VT_PAYLOAD, None)}
+  }
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn payload_as_hello(&self) -> Option<Hello<'a>> {
+    if self.payload_type() == ClientPayload::Hello {
+      self.payload().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { Hello::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn payload_as_player_input(&self) -> Option<PlayerInput<'a>> {
+    if self.payload_type() == ClientPayload::PlayerInput {
+      self.payload().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { PlayerInput::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn payload_as_player_action(&self) -> Option<PlayerAction<'a>> {
+    if self.payload_type() == ClientPayload::PlayerAction {
+      self.payload().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { PlayerAction::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn payload_as_hack(&self) -> Option<Hack<'a>> {
+    if self.payload_type() == ClientPayload::Hack {
+      self.payload().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { Hack::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn payload_as_mark(&self) -> Option<Mark<'a>> {
+    if self.payload_type() == ClientPayload::Mark {
+      self.payload().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { Mark::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn payload_as_unmark(&self) -> Option<Unmark<'a>> {
+    if self.payload_type() == ClientPayload::Unmark {
+      self.payload().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { Unmark::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn payload_as_state_ack(&self) -> Option<StateAck<'a>> {
+    if self.payload_type() == ClientPayload::StateAck {
+      self.payload().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { StateAck::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn payload_as_send_chat(&self) -> Option<SendChat<'a>> {
+    if self.payload_type() == ClientPayload::SendChat {
+      self.payload().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { SendChat::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn payload_as_leave(&self) -> Option<Leave<'a>> {
+    if self.payload_type() == ClientPayload::Leave {
+      self.payload().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { Leave::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn payload_as_pong(&self) -> Option<Pong<'a>> {
+    if self.payload_type() == ClientPayload::Pong {
+      self.payload().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { Pong::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn payload_as_error(&self) -> Option<Error<'a>> {
+    if self.payload_type() == ClientPayload::Error {
+      self.payload().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { Error::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+}
+
+impl flatbuffers::Verifiable for ClientMessage<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<u32>("player_id", Self::VT_PLAYER_ID, false)?
+     .visit_union::<ClientPayload, _>("payload_type", Self::VT_PAYLOAD_TYPE, "payload", Self::VT_PAYLOAD, false, |key, v, pos| {
+        match key {
+          ClientPayload::Hello => v.verify_union_variant::<flatbuffers::ForwardsUOffset<Hello>>("ClientPayload::Hello", pos),
+          ClientPayload::PlayerInput => v.verify_union_variant::<flatbuffers::ForwardsUOffset<PlayerInput>>("ClientPayload::PlayerInput", pos),
+          ClientPayload::PlayerAction => v.verify_union_variant::<flatbuffers::ForwardsUOffset<PlayerAction>>("ClientPayload::PlayerAction", pos),
+          ClientPayload::Hack => v.verify_union_variant::<flatbuffers::ForwardsUOffset<Hack>>("ClientPayload::Hack", pos),
+          ClientPayload::Mark => v.verify_union_variant::<flatbuffers::ForwardsUOffset<Mark>>("ClientPayload::Mark", pos),
+          ClientPayload::Unmark => v.verify_union_variant::<flatbuffers::ForwardsUOffset<Unmark>>("ClientPayload::Unmark", pos),
+          ClientPayload::StateAck => v.verify_union_variant::<flatbuffers::ForwardsUOffset<StateAck>>("ClientPayload::StateAck", pos),
+          ClientPayload::SendChat => v.verify_union_variant::<flatbuffers::ForwardsUOffset<SendChat>>("ClientPayload::SendChat", pos),
+          ClientPayload::Leave => v.verify_union_variant::<flatbuffers::ForwardsUOffset<Leave>>("ClientPayload::Leave", pos),
+          ClientPayload::Pong => v.verify_union_variant::<flatbuffers::ForwardsUOffset<Pong>>("ClientPayload::Pong", pos),
+          ClientPayload::Error => v.verify_union_variant::<flatbuffers::ForwardsUOffset<Error>>("ClientPayload::Error", pos),
+          _ => Ok(()),
+        }
+     })?
+     .finish();
+    Ok(())
+  }
+}
+pub struct ClientMessageArgs {
+    pub player_id: u32,
+    pub payload_type: ClientPayload,
+    pub payload: Option<flatbuffers::WIPOffset<flatbuffers::UnionWIPOffset>>,
+}
+impl Default for ClientMessageArgs {
+  #[inline]
+  fn default() -> Self {
+    ClientMessageArgs {
+      player_id: 0,
+      payload_type: ClientPayload::NONE,
+      payload: None,
+    }
+  }
+}
+
+pub struct ClientMessageBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ClientMessageBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_player_id(&mut self, player_id: u32) {
+    self.fbb_.push_slot::<u32>(ClientMessage::VT_PLAYER_ID, player_id, 0);
+  }
+  #[inline]
+  pub fn add_payload_type(&mut self, payload_type: ClientPayload) {
+    self.fbb_.push_slot::<ClientPayload>(ClientMessage::VT_PAYLOAD_TYPE, payload_type, ClientPayload::NONE);
+  }
+  #[inline]
+  pub fn add_payload(&mut self, payload: flatbuffers::WIPOffset<flatbuffers::UnionWIPOffset>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(ClientMessage::VT_PAYLOAD, payload);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> ClientMessageBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    ClientMessageBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<ClientMessage<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for ClientMessage<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("ClientMessage");
+      ds.field("player_id", &self.player_id());
+      ds.field("payload_type", &self.payload_type());
+      match self.payload_type() {
+        ClientPayload::Hello => {
+          if let Some(x) = self.payload_as_hello() {
+            ds.field("payload", &x)
+          } else {
+            ds.field("payload", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        ClientPayload::PlayerInput => {
+          if let Some(x) = self.payload_as_player_input() {
+            ds.field("payload", &x)
+          } else {
+            ds.field("payload", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        ClientPayload::PlayerAction => {
+          if let Some(x) = self.payload_as_player_action() {
+            ds.field("payload", &x)
+          } else {
+            ds.field("payload", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        ClientPayload::Hack => {
+          if let Some(x) = self.payload_as_hack() {
+            ds.field("payload", &x)
+          } else {
+            ds.field("payload", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        ClientPayload::Mark => {
+          if let Some(x) = self.payload_as_mark() {
+            ds.field("payload", &x)
+          } else {
+            ds.field("payload", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        ClientPayload::Unmark => {
+          if let Some(x) = self.payload_as_unmark() {
+            ds.field("payload", &x)
+          } else {
+            ds.field("payload", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        ClientPayload::StateAck => {
+          if let Some(x) = self.payload_as_state_ack() {
+            ds.field("payload", &x)
+          } else {
+            ds.field("payload", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        ClientPayload::SendChat => {
+          if let Some(x) = self.payload_as_send_chat() {
+            ds.field("payload", &x)
+          } else {
+            ds.field("payload", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        ClientPayload::Leave => {
+          if let Some(x) = self.payload_as_leave() {
+            ds.field("payload", &x)
+          } else {
+            ds.field("payload", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        ClientPayload::Pong => {
+          if let Some(x) = self.payload_as_pong() {
+            ds.field("payload", &x)
+          } else {
+            ds.field("payload", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        ClientPayload::Error => {
+          if let Some(x) = self.payload_as_error() {
+            ds.field("payload", &x)
+          } else {
+            ds.field("payload", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        _ => {
+          let x: Option<()> = None;
+          ds.field("payload", &x)
+        },
+      };
+      ds.finish()
+  }
+}
+#[inline]
+/// Verifies that a buffer of bytes contains a `ServerMessage`
+/// and returns it.
+/// Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `root_as_server_message_unchecked`.
+pub fn root_as_server_message(buf: &[u8]) -> Result<ServerMessage, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::root::<ServerMessage>(buf)
+}
+#[inline]
+/// Verifies that a buffer of bytes contains a size prefixed
+/// `ServerMessage` and returns it.
+/// Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `size_prefixed_root_as_server_message_unchecked`.
+pub fn size_prefixed_root_as_server_message(buf: &[u8]) -> Result<ServerMessage, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::size_prefixed_root::<ServerMessage>(buf)
+}
+#[inline]
+/// Verifies, with the given options, that a buffer of bytes
+/// contains a `ServerMessage` and returns it.
+/// Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `root_as_server_message_unchecked`.
+pub fn root_as_server_message_with_opts<'b, 'o>(
+  opts: &'o flatbuffers::VerifierOptions,
+  buf: &'b [u8],
+) -> Result<ServerMessage<'b>, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::root_with_opts::<ServerMessage<'b>>(opts, buf)
+}
+#[inline]
+/// Verifies, with the given verifier options, that a buffer of
+/// bytes contains a size prefixed `ServerMessage` and returns
+/// it. Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `root_as_server_message_unchecked`.
+pub fn size_prefixed_root_as_server_message_with_opts<'b, 'o>(
+  opts: &'o flatbuffers::VerifierOptions,
+  buf: &'b [u8],
+) -> Result<ServerMessage<'b>, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::size_prefixed_root_with_opts::<ServerMessage<'b>>(opts, buf)
+}
+#[inline]
+/// Assumes, without verification, that a buffer of bytes contains a ServerMessage and returns it.
+/// # Safety
+/// Callers must trust the given bytes do indeed contain a valid `ServerMessage`.
+pub unsafe fn root_as_server_message_unchecked(buf: &[u8]) -> ServerMessage {
+  unsafe { flatbuffers::root_unchecked::<ServerMessage>(buf) }
+}
+#[inline]
+/// Assumes, without verification, that a buffer of bytes contains a size prefixed ServerMessage and returns it.
+/// # Safety
+/// Callers must trust the given bytes do indeed contain a valid size prefixed `ServerMessage`.
+pub unsafe fn size_prefixed_root_as_server_message_unchecked(buf: &[u8]) -> ServerMessage {
+  unsafe { flatbuffers::size_prefixed_root_unchecked::<ServerMessage>(buf) }
+}
+#[inline]
+pub fn finish_server_message_buffer<'a, 'b, A: flatbuffers::Allocator + 'a>(
+    fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    root: flatbuffers::WIPOffset<ServerMessage<'a>>) {
+  fbb.finish(root, None);
+}
+
+#[inline]
+pub fn finish_size_prefixed_server_message_buffer<'a, 'b, A: flatbuffers::Allocator + 'a>(fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>, root: flatbuffers::WIPOffset<ServerMessage<'a>>) {
+  fbb.finish_size_prefixed(root, None);
+}
+}  // pub mod port
+}  // pub mod idaptik
+
@@ -0,0 +1,849 @@
+use crate::game::system::{GameEvent, GameSystem, PlayerInput};
+use crate::game::types::*;
+use crate::hacking::markers::MarkerKind;
+use crate::hacking::trace::TraceStage;
+use crate::mission::{FailureReason, Mission, MissionStatus, ObjectiveKind};
+use crate::net::delta::DeltaEncoder;
+use crate::net::interest::known_entities;
+use crate::net::level::LevelGeometry;
+use crate::net::wire::Protocol;
+use crate::security::door::InteractionResult;
+use crate::security::network::HackCommand;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::collections::VecDeque;
+use std::fmt;
+
+/// Port protocol version spoken by the headless server and the core client
+pub const PROTOCOL_VERSION: u32 = 4;
+
+/// Sequenced inputs a seat may have waiting; past this the oldest are dropped
+pub const MAX_QUEUED_INPUTS: usize = 32;
+
+/// Longest chat line relayed, in characters
+pub const MAX_CHAT_LENGTH: usize = 280;
+
+/// Optional protocol features, shared with the core client; a session gets those both sides list
+pub const CAPABILITIES: &[&str] =
+    &["state_delta", "mission_state", "prediction", "chat", "devices", "markers", "intel", "trace", "doors"];
+
+/// Message from a client, as the core writes it
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClientMessage {
+    pub msg_type: String,
+    pub player_id: u32,
+    #[serde(default)]
+    pub data: Value,
+}
+
+/// Message to clients; `to` names the one player it is for, or everyone when None
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ServerMessage {
+    pub msg_type: String,
+    pub data: Value,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub to: Option<u32>,
+}
+
+impl ServerMessage {
+    fn to_player(player_id: u32, msg_type: &str, data: Value) -> Self {
+        Self { msg_type: msg_type.to_string(), data, to: Some(player_id) }
+    }
+
+    fn broadcast(msg_type: &str, data: Value) -> Self {
+        Self { msg_type: msg_type.to_string(), data, to: None }
+    }
+}
+
+/// Why a client message was not acted on
+#[derive(Debug, Clone, PartialEq)]
+pub enum RoomError {
+    UnknownMessage(String),                           // msg_type the server doesn't handle
+    BadPayload { msg_type: String, reason: String },  // data didn't parse
+    NotSeated(u32),                                   // Player hasn't completed the handshake
+    Rejected { player_id: u32, reason: String },      // Handshake refused
+    Reported { player_id: u32, reason: String },      // The client reported an error to us
+    Refused { player_id: u32, reason: String },       // Understood, but not allowed
+}
+
+impl fmt::Display for RoomError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RoomError::UnknownMessage(msg_type) => write!(f, "unknown message type {:?}", msg_type),
+            RoomError::BadPayload { msg_type, reason } => write!(f, "bad {} payload: {}", msg_type, reason),
+            RoomError::NotSeated(player_id) => write!(f, "player {} sent a message before hello", player_id),
+            RoomError::Rejected { player_id, reason } => write!(f, "player {} rejected: {}", player_id, reason),
+            RoomError::Reported { player_id, reason } => write!(f, "player {} reported: {}", player_id, reason),
+            RoomError::Refused { player_id, reason } => write!(f, "player {} refused: {}", player_id, reason),
+        }
+    }
+}
+
+impl std::error::Error for RoomError {}
+
+#[derive(Deserialize)]
+struct HelloPayload {
+    version: u32,
+    #[serde(default)]
+    min_version: Option<u32>,
+    encoding: String,
+    role: PlayerRole,
+    #[serde(default)]
+    capabilities: Vec<String>,
+}
+
+#[derive(Deserialize)]
+struct Movement {
+    x: f32,
+    y: f32,
+}
+
+#[derive(Deserialize)]
+struct InputPayload {
+    movement: Movement,
+    #[serde(default)]
+    sequence: Option<u32>,
+    #[serde(default)]
+    sprint: bool,
+    #[serde(default)]
+    crouch: bool,
+    #[serde(default)]
+    prone: bool,
+}
+
+#[derive(Deserialize)]
+struct ActionPayload {
+    action: String,
+}
+
+#[derive(Deserialize)]
+struct StateAckPayload {
+    tick: u64,
+}
+
+#[derive(Deserialize)]
+struct ChatPayload {
+    text: String,
+}
+
+#[derive(Deserialize)]
+struct UnmarkPayload {
+    marker_id: u32,
+}
+
+/// One connected player and what the server tracks for them
+struct Seat {
+    player_id: u32,
+    role: PlayerRole,
+    entity_id: EntityId,
+    spawned: bool, // Entity was spawned for this seat rather than placed in the level
+    capabilities: Vec<String>,
+    input: PlayerInput,          // Held until the next player_input
+    queued: VecDeque<(u32, PlayerInput)>, // Sequenced inputs not yet simulated, one per tick
+    last_sequence: Option<u32>,  // Newest input sequence simulated
+    acked_sequence: Option<u32>, // Newest sequence sent back in an input_ack
+    markers_revision: Option<u32>, // Marker revision this player last received
+    intel_revision: Option<u32>,   // Hacker intel revision this player last received
+    trace_sent: Option<(u32, TraceStage, Option<EntityId>)>, // Whole trace level, stage and ICE last sent
+    mission_sent: Option<(MissionStatus, usize, Option<u32>)>, // Status, objectives done and whole seconds left last sent
+    encoder: DeltaEncoder,
+}
+
+impl Seat {
+    fn supports(&self, capability: &str) -> bool {
+        self.capabilities.iter().any(|c| c == capability)
+    }
+}
+
+/// One authoritative simulation shared by the players of a room
+pub struct Room {
+    pub system: GameSystem,
+    timestep: f32,
+    protocol: Protocol, // Encoding the Port was opened with; clients must hello in it
+    seats: Vec<Seat>,
+}
+
+impl Room {
+    /// Run `system` at `tick_rate` ticks per second, speaking newline-delimited JSON
+    pub fn new(system: GameSystem, tick_rate: f32) -> Self {
+        Self::with_protocol(system, tick_rate, Protocol::Json)
+    }
+
+    pub fn with_protocol(system: GameSystem, tick_rate: f32, protocol: Protocol) -> Self {
+        Self { system, timestep: 1.0 / tick_rate.max(1.0), protocol, seats: Vec::new() }
+    }
+
+    pub fn protocol(&self) -> Protocol {
+        self.protocol
+    }
+
+    pub fn timestep(&self) -> f32 {
+        self.timestep
+    }
+
+    /// Entity controlled by a seated player
+    pub fn entity_of(&self, player_id: u32) -> Option<EntityId> {
+        self.seat(player_id).map(|s| s.entity_id)
+    }
+
+    pub fn player_count(&self) -> usize {
+        self.seats.len()
+    }
+
+    fn seat(&self, player_id: u32) -> Option<&Seat> {
+        self.seats.iter().find(|s| s.player_id == player_id)
+    }
+
+    fn seat_mut(&mut self, player_id: u32) -> Result<&mut Seat, RoomError> {
+        self.seats.iter_mut()
+            .find(|s| s.player_id == player_id)
+            .ok_or(RoomError::NotSeated(player_id))
+    }
+
+    /// The seat of a hacker who negotiated `capability`, for hacker-only messages
+    fn hacker_seat(&mut self, player_id: u32, capability: &str) -> Result<&mut Seat, RoomError> {
+        let seat = self.seat_mut(player_id)?;
+        if seat.role != PlayerRole::Hacker || !seat.supports(capability) {
+            return Err(RoomError::Refused { player_id, reason: format!("only the hacker can use {}", capability) });
+        }
+        Ok(seat)
+    }
+
+    /// Act on one client message, returning any immediate replies
+    pub fn handle(&mut self, message: ClientMessage) -> Result<Vec<ServerMessage>, RoomError> {
+        let player_id = message.player_id;
+        match message.msg_type.as_str() {
+            "hello" => {
+                let hello: HelloPayload = parse(&message)?;
+                self.join(player_id, hello)
+            }
+            "player_input" => {
+                let payload: InputPayload = parse(&message)?;
+                let seat = self.seat_mut(player_id)?;
+                let input = PlayerInput {
+                    move_x: payload.movement.x,
+                    move_y: payload.movement.y,
+                    sprint: payload.sprint,
+                    crouch: payload.crouch,
+                    prone: payload.prone,
+                };
+                let Some(sequence) = payload.sequence else {
+                    seat.input = input;
+                    return Ok(Vec::new());
+                };
+
+                // A predicting client simulated this input for one tick, so it gets exactly one here
+                let newest = seat.queued.back().map(|(s, _)| *s).or(seat.last_sequence);
+                if newest.is_none_or(|newest| sequence > newest) {
+                    seat.queued.push_back((sequence, input));
+                    while seat.queued.len() > MAX_QUEUED_INPUTS {
+                        seat.queued.pop_front();
+                    }
+                }
+                Ok(Vec::new())
+            }
+            "player_action" => {
+                let payload: ActionPayload = parse(&message)?;
+                let entity_id = self.seat_mut(player_id)?.entity_id;
+                match payload.action.as_str() {
+                    "interact" => {
+                        // Doors are worked by hand; the hacker's way in is the network
+                        if self.seat_mut(player_id)?.role != PlayerRole::Infiltrator {
+                            return Err(RoomError::Refused { player_id, reason: "only the infiltrator can use doors".to_string() });
+                        }
+                        let event = self.system.interact(entity_id);
+                        if !self.seat_mut(player_id)?.supports("doors") {
+                            return Ok(Vec::new());
+                        }
+                        // Tell the player how it went; the door's new state goes to everyone next tick
+                        let (door_id, result) = match event {
+                            Some(GameEvent::DoorInteraction { door_id, result, .. }) => (Some(door_id), result),
+                            _ => (None, InteractionResult::NothingToInteract),
+                        };
+                        Ok(vec![ServerMessage::to_player(player_id, "interaction", json!({
+                            "door_id": door_id,
+                            "result": result,
+                        }))])
+                    }
+                    other => Err(RoomError::BadPayload {
+                        msg_type: message.msg_type.clone(),
+                        reason: format!("unknown action {:?}", other),
+                    }),
+                }
+            }
+            "hack" => {
+                let command: HackCommand = parse(&message)?;
+                let hacker_id = self.hacker_seat(player_id, "devices")?.entity_id;
+                self.system.hack(hacker_id, command).map_err(|e| RoomError::Refused { player_id, reason: e.to_string() })?;
+                Ok(Vec::new())
+            }
+            "mark" => {
+                let kind: MarkerKind = parse(&message)?;
+                self.hacker_seat(player_id, "markers")?;
+                self.system.place_marker(kind).map_err(|e| RoomError::Refused { player_id, reason: e.to_string() })?;
+                Ok(Vec::new())
+            }
+            "unmark" => {
+                let payload: UnmarkPayload = parse(&message)?;
+                self.hacker_seat(player_id, "markers")?;
+                if !self.system.clear_marker(payload.marker_id) {
+                    return Err(RoomError::Refused {
+                        player_id,
+                        reason: format!("no marker {}", payload.marker_id),
+                    });
+                }
+                Ok(Vec::new())
+            }
+            "state_ack" => {
+                let payload: StateAckPayload = parse(&message)?;
+                self.seat_mut(player_id)?.encoder.acknowledge(payload.tick);
+                Ok(Vec::new())
+            }
+            "chat" => {
+                let payload: ChatPayload = parse(&message)?;
+                self.seat_mut(player_id)?;
+                let length = payload.text.chars().count();
+                if length > MAX_CHAT_LENGTH {
+                    return Err(RoomError::Refused {
+                        player_id,
+                        reason: format!("chat is {} characters, at most {} allowed", length, MAX_CHAT_LENGTH),
+                    });
+                }
+                Ok(self.seats.iter()
+                    .filter(|s| s.supports("chat"))
+                    .map(|s| ServerMessage::to_player(s.player_id, "chat", json!({
+                        "player_id": player_id,
+                        "text": payload.text,
+                    })))
+                    .collect())
+            }
+            "leave" => Ok(self.leave(player_id)),
+            "pong" => Ok(Vec::new()),
+            "error" => Err(RoomError::Reported {
+                player_id,
+                reason: message.data.get("reason").and_then(Value::as_str).unwrap_or("unknown").to_string(),
+            }),
+            other => Err(RoomError::UnknownMessage(other.to_string())),
+        }
+    }
+
+    /// Seat a player after their hello; rejoining with the same id keeps the seat
+    fn join(&mut self, player_id: u32, hello: HelloPayload) -> Result<Vec<ServerMessage>, RoomError> {
+        let offered: Vec<String> = CAPABILITIES.iter()
+            .filter(|c| hello.capabilities.iter().any(|h| h == *c))
+            .map(|c| c.to_string())
+            .collect();
+        let welcome = ServerMessage::to_player(player_id, "welcome", json!({
+            "version": PROTOCOL_VERSION,
+            "encoding": self.protocol.name(),
+            "capabilities": offered,
+        }));
+
+        // The client explains the mismatch to its player once it reads the welcome
+        let min_version = hello.min_version.unwrap_or(hello.version);
+        if !(min_version..=hello.version).contains(&PROTOCOL_VERSION) || hello.encoding != self.protocol.name() {
+            return Ok(vec![welcome]);
+        }
+
+        let mut replies = vec![welcome];
+        if offered.iter().any(|c| c == "prediction") {
+            let level = LevelGeometry::of(&self.system.world, self.timestep);
+            replies.push(ServerMessage::to_player(player_id, "level", serde_json::to_value(level).unwrap_or_default()));
+        }
+
+        if let Some(seat) = self.seats.iter_mut().find(|s| s.player_id == player_id) {
+            if seat.role != hello.role {
+                return Err(RoomError::Rejected {
+                    player_id,
+                    reason: format!("already seated as {:?}", seat.role),
+                });
+            }
+            // The reconnected client starts from nothing, so everything goes out again
+            seat.capabilities = offered;
+            seat.queued.clear();
+            seat.encoder.reset();
+            seat.markers_revision = None;
+            seat.intel_revision = None;
+            seat.trace_sent = None;
+            seat.mission_sent = None;
+            return Ok(replies);
+        }
+
+        let (entity_id, spawned) = self.claim_entity(hello.role);
+
+        // Tell the newcomer who is already here
+        for seat in &self.seats {
+            replies.push(ServerMessage::to_player(player_id, "player_joined", json!({
+                "player_id": seat.player_id,
+                "role": seat.role,
+            })));
+        }
+        replies.push(ServerMessage::broadcast("player_joined", json!({
+            "player_id": player_id,
+            "role": hello.role,
+        })));
+
+        self.seats.push(Seat {
+            player_id,
+            role: hello.role,
+            entity_id,
+            spawned,
+            capabilities: offered,
+            input: PlayerInput::default(),
+            queued: VecDeque::new(),
+            last_sequence: None,
+            acked_sequence: None,
+            markers_revision: None,
+            intel_revision: None,
+            trace_sent: None,
+            mission_sent: None,
+            encoder: DeltaEncoder::new(),
+        });
+        Ok(replies)
+    }
+
+    /// Use an unclaimed entity of the role's type placed in the level, or spawn one
+    /// Returns the entity and whether it was spawned
+    fn claim_entity(&mut self, role: PlayerRole) -> (EntityId, bool) {
+        let claimed = self.system.world.entities.iter()
+            .find(|e| e.entity_type.role() == Some(role) && !self.seats.iter().any(|s| s.entity_id == e.id))
+            .map(|e| e.id);
+        if let Some(id) = claimed {
+            return (id, false);
+        }
+
+        let (width, height) = (self.system.world.width, self.system.world.height);
+        let id = match role {
+            PlayerRole::Infiltrator => self.system.spawn_player(width / 2.0, height / 2.0),
+            PlayerRole::Hacker => self.system.spawn_hacker(0.0, 0.0),
+        };
+        (id, true)
+    }
+
+    fn leave(&mut self, player_id: u32) -> Vec<ServerMessage> {
+        let Some(index) = self.seats.iter().position(|s| s.player_id == player_id) else {
+            return Vec::new();
+        };
+        // A level-placed entity stays for whoever takes the seat next
+        let seat = self.seats.remove(index);
+        if seat.spawned {
+            self.system.world.remove_entity(seat.entity_id);
+        }
+        vec![ServerMessage::broadcast("player_left", json!({ "player_id": player_id }))]
+    }
+
+    /// Advance one fixed tick and build each player's acks and state
+    pub fn tick(&mut self) -> Vec<ServerMessage> {
+        for seat in &mut self.seats {
+            // The next sequenced input, if any, is held until another replaces it
+            if let Some((sequence, input)) = seat.queued.pop_front() {
+                seat.input = input;
+                seat.last_sequence = Some(sequence);
+            }
+            self.system.apply_player_input(seat.entity_id, seat.input);
+        }
+        let events = self.system.step(self.timestep);
+        let door_changes: Vec<Value> = events.iter()
+            .filter_map(|event| match event {
+                GameEvent::DoorStateChanged { door_id, state } => Some(json!({ "door_id": door_id, "state": state })),
+                _ => None,
+            })
+            .collect();
+
+        let mut messages = Vec::new();
+        for seat in &mut self.seats {
+            // Acks go first so the state that follows reflects them
+            if seat.supports("prediction") && seat.last_sequence != seat.acked_sequence {
+                if let Some(sequence) = seat.last_sequence {
+                    messages.push(ServerMessage::to_player(seat.player_id, "input_ack", json!({ "sequence": sequence })));
+                    seat.acked_sequence = Some(sequence);
+                }
+            }
+
+            if seat.supports("state_delta") {
+                let delta = seat.encoder.encode(self.system.world_snapshot_for(seat.entity_id));
+                messages.push(ServerMessage::to_player(
+                    seat.player_id,
+                    "state_delta",
+                    serde_json::to_value(delta).unwrap_or_default(),
+                ));
+            } else {
+                let known = known_entities(&self.system.world, seat.entity_id);
+                let entities: Vec<&Entity> = self.system.world.entities.iter()
+                    .filter(|e| known.contains(&e.id))
+                    .collect();
+                messages.push(ServerMessage::to_player(
+                    seat.player_id,
+                    "state_update",
+                    serde_json::to_value(entities).unwrap_or_default(),
+                ));
+            }
+
+            // Markers go out whole, and only when they have changed
+            let markers = &self.system.world.markers;
+            if seat.supports("markers") && seat.markers_revision != Some(markers.revision) {
+                messages.push(ServerMessage::to_player(seat.player_id, "markers", json!({ "markers": markers.markers })));
+                seat.markers_revision = Some(markers.revision);
+            }
+
+            // The hacker's sensor coverage and ghosts, likewise
+            let intel = &self.system.world.intel;
+            if seat.role == PlayerRole::Hacker && seat.supports("intel") && seat.intel_revision != Some(intel.revision) {
+                messages.push(ServerMessage::to_player(seat.player_id, "hacker_intel", json!({
+                    "sensors": intel.sensors,
+                    "ghosts": intel.ghosts,
+                })));
+                seat.intel_revision = Some(intel.revision);
+            }
+
+            // The hacker's trace meter, when a whole point, its stage or the ICE has moved
+            let trace = self.system.trace(seat.entity_id);
+            if let Some(meter) = trace.filter(|_| seat.role == PlayerRole::Hacker && seat.supports("trace")) {
+                let ice = meter.ice.map(|ice| ice.device_id);
+                let sent = Some((meter.level.ceil() as u32, meter.stage, ice));
+                if seat.trace_sent != sent {
+                    messages.push(ServerMessage::to_player(seat.player_id, "trace", json!({
+                        "level": meter.level,
+                        "stage": meter.stage,
+                        "ice": ice,
+                    })));
+                    seat.trace_sent = sent;
+                }
+            }
+
+            // Mission progress, when the outcome, an objective or a whole second left has changed
+            if let Some(mission) = self.system.world.mission.as_ref().filter(|_| seat.supports("mission_state")) {
+                let done = mission.objectives.iter().filter(|o| o.is_complete()).count();
+                let sent = Some((mission.status, done, time_remaining(mission).map(|t| t.ceil() as u32)));
+                if seat.mission_sent != sent {
+                    messages.push(ServerMessage::to_player(seat.player_id, "mission_state", mission_state(mission)));
+                    seat.mission_sent = sent;
+                }
+            }
+
+            // Doors opened, shut, locked down or hacked this tick
+            if seat.supports("doors") {
+                for change in &door_changes {
+                    messages.push(ServerMessage::to_player(seat.player_id, "door_state", change.clone()));
+                }
+            }
+        }
+        messages
+    }
+}
+
+fn time_remaining(mission: &Mission) -> Option<f32> {
+    mission.time_limit.map(|limit| (limit - mission.elapsed).max(0.0))
+}
+
+/// A mission as the `mission_state` message describes it
+fn mission_state(mission: &Mission) -> Value {
+    let (status, failure_reason) = match mission.status {
+        MissionStatus::InProgress => ("in_progress", None),
+        MissionStatus::Succeeded => ("succeeded", None),
+        MissionStatus::Failed { reason: FailureReason::Caught { guard_id, .. } } => {
+            ("failed", Some(format!("caught by guard {}", guard_id)))
+        }
+        MissionStatus::Failed { reason: FailureReason::TimeExpired } => ("failed", Some("time expired".to_string())),
+    };
+    let objectives: Vec<Value> = mission.objectives.iter()
+        .map(|o| {
+            let description = match o.kind {
+                ObjectiveKind::ReachZone { position, .. } => format!("Reach ({:.0}, {:.0})", position.x, position.y),
+                ObjectiveKind::StealItem { item_id } => format!("Steal item {}", item_id),
+                ObjectiveKind::HackTerminal { terminal_id } => format!("Hack terminal {}", terminal_id),
+                ObjectiveKind::Extract { position, .. } => format!("Extract at ({:.0}, {:.0})", position.x, position.y),
+            };
+            json!({
+                "objective_id": o.objective_id,
+                "description": description,
+                "complete": o.is_complete(),
+                "optional": o.optional,
+            })
+        })
+        .collect();
+    json!({
+        "status": status,
+        "objectives": objectives,
+        "time_remaining": time_remaining(mission),
+        "failure_reason": failure_reason,
+    })
+}
+
+fn parse<T: for<'de> Deserialize<'de>>(message: &ClientMessage) -> Result<T, RoomError> {
+    serde_json::from_value(message.data.clone()).map_err(|e| RoomError::BadPayload {
+        msg_type: message.msg_type.clone(),
+        reason: e.to_string(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hacking::Puzzle;
+    use crate::mission::MissionOrder;
+    use crate::security::door::{DoorState, UnlockRequirement};
+
+    fn hello(player_id: u32, role: &str) -> ClientMessage {
+        ClientMessage {
+            msg_type: "hello".to_string(),
+            player_id,
+            data: json!({
+                "version": PROTOCOL_VERSION,
+                "min_version": PROTOCOL_VERSION,
+                "encoding": "json",
+                "role": role,
+                "capabilities": CAPABILITIES,
+            }),
+        }
+    }
+
+    #[test]
+    fn test_handshake_seats_players_and_streams_state() {
+        let mut game = GameSystem::new(100.0, 100.0);
+        let placed = game.spawn_player(10.0, 10.0);
+        let mut room = Room::new(game, 20.0);
+
+        let replies = room.handle(hello(7, "Infiltrator")).unwrap();
+        assert_eq!(replies[0].msg_type, "welcome");
+        assert_eq!(replies[0].to, Some(7));
+        assert!(replies.iter().any(|m| m.msg_type == "player_joined" && m.to.is_none()));
+        assert_eq!(room.entity_of(7), Some(placed), "level-placed infiltrator is claimed");
+
+        room.handle(ClientMessage {
+            msg_type: "player_input".to_string(),
+            player_id: 7,
+            data: json!({ "movement": { "x": 1.0, "y": 0.0 }, "sequence": 3 }),
+        }).unwrap();
+
+        let messages = room.tick();
+        assert_eq!(messages[0].msg_type, "input_ack");
+        assert_eq!(messages[0].data["sequence"], 3);
+        assert_eq!(messages[1].msg_type, "state_delta");
+        assert!(room.system.world.get_entity(placed).unwrap().position.x > 10.0);
+
+        // Nothing new to acknowledge on the next tick
+        assert!(room.tick().iter().all(|m| m.msg_type != "input_ack"));
+    }
+
+    #[test]
+    fn test_each_sequenced_input_gets_one_tick() {
+        let mut game = GameSystem::new(200.0, 100.0);
+        let placed = game.spawn_player(10.0, 10.0);
+        game.add_obstacle(150.0, 50.0, 5.0);
+        let mut room = Room::new(game, 20.0);
+
+        let replies = room.handle(hello(7, "Infiltrator")).unwrap();
+        let level = replies.iter().find(|m| m.msg_type == "level").expect("a predicting client gets the level");
+        assert_eq!(level.to, Some(7));
+        let level: LevelGeometry = serde_json::from_value(level.data.clone()).unwrap();
+        assert_eq!(level.timestep, 0.05);
+        assert_eq!(level.obstacles.len(), 1);
+
+        // Two inputs arriving between ticks are both simulated, in order; a stale one is dropped
+        for (sequence, x) in [(1, 1.0), (2, -1.0), (1, 1.0)] {
+            room.handle(ClientMessage {
+                msg_type: "player_input".to_string(),
+                player_id: 7,
+                data: json!({ "movement": { "x": x, "y": 0.0 }, "sequence": sequence }),
+            }).unwrap();
+        }
+
+        let x = |room: &Room| room.system.world.get_entity(placed).unwrap().position.x;
+        let acked = |messages: &[ServerMessage]| messages.iter()
+            .find(|m| m.msg_type == "input_ack")
+            .map(|m| m.data["sequence"].clone());
+
+        let first = room.tick();
+        assert_eq!(acked(&first), Some(json!(1)));
+        let after_first = x(&room);
+        assert!(after_first > 10.0);
+
+        let second = room.tick();
+        assert_eq!(acked(&second), Some(json!(2)));
+        assert!(x(&room) < after_first);
+
+        // The last input is held once the queue runs dry
+        let third = room.tick();
+        assert_eq!(acked(&third), None);
+        assert!(x(&room) < after_first);
+    }
+
+    #[test]
+    fn test_hacker_markers_reach_both_players() {
+        let mut game = GameSystem::new(100.0, 100.0);
+        let guard = game.spawn_guard(50.0, 50.0);
+        let terminal = game.spawn_terminal(0.0, 0.0);
+        let puzzle = r#"{"name": "lock", "initialState": {"x": 5, "y": 3}, "goalState": {"x": 8, "y": 3}}"#;
+        game.set_device_puzzle(terminal, Puzzle::from_json(puzzle).unwrap());
+        let mut room = Room::new(game, 20.0);
+        room.handle(hello(1, "Infiltrator")).unwrap();
+        room.handle(hello(2, "Hacker")).unwrap();
+        let mark = |player_id, data| ClientMessage { msg_type: "mark".to_string(), player_id, data };
+        let hack = |data| ClientMessage { msg_type: "hack".to_string(), player_id: 2, data };
+
+        // The terminal only gives in once its puzzle is solved
+        assert!(matches!(
+            room.handle(hack(json!({ "command": "compromise", "device_id": terminal }))),
+            Err(RoomError::Refused { player_id: 2, .. })
+        ));
+        room.handle(hack(json!({ "command": "execute", "device_id": terminal, "instruction": "ADD x y" }))).unwrap();
+        assert!(room.system.world.network.node(terminal).unwrap().compromised);
+
+        assert!(matches!(
+            room.handle(mark(1, json!({ "marker": "ping", "position": { "x": 1.0, "y": 2.0 } }))),
+            Err(RoomError::Refused { player_id: 1, .. })
+        ));
+        room.handle(mark(2, json!({ "marker": "tag", "entity_id": guard }))).unwrap();
+
+        let messages = room.tick();
+        let sent: Vec<_> = messages.iter().filter(|m| m.msg_type == "markers").collect();
+        assert_eq!(sent.len(), 2);
+        assert!(messages.iter().any(|m| m.msg_type == "hacker_intel" && m.to == Some(2)));
+        assert!(messages.iter().all(|m| m.msg_type != "hacker_intel" || m.to != Some(1)));
+        assert_eq!(sent[0].data["markers"][0]["entity_id"], guard);
+        let trace: Vec<_> = messages.iter().filter(|m| m.msg_type == "trace").collect();
+        assert_eq!(trace.len(), 1);
+        assert_eq!((trace[0].to, &trace[0].data["stage"]), (Some(2), &json!("Clear")));
+
+        // Unchanged markers aren't sent again, nor is a trace that has barely moved
+        assert!(room.tick().iter().all(|m| m.msg_type != "markers" && m.msg_type != "trace"));
+
+        // Until the hacker reconnects and needs all of it again
+        room.handle(hello(2, "Hacker")).unwrap();
+        let resent: Vec<_> = room.tick().into_iter().filter(|m| m.to == Some(2)).map(|m| m.msg_type).collect();
+        for msg_type in ["markers", "hacker_intel", "trace"] {
+            assert!(resent.iter().any(|m| m == msg_type), "{} not resent", msg_type);
+        }
+    }
+
+    #[test]
+    fn test_interacting_reports_back_and_shares_the_door() {
+        let mut game = GameSystem::new(100.0, 100.0);
+        game.spawn_player(10.0, 10.0);
+        let door = game.spawn_door(11.0, 10.0, 1.0, DoorState::Closed, UnlockRequirement::None);
+        let mut room = Room::new(game, 20.0);
+        room.handle(hello(1, "Infiltrator")).unwrap();
+        room.handle(hello(2, "Hacker")).unwrap();
+
+        let replies = room.handle(ClientMessage {
+            msg_type: "player_action".to_string(),
+            player_id: 1,
+            data: json!({ "action": "interact" }),
+        }).unwrap();
+        assert_eq!(replies.len(), 1);
+        assert_eq!((replies[0].msg_type.as_str(), replies[0].to), ("interaction", Some(1)));
+        assert_eq!((&replies[0].data["door_id"], &replies[0].data["result"]), (&json!(door), &json!("Opened")));
+
+        let changes: Vec<_> = room.tick().into_iter().filter(|m| m.msg_type == "door_state").collect();
+        assert_eq!(changes.iter().map(|m| m.to).collect::<Vec<_>>(), vec![Some(1), Some(2)]);
+        assert_eq!(changes[0].data, json!({ "door_id": door, "state": "Open" }));
+        assert!(room.tick().iter().all(|m| m.msg_type != "door_state"));
+    }
+
+    #[test]
+    fn test_mission_progress_is_sent_when_it_changes() {
+        let mut game = GameSystem::new(100.0, 100.0);
+        game.spawn_player(10.0, 10.0);
+        game.start_mission(MissionOrder::Parallel, Some(60.0), true);
+        game.add_objective(ObjectiveKind::ReachZone { position: Position::new(12.0, 10.0), radius: 1.0 }, false);
+        let mut room = Room::new(game, 20.0);
+        room.handle(hello(1, "Infiltrator")).unwrap();
+
+        let mission = |messages: Vec<ServerMessage>| messages.into_iter().find(|m| m.msg_type == "mission_state");
+        let first = mission(room.tick()).expect("sent on the first tick");
+        assert_eq!(first.data["status"], "in_progress");
+        assert_eq!(first.data["objectives"][0]["complete"], false);
+        assert!(mission(room.tick()).is_none(), "nothing worth sending has changed");
+
+        room.system.world.get_entity_mut(room.entity_of(1).unwrap()).unwrap().position = Position::new(12.0, 10.0);
+        let done = mission(room.tick()).unwrap();
+        assert_eq!(done.data["objectives"][0]["complete"], true);
+        assert_eq!(done.data["status"], "succeeded");
+    }
+
+    #[test]
+    fn test_hacker_cannot_work_doors_by_hand() {
+        let mut game = GameSystem::new(100.0, 100.0);
+        let door = game.spawn_door(1.0, 0.0, 1.0, DoorState::Closed, UnlockRequirement::None);
+        let mut room = Room::new(game, 20.0);
+        room.handle(hello(2, "Hacker")).unwrap();
+
+        let result = room.handle(ClientMessage {
+            msg_type: "player_action".to_string(),
+            player_id: 2,
+            data: json!({ "action": "interact" }),
+        });
+        assert!(matches!(result, Err(RoomError::Refused { player_id: 2, .. })));
+        assert_eq!(room.system.world.get_door(door).unwrap().state, DoorState::Closed);
+
+        // Nor through the engine directly
+        let hacker = room.entity_of(2).unwrap();
+        assert!(room.system.interact(hacker).is_none());
+    }
+
+    #[test]
+    fn test_messages_before_hello_are_refused() {
+        let mut room = Room::new(GameSystem::new(100.0, 100.0), 20.0);
+        let result = room.handle(ClientMessage {
+            msg_type: "state_ack".to_string(),
+            player_id: 1,
+            data: json!({ "tick": 1 }),
+        });
+        assert_eq!(result.unwrap_err(), RoomError::NotSeated(1));
+
+        room.handle(hello(2, "Hacker")).unwrap();
+        let hacker = room.entity_of(2).unwrap();
+        assert_eq!(room.system.world.get_entity(hacker).unwrap().entity_type, EntityType::Hacker);
+        assert_eq!(room.handle(ClientMessage {
+            msg_type: "leave".to_string(),
+            player_id: 2,
+            data: Value::Null,
+        }).unwrap()[0].msg_type, "player_left");
+        assert!(room.system.world.get_entity(hacker).is_none());
+    }
+
+    #[test]
+    fn test_leaving_keeps_level_placed_entities() {
+        let mut game = GameSystem::new(100.0, 100.0);
+        let placed = game.spawn_player(10.0, 10.0);
+        let mut room = Room::new(game, 20.0);
+        let leave = |player_id| ClientMessage { msg_type: "leave".to_string(), player_id, data: Value::Null };
+
+        room.handle(hello(1, "Infiltrator")).unwrap();
+        room.handle(leave(1)).unwrap();
+        assert!(room.system.world.get_entity(placed).is_some());
+
+        // The next infiltrator takes it over
+        room.handle(hello(3, "Infiltrator")).unwrap();
+        assert_eq!(room.entity_of(3), Some(placed));
+    }
+
+    #[test]
+    fn test_oversized_chat_is_refused() {
+        let mut room = Room::new(GameSystem::new(100.0, 100.0), 20.0);
+        room.handle(hello(1, "Infiltrator")).unwrap();
+        room.handle(hello(2, "Hacker")).unwrap();
+        let chat = |text: String| ClientMessage { msg_type: "chat".to_string(), player_id: 1, data: json!({ "text": text }) };
+
+        assert_eq!(room.handle(chat("x".repeat(MAX_CHAT_LENGTH))).unwrap().len(), 2);
+        let result = room.handle(chat("x".repeat(MAX_CHAT_LENGTH + 1)));
+        assert!(matches!(result, Err(RoomError::Refused { player_id: 1, .. })));
+    }
+
+    #[test]
+    fn test_hello_must_use_the_ports_encoding() {
+        let mut room = Room::with_protocol(GameSystem::new(100.0, 100.0), 20.0, Protocol::FlatBuffers);
+
+        // A JSON client on a FlatBuffers Port is told what the server speaks, and not seated
+        let replies = room.handle(hello(1, "Infiltrator")).unwrap();
+        assert_eq!(replies.len(), 1);
+        assert_eq!(replies[0].data["encoding"], "flatbuffers");
+        assert_eq!(room.entity_of(1), None);
+
+        let mut matching = hello(1, "Infiltrator");
+        matching.data["encoding"] = json!("flatbuffers");
+        room.handle(matching).unwrap();
+        assert!(room.entity_of(1).is_some());
+    }
+}
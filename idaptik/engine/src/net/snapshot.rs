@@ -0,0 +1,300 @@
+use crate::game::types::*;
+use glam::Vec2;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Positions and velocities are sent in hundredths of a world unit
+pub const POSITION_SCALE: f32 = 100.0;
+
+/// Rotations are sent as a u16 fraction of a full turn
+const ROTATION_STEPS: f32 = 65536.0;
+
+const TWO_PI: f32 = std::f32::consts::PI * 2.0;
+
+pub fn quantize_position(value: f32) -> i32 {
+    (value * POSITION_SCALE).round() as i32
+}
+
+pub fn dequantize_position(value: i32) -> f32 {
+    value as f32 / POSITION_SCALE
+}
+
+pub fn quantize_rotation(radians: f32) -> u16 {
+    let turn = radians.rem_euclid(TWO_PI) / TWO_PI;
+    ((turn * ROTATION_STEPS).round() as u32 % ROTATION_STEPS as u32) as u16
+}
+
+pub fn dequantize_rotation(value: u16) -> f32 {
+    let radians = value as f32 / ROTATION_STEPS * TWO_PI;
+    // Back into (-PI, PI] to match the simulation's convention
+    if radians > std::f32::consts::PI { radians - TWO_PI } else { radians }
+}
+
+/// Quantize a 0.0..=1.0 level into a byte
+pub fn quantize_unit(value: f32) -> u8 {
+    (value.clamp(0.0, 1.0) * 255.0).round() as u8
+}
+
+pub fn dequantize_unit(value: u8) -> f32 {
+    value as f32 / 255.0
+}
+
+/// The network-visible part of an entity, quantized
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EntitySnapshot {
+    pub id: EntityId,
+    pub kind: EntityType,
+    pub x: i32,
+    pub y: i32,
+    pub rot: u16,
+    pub vx: i32,
+    pub vy: i32,
+    pub stance: Stance,
+    pub state: ActorState,
+    pub det: u8, // Detection level
+    pub hp: u8,  // Health, whole points
+    pub vis: bool,
+    pub vh: bool, // Shown to the hacker
+    pub vi: bool, // Shown to the infiltrator
+}
+
+impl EntitySnapshot {
+    pub fn capture(entity: &Entity) -> Self {
+        Self {
+            id: entity.id,
+            kind: entity.entity_type,
+            x: quantize_position(entity.position.x),
+            y: quantize_position(entity.position.y),
+            rot: quantize_rotation(entity.rotation),
+            vx: quantize_position(entity.velocity.x),
+            vy: quantize_position(entity.velocity.y),
+            stance: entity.stance,
+            state: entity.state,
+            det: quantize_unit(entity.detection_level),
+            hp: entity.health.clamp(0.0, 255.0).round() as u8,
+            vis: entity.visible,
+            vh: entity.visible_to_hacker,
+            vi: entity.visible_to_infiltrator,
+        }
+    }
+
+    /// Write the snapshot's fields back onto an entity
+    pub fn apply_to(&self, entity: &mut Entity) {
+        entity.entity_type = self.kind;
+        entity.position.x = dequantize_position(self.x);
+        entity.position.y = dequantize_position(self.y);
+        entity.rotation = dequantize_rotation(self.rot);
+        entity.velocity = Vec2::new(dequantize_position(self.vx), dequantize_position(self.vy));
+        entity.stance = self.stance;
+        entity.state = self.state;
+        entity.detection_level = dequantize_unit(self.det);
+        entity.health = self.hp as f32;
+        entity.visible = self.vis;
+        entity.visible_to_hacker = self.vh;
+        entity.visible_to_infiltrator = self.vi;
+    }
+
+    /// Build a fresh entity of the right kind for a snapshot the world doesn't have yet
+    pub fn to_entity(&self) -> Entity {
+        let position = Position::new(dequantize_position(self.x), dequantize_position(self.y));
+        let mut entity = match self.kind {
+            EntityType::Player => Entity::new_player(self.id, position),
+            EntityType::Guard => Entity::new_guard(self.id, position),
+            EntityType::Drone => Entity::new_drone(self.id, position),
+            EntityType::Camera => Entity::new_camera(self.id, position, 0.0),
+            kind => Entity::new_static(self.id, kind, position),
+        };
+        self.apply_to(&mut entity);
+        entity
+    }
+}
+
+/// Only the fields of an entity that changed since the baseline
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EntityDelta {
+    pub id: EntityId,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub kind: Option<EntityType>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub x: Option<i32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub y: Option<i32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rot: Option<u16>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub vx: Option<i32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub vy: Option<i32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stance: Option<Stance>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub state: Option<ActorState>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub det: Option<u8>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hp: Option<u8>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub vis: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub vh: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub vi: Option<bool>,
+}
+
+impl EntityDelta {
+    /// Fields that differ from `baseline` (every field if there is none)
+    pub fn between(baseline: Option<&EntitySnapshot>, current: &EntitySnapshot) -> Self {
+        fn changed<T: PartialEq + Copy>(old: Option<T>, new: T) -> Option<T> {
+            (old != Some(new)).then_some(new)
+        }
+
+        Self {
+            id: current.id,
+            kind: changed(baseline.map(|b| b.kind), current.kind),
+            x: changed(baseline.map(|b| b.x), current.x),
+            y: changed(baseline.map(|b| b.y), current.y),
+            rot: changed(baseline.map(|b| b.rot), current.rot),
+            vx: changed(baseline.map(|b| b.vx), current.vx),
+            vy: changed(baseline.map(|b| b.vy), current.vy),
+            stance: changed(baseline.map(|b| b.stance), current.stance),
+            state: changed(baseline.map(|b| b.state), current.state),
+            det: changed(baseline.map(|b| b.det), current.det),
+            hp: changed(baseline.map(|b| b.hp), current.hp),
+            vis: changed(baseline.map(|b| b.vis), current.vis),
+            vh: changed(baseline.map(|b| b.vh), current.vh),
+            vi: changed(baseline.map(|b| b.vi), current.vi),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        *self == Self { id: self.id, ..Self::default() }
+    }
+
+    /// Patch a baseline entity; a new entity needs every field present
+    pub fn apply(&self, baseline: Option<&EntitySnapshot>) -> Option<EntitySnapshot> {
+        Some(EntitySnapshot {
+            id: self.id,
+            kind: self.kind.or(baseline.map(|b| b.kind))?,
+            x: self.x.or(baseline.map(|b| b.x))?,
+            y: self.y.or(baseline.map(|b| b.y))?,
+            rot: self.rot.or(baseline.map(|b| b.rot))?,
+            vx: self.vx.or(baseline.map(|b| b.vx))?,
+            vy: self.vy.or(baseline.map(|b| b.vy))?,
+            stance: self.stance.or(baseline.map(|b| b.stance))?,
+            state: self.state.or(baseline.map(|b| b.state))?,
+            det: self.det.or(baseline.map(|b| b.det))?,
+            hp: self.hp.or(baseline.map(|b| b.hp))?,
+            vis: self.vis.or(baseline.map(|b| b.vis))?,
+            vh: self.vh.or(baseline.map(|b| b.vh))?,
+            vi: self.vi.or(baseline.map(|b| b.vi))?,
+        })
+    }
+}
+
+/// Quantized entity state at one tick, sorted by entity id
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WorldSnapshot {
+    pub tick: u64,
+    pub entities: Vec<EntitySnapshot>,
+}
+
+/// Entity changes between an acknowledged baseline and the current tick
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SnapshotDelta {
+    pub tick: u64,
+    pub baseline: Option<u64>, // None: full snapshot
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub changed: Vec<EntityDelta>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub removed: Vec<EntityId>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DeltaError {
+    MissingBaseline(u64),        // The receiver no longer has the baseline tick
+    IncompleteEntity(EntityId),  // A new entity arrived without all its fields
+    Stale(u64),                  // Older than a snapshot already applied
+}
+
+impl fmt::Display for DeltaError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DeltaError::MissingBaseline(tick) => write!(f, "baseline tick {} not available", tick),
+            DeltaError::IncompleteEntity(id) => write!(f, "entity {} is new but the delta is partial", id),
+            DeltaError::Stale(tick) => write!(f, "snapshot for tick {} arrived out of order", tick),
+        }
+    }
+}
+
+impl std::error::Error for DeltaError {}
+
+impl WorldSnapshot {
+    pub fn capture(world: &World, tick: u64) -> Self {
+        let mut entities: Vec<EntitySnapshot> = world.entities.iter().map(EntitySnapshot::capture).collect();
+        entities.sort_by_key(|e| e.id);
+        Self { tick, entities }
+    }
+
+    pub fn get(&self, id: EntityId) -> Option<&EntitySnapshot> {
+        self.entities.binary_search_by_key(&id, |e| e.id).ok().map(|i| &self.entities[i])
+    }
+
+    /// Everything that changed since `baseline`; a full snapshot if there is none
+    pub fn diff(&self, baseline: Option<&WorldSnapshot>) -> SnapshotDelta {
+        let changed = self.entities.iter()
+            .map(|current| EntityDelta::between(baseline.and_then(|b| b.get(current.id)), current))
+            .filter(|delta| !delta.is_empty())
+            .collect();
+
+        let removed = baseline
+            .map(|b| b.entities.iter().filter(|e| self.get(e.id).is_none()).map(|e| e.id).collect())
+            .unwrap_or_default();
+
+        SnapshotDelta {
+            tick: self.tick,
+            baseline: baseline.map(|b| b.tick),
+            changed,
+            removed,
+        }
+    }
+
+    /// Rebuild the snapshot a delta describes, starting from `baseline`
+    pub fn from_delta(baseline: Option<&WorldSnapshot>, delta: &SnapshotDelta) -> Result<Self, DeltaError> {
+        let mut entities: Vec<EntitySnapshot> = baseline
+            .map(|b| b.entities.iter().filter(|e| !delta.removed.contains(&e.id)).copied().collect())
+            .unwrap_or_default();
+
+        for change in &delta.changed {
+            let existing = entities.binary_search_by_key(&change.id, |e| e.id);
+            let base = existing.ok().map(|i| &entities[i]);
+            let patched = change.apply(base).ok_or(DeltaError::IncompleteEntity(change.id))?;
+            match existing {
+                Ok(i) => entities[i] = patched,
+                Err(i) => entities.insert(i, patched),
+            }
+        }
+
+        Ok(Self { tick: delta.tick, entities })
+    }
+
+    /// Bring a world's entities in line with this snapshot
+    /// Fields the snapshot doesn't carry (stealth internals, inventory) are left alone
+    pub fn apply_to_world(&self, world: &mut World) {
+        let stale: Vec<EntityId> = world.entities.iter()
+            .filter(|e| self.get(e.id).is_none())
+            .map(|e| e.id)
+            .collect();
+        for id in stale {
+            world.remove_entity(id);
+        }
+
+        for snapshot in &self.entities {
+            match world.get_entity_mut(snapshot.id) {
+                Some(entity) => snapshot.apply_to(entity),
+                None => {
+                    world.add_entity(snapshot.to_entity());
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,1347 @@
+use crate::game::types::*;
+use crate::hacking::markers::{Marker, MarkerKind};
+use crate::hacking::trace::TraceStage;
+use crate::net::intel::{Sensor, Sighting};
+use crate::net::level::{DoorGeometry, LevelGeometry};
+use crate::net::port_protocol_generated::idaptik::port as fb;
+use crate::net::room::{ClientMessage, ServerMessage};
+use crate::net::snapshot::{EntityDelta, SnapshotDelta};
+use crate::security::door::{DoorState, InteractionResult};
+use crate::security::network::{DeviceKind, HackCommand};
+use flatbuffers::{FlatBufferBuilder, Follow, ForwardsUOffset, UnionWIPOffset, Vector, WIPOffset};
+use glam::Vec2;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::fmt;
+use std::io::{self, BufRead, Write};
+
+/// Largest FlatBuffers frame accepted from the other side
+pub const MAX_FRAME_SIZE: usize = 16 * 1024 * 1024;
+
+/// Encoding used on the Port's stdin/stdout
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Protocol {
+    Json,        // One JSON message per line (readable, for debugging)
+    FlatBuffers, // 4-byte big-endian length prefix + buffer (Erlang {packet, 4})
+}
+
+impl Protocol {
+    pub const ALL: [Protocol; 2] = [Protocol::FlatBuffers, Protocol::Json];
+
+    /// Name used on the command line and in the handshake
+    pub fn name(self) -> &'static str {
+        match self {
+            Protocol::Json => "json",
+            Protocol::FlatBuffers => "flatbuffers",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|p| p.name() == name)
+    }
+}
+
+/// Read one raw frame; None on EOF
+pub fn read_frame(reader: &mut impl BufRead, protocol: Protocol) -> io::Result<Option<Vec<u8>>> {
+    match protocol {
+        Protocol::Json => {
+            let mut line = Vec::new();
+            if reader.read_until(b'\n', &mut line)? == 0 {
+                return Ok(None);
+            }
+            Ok(Some(line))
+        }
+        Protocol::FlatBuffers => {
+            let mut header = [0u8; 4];
+            match reader.read_exact(&mut header) {
+                Ok(()) => {}
+                Err(e) if e.kind() == io::ErrorKind::UnexpectedEof => return Ok(None),
+                Err(e) => return Err(e),
+            }
+
+            let len = u32::from_be_bytes(header) as usize;
+            if len > MAX_FRAME_SIZE {
+                return Err(io::Error::new(io::ErrorKind::InvalidData, format!("frame of {} bytes is too large", len)));
+            }
+            let mut payload = vec![0u8; len];
+            reader.read_exact(&mut payload)?;
+            Ok(Some(payload))
+        }
+    }
+}
+
+/// Write one frame and flush it
+pub fn write_frame(writer: &mut impl Write, protocol: Protocol, payload: &[u8]) -> io::Result<()> {
+    match protocol {
+        Protocol::Json => {
+            writer.write_all(payload)?;
+            writer.write_all(b"\n")?;
+        }
+        Protocol::FlatBuffers => {
+            let len = u32::try_from(payload.len())
+                .map_err(|_| io::Error::new(io::ErrorKind::InvalidInput, "frame too large"))?;
+            writer.write_all(&len.to_be_bytes())?;
+            writer.write_all(payload)?;
+        }
+    }
+    writer.flush()
+}
+
+/// Why a frame couldn't be encoded or decoded
+#[derive(Debug, Clone, PartialEq)]
+pub enum WireError {
+    Json(String),                                     // Not a JSON message
+    FlatBuffers(String),                              // Buffer failed verification or lacks a field
+    UnknownMessage(String),                           // msg_type the schema has no table for
+    BadPayload { msg_type: String, reason: String },  // data doesn't fit the message's table
+}
+
+impl fmt::Display for WireError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WireError::Json(reason) => write!(f, "bad JSON message: {}", reason),
+            WireError::FlatBuffers(reason) => write!(f, "bad FlatBuffers message: {}", reason),
+            WireError::UnknownMessage(msg_type) => write!(f, "no table for message type {:?}", msg_type),
+            WireError::BadPayload { msg_type, reason } => write!(f, "bad {} payload: {}", msg_type, reason),
+        }
+    }
+}
+
+impl std::error::Error for WireError {}
+
+pub fn encode_server_message(message: &ServerMessage, protocol: Protocol) -> Result<Vec<u8>, WireError> {
+    match protocol {
+        Protocol::Json => serde_json::to_vec(message).map_err(|e| WireError::Json(e.to_string())),
+        Protocol::FlatBuffers => {
+            let mut fbb = FlatBufferBuilder::new();
+            let (payload_type, payload) = build_server_payload(&mut fbb, &message.msg_type, &message.data)?;
+            let root = fb::ServerMessage::create(&mut fbb, &fb::ServerMessageArgs {
+                to: message.to,
+                payload_type,
+                payload: Some(payload),
+            });
+            fb::finish_server_message_buffer(&mut fbb, root);
+            Ok(fbb.finished_data().to_vec())
+        }
+    }
+}
+
+pub fn decode_server_message(frame: &[u8], protocol: Protocol) -> Result<ServerMessage, WireError> {
+    match protocol {
+        Protocol::Json => serde_json::from_slice(frame.trim_ascii()).map_err(|e| WireError::Json(e.to_string())),
+        Protocol::FlatBuffers => {
+            let message = fb::root_as_server_message(frame).map_err(|e| WireError::FlatBuffers(e.to_string()))?;
+            let (msg_type, data) = read_server_payload(&message)?;
+            Ok(ServerMessage { msg_type: msg_type.to_string(), data, to: message.to() })
+        }
+    }
+}
+
+pub fn encode_client_message(message: &ClientMessage, protocol: Protocol) -> Result<Vec<u8>, WireError> {
+    match protocol {
+        Protocol::Json => serde_json::to_vec(message).map_err(|e| WireError::Json(e.to_string())),
+        Protocol::FlatBuffers => {
+            let mut fbb = FlatBufferBuilder::new();
+            let (payload_type, payload) = build_client_payload(&mut fbb, &message.msg_type, &message.data)?;
+            let root = fb::ClientMessage::create(&mut fbb, &fb::ClientMessageArgs {
+                player_id: message.player_id,
+                payload_type,
+                payload: Some(payload),
+            });
+            fbb.finish(root, None);
+            Ok(fbb.finished_data().to_vec())
+        }
+    }
+}
+
+pub fn decode_client_message(frame: &[u8], protocol: Protocol) -> Result<ClientMessage, WireError> {
+    match protocol {
+        Protocol::Json => serde_json::from_slice(frame.trim_ascii()).map_err(|e| WireError::Json(e.to_string())),
+        Protocol::FlatBuffers => {
+            let message = flatbuffers::root::<fb::ClientMessage>(frame)
+                .map_err(|e| WireError::FlatBuffers(e.to_string()))?;
+            let (msg_type, data) = read_client_payload(&message)?;
+            Ok(ClientMessage { msg_type: msg_type.to_string(), player_id: message.player_id(), data })
+        }
+    }
+}
+
+// --- JSON shapes of the payloads the engine has no type for ---
+
+#[derive(Serialize, Deserialize)]
+struct WelcomeData {
+    version: u32,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    encoding: Option<String>,
+    #[serde(default)]
+    capabilities: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct PlayerJoinedData {
+    player_id: u32,
+    role: PlayerRole,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct PlayerLeftData {
+    player_id: u32,
+}
+
+#[derive(Serialize, Deserialize)]
+struct EntityRemovedData {
+    entity_id: EntityId,
+}
+
+#[derive(Serialize, Deserialize)]
+struct EntityUpdatedData {
+    id: EntityId,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    position: Option<Position>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    velocity: Option<Vec2>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    rotation: Option<f32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    stance: Option<Stance>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    state: Option<ActorState>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    detection_level: Option<f32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    visible_to_hacker: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    visible_to_infiltrator: Option<bool>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct RoleChangedData {
+    player_id: u32,
+    role: PlayerRole,
+}
+
+#[derive(Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum MissionStatusData {
+    InProgress,
+    Succeeded,
+    Failed,
+}
+
+#[derive(Serialize, Deserialize)]
+struct ObjectiveData {
+    objective_id: u32,
+    description: String,
+    complete: bool,
+    #[serde(default)]
+    optional: bool,
+}
+
+#[derive(Serialize, Deserialize)]
+struct MissionStateData {
+    status: MissionStatusData,
+    #[serde(default)]
+    objectives: Vec<ObjectiveData>,
+    #[serde(default)]
+    time_remaining: Option<f32>,
+    #[serde(default)]
+    failure_reason: Option<String>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct ChatData {
+    player_id: u32,
+    text: String,
+}
+
+#[derive(Serialize, Deserialize)]
+struct NonceData {
+    nonce: u64,
+}
+
+#[derive(Serialize, Deserialize)]
+struct InputAckData {
+    sequence: u32,
+}
+
+#[derive(Serialize, Deserialize)]
+struct MarkersData {
+    markers: Vec<Marker>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct IntelData {
+    sensors: Vec<Sensor>,
+    ghosts: Vec<Sighting>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct TraceData {
+    level: f32,
+    stage: TraceStage,
+    #[serde(default)]
+    ice: Option<EntityId>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct InteractionData {
+    #[serde(default)]
+    door_id: Option<EntityId>,
+    result: InteractionResult,
+}
+
+#[derive(Serialize, Deserialize)]
+struct DoorStateData {
+    door_id: EntityId,
+    state: DoorState,
+}
+
+#[derive(Serialize, Deserialize)]
+struct HelloData {
+    version: u32,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    min_version: Option<u32>,
+    encoding: String,
+    #[serde(default)]
+    encodings: Vec<String>,
+    role: PlayerRole,
+    #[serde(default)]
+    player_id: u32,
+    #[serde(default)]
+    capabilities: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct MovementData {
+    x: f32,
+    y: f32,
+}
+
+#[derive(Serialize, Deserialize)]
+struct InputData {
+    movement: MovementData,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    sequence: Option<u32>,
+    #[serde(default)]
+    sprint: bool,
+    #[serde(default)]
+    crouch: bool,
+    #[serde(default)]
+    prone: bool,
+}
+
+#[derive(Serialize, Deserialize)]
+struct ActionData {
+    action: String,
+}
+
+#[derive(Serialize, Deserialize)]
+struct UnmarkData {
+    marker_id: u32,
+}
+
+#[derive(Serialize, Deserialize)]
+struct StateAckData {
+    tick: u64,
+}
+
+#[derive(Serialize, Deserialize)]
+struct SendChatData {
+    text: String,
+}
+
+#[derive(Serialize, Deserialize)]
+struct ErrorData {
+    #[serde(default)]
+    msg_type: Option<String>,
+    reason: String,
+}
+
+fn parse<T: for<'de> Deserialize<'de>>(msg_type: &str, data: &Value) -> Result<T, WireError> {
+    T::deserialize(data).map_err(|e| WireError::BadPayload { msg_type: msg_type.to_string(), reason: e.to_string() })
+}
+
+fn to_data<T: Serialize>(data: T) -> Result<Value, WireError> {
+    serde_json::to_value(data).map_err(|e| WireError::Json(e.to_string()))
+}
+
+fn required<T>(field: Option<T>, name: &str) -> Result<T, WireError> {
+    field.ok_or_else(|| WireError::FlatBuffers(format!("missing {}", name)))
+}
+
+// Engine enums travel as their declaration index (schemas/port_protocol.fbs keeps the order)
+const ENTITY_TYPES: [EntityType; 12] = [
+    EntityType::Player, EntityType::Guard, EntityType::Camera, EntityType::Door,
+    EntityType::LightSource, EntityType::SoundEmitter, EntityType::CoverObject, EntityType::Item,
+    EntityType::Terminal, EntityType::Hacker, EntityType::Drone, EntityType::Wall,
+];
+const STANCES: [Stance; 3] = [Stance::Standing, Stance::Crouching, Stance::Prone];
+const ACTOR_STATES: [ActorState; 7] = [
+    ActorState::Idle, ActorState::Walking, ActorState::Running, ActorState::Hiding,
+    ActorState::Investigating, ActorState::Alerted, ActorState::Hunting,
+];
+const ROLES: [PlayerRole; 2] = [PlayerRole::Hacker, PlayerRole::Infiltrator];
+const DEVICE_KINDS: [DeviceKind; 4] = [DeviceKind::Camera, DeviceKind::Door, DeviceKind::Light, DeviceKind::Terminal];
+const TRACE_STAGES: [TraceStage; 4] = [TraceStage::Clear, TraceStage::Relock, TraceStage::Hunted, TraceStage::Traced];
+const DOOR_STATES: [DoorState; 4] = [DoorState::Open, DoorState::Closed, DoorState::Locked, DoorState::Hacked];
+const MISSION_STATUSES: [MissionStatusData; 3] =
+    [MissionStatusData::InProgress, MissionStatusData::Succeeded, MissionStatusData::Failed];
+
+fn from_wire<T: Copy>(values: &[T], value: u8, what: &str) -> Result<T, WireError> {
+    values.get(value as usize).copied().ok_or_else(|| WireError::FlatBuffers(format!("unknown {} {}", what, value)))
+}
+
+fn fb_position(position: &Position) -> fb::Position {
+    fb::Position::new(position.x, position.y, position.height)
+}
+
+fn position(position: &fb::Position) -> Position {
+    Position::with_height(position.x(), position.y(), position.height())
+}
+
+fn create_strings<'a>(
+    fbb: &mut FlatBufferBuilder<'a>,
+    strings: &[String],
+) -> WIPOffset<Vector<'a, ForwardsUOffset<&'a str>>> {
+    let offsets: Vec<_> = strings.iter().map(|s| fbb.create_string(s)).collect();
+    fbb.create_vector(&offsets)
+}
+
+fn read_strings(strings: Option<Vector<'_, ForwardsUOffset<&str>>>) -> Vec<String> {
+    strings.map(|v| v.iter().map(str::to_string).collect()).unwrap_or_default()
+}
+
+fn read_all<'a, T: Follow<'a> + 'a, U>(
+    vector: Option<Vector<'a, T>>,
+    read: impl Fn(T::Inner) -> Result<U, WireError>,
+) -> Result<Vec<U>, WireError> {
+    vector.map_or(Ok(Vec::new()), |v| v.iter().map(read).collect())
+}
+
+fn build_entity<'a>(fbb: &mut FlatBufferBuilder<'a>, entity: &Entity) -> WIPOffset<fb::GameEntity<'a>> {
+    let keycards = fbb.create_vector(&entity.keycards);
+    fb::GameEntity::create(fbb, &fb::GameEntityArgs {
+        id: entity.id,
+        entity_type: fb::EntityType(entity.entity_type as u8),
+        x: entity.position.x,
+        y: entity.position.y,
+        height: entity.position.height,
+        rotation: entity.rotation,
+        vx: entity.velocity.x,
+        vy: entity.velocity.y,
+        stance: fb::Stance(entity.stance as u8),
+        state: fb::ActorState(entity.state as u8),
+        visible: entity.visible,
+        visibility_radius: entity.visibility_radius,
+        field_of_view: entity.field_of_view,
+        noise_level: entity.noise_level,
+        detection_level: entity.detection_level,
+        light_exposure: entity.light_exposure,
+        speed: entity.speed,
+        health: entity.health,
+        keycards: Some(keycards),
+        visible_to_hacker: entity.visible_to_hacker,
+        visible_to_infiltrator: entity.visible_to_infiltrator,
+    })
+}
+
+fn read_entity(table: fb::GameEntity) -> Result<Entity, WireError> {
+    Ok(Entity {
+        id: table.id(),
+        entity_type: from_wire(&ENTITY_TYPES, table.entity_type().0, "entity type")?,
+        position: Position::with_height(table.x(), table.y(), table.height()),
+        rotation: table.rotation(),
+        velocity: Vec2::new(table.vx(), table.vy()),
+        stance: from_wire(&STANCES, table.stance().0, "stance")?,
+        state: from_wire(&ACTOR_STATES, table.state().0, "actor state")?,
+        visible: table.visible(),
+        visibility_radius: table.visibility_radius(),
+        field_of_view: table.field_of_view(),
+        noise_level: table.noise_level(),
+        detection_level: table.detection_level(),
+        light_exposure: table.light_exposure(),
+        speed: table.speed(),
+        health: table.health(),
+        keycards: table.keycards().map(|k| k.iter().collect()).unwrap_or_default(),
+        visible_to_hacker: table.visible_to_hacker(),
+        visible_to_infiltrator: table.visible_to_infiltrator(),
+    })
+}
+
+fn build_entity_delta<'a>(fbb: &mut FlatBufferBuilder<'a>, delta: &EntityDelta) -> WIPOffset<fb::EntityDelta<'a>> {
+    fb::EntityDelta::create(fbb, &fb::EntityDeltaArgs {
+        id: delta.id,
+        kind: delta.kind.map(|k| fb::EntityType(k as u8)),
+        x: delta.x,
+        y: delta.y,
+        rot: delta.rot,
+        vx: delta.vx,
+        vy: delta.vy,
+        stance: delta.stance.map(|s| fb::Stance(s as u8)),
+        state: delta.state.map(|s| fb::ActorState(s as u8)),
+        det: delta.det,
+        hp: delta.hp,
+        vis: delta.vis,
+        vh: delta.vh,
+        vi: delta.vi,
+    })
+}
+
+fn read_entity_delta(table: fb::EntityDelta) -> Result<EntityDelta, WireError> {
+    Ok(EntityDelta {
+        id: table.id(),
+        kind: table.kind().map(|k| from_wire(&ENTITY_TYPES, k.0, "entity type")).transpose()?,
+        x: table.x(),
+        y: table.y(),
+        rot: table.rot(),
+        vx: table.vx(),
+        vy: table.vy(),
+        stance: table.stance().map(|s| from_wire(&STANCES, s.0, "stance")).transpose()?,
+        state: table.state().map(|s| from_wire(&ACTOR_STATES, s.0, "actor state")).transpose()?,
+        det: table.det(),
+        hp: table.hp(),
+        vis: table.vis(),
+        vh: table.vh(),
+        vi: table.vi(),
+    })
+}
+
+fn build_marker_kind(fbb: &mut FlatBufferBuilder<'_>, kind: &MarkerKind) -> (fb::MarkerKind, WIPOffset<UnionWIPOffset>) {
+    match kind {
+        MarkerKind::Ping { position } => {
+            let position = fb_position(position);
+            let table = fb::PingMarker::create(fbb, &fb::PingMarkerArgs { position: Some(&position) });
+            (fb::MarkerKind::PingMarker, table.as_union_value())
+        }
+        MarkerKind::Tag { entity_id } => {
+            let table = fb::TagMarker::create(fbb, &fb::TagMarkerArgs { entity_id: *entity_id });
+            (fb::MarkerKind::TagMarker, table.as_union_value())
+        }
+        MarkerKind::Route { points } => {
+            let points: Vec<fb::Position> = points.iter().map(fb_position).collect();
+            let points = fbb.create_vector(&points);
+            let table = fb::RouteMarker::create(fbb, &fb::RouteMarkerArgs { points: Some(points) });
+            (fb::MarkerKind::RouteMarker, table.as_union_value())
+        }
+    }
+}
+
+fn read_marker_kind(
+    ping: Option<fb::PingMarker>,
+    tag: Option<fb::TagMarker>,
+    route: Option<fb::RouteMarker>,
+) -> Result<MarkerKind, WireError> {
+    if let Some(ping) = ping {
+        return Ok(MarkerKind::Ping { position: position(required(ping.position(), "ping position")?) });
+    }
+    if let Some(tag) = tag {
+        return Ok(MarkerKind::Tag { entity_id: tag.entity_id() });
+    }
+    let route = required(route, "marker")?;
+    let points = route.points().map(|p| p.iter().map(position).collect()).unwrap_or_default();
+    Ok(MarkerKind::Route { points })
+}
+
+fn build_server_payload(
+    fbb: &mut FlatBufferBuilder<'_>,
+    msg_type: &str,
+    data: &Value,
+) -> Result<(fb::ServerPayload, WIPOffset<UnionWIPOffset>), WireError> {
+    Ok(match msg_type {
+        "welcome" => {
+            let welcome: WelcomeData = parse(msg_type, data)?;
+            let encoding = welcome.encoding.as_deref().map(|e| fbb.create_string(e));
+            let capabilities = create_strings(fbb, &welcome.capabilities);
+            let table = fb::Welcome::create(fbb, &fb::WelcomeArgs {
+                version: welcome.version,
+                encoding,
+                capabilities: Some(capabilities),
+            });
+            (fb::ServerPayload::Welcome, table.as_union_value())
+        }
+        "state_update" => {
+            let entities: Vec<Entity> = parse(msg_type, data)?;
+            let entities: Vec<_> = entities.iter().map(|e| build_entity(fbb, e)).collect();
+            let entities = fbb.create_vector(&entities);
+            let table = fb::StateUpdate::create(fbb, &fb::StateUpdateArgs { entities: Some(entities) });
+            (fb::ServerPayload::StateUpdate, table.as_union_value())
+        }
+        "state_delta" => {
+            let delta: SnapshotDelta = parse(msg_type, data)?;
+            let changed: Vec<_> = delta.changed.iter().map(|d| build_entity_delta(fbb, d)).collect();
+            let changed = fbb.create_vector(&changed);
+            let removed = fbb.create_vector(&delta.removed);
+            let table = fb::SnapshotDelta::create(fbb, &fb::SnapshotDeltaArgs {
+                tick: delta.tick,
+                baseline: delta.baseline,
+                changed: Some(changed),
+                removed: Some(removed),
+            });
+            (fb::ServerPayload::SnapshotDelta, table.as_union_value())
+        }
+        "player_joined" => {
+            let joined: PlayerJoinedData = parse(msg_type, data)?;
+            let name = joined.name.as_deref().map(|n| fbb.create_string(n));
+            let table = fb::PlayerJoined::create(fbb, &fb::PlayerJoinedArgs {
+                player_id: joined.player_id,
+                role: fb::PlayerRole(joined.role as u8),
+                name,
+            });
+            (fb::ServerPayload::PlayerJoined, table.as_union_value())
+        }
+        "player_left" => {
+            let left: PlayerLeftData = parse(msg_type, data)?;
+            let table = fb::PlayerLeft::create(fbb, &fb::PlayerLeftArgs { player_id: left.player_id });
+            (fb::ServerPayload::PlayerLeft, table.as_union_value())
+        }
+        "entity_spawned" => {
+            let entity: Entity = parse(msg_type, data)?;
+            let entity = build_entity(fbb, &entity);
+            let table = fb::EntitySpawned::create(fbb, &fb::EntitySpawnedArgs { entity: Some(entity) });
+            (fb::ServerPayload::EntitySpawned, table.as_union_value())
+        }
+        "entity_removed" => {
+            let removed: EntityRemovedData = parse(msg_type, data)?;
+            let table = fb::EntityRemoved::create(fbb, &fb::EntityRemovedArgs { entity_id: removed.entity_id });
+            (fb::ServerPayload::EntityRemoved, table.as_union_value())
+        }
+        "entity_updated" => {
+            let update: EntityUpdatedData = parse(msg_type, data)?;
+            let position = update.position.as_ref().map(fb_position);
+            let velocity = update.velocity.map(|v| fb::Vec2::new(v.x, v.y));
+            let table = fb::EntityUpdated::create(fbb, &fb::EntityUpdatedArgs {
+                id: update.id,
+                position: position.as_ref(),
+                velocity: velocity.as_ref(),
+                rotation: update.rotation,
+                stance: update.stance.map(|s| fb::Stance(s as u8)),
+                state: update.state.map(|s| fb::ActorState(s as u8)),
+                detection_level: update.detection_level,
+                visible_to_hacker: update.visible_to_hacker,
+                visible_to_infiltrator: update.visible_to_infiltrator,
+            });
+            (fb::ServerPayload::EntityUpdated, table.as_union_value())
+        }
+        "role_changed" => {
+            let change: RoleChangedData = parse(msg_type, data)?;
+            let table = fb::RoleChanged::create(fbb, &fb::RoleChangedArgs {
+                player_id: change.player_id,
+                role: fb::PlayerRole(change.role as u8),
+            });
+            (fb::ServerPayload::RoleChanged, table.as_union_value())
+        }
+        "mission_state" => {
+            let mission: MissionStateData = parse(msg_type, data)?;
+            let objectives: Vec<_> = mission.objectives.iter()
+                .map(|o| {
+                    let description = fbb.create_string(&o.description);
+                    fb::ObjectiveStatus::create(fbb, &fb::ObjectiveStatusArgs {
+                        objective_id: o.objective_id,
+                        description: Some(description),
+                        complete: o.complete,
+                        optional: o.optional,
+                    })
+                })
+                .collect();
+            let objectives = fbb.create_vector(&objectives);
+            let failure_reason = mission.failure_reason.as_deref().map(|r| fbb.create_string(r));
+            let table = fb::MissionState::create(fbb, &fb::MissionStateArgs {
+                status: fb::MissionStatus(mission.status as u8),
+                objectives: Some(objectives),
+                time_remaining: mission.time_remaining,
+                failure_reason,
+            });
+            (fb::ServerPayload::MissionState, table.as_union_value())
+        }
+        "chat" => {
+            let chat: ChatData = parse(msg_type, data)?;
+            let text = fbb.create_string(&chat.text);
+            let table = fb::Chat::create(fbb, &fb::ChatArgs { player_id: chat.player_id, text: Some(text) });
+            (fb::ServerPayload::Chat, table.as_union_value())
+        }
+        "ping" => {
+            let ping: NonceData = parse(msg_type, data)?;
+            let table = fb::Ping::create(fbb, &fb::PingArgs { nonce: ping.nonce });
+            (fb::ServerPayload::Ping, table.as_union_value())
+        }
+        "input_ack" => {
+            let ack: InputAckData = parse(msg_type, data)?;
+            let table = fb::InputAck::create(fbb, &fb::InputAckArgs { sequence: ack.sequence });
+            (fb::ServerPayload::InputAck, table.as_union_value())
+        }
+        "markers" => {
+            let markers: MarkersData = parse(msg_type, data)?;
+            let markers: Vec<_> = markers.markers.iter()
+                .map(|m| {
+                    let (kind_type, kind) = build_marker_kind(fbb, &m.kind);
+                    fb::Marker::create(fbb, &fb::MarkerArgs {
+                        marker_id: m.marker_id,
+                        kind_type,
+                        kind: Some(kind),
+                        expires_at: m.expires_at,
+                    })
+                })
+                .collect();
+            let markers = fbb.create_vector(&markers);
+            let table = fb::Markers::create(fbb, &fb::MarkersArgs { markers: Some(markers) });
+            (fb::ServerPayload::Markers, table.as_union_value())
+        }
+        "hacker_intel" => {
+            let intel: IntelData = parse(msg_type, data)?;
+            let sensors: Vec<_> = intel.sensors.iter()
+                .map(|s| fb::Sensor::create(fbb, &fb::SensorArgs {
+                    sensor_id: s.sensor_id,
+                    kind: fb::DeviceKind(s.kind as u8),
+                    range: s.range,
+                    field_of_view: s.field_of_view,
+                }))
+                .collect();
+            let sensors = fbb.create_vector(&sensors);
+            let ghosts: Vec<_> = intel.ghosts.iter()
+                .map(|g| {
+                    let position = fb_position(&g.position);
+                    fb::Sighting::create(fbb, &fb::SightingArgs {
+                        entity_id: g.entity_id,
+                        entity_type: fb::EntityType(g.entity_type as u8),
+                        position: Some(&position),
+                        seen_at: g.seen_at,
+                    })
+                })
+                .collect();
+            let ghosts = fbb.create_vector(&ghosts);
+            let table = fb::HackerIntel::create(fbb, &fb::HackerIntelArgs {
+                sensors: Some(sensors),
+                ghosts: Some(ghosts),
+            });
+            (fb::ServerPayload::HackerIntel, table.as_union_value())
+        }
+        "trace" => {
+            let trace: TraceData = parse(msg_type, data)?;
+            let table = fb::Trace::create(fbb, &fb::TraceArgs {
+                level: trace.level,
+                stage: fb::TraceStage(trace.stage as u8),
+                ice: trace.ice,
+            });
+            (fb::ServerPayload::Trace, table.as_union_value())
+        }
+        "interaction" => {
+            let interaction: InteractionData = parse(msg_type, data)?;
+            let (result, keycard_id) = match interaction.result {
+                InteractionResult::Opened => (fb::InteractionResult::Opened, 0),
+                InteractionResult::Closed => (fb::InteractionResult::Closed, 0),
+                InteractionResult::Unlocked => (fb::InteractionResult::Unlocked, 0),
+                InteractionResult::MissingKeycard { keycard_id } => (fb::InteractionResult::MissingKeycard, keycard_id),
+                InteractionResult::RequiresHacker => (fb::InteractionResult::RequiresHacker, 0),
+                InteractionResult::AlreadyOpen => (fb::InteractionResult::AlreadyOpen, 0),
+                InteractionResult::OutOfRange => (fb::InteractionResult::OutOfRange, 0),
+                InteractionResult::NothingToInteract => (fb::InteractionResult::NothingToInteract, 0),
+            };
+            let table = fb::Interaction::create(fbb, &fb::InteractionArgs {
+                door_id: interaction.door_id,
+                result,
+                keycard_id,
+            });
+            (fb::ServerPayload::Interaction, table.as_union_value())
+        }
+        "door_state" => {
+            let change: DoorStateData = parse(msg_type, data)?;
+            let table = fb::DoorStateChanged::create(fbb, &fb::DoorStateChangedArgs {
+                door_id: change.door_id,
+                state: fb::DoorState(change.state as u8),
+            });
+            (fb::ServerPayload::DoorStateChanged, table.as_union_value())
+        }
+        "level" => {
+            let level: LevelGeometry = parse(msg_type, data)?;
+            let obstacles: Vec<_> = level.obstacles.iter()
+                .map(|o| {
+                    let position = fb_position(&o.position);
+                    fb::Obstacle::create(fbb, &fb::ObstacleArgs {
+                        position: Some(&position),
+                        radius: o.radius,
+                        blocks_vision: o.blocks_vision,
+                        blocks_sound: o.blocks_sound,
+                        provides_cover: o.provides_cover,
+                    })
+                })
+                .collect();
+            let obstacles = fbb.create_vector(&obstacles);
+            let doors: Vec<_> = level.doors.iter()
+                .map(|d| {
+                    let position = fb_position(&d.position);
+                    fb::DoorGeometry::create(fbb, &fb::DoorGeometryArgs {
+                        door_id: d.door_id,
+                        position: Some(&position),
+                        radius: d.radius,
+                        state: fb::DoorState(d.state as u8),
+                    })
+                })
+                .collect();
+            let doors = fbb.create_vector(&doors);
+            let table = fb::Level::create(fbb, &fb::LevelArgs {
+                timestep: level.timestep,
+                width: level.width,
+                height: level.height,
+                obstacles: Some(obstacles),
+                doors: Some(doors),
+            });
+            (fb::ServerPayload::Level, table.as_union_value())
+        }
+        other => return Err(WireError::UnknownMessage(other.to_string())),
+    })
+}
+
+fn read_server_payload(message: &fb::ServerMessage) -> Result<(&'static str, Value), WireError> {
+    Ok(match message.payload_type() {
+        fb::ServerPayload::Welcome => {
+            let welcome = required(message.payload_as_welcome(), "payload")?;
+            ("welcome", to_data(WelcomeData {
+                version: welcome.version(),
+                encoding: welcome.encoding().map(str::to_string),
+                capabilities: read_strings(welcome.capabilities()),
+            })?)
+        }
+        fb::ServerPayload::StateUpdate => {
+            let update = required(message.payload_as_state_update(), "payload")?;
+            ("state_update", to_data(read_all(update.entities(), read_entity)?)?)
+        }
+        fb::ServerPayload::SnapshotDelta => {
+            let delta = required(message.payload_as_snapshot_delta(), "payload")?;
+            ("state_delta", to_data(SnapshotDelta {
+                tick: delta.tick(),
+                baseline: delta.baseline(),
+                changed: read_all(delta.changed(), read_entity_delta)?,
+                removed: delta.removed().map(|r| r.iter().collect()).unwrap_or_default(),
+            })?)
+        }
+        fb::ServerPayload::PlayerJoined => {
+            let joined = required(message.payload_as_player_joined(), "payload")?;
+            ("player_joined", to_data(PlayerJoinedData {
+                player_id: joined.player_id(),
+                role: from_wire(&ROLES, joined.role().0, "role")?,
+                name: joined.name().map(str::to_string),
+            })?)
+        }
+        fb::ServerPayload::PlayerLeft => {
+            let left = required(message.payload_as_player_left(), "payload")?;
+            ("player_left", to_data(PlayerLeftData { player_id: left.player_id() })?)
+        }
+        fb::ServerPayload::EntitySpawned => {
+            let spawned = required(message.payload_as_entity_spawned(), "payload")?;
+            ("entity_spawned", to_data(read_entity(required(spawned.entity(), "entity")?)?)?)
+        }
+        fb::ServerPayload::EntityRemoved => {
+            let removed = required(message.payload_as_entity_removed(), "payload")?;
+            ("entity_removed", to_data(EntityRemovedData { entity_id: removed.entity_id() })?)
+        }
+        fb::ServerPayload::EntityUpdated => {
+            let update = required(message.payload_as_entity_updated(), "payload")?;
+            ("entity_updated", to_data(EntityUpdatedData {
+                id: update.id(),
+                position: update.position().map(position),
+                velocity: update.velocity().map(|v| Vec2::new(v.x(), v.y())),
+                rotation: update.rotation(),
+                stance: update.stance().map(|s| from_wire(&STANCES, s.0, "stance")).transpose()?,
+                state: update.state().map(|s| from_wire(&ACTOR_STATES, s.0, "actor state")).transpose()?,
+                detection_level: update.detection_level(),
+                visible_to_hacker: update.visible_to_hacker(),
+                visible_to_infiltrator: update.visible_to_infiltrator(),
+            })?)
+        }
+        fb::ServerPayload::RoleChanged => {
+            let change = required(message.payload_as_role_changed(), "payload")?;
+            ("role_changed", to_data(RoleChangedData {
+                player_id: change.player_id(),
+                role: from_wire(&ROLES, change.role().0, "role")?,
+            })?)
+        }
+        fb::ServerPayload::MissionState => {
+            let mission = required(message.payload_as_mission_state(), "payload")?;
+            let objectives = read_all(mission.objectives(), |o| Ok(ObjectiveData {
+                objective_id: o.objective_id(),
+                description: o.description().unwrap_or_default().to_string(),
+                complete: o.complete(),
+                optional: o.optional(),
+            }))?;
+            ("mission_state", to_data(MissionStateData {
+                status: from_wire(&MISSION_STATUSES, mission.status().0, "mission status")?,
+                objectives,
+                time_remaining: mission.time_remaining(),
+                failure_reason: mission.failure_reason().map(str::to_string),
+            })?)
+        }
+        fb::ServerPayload::Chat => {
+            let chat = required(message.payload_as_chat(), "payload")?;
+            ("chat", to_data(ChatData {
+                player_id: chat.player_id(),
+                text: chat.text().unwrap_or_default().to_string(),
+            })?)
+        }
+        fb::ServerPayload::Ping => {
+            let ping = required(message.payload_as_ping(), "payload")?;
+            ("ping", to_data(NonceData { nonce: ping.nonce() })?)
+        }
+        fb::ServerPayload::InputAck => {
+            let ack = required(message.payload_as_input_ack(), "payload")?;
+            ("input_ack", to_data(InputAckData { sequence: ack.sequence() })?)
+        }
+        fb::ServerPayload::Markers => {
+            let markers = required(message.payload_as_markers(), "payload")?;
+            let markers = read_all(markers.markers(), |m| Ok(Marker {
+                marker_id: m.marker_id(),
+                kind: read_marker_kind(m.kind_as_ping_marker(), m.kind_as_tag_marker(), m.kind_as_route_marker())?,
+                expires_at: m.expires_at(),
+            }))?;
+            ("markers", to_data(MarkersData { markers })?)
+        }
+        fb::ServerPayload::HackerIntel => {
+            let intel = required(message.payload_as_hacker_intel(), "payload")?;
+            let sensors = read_all(intel.sensors(), |s| Ok(Sensor {
+                sensor_id: s.sensor_id(),
+                kind: from_wire(&DEVICE_KINDS, s.kind().0, "device kind")?,
+                range: s.range(),
+                field_of_view: s.field_of_view(),
+            }))?;
+            let ghosts = read_all(intel.ghosts(), |g| Ok(Sighting {
+                entity_id: g.entity_id(),
+                entity_type: from_wire(&ENTITY_TYPES, g.entity_type().0, "entity type")?,
+                position: position(required(g.position(), "ghost position")?),
+                seen_at: g.seen_at(),
+            }))?;
+            ("hacker_intel", to_data(IntelData { sensors, ghosts })?)
+        }
+        fb::ServerPayload::Trace => {
+            let trace = required(message.payload_as_trace(), "payload")?;
+            ("trace", to_data(TraceData {
+                level: trace.level(),
+                stage: from_wire(&TRACE_STAGES, trace.stage().0, "trace stage")?,
+                ice: trace.ice(),
+            })?)
+        }
+        fb::ServerPayload::Interaction => {
+            let interaction = required(message.payload_as_interaction(), "payload")?;
+            let result = match interaction.result() {
+                fb::InteractionResult::Opened => InteractionResult::Opened,
+                fb::InteractionResult::Closed => InteractionResult::Closed,
+                fb::InteractionResult::Unlocked => InteractionResult::Unlocked,
+                fb::InteractionResult::MissingKeycard => InteractionResult::MissingKeycard {
+                    keycard_id: interaction.keycard_id(),
+                },
+                fb::InteractionResult::RequiresHacker => InteractionResult::RequiresHacker,
+                fb::InteractionResult::AlreadyOpen => InteractionResult::AlreadyOpen,
+                fb::InteractionResult::OutOfRange => InteractionResult::OutOfRange,
+                fb::InteractionResult::NothingToInteract => InteractionResult::NothingToInteract,
+                other => return Err(WireError::FlatBuffers(format!("unknown interaction result {}", other.0))),
+            };
+            ("interaction", to_data(InteractionData { door_id: interaction.door_id(), result })?)
+        }
+        fb::ServerPayload::DoorStateChanged => {
+            let change = required(message.payload_as_door_state_changed(), "payload")?;
+            ("door_state", to_data(DoorStateData {
+                door_id: change.door_id(),
+                state: from_wire(&DOOR_STATES, change.state().0, "door state")?,
+            })?)
+        }
+        fb::ServerPayload::Level => {
+            let level = required(message.payload_as_level(), "payload")?;
+            let obstacles = read_all(level.obstacles(), |o| Ok(Obstacle {
+                position: position(required(o.position(), "obstacle position")?),
+                radius: o.radius(),
+                blocks_vision: o.blocks_vision(),
+                blocks_sound: o.blocks_sound(),
+                provides_cover: o.provides_cover(),
+            }))?;
+            let doors = read_all(level.doors(), |d| Ok(DoorGeometry {
+                door_id: d.door_id(),
+                position: position(required(d.position(), "door position")?),
+                radius: d.radius(),
+                state: from_wire(&DOOR_STATES, d.state().0, "door state")?,
+            }))?;
+            ("level", to_data(LevelGeometry {
+                timestep: level.timestep(),
+                width: level.width(),
+                height: level.height(),
+                obstacles,
+                doors,
+            })?)
+        }
+        other => return Err(WireError::FlatBuffers(format!("unknown server payload {}", other.0))),
+    })
+}
+
+fn build_client_payload(
+    fbb: &mut FlatBufferBuilder<'_>,
+    msg_type: &str,
+    data: &Value,
+) -> Result<(fb::ClientPayload, WIPOffset<UnionWIPOffset>), WireError> {
+    Ok(match msg_type {
+        "hello" => {
+            let hello: HelloData = parse(msg_type, data)?;
+            let encoding = fbb.create_string(&hello.encoding);
+            let encodings = create_strings(fbb, &hello.encodings);
+            let capabilities = create_strings(fbb, &hello.capabilities);
+            let table = fb::Hello::create(fbb, &fb::HelloArgs {
+                version: hello.version,
+                min_version: hello.min_version,
+                encoding: Some(encoding),
+                encodings: Some(encodings),
+                role: fb::PlayerRole(hello.role as u8),
+                player_id: hello.player_id,
+                capabilities: Some(capabilities),
+            });
+            (fb::ClientPayload::Hello, table.as_union_value())
+        }
+        "player_input" => {
+            let input: InputData = parse(msg_type, data)?;
+            let movement = fb::Vec2::new(input.movement.x, input.movement.y);
+            let table = fb::PlayerInput::create(fbb, &fb::PlayerInputArgs {
+                movement: Some(&movement),
+                sequence: input.sequence,
+                sprint: input.sprint,
+                crouch: input.crouch,
+                prone: input.prone,
+            });
+            (fb::ClientPayload::PlayerInput, table.as_union_value())
+        }
+        "player_action" => {
+            let action: ActionData = parse(msg_type, data)?;
+            let action = fbb.create_string(&action.action);
+            let table = fb::PlayerAction::create(fbb, &fb::PlayerActionArgs { action: Some(action) });
+            (fb::ClientPayload::PlayerAction, table.as_union_value())
+        }
+        "hack" => {
+            let command: HackCommand = parse(msg_type, data)?;
+            let (kind, instruction) = match &command {
+                HackCommand::Compromise { .. } => (fb::HackCommandKind::Compromise, None),
+                HackCommand::Execute { instruction, .. } => (fb::HackCommandKind::Execute, Some(instruction.as_str())),
+                HackCommand::Undo { .. } => (fb::HackCommandKind::Undo, None),
+                HackCommand::LoopFeed { .. } => (fb::HackCommandKind::LoopFeed, None),
+                HackCommand::RestoreFeed { .. } => (fb::HackCommandKind::RestoreFeed, None),
+                HackCommand::UnlockDoor { .. } => (fb::HackCommandKind::UnlockDoor, None),
+                HackCommand::CutLights { .. } => (fb::HackCommandKind::CutLights, None),
+                HackCommand::RestoreLights { .. } => (fb::HackCommandKind::RestoreLights, None),
+            };
+            let instruction = instruction.map(|i| fbb.create_string(i));
+            let table = fb::Hack::create(fbb, &fb::HackArgs {
+                command: kind,
+                device_id: command.device_id(),
+                instruction,
+            });
+            (fb::ClientPayload::Hack, table.as_union_value())
+        }
+        "mark" => {
+            let kind: MarkerKind = parse(msg_type, data)?;
+            let (marker_type, marker) = build_marker_kind(fbb, &kind);
+            let table = fb::Mark::create(fbb, &fb::MarkArgs { marker_type, marker: Some(marker) });
+            (fb::ClientPayload::Mark, table.as_union_value())
+        }
+        "unmark" => {
+            let unmark: UnmarkData = parse(msg_type, data)?;
+            let table = fb::Unmark::create(fbb, &fb::UnmarkArgs { marker_id: unmark.marker_id });
+            (fb::ClientPayload::Unmark, table.as_union_value())
+        }
+        "state_ack" => {
+            let ack: StateAckData = parse(msg_type, data)?;
+            let table = fb::StateAck::create(fbb, &fb::StateAckArgs { tick: ack.tick });
+            (fb::ClientPayload::StateAck, table.as_union_value())
+        }
+        "chat" => {
+            let chat: SendChatData = parse(msg_type, data)?;
+            let text = fbb.create_string(&chat.text);
+            let table = fb::SendChat::create(fbb, &fb::SendChatArgs { text: Some(text) });
+            (fb::ClientPayload::SendChat, table.as_union_value())
+        }
+        "leave" => {
+            let table = fb::Leave::create(fbb, &fb::LeaveArgs {});
+            (fb::ClientPayload::Leave, table.as_union_value())
+        }
+        "pong" => {
+            let pong: NonceData = parse(msg_type, data)?;
+            let table = fb::Pong::create(fbb, &fb::PongArgs { nonce: pong.nonce });
+            (fb::ClientPayload::Pong, table.as_union_value())
+        }
+        "error" => {
+            let error: ErrorData = parse(msg_type, data)?;
+            let offending = error.msg_type.as_deref().map(|t| fbb.create_string(t));
+            let reason = fbb.create_string(&error.reason);
+            let table = fb::Error::create(fbb, &fb::ErrorArgs { msg_type: offending, reason: Some(reason) });
+            (fb::ClientPayload::Error, table.as_union_value())
+        }
+        other => return Err(WireError::UnknownMessage(other.to_string())),
+    })
+}
+
+fn read_client_payload(message: &fb::ClientMessage) -> Result<(&'static str, Value), WireError> {
+    Ok(match message.payload_type() {
+        fb::ClientPayload::Hello => {
+            let hello = required(message.payload_as_hello(), "payload")?;
+            ("hello", to_data(HelloData {
+                version: hello.version(),
+                min_version: hello.min_version(),
+                encoding: hello.encoding().unwrap_or_default().to_string(),
+                encodings: read_strings(hello.encodings()),
+                role: from_wire(&ROLES, hello.role().0, "role")?,
+                player_id: hello.player_id(),
+                capabilities: read_strings(hello.capabilities()),
+            })?)
+        }
+        fb::ClientPayload::PlayerInput => {
+            let input = required(message.payload_as_player_input(), "payload")?;
+            let movement = required(input.movement(), "movement")?;
+            ("player_input", to_data(InputData {
+                movement: MovementData { x: movement.x(), y: movement.y() },
+                sequence: input.sequence(),
+                sprint: input.sprint(),
+                crouch: input.crouch(),
+                prone: input.prone(),
+            })?)
+        }
+        fb::ClientPayload::PlayerAction => {
+            let action = required(message.payload_as_player_action(), "payload")?;
+            ("player_action", to_data(ActionData { action: action.action().unwrap_or_default().to_string() })?)
+        }
+        fb::ClientPayload::Hack => {
+            let hack = required(message.payload_as_hack(), "payload")?;
+            let device_id = hack.device_id();
+            let command = match hack.command() {
+                fb::HackCommandKind::Compromise => HackCommand::Compromise { device_id },
+                fb::HackCommandKind::Execute => HackCommand::Execute {
+                    device_id,
+                    instruction: hack.instruction().unwrap_or_default().to_string(),
+                },
+                fb::HackCommandKind::Undo => HackCommand::Undo { device_id },
+                fb::HackCommandKind::LoopFeed => HackCommand::LoopFeed { camera_id: device_id },
+                fb::HackCommandKind::RestoreFeed => HackCommand::RestoreFeed { camera_id: device_id },
+                fb::HackCommandKind::UnlockDoor => HackCommand::UnlockDoor { door_id: device_id },
+                fb::HackCommandKind::CutLights => HackCommand::CutLights { light_id: device_id },
+                fb::HackCommandKind::RestoreLights => HackCommand::RestoreLights { light_id: device_id },
+                other => return Err(WireError::FlatBuffers(format!("unknown hack command {}", other.0))),
+            };
+            ("hack", to_data(command)?)
+        }
+        fb::ClientPayload::Mark => {
+            let mark = required(message.payload_as_mark(), "payload")?;
+            let kind = read_marker_kind(
+                mark.marker_as_ping_marker(),
+                mark.marker_as_tag_marker(),
+                mark.marker_as_route_marker(),
+            )?;
+            ("mark", to_data(kind)?)
+        }
+        fb::ClientPayload::Unmark => {
+            let unmark = required(message.payload_as_unmark(), "payload")?;
+            ("unmark", to_data(UnmarkData { marker_id: unmark.marker_id() })?)
+        }
+        fb::ClientPayload::StateAck => {
+            let ack = required(message.payload_as_state_ack(), "payload")?;
+            ("state_ack", to_data(StateAckData { tick: ack.tick() })?)
+        }
+        fb::ClientPayload::SendChat => {
+            let chat = required(message.payload_as_send_chat(), "payload")?;
+            ("chat", to_data(SendChatData { text: chat.text().unwrap_or_default().to_string() })?)
+        }
+        fb::ClientPayload::Leave => ("leave", json!({})),
+        fb::ClientPayload::Pong => {
+            let pong = required(message.payload_as_pong(), "payload")?;
+            ("pong", to_data(NonceData { nonce: pong.nonce() })?)
+        }
+        fb::ClientPayload::Error => {
+            let error = required(message.payload_as_error(), "payload")?;
+            ("error", to_data(ErrorData {
+                msg_type: error.msg_type().map(str::to_string),
+                reason: error.reason().unwrap_or_default().to_string(),
+            })?)
+        }
+        other => return Err(WireError::FlatBuffers(format!("unknown client payload {}", other.0))),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::system::GameSystem;
+    use crate::net::delta::DeltaEncoder;
+    use std::io::Cursor;
+
+    fn server(msg_type: &str, data: Value, to: Option<u32>) -> ServerMessage {
+        ServerMessage { msg_type: msg_type.to_string(), data, to }
+    }
+
+    fn client(msg_type: &str, data: Value) -> ClientMessage {
+        ClientMessage { msg_type: msg_type.to_string(), player_id: 7, data }
+    }
+
+    #[test]
+    fn test_frames_round_trip() {
+        for protocol in Protocol::ALL {
+            let mut buffer = Vec::new();
+            write_frame(&mut buffer, protocol, b"first").unwrap();
+            write_frame(&mut buffer, protocol, b"second").unwrap();
+
+            let mut reader = Cursor::new(buffer);
+            let first = read_frame(&mut reader, protocol).unwrap().unwrap();
+            let second = read_frame(&mut reader, protocol).unwrap().unwrap();
+            assert_eq!(first.trim_ascii(), b"first");
+            assert_eq!(second.trim_ascii(), b"second");
+            assert!(read_frame(&mut reader, protocol).unwrap().is_none(), "{:?} should hit EOF", protocol);
+        }
+    }
+
+    #[test]
+    fn test_frame_errors() {
+        // Nothing at all is a clean EOF
+        assert!(read_frame(&mut Cursor::new(Vec::new()), Protocol::FlatBuffers).unwrap().is_none());
+
+        // A header over the limit is refused before anything is allocated
+        let header = ((MAX_FRAME_SIZE + 1) as u32).to_be_bytes();
+        let err = read_frame(&mut Cursor::new(header.to_vec()), Protocol::FlatBuffers).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+
+        // A frame cut short is an error, not an EOF
+        let mut short = 10u32.to_be_bytes().to_vec();
+        short.extend_from_slice(b"abc");
+        let err = read_frame(&mut Cursor::new(short), Protocol::FlatBuffers).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::UnexpectedEof);
+    }
+
+    #[test]
+    fn test_protocol_names() {
+        for protocol in Protocol::ALL {
+            assert_eq!(Protocol::from_name(protocol.name()), Some(protocol));
+        }
+        assert_eq!(Protocol::from_name("xml"), None);
+    }
+
+    #[test]
+    fn test_server_messages_round_trip() {
+        let mut game = GameSystem::new(100.0, 100.0);
+        let player_id = game.spawn_player(10.25, 20.5);
+        game.spawn_guard(50.0, 50.0);
+        game.world.get_entity_mut(player_id).unwrap().keycards = vec![3, 9];
+        let entities = serde_json::to_value(&game.world.entities).unwrap();
+        let entity = serde_json::to_value(&game.world.entities[0]).unwrap();
+
+        let mut encoder = DeltaEncoder::new();
+        let full = encoder.encode(game.world_snapshot_for(player_id));
+        encoder.acknowledge(full.tick);
+        game.world.get_entity_mut(player_id).unwrap().position.x = 30.0;
+        game.step(0.05);
+        let partial = encoder.encode(game.world_snapshot_for(player_id));
+        assert!(partial.baseline.is_some());
+
+        let messages = vec![
+            server("welcome", json!({ "version": 4, "encoding": "flatbuffers", "capabilities": ["chat", "trace"] }), Some(1)),
+            server("state_update", entities, Some(1)),
+            server("state_delta", serde_json::to_value(&full).unwrap(), Some(1)),
+            server("state_delta", serde_json::to_value(&partial).unwrap(), Some(1)),
+            server("player_joined", json!({ "player_id": 2, "role": "Hacker" }), None),
+            server("player_joined", json!({ "player_id": 3, "role": "Infiltrator", "name": "ghost" }), None),
+            server("player_left", json!({ "player_id": 2 }), None),
+            server("entity_spawned", entity, None),
+            server("entity_removed", json!({ "entity_id": 4 }), None),
+            server("entity_updated", json!({ "id": 1, "position": { "x": 1.5, "y": 2.5, "height": 0.0 }, "velocity": [0.5, -0.5], "stance": "Prone" }), None),
+            server("entity_updated", json!({ "id": 1, "detection_level": 0.25, "visible_to_hacker": false }), None),
+            server("role_changed", json!({ "player_id": 1, "role": "Hacker" }), None),
+            server("mission_state", json!({
+                "status": "failed",
+                "objectives": [{ "objective_id": 1, "description": "Reach the vault", "complete": true, "optional": false }],
+                "time_remaining": 12.5,
+                "failure_reason": "caught",
+            }), None),
+            server("mission_state", json!({ "status": "in_progress", "objectives": [], "time_remaining": null, "failure_reason": null }), None),
+            server("chat", json!({ "player_id": 1, "text": "left door" }), Some(2)),
+            server("ping", json!({ "nonce": 1u64 << 40 }), Some(1)),
+            server("input_ack", json!({ "sequence": 17 }), Some(1)),
+            server("markers", json!({ "markers": [
+                { "marker_id": 1, "marker": "ping", "position": { "x": 1.0, "y": 2.0, "height": 0.5 }, "expires_at": 8.0 },
+                { "marker_id": 2, "marker": "tag", "entity_id": 5, "expires_at": 20.0 },
+                { "marker_id": 3, "marker": "route", "points": [{ "x": 1.0, "y": 1.0, "height": 0.0 }, { "x": 4.0, "y": 2.0, "height": 0.0 }], "expires_at": 30.0 },
+            ] }), Some(2)),
+            server("hacker_intel", json!({
+                "sensors": [{ "sensor_id": 4, "kind": "Camera", "range": 150.0, "field_of_view": 1.5 }],
+                "ghosts": [{ "entity_id": 5, "entity_type": "Guard", "position": { "x": 3.0, "y": 4.0, "height": 0.0 }, "seen_at": 2.5 }],
+            }), Some(2)),
+            server("trace", json!({ "level": 42.5, "stage": "Hunted", "ice": 4 }), Some(2)),
+            server("trace", json!({ "level": 0.0, "stage": "Clear", "ice": null }), Some(2)),
+            server("interaction", json!({ "door_id": 6, "result": { "MissingKeycard": { "keycard_id": 3 } } }), Some(1)),
+            server("interaction", json!({ "door_id": null, "result": "NothingToInteract" }), Some(1)),
+            server("door_state", json!({ "door_id": 6, "state": "Hacked" }), Some(1)),
+            server("level", json!({
+                "timestep": 0.0625, "width": 200.0, "height": 100.0,
+                "obstacles": [{ "position": { "x": 40.0, "y": 10.0, "height": 0.0 }, "radius": 5.0, "blocks_vision": true, "blocks_sound": false, "provides_cover": true }],
+                "doors": [{ "door_id": 6, "position": { "x": 80.0, "y": 10.0, "height": 0.0 }, "radius": 3.0, "state": "Locked" }],
+            }), Some(1)),
+        ];
+
+        for message in &messages {
+            for protocol in Protocol::ALL {
+                let frame = encode_server_message(message, protocol).unwrap();
+                let decoded = decode_server_message(&frame, protocol).unwrap();
+                assert_eq!(decoded.msg_type, message.msg_type);
+                assert_eq!(decoded.to, message.to);
+                assert_eq!(decoded.data, message.data, "{} over {:?}", message.msg_type, protocol);
+            }
+        }
+    }
+
+    #[test]
+    fn test_client_messages_round_trip() {
+        let messages = vec![
+            client("hello", json!({
+                "version": 4,
+                "min_version": 4,
+                "encoding": "flatbuffers",
+                "encodings": ["flatbuffers", "json"],
+                "role": "Infiltrator",
+                "player_id": 7,
+                "capabilities": ["state_delta", "prediction"],
+            })),
+            client("player_input", json!({ "movement": { "x": 1.0, "y": -1.0 }, "sequence": 12, "sprint": false, "crouch": true, "prone": false })),
+            client("player_input", json!({ "movement": { "x": 0.0, "y": 0.0 }, "sprint": false, "crouch": false, "prone": false })),
+            client("player_action", json!({ "action": "interact" })),
+            client("hack", json!({ "command": "compromise", "device_id": 4 })),
+            client("hack", json!({ "command": "execute", "device_id": 4, "instruction": "ADD x y" })),
+            client("hack", json!({ "command": "loop_feed", "camera_id": 5 })),
+            client("hack", json!({ "command": "unlock_door", "door_id": 6 })),
+            client("hack", json!({ "command": "cut_lights", "light_id": 8 })),
+            client("mark", json!({ "marker": "tag", "entity_id": 5 })),
+            client("mark", json!({ "marker": "ping", "position": { "x": 2.0, "y": 3.0, "height": 0.0 } })),
+            client("unmark", json!({ "marker_id": 2 })),
+            client("state_ack", json!({ "tick": 99 })),
+            client("chat", json!({ "text": "go" })),
+            client("leave", json!({})),
+            client("pong", json!({ "nonce": 5 })),
+            client("error", json!({ "msg_type": "trace", "reason": "bad stage" })),
+            client("error", json!({ "msg_type": null, "reason": "unreadable frame" })),
+        ];
+
+        for message in &messages {
+            for protocol in Protocol::ALL {
+                let frame = encode_client_message(message, protocol).unwrap();
+                let decoded = decode_client_message(&frame, protocol).unwrap();
+                assert_eq!(decoded.msg_type, message.msg_type);
+                assert_eq!(decoded.player_id, message.player_id);
+                assert_eq!(decoded.data, message.data, "{} over {:?}", message.msg_type, protocol);
+            }
+        }
+    }
+
+    #[test]
+    fn test_bad_messages_are_refused() {
+        let unknown = server("teleport", json!({}), None);
+        assert_eq!(
+            encode_server_message(&unknown, Protocol::FlatBuffers),
+            Err(WireError::UnknownMessage("teleport".to_string())),
+        );
+
+        let wrong_shape = client("unmark", json!({ "marker": 2 }));
+        assert!(matches!(
+            encode_client_message(&wrong_shape, Protocol::FlatBuffers),
+            Err(WireError::BadPayload { .. }),
+        ));
+
+        assert!(matches!(decode_server_message(b"\x01\x02", Protocol::FlatBuffers), Err(WireError::FlatBuffers(_))));
+    }
+}
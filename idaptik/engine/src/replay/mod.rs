@@ -0,0 +1,5 @@
+pub mod player;
+pub mod recording;
+
+pub use player::*;
+pub use recording::*;
@@ -0,0 +1,163 @@
+use crate::game::system::{GameEvent, GameSystem};
+use crate::replay::recording::*;
+
+/// First tick at which a replay no longer reproduces the recorded state
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Divergence {
+    pub tick: usize, // Index into `Replay::ticks`
+    pub expected: u64,
+    pub actual: u64,
+}
+
+/// Re-runs a recorded session through `GameSystem::step`
+pub struct ReplayPlayer {
+    replay: Replay,
+    system: GameSystem,
+    cursor: usize, // Next tick to run
+}
+
+impl ReplayPlayer {
+    pub fn new(replay: Replay) -> Self {
+        let system = system_from_replay(&replay);
+        Self { replay, system, cursor: 0 }
+    }
+
+    pub fn system(&self) -> &GameSystem {
+        &self.system
+    }
+
+    /// Ticks already played
+    pub fn position(&self) -> usize {
+        self.cursor
+    }
+
+    pub fn len(&self) -> usize {
+        self.replay.ticks.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.replay.ticks.is_empty()
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.cursor >= self.replay.ticks.len()
+    }
+
+    /// Run the next recorded tick; None once the replay is over
+    pub fn step(&mut self) -> Option<Vec<GameEvent>> {
+        let tick = self.replay.ticks.get(self.cursor)?;
+        for recorded in &tick.inputs {
+            self.system.apply_player_input(recorded.player_id, recorded.input);
+        }
+        let events = self.system.step(tick.delta_time);
+        self.cursor += 1;
+        Some(events)
+    }
+
+    /// Jump to just after `tick` ticks have run
+    /// Seeking backwards restarts from the initial snapshot
+    pub fn seek(&mut self, tick: usize) {
+        let tick = tick.min(self.replay.ticks.len());
+        if tick < self.cursor {
+            self.system = system_from_replay(&self.replay);
+            self.cursor = 0;
+        }
+        while self.cursor < tick {
+            self.step();
+        }
+    }
+
+    /// Play to the end, returning every event tagged with the tick it fired on
+    pub fn run_to_end(&mut self) -> Vec<(usize, GameEvent)> {
+        let mut all = Vec::new();
+        while !self.is_finished() {
+            let tick = self.cursor;
+            if let Some(events) = self.step() {
+                all.extend(events.into_iter().map(|e| (tick, e)));
+            }
+        }
+        all
+    }
+
+    /// Play the whole replay from the start, checking every tick against its recorded checksum
+    pub fn verify(&mut self) -> Result<(), Divergence> {
+        self.seek(0);
+        while !self.is_finished() {
+            let tick = self.cursor;
+            self.step();
+            let expected = self.replay.ticks[tick].checksum;
+            let actual = world_checksum(&self.system.world);
+            if expected != actual {
+                return Err(Divergence { tick, expected, actual });
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::system::PlayerInput;
+
+    fn record_session() -> Replay {
+        let mut game = GameSystem::with_seed(100.0, 100.0, 3);
+        let player_id = game.spawn_player(30.0, 10.0);
+        game.spawn_guard(10.0, 10.0);
+        game.add_light(20.0, 10.0, 30.0, 1.0);
+        game.add_obstacle(20.0, 25.0, 3.0);
+
+        game.start_recording();
+        for i in 0..120 {
+            // Walk towards the guard, then stop
+            let move_x = if i < 60 { -1.0 } else { 0.0 };
+            game.apply_player_input(player_id, PlayerInput { move_x, ..PlayerInput::default() });
+            game.step(0.05);
+        }
+        game.stop_recording().unwrap()
+    }
+
+    #[test]
+    fn test_replay_reproduces_session() {
+        let replay = Replay::from_json(&record_session().to_json().unwrap()).unwrap();
+        let mut player = ReplayPlayer::new(replay);
+        assert_eq!(player.verify(), Ok(()));
+
+        // The event stream can be asserted on directly
+        player.seek(0);
+        let events = player.run_to_end();
+        assert!(events.iter().any(|(_, e)| matches!(e, GameEvent::GuardAlerted { .. })));
+    }
+
+    #[test]
+    fn test_verify_flags_first_divergent_tick() {
+        let mut replay = record_session();
+        replay.ticks[40].inputs[0].input.move_y = 1.0;
+
+        let mut player = ReplayPlayer::new(replay);
+        assert_eq!(player.verify().unwrap_err().tick, 40);
+    }
+
+    #[test]
+    fn test_seek_backwards_matches_straight_run() {
+        let replay = record_session();
+        let mut straight = ReplayPlayer::new(replay.clone());
+        straight.seek(50);
+
+        let mut seeking = ReplayPlayer::new(replay);
+        seeking.seek(90);
+        seeking.seek(50);
+        assert_eq!(
+            world_checksum(&seeking.system().world),
+            world_checksum(&straight.system().world)
+        );
+    }
+
+    #[test]
+    fn test_rejects_other_versions() {
+        let mut replay = record_session();
+        replay.version = REPLAY_FORMAT_VERSION + 1;
+        let json = replay.to_json().unwrap();
+        assert!(matches!(Replay::from_json(&json), Err(ReplayError::UnsupportedVersion(_))));
+    }
+}
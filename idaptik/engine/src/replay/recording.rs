@@ -0,0 +1,132 @@
+use crate::game::system::{GameSystem, PlayerInput, SimulationSnapshot};
+use crate::game::types::*;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Bumped whenever the replay layout or simulation rules change incompatibly
+pub const REPLAY_FORMAT_VERSION: u32 = 1;
+
+/// One input applied through `GameSystem::apply_player_input`
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct RecordedInput {
+    pub player_id: EntityId,
+    pub input: PlayerInput,
+}
+
+/// Everything needed to re-run one tick, plus the state it should end in
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReplayTick {
+    pub delta_time: f32,
+    pub inputs: Vec<RecordedInput>, // Applied before the tick runs, in order
+    pub checksum: u64,              // World checksum after the tick
+}
+
+/// A recorded session: the starting state and every tick after it
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Replay {
+    pub version: u32,
+    pub initial: SimulationSnapshot,
+    pub ticks: Vec<ReplayTick>,
+}
+
+#[derive(Debug)]
+pub enum ReplayError {
+    Json(serde_json::Error),
+    UnsupportedVersion(u32),
+}
+
+impl fmt::Display for ReplayError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReplayError::Json(err) => write!(f, "invalid replay: {}", err),
+            ReplayError::UnsupportedVersion(version) => write!(
+                f,
+                "replay format version {} is not supported (expected {})",
+                version, REPLAY_FORMAT_VERSION
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ReplayError {}
+
+impl From<serde_json::Error> for ReplayError {
+    fn from(err: serde_json::Error) -> Self {
+        ReplayError::Json(err)
+    }
+}
+
+impl Replay {
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string(self)
+    }
+
+    pub fn from_json(json: &str) -> Result<Self, ReplayError> {
+        // Check the version before parsing the rest so old files fail clearly
+        #[derive(Deserialize)]
+        struct Header {
+            version: u32,
+        }
+        let header: Header = serde_json::from_str(json)?;
+        if header.version != REPLAY_FORMAT_VERSION {
+            return Err(ReplayError::UnsupportedVersion(header.version));
+        }
+        Ok(serde_json::from_str(json)?)
+    }
+}
+
+/// Builds a replay while a `GameSystem` runs
+#[derive(Debug, Clone)]
+pub struct ReplayRecorder {
+    replay: Replay,
+    pending_inputs: Vec<RecordedInput>,
+}
+
+impl ReplayRecorder {
+    pub fn new(initial: SimulationSnapshot) -> Self {
+        Self {
+            replay: Replay {
+                version: REPLAY_FORMAT_VERSION,
+                initial,
+                ticks: Vec::new(),
+            },
+            pending_inputs: Vec::new(),
+        }
+    }
+
+    pub fn record_input(&mut self, player_id: EntityId, input: PlayerInput) {
+        self.pending_inputs.push(RecordedInput { player_id, input });
+    }
+
+    /// Close off a tick once it has run
+    pub fn record_tick(&mut self, delta_time: f32, world: &World) {
+        self.replay.ticks.push(ReplayTick {
+            delta_time,
+            inputs: std::mem::take(&mut self.pending_inputs),
+            checksum: world_checksum(world),
+        });
+    }
+
+    /// Finished replay; inputs applied after the last tick are dropped
+    pub fn finish(self) -> Replay {
+        self.replay
+    }
+}
+
+/// FNV-1a over the serialized world; any bit of drift changes it
+pub fn world_checksum(world: &World) -> u64 {
+    let json = serde_json::to_string(world).unwrap_or_default();
+    let mut hash: u64 = 0xcbf29ce484222325;
+    for byte in json.bytes() {
+        hash ^= byte as u64;
+        hash = hash.wrapping_mul(0x100000001b3);
+    }
+    hash
+}
+
+/// Start a game from a replay's initial snapshot
+pub fn system_from_replay(replay: &Replay) -> GameSystem {
+    let mut system = GameSystem::new(replay.initial.world.width, replay.initial.world.height);
+    system.restore(replay.initial.clone());
+    system
+}
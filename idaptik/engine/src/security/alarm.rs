@@ -0,0 +1,187 @@
+use crate::game::types::*;
+use serde::{Deserialize, Serialize};
+
+/// Guards this close to an alarm's origin respond even at Caution
+pub const ALARM_RESPONSE_RADIUS: f32 = 40.0;
+
+/// Ambient light level once the alarm turns the lights on
+pub const ALARM_AMBIENT_LIGHT: f32 = 0.8;
+
+/// Level-wide alarm tiers, in escalating order
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum AlarmLevel {
+    Calm,
+    Caution,  // Something is off: nearby guards check it out
+    Alert,    // Intruder confirmed: all guards converge, lights on
+    Lockdown, // Repeated sightings: doors lock as well
+}
+
+impl AlarmLevel {
+    /// Seconds without new triggers before dropping a tier
+    pub fn cooldown(&self) -> f32 {
+        match self {
+            AlarmLevel::Calm => 0.0,
+            AlarmLevel::Caution => 20.0,
+            AlarmLevel::Alert => 45.0,
+            AlarmLevel::Lockdown => 90.0,
+        }
+    }
+
+    pub fn escalated(&self) -> Self {
+        match self {
+            AlarmLevel::Calm => AlarmLevel::Caution,
+            AlarmLevel::Caution => AlarmLevel::Alert,
+            AlarmLevel::Alert | AlarmLevel::Lockdown => AlarmLevel::Lockdown,
+        }
+    }
+
+    pub fn deescalated(&self) -> Self {
+        match self {
+            AlarmLevel::Calm | AlarmLevel::Caution => AlarmLevel::Calm,
+            AlarmLevel::Alert => AlarmLevel::Caution,
+            AlarmLevel::Lockdown => AlarmLevel::Alert,
+        }
+    }
+}
+
+/// What raised the alarm
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AlarmTrigger {
+    Camera { camera_id: EntityId },
+    Guard { guard_id: EntityId },
+    Tamper { entity_id: EntityId },
+    Intrusion { device_id: EntityId }, // The hacker's trace ran out
+}
+
+impl AlarmTrigger {
+    /// Lowest level this kind of trigger raises the alarm to
+    pub fn severity(&self) -> AlarmLevel {
+        match self {
+            AlarmTrigger::Tamper { .. } => AlarmLevel::Caution,
+            AlarmTrigger::Camera { .. } | AlarmTrigger::Guard { .. } | AlarmTrigger::Intrusion { .. } => {
+                AlarmLevel::Alert
+            }
+        }
+    }
+}
+
+/// Level-wide alarm state
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AlarmState {
+    pub level: AlarmLevel,
+    pub cooldown_remaining: f32, // Seconds until dropping a tier
+    pub last_trigger: Option<AlarmTrigger>,
+    pub last_known_position: Option<Position>,
+    #[serde(default)]
+    pub raised_this_tick: bool, // Triggers arriving together escalate only once
+}
+
+impl Default for AlarmState {
+    fn default() -> Self {
+        Self {
+            level: AlarmLevel::Calm,
+            cooldown_remaining: 0.0,
+            last_trigger: None,
+            last_known_position: None,
+            raised_this_tick: false,
+        }
+    }
+}
+
+impl AlarmState {
+    /// Raise the alarm, returning the new level
+    /// A trigger lifts the alarm to its severity, or one tier higher if already there;
+    /// after the first trigger of a tick, others can only lift it to their severity
+    pub fn raise(&mut self, trigger: AlarmTrigger, position: Position) -> AlarmLevel {
+        let severity = trigger.severity();
+        self.level = if self.level >= severity && !self.raised_this_tick {
+            self.level.escalated()
+        } else {
+            self.level.max(severity)
+        };
+        self.raised_this_tick = true;
+        self.cooldown_remaining = self.level.cooldown();
+        self.last_trigger = Some(trigger);
+        self.last_known_position = Some(position);
+        self.level
+    }
+
+    /// Count down towards de-escalation; `hold` pauses the countdown
+    /// Returns the new level if it dropped this tick
+    pub fn tick(&mut self, delta_time: f32, hold: bool) -> Option<AlarmLevel> {
+        self.raised_this_tick = false;
+        if self.level == AlarmLevel::Calm || hold {
+            return None;
+        }
+
+        self.cooldown_remaining -= delta_time;
+        if self.cooldown_remaining > 0.0 {
+            return None;
+        }
+
+        self.level = self.level.deescalated();
+        self.cooldown_remaining = self.level.cooldown();
+        if self.level == AlarmLevel::Calm {
+            self.last_trigger = None;
+            self.last_known_position = None;
+        }
+        Some(self.level)
+    }
+
+    /// Every guard converges on the alarm from Alert upwards
+    pub fn all_guards_respond(&self) -> bool {
+        self.level >= AlarmLevel::Alert
+    }
+
+    pub fn lights_on(&self) -> bool {
+        self.level >= AlarmLevel::Alert
+    }
+
+    pub fn doors_locked(&self) -> bool {
+        self.level >= AlarmLevel::Lockdown
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_repeated_triggers_escalate() {
+        let mut alarm = AlarmState::default();
+        let position = Position::new(0.0, 0.0);
+
+        assert_eq!(alarm.raise(AlarmTrigger::Tamper { entity_id: 1 }, position), AlarmLevel::Caution);
+        alarm.tick(0.1, false);
+        assert_eq!(alarm.raise(AlarmTrigger::Camera { camera_id: 2 }, position), AlarmLevel::Alert);
+        alarm.tick(0.1, false);
+        assert_eq!(alarm.raise(AlarmTrigger::Guard { guard_id: 3 }, position), AlarmLevel::Lockdown);
+        assert!(alarm.doors_locked());
+    }
+
+    #[test]
+    fn test_simultaneous_triggers_escalate_once() {
+        let mut alarm = AlarmState::default();
+        let position = Position::new(0.0, 0.0);
+
+        // Two cameras catching the same player in one tick are one sighting's worth
+        assert_eq!(alarm.raise(AlarmTrigger::Camera { camera_id: 1 }, position), AlarmLevel::Alert);
+        assert_eq!(alarm.raise(AlarmTrigger::Camera { camera_id: 2 }, position), AlarmLevel::Alert);
+        alarm.tick(0.1, false);
+        assert_eq!(alarm.raise(AlarmTrigger::Camera { camera_id: 1 }, position), AlarmLevel::Lockdown);
+    }
+
+    #[test]
+    fn test_alarm_winds_down_tier_by_tier() {
+        let mut alarm = AlarmState::default();
+        alarm.raise(AlarmTrigger::Camera { camera_id: 1 }, Position::new(0.0, 0.0));
+
+        // Held while a guard is still hunting
+        assert_eq!(alarm.tick(100.0, true), None);
+
+        assert_eq!(alarm.tick(AlarmLevel::Alert.cooldown(), false), Some(AlarmLevel::Caution));
+        assert_eq!(alarm.tick(AlarmLevel::Caution.cooldown(), false), Some(AlarmLevel::Calm));
+        assert!(alarm.last_known_position.is_none());
+        assert_eq!(alarm.tick(100.0, false), None);
+    }
+}
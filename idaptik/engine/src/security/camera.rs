@@ -0,0 +1,88 @@
+use crate::ai::steering::{rotate_towards, wrap_angle};
+use crate::game::types::*;
+use crate::stealth::suspicion::DetectionThresholds;
+use serde::{Deserialize, Serialize};
+
+/// Sweep behaviour and runtime progress of one security camera
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CameraRig {
+    pub camera_id: EntityId,
+    pub center_heading: f32, // Heading the sweep is centred on (radians)
+    pub sweep_arc: f32,      // Total angle covered by the sweep (radians)
+    pub sweep_speed: f32,    // Radians per second
+    pub pause_time: f32,     // Seconds held at each end of the sweep
+    pub thresholds: DetectionThresholds,
+
+    // Runtime progress
+    pub sweep_direction: f32, // +1.0 or -1.0
+    pub pause_remaining: f32,
+}
+
+impl CameraRig {
+    pub fn new(camera_id: EntityId, center_heading: f32, sweep_arc: f32, sweep_speed: f32, pause_time: f32) -> Self {
+        Self {
+            camera_id,
+            center_heading,
+            sweep_arc: sweep_arc.abs(),
+            sweep_speed: sweep_speed.abs(),
+            pause_time: pause_time.max(0.0),
+            thresholds: DetectionThresholds {
+                // Cameras are slower to be sure but never lose interest quickly
+                gain_rate: 0.4,
+                decay_rate: 0.1,
+                ..DetectionThresholds::default()
+            },
+            sweep_direction: 1.0,
+            pause_remaining: 0.0,
+        }
+    }
+}
+
+/// Rotate a camera back and forth across its sweep arc
+pub fn step_camera_sweep(camera: &mut Entity, rig: &mut CameraRig, delta_time: f32) {
+    // Fixed cameras just hold their heading
+    if rig.sweep_arc <= 0.0 || rig.sweep_speed <= 0.0 {
+        camera.rotation = rig.center_heading;
+        return;
+    }
+
+    if rig.pause_remaining > 0.0 {
+        rig.pause_remaining -= delta_time;
+        return;
+    }
+
+    let half_arc = rig.sweep_arc / 2.0;
+    let end = wrap_angle(rig.center_heading + half_arc * rig.sweep_direction);
+    camera.rotation = rotate_towards(camera.rotation, end, rig.sweep_speed * delta_time);
+
+    // Reached the end of the arc: hold, then sweep back
+    if wrap_angle(camera.rotation - end).abs() < f32::EPSILON {
+        rig.sweep_direction = -rig.sweep_direction;
+        rig.pause_remaining = rig.pause_time;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_camera_sweeps_and_pauses() {
+        let mut camera = Entity::new_camera(1, Position::new(0.0, 0.0), 0.0);
+        let mut rig = CameraRig::new(1, 0.0, 1.0, 1.0, 2.0);
+
+        // Half a radian to the first end of the arc
+        step_camera_sweep(&mut camera, &mut rig, 0.5);
+        assert!((camera.rotation - 0.5).abs() < 1e-5);
+        assert_eq!(rig.sweep_direction, -1.0);
+
+        // Holds during the pause
+        step_camera_sweep(&mut camera, &mut rig, 1.0);
+        assert!((camera.rotation - 0.5).abs() < 1e-5);
+
+        // Then sweeps back the other way
+        step_camera_sweep(&mut camera, &mut rig, 1.5);
+        step_camera_sweep(&mut camera, &mut rig, 0.5);
+        assert!(camera.rotation.abs() < 1e-5);
+    }
+}
@@ -0,0 +1,156 @@
+use crate::game::types::*;
+use serde::{Deserialize, Serialize};
+
+/// Extra reach beyond a door's radius for interacting with it
+pub const INTERACT_RANGE: f32 = 1.5;
+
+/// Current state of a door
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DoorState {
+    Open,
+    Closed,
+    Locked,
+    Hacked, // Forced open by the hacker; lockdowns can't shut it
+}
+
+/// What it takes to get a locked door open
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum UnlockRequirement {
+    None,
+    Keycard { keycard_id: u32 },
+    Hacker,
+}
+
+/// Outcome of an entity trying to use a door
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum InteractionResult {
+    Opened,
+    Closed,
+    Unlocked,      // Keycard accepted, door now open
+    MissingKeycard { keycard_id: u32 },
+    RequiresHacker,
+    AlreadyOpen,   // Hacked doors are held open
+    OutOfRange,
+    NothingToInteract,
+}
+
+/// Door state attached to an EntityType::Door entity
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Door {
+    pub door_id: EntityId,
+    pub state: DoorState,
+    pub requirement: UnlockRequirement,
+    pub radius: f32,
+    pub locks_on_lockdown: bool,
+    pub locked_by_alarm: bool,
+}
+
+impl Door {
+    pub fn new(door_id: EntityId, radius: f32, state: DoorState) -> Self {
+        Self {
+            door_id,
+            state,
+            requirement: UnlockRequirement::None,
+            radius,
+            locks_on_lockdown: true,
+            locked_by_alarm: false,
+        }
+    }
+
+    /// Closed and locked doors block movement, vision and sound
+    pub fn is_blocking(&self) -> bool {
+        matches!(self.state, DoorState::Closed | DoorState::Locked)
+    }
+
+    /// Obstacle equivalent of this door while it is shut
+    pub fn as_obstacle(&self, position: Position) -> Obstacle {
+        Obstacle {
+            position,
+            radius: self.radius,
+            blocks_vision: true,
+            blocks_sound: true,
+            provides_cover: true,
+        }
+    }
+
+    /// Try to open or close the door as an actor carrying `keycards`
+    pub fn interact(&mut self, keycards: &[u32]) -> InteractionResult {
+        match self.state {
+            DoorState::Open => {
+                self.state = DoorState::Closed;
+                InteractionResult::Closed
+            }
+            DoorState::Closed => {
+                self.state = DoorState::Open;
+                InteractionResult::Opened
+            }
+            DoorState::Hacked => InteractionResult::AlreadyOpen,
+            DoorState::Locked => match self.requirement {
+                UnlockRequirement::None if !self.locked_by_alarm => {
+                    self.state = DoorState::Open;
+                    InteractionResult::Unlocked
+                }
+                UnlockRequirement::Keycard { keycard_id } if keycards.contains(&keycard_id) => {
+                    self.state = DoorState::Open;
+                    self.locked_by_alarm = false;
+                    InteractionResult::Unlocked
+                }
+                UnlockRequirement::Keycard { keycard_id } => InteractionResult::MissingKeycard { keycard_id },
+                _ => InteractionResult::RequiresHacker,
+            },
+        }
+    }
+
+    /// Lock for an alarm lockdown; returns true if the state changed
+    pub fn lock_down(&mut self) -> bool {
+        if !self.locks_on_lockdown || matches!(self.state, DoorState::Locked | DoorState::Hacked) {
+            return false;
+        }
+        self.state = DoorState::Locked;
+        self.locked_by_alarm = true;
+        true
+    }
+
+    /// Undo a lockdown lock; returns true if the state changed
+    pub fn release_lockdown(&mut self) -> bool {
+        if !self.locked_by_alarm {
+            return false;
+        }
+        self.locked_by_alarm = false;
+        if self.state == DoorState::Locked {
+            self.state = DoorState::Closed;
+            return true;
+        }
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_keycard_door() {
+        let mut door = Door::new(1, 1.0, DoorState::Locked);
+        door.requirement = UnlockRequirement::Keycard { keycard_id: 7 };
+
+        assert_eq!(door.interact(&[]), InteractionResult::MissingKeycard { keycard_id: 7 });
+        assert!(door.is_blocking());
+        assert_eq!(door.interact(&[7]), InteractionResult::Unlocked);
+        assert!(!door.is_blocking());
+        assert_eq!(door.interact(&[7]), InteractionResult::Closed);
+    }
+
+    #[test]
+    fn test_hacker_door_and_lockdown() {
+        let mut door = Door::new(1, 1.0, DoorState::Locked);
+        door.requirement = UnlockRequirement::Hacker;
+        assert_eq!(door.interact(&[1, 2, 3]), InteractionResult::RequiresHacker);
+
+        let mut plain = Door::new(2, 1.0, DoorState::Open);
+        assert!(plain.lock_down());
+        assert_eq!(plain.interact(&[]), InteractionResult::RequiresHacker);
+        assert!(plain.release_lockdown());
+        assert_eq!(plain.state, DoorState::Closed);
+    }
+}
@@ -0,0 +1,9 @@
+pub mod alarm;
+pub mod camera;
+pub mod door;
+pub mod network;
+
+pub use alarm::*;
+pub use camera::*;
+pub use door::*;
+pub use network::*;
@@ -0,0 +1,341 @@
+use crate::game::types::EntityId;
+use crate::hacking::{Instruction, PuzzleRun, VmError};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// What a node in the device network controls
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DeviceKind {
+    Camera,
+    Door,
+    Light,
+    Terminal,
+}
+
+/// One hackable device, keyed by the entity it controls
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeviceNode {
+    pub device_id: EntityId,
+    pub kind: DeviceKind,
+    pub entry_point: bool, // Reachable from outside without a compromised neighbour
+    pub compromised: bool,
+    pub tampered: bool,    // Camera feed looped, light cut or door forced
+    #[serde(default)]
+    pub lock: Option<PuzzleRun>, // Puzzle to solve before the device gives in
+}
+
+impl DeviceNode {
+    pub fn new(device_id: EntityId, kind: DeviceKind) -> Self {
+        Self {
+            device_id,
+            kind,
+            // The hacker gets in through terminals and works outward along access links
+            entry_point: kind == DeviceKind::Terminal,
+            compromised: false,
+            tampered: false,
+            lock: None,
+        }
+    }
+}
+
+/// Access link between two devices; either end can be reached from the other
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DeviceLink {
+    pub a: EntityId,
+    pub b: EntityId,
+}
+
+impl DeviceLink {
+    /// The other end of this link, if it touches `device_id`
+    pub fn other(&self, device_id: EntityId) -> Option<EntityId> {
+        if self.a == device_id {
+            Some(self.b)
+        } else if self.b == device_id {
+            Some(self.a)
+        } else {
+            None
+        }
+    }
+}
+
+/// Graph of the level's cameras, doors, lights and terminals
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DeviceNetwork {
+    pub nodes: Vec<DeviceNode>,
+    pub links: Vec<DeviceLink>,
+    #[serde(default)]
+    pub open_access: bool, // Level flag: devices without a puzzle give in to a plain compromise
+}
+
+/// Typed hacker command, as sent over the Port protocol
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "command", rename_all = "snake_case")]
+pub enum HackCommand {
+    Compromise { device_id: EntityId },
+    Execute { device_id: EntityId, instruction: String }, // One move on a locked device's puzzle, e.g. "ADD x y"
+    Undo { device_id: EntityId },
+    LoopFeed { camera_id: EntityId },
+    RestoreFeed { camera_id: EntityId },
+    UnlockDoor { door_id: EntityId },
+    CutLights { light_id: EntityId },
+    RestoreLights { light_id: EntityId },
+}
+
+impl HackCommand {
+    /// Device the command works through
+    pub fn device_id(&self) -> EntityId {
+        match *self {
+            HackCommand::Compromise { device_id }
+            | HackCommand::Execute { device_id, .. }
+            | HackCommand::Undo { device_id } => device_id,
+            HackCommand::LoopFeed { camera_id } | HackCommand::RestoreFeed { camera_id } => camera_id,
+            HackCommand::UnlockDoor { door_id } => door_id,
+            HackCommand::CutLights { light_id } | HackCommand::RestoreLights { light_id } => light_id,
+        }
+    }
+}
+
+/// Why a hack command was refused
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum HackError {
+    NotAHacker(EntityId),     // Only a hacker entity can run commands
+    UnknownDevice(EntityId),
+    NotReachable(EntityId),   // No entry point or compromised neighbour leads here
+    NotCompromised(EntityId), // Compromise the device before using it
+    WrongDevice { device_id: EntityId, expected: DeviceKind },
+    Locked(EntityId),         // Solve the device's puzzle to compromise it
+    NoPuzzle(EntityId),       // Nothing to solve, and the level doesn't leave devices open
+    Puzzle { device_id: EntityId, error: VmError },
+}
+
+impl fmt::Display for HackError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HackError::NotAHacker(id) => write!(f, "entity {} is not a hacker", id),
+            HackError::UnknownDevice(id) => write!(f, "device {} is not on the network", id),
+            HackError::NotReachable(id) => write!(f, "device {} can't be reached yet", id),
+            HackError::NotCompromised(id) => write!(f, "device {} hasn't been compromised", id),
+            HackError::WrongDevice { device_id, expected } => {
+                write!(f, "device {} is not a {:?}", device_id, expected)
+            }
+            HackError::Locked(id) => write!(f, "device {} is locked behind a puzzle", id),
+            HackError::NoPuzzle(id) => write!(f, "device {} has no puzzle", id),
+            HackError::Puzzle { device_id, error } => write!(f, "device {}: {}", device_id, error),
+        }
+    }
+}
+
+impl std::error::Error for HackError {}
+
+impl DeviceNetwork {
+    /// Add a device; adding one that is already present leaves it as it was
+    pub fn add_node(&mut self, device_id: EntityId, kind: DeviceKind) {
+        if self.node(device_id).is_none() {
+            self.nodes.push(DeviceNode::new(device_id, kind));
+        }
+    }
+
+    /// Link two devices; false if either isn't on the network
+    pub fn link(&mut self, a: EntityId, b: EntityId) -> bool {
+        if a == b || self.node(a).is_none() || self.node(b).is_none() {
+            return false;
+        }
+        if !self.links.iter().any(|l| l.other(a) == Some(b)) {
+            self.links.push(DeviceLink { a, b });
+        }
+        true
+    }
+
+    pub fn node(&self, device_id: EntityId) -> Option<&DeviceNode> {
+        self.nodes.iter().find(|n| n.device_id == device_id)
+    }
+
+    pub fn node_mut(&mut self, device_id: EntityId) -> Option<&mut DeviceNode> {
+        self.nodes.iter_mut().find(|n| n.device_id == device_id)
+    }
+
+    pub fn neighbours(&self, device_id: EntityId) -> impl Iterator<Item = EntityId> + '_ {
+        self.links.iter().filter_map(move |l| l.other(device_id))
+    }
+
+    /// Whether the hacker can attempt to compromise this device now
+    pub fn is_reachable(&self, device_id: EntityId) -> bool {
+        let Some(node) = self.node(device_id) else {
+            return false;
+        };
+        node.entry_point
+            || self.neighbours(device_id).any(|n| self.node(n).is_some_and(|n| n.compromised))
+    }
+
+    /// Take over a reachable device; returns true if it wasn't already compromised
+    /// A device only gives in once its puzzle is solved, unless the level leaves
+    /// devices without one open
+    pub fn compromise(&mut self, device_id: EntityId) -> Result<bool, HackError> {
+        let node = self.reachable_node(device_id)?;
+        if node.compromised {
+            return Ok(false);
+        }
+        match &node.lock {
+            Some(run) if !run.is_solved() => return Err(HackError::Locked(device_id)),
+            None if !self.open_access => return Err(HackError::NoPuzzle(device_id)),
+            _ => {}
+        }
+        self.node_mut(device_id).expect("checked above").compromised = true;
+        Ok(true)
+    }
+
+    /// Make one move on a reachable device's puzzle; returns true once it is solved
+    pub fn execute(&mut self, device_id: EntityId, instruction: &str) -> Result<bool, HackError> {
+        let run = self.puzzle_mut(device_id)?;
+        let puzzle_error = |error| HackError::Puzzle { device_id, error };
+        let instruction = Instruction::parse(instruction).map_err(puzzle_error)?;
+        run.execute(instruction).map_err(puzzle_error)?;
+        Ok(run.is_solved())
+    }
+
+    /// Take back the last move on a device's puzzle
+    pub fn undo(&mut self, device_id: EntityId) -> Result<(), HackError> {
+        let run = self.puzzle_mut(device_id)?;
+        run.undo().map_err(|error| HackError::Puzzle { device_id, error })?;
+        Ok(())
+    }
+
+    fn reachable_node(&self, device_id: EntityId) -> Result<&DeviceNode, HackError> {
+        let node = self.node(device_id).ok_or(HackError::UnknownDevice(device_id))?;
+        if !self.is_reachable(device_id) {
+            return Err(HackError::NotReachable(device_id));
+        }
+        Ok(node)
+    }
+
+    fn puzzle_mut(&mut self, device_id: EntityId) -> Result<&mut PuzzleRun, HackError> {
+        self.reachable_node(device_id)?;
+        let node = self.node_mut(device_id).expect("checked above");
+        if node.compromised {
+            // Nothing left to solve
+            return Err(HackError::NoPuzzle(device_id));
+        }
+        node.lock.as_mut().ok_or(HackError::NoPuzzle(device_id))
+    }
+
+    /// Next device on the shortest path of links from `from` to `to`
+    /// None if `to` can't be reached, or `from` is already there
+    pub fn next_hop(&self, from: EntityId, to: EntityId) -> Option<EntityId> {
+        if from == to {
+            return None;
+        }
+        // Breadth-first from the destination, so the first time `from` is reached we know its step
+        let mut visited = vec![to];
+        let mut frontier = std::collections::VecDeque::from([to]);
+        while let Some(device_id) = frontier.pop_front() {
+            for neighbour in self.neighbours(device_id) {
+                if neighbour == from {
+                    return Some(device_id);
+                }
+                if !visited.contains(&neighbour) {
+                    visited.push(neighbour);
+                    frontier.push_back(neighbour);
+                }
+            }
+        }
+        None
+    }
+
+    /// Throw the hacker out of every device past the entry points, undoing puzzle progress
+    /// Returns the devices locked again
+    pub fn relock(&mut self) -> Vec<EntityId> {
+        let mut relocked = Vec::new();
+        for node in self.nodes.iter_mut().filter(|n| n.compromised && !n.entry_point) {
+            node.compromised = false;
+            node.tampered = false;
+            if let Some(run) = &mut node.lock {
+                run.reset();
+            }
+            relocked.push(node.device_id);
+        }
+        relocked
+    }
+
+    /// A compromised device of the given kind, ready to be used
+    pub fn controlled(&mut self, device_id: EntityId, kind: DeviceKind) -> Result<&mut DeviceNode, HackError> {
+        let node = self.node_mut(device_id).ok_or(HackError::UnknownDevice(device_id))?;
+        if node.kind != kind {
+            return Err(HackError::WrongDevice { device_id, expected: kind });
+        }
+        if !node.compromised {
+            return Err(HackError::NotCompromised(device_id));
+        }
+        Ok(node)
+    }
+
+    /// A camera showing a looped recording instead of what is in front of it
+    pub fn feed_looped(&self, camera_id: EntityId) -> bool {
+        self.is_tampered(camera_id, DeviceKind::Camera)
+    }
+
+    /// A light the hacker has switched off
+    pub fn light_cut(&self, light_id: EntityId) -> bool {
+        self.is_tampered(light_id, DeviceKind::Light)
+    }
+
+    fn is_tampered(&self, device_id: EntityId, kind: DeviceKind) -> bool {
+        self.node(device_id).is_some_and(|n| n.kind == kind && n.tampered)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hacking::Puzzle;
+
+    #[test]
+    fn test_compromise_spreads_along_links() {
+        let mut network = DeviceNetwork::default();
+        network.add_node(1, DeviceKind::Terminal);
+        network.add_node(2, DeviceKind::Camera);
+        network.add_node(3, DeviceKind::Door);
+        assert!(network.link(1, 2));
+        assert!(network.link(2, 3));
+        assert!(!network.link(2, 9));
+
+        assert_eq!(network.compromise(3), Err(HackError::NotReachable(3)));
+        assert_eq!(network.controlled(2, DeviceKind::Camera).unwrap_err(), HackError::NotCompromised(2));
+        assert_eq!(network.compromise(1), Err(HackError::NoPuzzle(1)), "devices need a puzzle by default");
+        network.open_access = true;
+        assert_eq!(network.compromise(1), Ok(true));
+        assert_eq!(network.compromise(2), Ok(true));
+        assert_eq!(network.compromise(2), Ok(false));
+
+        // A locked device needs its puzzle solved first
+        network.node_mut(3).unwrap().lock = Some(PuzzleRun::new(Puzzle {
+            name: "lock".into(),
+            description: String::new(),
+            difficulty: None,
+            initial_state: [("x".to_string(), 1), ("y".to_string(), 2)].into_iter().collect(),
+            goal_state: [("x".to_string(), 3), ("y".to_string(), 2)].into_iter().collect(),
+            max_moves: None,
+            optimal_moves: None,
+            allowed_instructions: None,
+            hints: Vec::new(),
+        }));
+        assert_eq!(network.compromise(3), Err(HackError::Locked(3)));
+        assert_eq!(network.execute(3, "ADD x y"), Ok(true));
+        assert_eq!(network.compromise(3), Ok(true));
+        assert_eq!(network.execute(3, "SUB x y"), Err(HackError::NoPuzzle(3)));
+
+        assert_eq!(network.next_hop(1, 3), Some(2));
+        assert_eq!(network.next_hop(3, 3), None);
+
+        network.controlled(2, DeviceKind::Camera).unwrap().tampered = true;
+        assert!(network.feed_looped(2));
+        assert!(!network.light_cut(2));
+        assert_eq!(
+            network.controlled(3, DeviceKind::Light).unwrap_err(),
+            HackError::WrongDevice { device_id: 3, expected: DeviceKind::Light }
+        );
+
+        assert_eq!(network.relock(), vec![2, 3]);
+        assert!(network.node(1).unwrap().compromised, "entry points stay open");
+        assert!(!network.feed_looped(2));
+    }
+}
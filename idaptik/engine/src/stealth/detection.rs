@@ -1,11 +1,11 @@
 use crate::game::types::*;
-use glam::Vec2;
+use std::borrow::Borrow;
 
 /// Calculate line-of-sight between two positions
 pub fn has_line_of_sight(
     from: &Position,
     to: &Position,
-    obstacles: &[Obstacle],
+    obstacles: impl IntoIterator<Item = impl Borrow<Obstacle>>,
 ) -> bool {
     let from_vec = from.to_vec2();
     let to_vec = to.to_vec2();
@@ -14,6 +14,7 @@ pub fn has_line_of_sight(
 
     // Check if any obstacle blocks the line
     for obstacle in obstacles {
+        let obstacle = obstacle.borrow();
         if !obstacle.blocks_vision {
             continue;
         }
@@ -47,6 +48,11 @@ pub fn calculate_visibility(
     target: &Entity,
     world: &World,
 ) -> f32 {
+    // A looped camera only shows old footage
+    if world.network.feed_looped(observer.id) {
+        return 0.0;
+    }
+
     // Distance factor (closer = more visible)
     let distance = observer.position.distance_2d(&target.position);
     if distance > observer.visibility_radius {
@@ -61,7 +67,7 @@ pub fn calculate_visibility(
     }
 
     // Check line of sight
-    if !has_line_of_sight(&observer.position, &target.position, &world.obstacles) {
+    if !has_line_of_sight(&observer.position, &target.position, world.blockers()) {
         return 0.0; // Blocked by obstacle
     }
 
@@ -92,9 +98,13 @@ pub fn calculate_light_exposure(
     entity: &Entity,
     world: &World,
 ) -> f32 {
-    let mut total_light = world.ambient_light;
+    let mut total_light = world.ambient_light_level();
 
     for light in &world.lights {
+        if light.light_id.is_some_and(|id| world.network.light_cut(id)) {
+            continue; // Switched off by the hacker
+        }
+
         let distance = entity.position.distance_to(&light.position);
         
         if distance > light.radius {
@@ -102,7 +112,7 @@ pub fn calculate_light_exposure(
         }
 
         // Check if light is blocked by obstacles
-        if !has_line_of_sight(&light.position, &entity.position, &world.obstacles) {
+        if !has_line_of_sight(&light.position, &entity.position, world.blockers()) {
             continue; // In shadow
         }
 
@@ -114,47 +124,83 @@ pub fn calculate_light_exposure(
     total_light.clamp(0.0, 1.0)
 }
 
-/// Calculate noise propagation
-pub fn calculate_noise_at_position(
+/// A sound made this tick, attributed to the entity that made it
+#[derive(Debug, Clone, Copy)]
+pub struct NoiseSource {
+    pub emitter_id: EntityId,
+    pub position: Position,
+    pub level: f32,
+}
+
+/// How loud a single noise source is at a listener position
+pub fn noise_loudness_at(
     position: &Position,
-    noise_sources: &[(Position, f32)], // (source position, noise level)
+    source_pos: &Position,
+    noise_level: f32,
     obstacles: &[Obstacle],
 ) -> f32 {
-    let mut total_noise = 0.0;
+    let distance = position.distance_2d(source_pos);
 
-    for (source_pos, noise_level) in noise_sources {
-        let distance = position.distance_2d(source_pos);
-        
-        // Sound attenuates with distance
-        let base_attenuation = (1.0 - (distance / 30.0).min(1.0)) * noise_level;
-        
-        // Count blocking obstacles
-        let mut blocking_factor = 1.0;
-        for obstacle in obstacles {
-            if !obstacle.blocks_sound {
-                continue;
-            }
+    // Sound attenuates with distance
+    let base_attenuation = (1.0 - (distance / 30.0).min(1.0)) * noise_level;
 
-            // Simple check: is obstacle between source and listener?
-            let to_obstacle = obstacle.position.to_vec2() - source_pos.to_vec2();
-            let to_listener = position.to_vec2() - source_pos.to_vec2();
-            
-            if to_obstacle.dot(to_listener) > 0.0 {
-                let obstacle_dist = source_pos.distance_2d(&obstacle.position);
-                let listener_dist = source_pos.distance_2d(position);
-                
-                if obstacle_dist < listener_dist {
-                    blocking_factor *= 0.7; // Each obstacle reduces sound by 30%
-                }
-            }
+    // Count blocking obstacles
+    let mut blocking_factor = 1.0;
+    for obstacle in obstacles {
+        if !obstacle.blocks_sound {
+            continue;
         }
 
-        total_noise += base_attenuation * blocking_factor;
+        // Simple check: is obstacle between source and listener?
+        let to_obstacle = obstacle.position.to_vec2() - source_pos.to_vec2();
+        let to_listener = position.to_vec2() - source_pos.to_vec2();
+
+        if to_obstacle.dot(to_listener) > 0.0 {
+            let obstacle_dist = source_pos.distance_2d(&obstacle.position);
+            let listener_dist = source_pos.distance_2d(position);
+
+            if obstacle_dist < listener_dist {
+                blocking_factor *= 0.7; // Each obstacle reduces sound by 30%
+            }
+        }
     }
 
+    base_attenuation * blocking_factor
+}
+
+/// Calculate noise propagation
+pub fn calculate_noise_at_position(
+    position: &Position,
+    noise_sources: &[(Position, f32)], // (source position, noise level)
+    obstacles: &[Obstacle],
+) -> f32 {
+    let total_noise: f32 = noise_sources.iter()
+        .map(|(source_pos, noise_level)| noise_loudness_at(position, source_pos, *noise_level, obstacles))
+        .sum();
+
     total_noise.clamp(0.0, 1.0)
 }
 
+/// Find the loudest sound a listener can hear, ignoring its own noise
+/// Returns the source and how loud it is at the listener
+pub fn loudest_noise_at(
+    listener: &Entity,
+    noise_sources: &[NoiseSource],
+    obstacles: &[Obstacle],
+) -> Option<(NoiseSource, f32)> {
+    noise_sources.iter()
+        .filter(|source| source.emitter_id != listener.id && source.level > 0.0)
+        .map(|source| {
+            let loudness = noise_loudness_at(&listener.position, &source.position, source.level, obstacles);
+            (*source, loudness.clamp(0.0, 1.0))
+        })
+        .filter(|(_, loudness)| *loudness > 0.0)
+        .fold(None, |loudest: Option<(NoiseSource, f32)>, candidate| match loudest {
+            Some(best) if best.1 >= candidate.1 => Some(best),
+            _ => Some(candidate),
+        })
+}
+
 /// Calculate noise level based on movement and stance
 pub fn calculate_movement_noise(entity: &Entity) -> f32 {
     let speed = entity.velocity.length();
@@ -191,7 +237,7 @@ pub fn is_in_cover(
 ) -> bool {
     let direction = (entity.position.to_vec2() - observer.position.to_vec2()).normalize();
     
-    for obstacle in &world.obstacles {
+    for obstacle in world.blockers() {
         if !obstacle.provides_cover {
             continue;
         }
@@ -233,6 +279,7 @@ pub fn update_detection(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use glam::Vec2;
 
     #[test]
     fn test_line_of_sight_clear() {
@@ -260,6 +307,21 @@ fn test_line_of_sight_blocked() {
         assert!(!has_line_of_sight(&from, &to, &obstacles));
     }
 
+    #[test]
+    fn test_loudest_noise_attribution() {
+        let listener = Entity::new_guard(1, Position::new(0.0, 0.0));
+        let sources = vec![
+            NoiseSource { emitter_id: 1, position: Position::new(0.0, 0.0), level: 1.0 },
+            NoiseSource { emitter_id: 2, position: Position::new(20.0, 0.0), level: 1.0 },
+            NoiseSource { emitter_id: 3, position: Position::new(5.0, 0.0), level: 0.8 },
+        ];
+
+        // The listener's own footsteps are ignored
+        let (source, loudness) = loudest_noise_at(&listener, &sources, &[]).unwrap();
+        assert_eq!(source.emitter_id, 3);
+        assert!(loudness > 0.5);
+    }
+
     #[test]
     fn test_movement_noise() {
         let mut entity = Entity::new_player(1, Position::new(0.0, 0.0));
@@ -1,3 +1,5 @@
 pub mod detection;
+pub mod suspicion;
 
 pub use detection::*;
+pub use suspicion::*;
@@ -0,0 +1,107 @@
+use crate::game::types::*;
+use serde::{Deserialize, Serialize};
+
+/// Default level at which an observer becomes suspicious
+pub const SUSPICION_THRESHOLD: f32 = 0.3;
+
+/// Default level at which an observer has fully spotted its target
+pub const DETECTION_THRESHOLD: f32 = 0.9;
+
+/// Level below which a target counts as hidden again
+pub const HIDDEN_THRESHOLD: f32 = 0.1;
+
+/// Per-observer detection thresholds
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct DetectionThresholds {
+    pub suspicious: f32,
+    pub detected: f32,
+    pub gain_rate: f32,  // Detection gained per second at full visibility
+    pub decay_rate: f32, // Detection lost per second out of sight
+}
+
+impl Default for DetectionThresholds {
+    fn default() -> Self {
+        Self {
+            suspicious: SUSPICION_THRESHOLD,
+            detected: DETECTION_THRESHOLD,
+            gain_rate: 0.5,  // Takes ~2 seconds to fully detect in ideal conditions
+            decay_rate: 0.2, // Takes 5 seconds to lose detection
+        }
+    }
+}
+
+/// How much one observer suspects one target
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SuspicionMeter {
+    pub observer_id: EntityId,
+    pub target_id: EntityId,
+    pub level: f32, // 0.0 = undetected, 1.0 = fully detected
+    pub last_seen: Option<Position>,
+    pub last_seen_time: f32,
+}
+
+impl SuspicionMeter {
+    pub fn new(observer_id: EntityId, target_id: EntityId) -> Self {
+        Self {
+            observer_id,
+            target_id,
+            level: 0.0,
+            last_seen: None,
+            last_seen_time: 0.0,
+        }
+    }
+}
+
+/// Raise or decay a meter based on how visible the target is to its observer
+pub fn update_suspicion(
+    meter: &mut SuspicionMeter,
+    thresholds: &DetectionThresholds,
+    visibility: f32,
+    target_position: Position,
+    time: f32,
+    delta_time: f32,
+) {
+    if visibility > HIDDEN_THRESHOLD {
+        meter.level += visibility * thresholds.gain_rate * delta_time;
+        meter.last_seen = Some(target_position);
+        meter.last_seen_time = time;
+    } else {
+        meter.level -= thresholds.decay_rate * delta_time;
+    }
+
+    meter.level = meter.level.clamp(0.0, 1.0);
+}
+
+/// Highest suspicion any observer holds for a target (what the HUD shows)
+pub fn aggregate_suspicion(meters: &[SuspicionMeter], target_id: EntityId) -> f32 {
+    meters.iter()
+        .filter(|m| m.target_id == target_id)
+        .map(|m| m.level)
+        .fold(0.0, f32::max)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_meters_are_independent() {
+        let thresholds = DetectionThresholds::default();
+        let mut seen_by = SuspicionMeter::new(1, 3);
+        let mut hidden_from = SuspicionMeter::new(2, 3);
+        hidden_from.level = 0.5;
+
+        // One guard gaining doesn't stop the other from decaying, and vice versa
+        update_suspicion(&mut seen_by, &thresholds, 1.0, Position::new(5.0, 5.0), 1.0, 1.0);
+        update_suspicion(&mut hidden_from, &thresholds, 0.0, Position::new(5.0, 5.0), 1.0, 1.0);
+
+        assert!((seen_by.level - 0.5).abs() < 1e-5);
+        assert!((hidden_from.level - 0.3).abs() < 1e-5);
+        assert!(seen_by.last_seen.is_some());
+        assert!(hidden_from.last_seen.is_none());
+
+        let meters = vec![seen_by, hidden_from];
+        assert!((aggregate_suspicion(&meters, 3) - 0.5).abs() < 1e-5);
+        assert_eq!(aggregate_suspicion(&meters, 4), 0.0);
+    }
+}
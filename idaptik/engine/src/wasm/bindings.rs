@@ -1,10 +1,18 @@
 use wasm_bindgen::prelude::*;
-use crate::game::{GameSystem, PlayerInput, GameEvent};
+use crate::ai::PatrolMode;
+use crate::game::{GameSystem, PlayerInput, Position};
+use crate::hacking::{Instruction, MarkerKind, Puzzle, PuzzleRun};
+use crate::mission::{MissionOrder, ObjectiveKind};
+use crate::net::{DeltaDecoder, DeltaEncoder, SnapshotDelta};
+use crate::security::{DoorState, HackCommand, UnlockRequirement};
 
 /// WASM-exported game instance
 #[wasm_bindgen]
 pub struct WasmGame {
     system: GameSystem,
+    encoder: DeltaEncoder,
+    decoder: DeltaDecoder,
+    puzzle: Option<PuzzleRun>, // Standalone minigame, not tied to a device
 }
 
 #[wasm_bindgen]
@@ -18,6 +26,9 @@ pub fn new(width: f32, height: f32) -> Self {
 
         Self {
             system: GameSystem::new(width, height),
+            encoder: DeltaEncoder::new(),
+            decoder: DeltaDecoder::new(),
+            puzzle: None,
         }
     }
 
@@ -33,6 +44,263 @@ pub fn spawn_guard(&mut self, x: f32, y: f32) -> u32 {
         self.system.spawn_guard(x, y)
     }
 
+    /// Spawn the hacker (the only entity that can run `hack` commands) and return their ID
+    #[wasm_bindgen(js_name = spawnHacker)]
+    pub fn spawn_hacker(&mut self, x: f32, y: f32) -> u32 {
+        self.system.spawn_hacker(x, y)
+    }
+
+    /// Spawn a security camera and return its ID
+    /// Sweeps `sweep_arc` radians around `heading`, pausing at each end
+    #[wasm_bindgen(js_name = spawnCamera)]
+    pub fn spawn_camera(
+        &mut self,
+        x: f32,
+        y: f32,
+        heading: f32,
+        sweep_arc: f32,
+        sweep_speed: f32,
+        pause_time: f32,
+    ) -> u32 {
+        self.system.spawn_camera(x, y, heading, sweep_arc, sweep_speed, pause_time)
+    }
+
+    /// Spawn a door and return its ID
+    #[wasm_bindgen(js_name = spawnDoor)]
+    pub fn spawn_door(&mut self, x: f32, y: f32, radius: f32, locked: bool) -> u32 {
+        let state = if locked { DoorState::Locked } else { DoorState::Closed };
+        self.system.spawn_door(x, y, radius, state, UnlockRequirement::None)
+    }
+
+    /// Require a keycard to unlock a door
+    #[wasm_bindgen(js_name = setDoorKeycard)]
+    pub fn set_door_keycard(&mut self, door_id: u32, keycard_id: u32) -> bool {
+        self.system.set_door_requirement(door_id, UnlockRequirement::Keycard { keycard_id })
+    }
+
+    /// Make a door openable only by the hacker
+    #[wasm_bindgen(js_name = setDoorHackerOnly)]
+    pub fn set_door_hacker_only(&mut self, door_id: u32) -> bool {
+        self.system.set_door_requirement(door_id, UnlockRequirement::Hacker)
+    }
+
+    /// Hand an entity a keycard
+    #[wasm_bindgen(js_name = giveKeycard)]
+    pub fn give_keycard(&mut self, entity_id: u32, keycard_id: u32) -> bool {
+        self.system.give_keycard(entity_id, keycard_id)
+    }
+
+    /// Use the nearest door
+    /// Returns the interaction event as JSON ("null" if the actor isn't a player or there are no doors)
+    #[wasm_bindgen]
+    pub fn interact(&mut self, actor_id: u32) -> String {
+        let event = self.system.interact(actor_id);
+        serde_json::to_string(&event).unwrap_or_else(|_| "null".to_string())
+    }
+
+    /// Spawn a light the hacker can cut and return its ID
+    #[wasm_bindgen(js_name = spawnLight)]
+    pub fn spawn_light(&mut self, x: f32, y: f32, radius: f32, intensity: f32) -> u32 {
+        self.system.spawn_light(x, y, radius, intensity)
+    }
+
+    /// Add an access link between two devices on the network
+    #[wasm_bindgen(js_name = connectDevices)]
+    pub fn connect_devices(&mut self, a: u32, b: u32) -> bool {
+        self.system.connect_devices(a, b)
+    }
+
+    /// Run a hacker command given as JSON, e.g. {"command": "loop_feed", "camera_id": 4}
+    /// Returns "null" on success, or why it was refused as JSON
+    #[wasm_bindgen]
+    pub fn hack(&mut self, hacker_id: u32, command_json: &str) -> String {
+        let result = serde_json::from_str::<HackCommand>(command_json)
+            .map_err(|e| serde_json::json!({ "InvalidCommand": e.to_string() }))
+            .and_then(|command| self.system.hack(hacker_id, command).map_err(|e| serde_json::json!(e)));
+        match result {
+            Ok(()) => "null".to_string(),
+            Err(error) => error.to_string(),
+        }
+    }
+
+    /// Get the device network (nodes and links) as JSON
+    #[wasm_bindgen(js_name = getNetwork)]
+    pub fn get_network(&self) -> String {
+        serde_json::to_string(&self.system.world.network).unwrap_or_else(|_| "{}".to_string())
+    }
+
+    /// Place a hacker marker given as JSON, e.g. {"marker": "tag", "entity_id": 3}
+    /// Returns the marker ID, or undefined if it was refused
+    #[wasm_bindgen(js_name = placeMarker)]
+    pub fn place_marker(&mut self, marker_json: &str) -> Option<u32> {
+        let kind: MarkerKind = serde_json::from_str(marker_json).ok()?;
+        self.system.place_marker(kind).ok()
+    }
+
+    /// Take a marker down before it runs out
+    #[wasm_bindgen(js_name = clearMarker)]
+    pub fn clear_marker(&mut self, marker_id: u32) -> bool {
+        self.system.clear_marker(marker_id)
+    }
+
+    /// Get the live pings, tags and route as JSON
+    #[wasm_bindgen(js_name = getMarkers)]
+    pub fn get_markers(&self) -> String {
+        serde_json::to_string(&self.system.world.markers.markers).unwrap_or_else(|_| "[]".to_string())
+    }
+
+    /// Get the hacker's sensor coverage and last-known ghosts as JSON
+    #[wasm_bindgen(js_name = getHackerIntel)]
+    pub fn get_hacker_intel(&self) -> String {
+        serde_json::to_string(&self.system.world.intel).unwrap_or_else(|_| "{}".to_string())
+    }
+
+    /// Get a hacker's trace meter and any ICE hunting them as JSON ("null" before they've hacked anything)
+    #[wasm_bindgen(js_name = getTrace)]
+    pub fn get_trace(&self, hacker_id: u32) -> String {
+        serde_json::to_string(&self.system.trace(hacker_id)).unwrap_or_else(|_| "null".to_string())
+    }
+
+    /// Lock a device behind a puzzle (puzzle JSON as in vm/data/puzzles)
+    /// Returns "null" on success, or why it was refused as JSON
+    #[wasm_bindgen(js_name = setDevicePuzzle)]
+    pub fn set_device_puzzle(&mut self, device_id: u32, puzzle_json: &str) -> String {
+        let result = Puzzle::from_json(puzzle_json)
+            .map_err(|e| e.to_string())
+            .and_then(|puzzle| {
+                if self.system.set_device_puzzle(device_id, puzzle) {
+                    Ok(())
+                } else {
+                    Err(format!("device {} is not on the network", device_id))
+                }
+            });
+        error_json(result)
+    }
+
+    /// Get the puzzle guarding a device as JSON, or "null" if it has none
+    #[wasm_bindgen(js_name = getDevicePuzzle)]
+    pub fn get_device_puzzle(&self, device_id: u32) -> String {
+        let lock = self.system.world.network.node(device_id).and_then(|n| n.lock.as_ref());
+        puzzle_json(lock)
+    }
+
+    /// Start the standalone puzzle minigame (puzzle JSON as in vm/data/puzzles)
+    /// Returns "null" on success, or why the puzzle couldn't be loaded as JSON
+    #[wasm_bindgen(js_name = loadPuzzle)]
+    pub fn load_puzzle(&mut self, puzzle_json: &str) -> String {
+        let result = Puzzle::from_json(puzzle_json).map(|puzzle| self.puzzle = Some(PuzzleRun::new(puzzle)));
+        error_json(result.map_err(|e| e.to_string()))
+    }
+
+    /// Run one instruction on the minigame puzzle, e.g. "ADD x y"
+    /// Returns "null" on success, or why it was refused as JSON
+    #[wasm_bindgen(js_name = puzzleExecute)]
+    pub fn puzzle_execute(&mut self, instruction: &str) -> String {
+        let Some(run) = self.puzzle.as_mut() else {
+            return error_json(Err("no puzzle loaded".to_string()));
+        };
+        let result = Instruction::parse(instruction).and_then(|instruction| run.execute(instruction));
+        error_json(result.map_err(|e| e.to_string()))
+    }
+
+    /// Undo the last minigame move
+    #[wasm_bindgen(js_name = puzzleUndo)]
+    pub fn puzzle_undo(&mut self) -> bool {
+        self.puzzle.as_mut().is_some_and(|run| run.undo().is_ok())
+    }
+
+    /// Undo every minigame move
+    #[wasm_bindgen(js_name = puzzleReset)]
+    pub fn puzzle_reset(&mut self) {
+        if let Some(run) = self.puzzle.as_mut() {
+            run.reset();
+        }
+    }
+
+    /// Get the minigame state (registers, goal, moves, unlocked hints) as JSON
+    #[wasm_bindgen(js_name = getPuzzle)]
+    pub fn get_puzzle(&self) -> String {
+        puzzle_json(self.puzzle.as_ref())
+    }
+
+    /// Spawn an item a player can steal and return its ID
+    #[wasm_bindgen(js_name = spawnItem)]
+    pub fn spawn_item(&mut self, x: f32, y: f32) -> u32 {
+        self.system.spawn_item(x, y)
+    }
+
+    /// Spawn a hackable terminal and return its ID
+    #[wasm_bindgen(js_name = spawnTerminal)]
+    pub fn spawn_terminal(&mut self, x: f32, y: f32) -> u32 {
+        self.system.spawn_terminal(x, y)
+    }
+
+    /// Start a mission; a time limit of zero or less means none
+    #[wasm_bindgen(js_name = startMission)]
+    pub fn start_mission(&mut self, ordered: bool, time_limit: f32, fail_on_caught: bool) {
+        let order = if ordered { MissionOrder::Ordered } else { MissionOrder::Parallel };
+        let time_limit = (time_limit > 0.0).then_some(time_limit);
+        self.system.start_mission(order, time_limit, fail_on_caught);
+    }
+
+    /// Add a reach-zone objective; returns its ID, or undefined without a mission
+    #[wasm_bindgen(js_name = addReachObjective)]
+    pub fn add_reach_objective(&mut self, x: f32, y: f32, radius: f32, optional: bool) -> Option<u32> {
+        let kind = ObjectiveKind::ReachZone { position: Position::new(x, y), radius };
+        self.system.add_objective(kind, optional)
+    }
+
+    /// Add a steal-item objective
+    #[wasm_bindgen(js_name = addStealObjective)]
+    pub fn add_steal_objective(&mut self, item_id: u32, optional: bool) -> Option<u32> {
+        self.system.add_objective(ObjectiveKind::StealItem { item_id }, optional)
+    }
+
+    /// Add a hack-terminal objective
+    #[wasm_bindgen(js_name = addHackObjective)]
+    pub fn add_hack_objective(&mut self, terminal_id: u32, optional: bool) -> Option<u32> {
+        self.system.add_objective(ObjectiveKind::HackTerminal { terminal_id }, optional)
+    }
+
+    /// Add an extraction objective
+    #[wasm_bindgen(js_name = addExtractObjective)]
+    pub fn add_extract_objective(&mut self, x: f32, y: f32, radius: f32, optional: bool) -> Option<u32> {
+        let kind = ObjectiveKind::Extract { position: Position::new(x, y), radius };
+        self.system.add_objective(kind, optional)
+    }
+
+    /// Get the current mission as JSON ("null" if none)
+    #[wasm_bindgen(js_name = getMission)]
+    pub fn get_mission(&self) -> String {
+        serde_json::to_string(&self.system.world.mission).unwrap_or_else(|_| "null".to_string())
+    }
+
+    /// Give a guard a patrol route (loops unless ping_pong is set)
+    #[wasm_bindgen(js_name = setPatrolRoute)]
+    pub fn set_patrol_route(&mut self, guard_id: u32, ping_pong: bool) -> bool {
+        let mode = if ping_pong { PatrolMode::PingPong } else { PatrolMode::Loop };
+        self.system.set_patrol_route(guard_id, mode)
+    }
+
+    /// Append a waypoint to a guard's patrol route
+    #[wasm_bindgen(js_name = addPatrolWaypoint)]
+    pub fn add_patrol_waypoint(
+        &mut self,
+        guard_id: u32,
+        x: f32,
+        y: f32,
+        dwell_time: f32,
+        look_around: f32,
+    ) -> bool {
+        self.system.add_patrol_waypoint(guard_id, x, y, dwell_time, look_around)
+    }
+
+    /// Remove a guard's patrol route
+    #[wasm_bindgen(js_name = clearPatrolRoute)]
+    pub fn clear_patrol_route(&mut self, guard_id: u32) -> bool {
+        self.system.clear_patrol_route(guard_id)
+    }
+
     /// Add an obstacle to the world
     #[wasm_bindgen(js_name = addObstacle)]
     pub fn add_obstacle(&mut self, x: f32, y: f32, radius: f32) {
@@ -45,6 +313,51 @@ pub fn add_light(&mut self, x: f32, y: f32, radius: f32, intensity: f32) {
         self.system.add_light(x, y, radius, intensity);
     }
 
+    /// Run on a fixed timestep (seconds); zero or less goes back to variable steps
+    #[wasm_bindgen(js_name = setFixedTimestep)]
+    pub fn set_fixed_timestep(&mut self, timestep: f32) {
+        self.system.set_fixed_timestep((timestep > 0.0).then_some(timestep));
+    }
+
+    /// Re-seed the simulation RNG (integer seeds up to 2^53)
+    #[wasm_bindgen(js_name = seedRng)]
+    pub fn seed_rng(&mut self, seed: f64) {
+        self.system.seed_rng(seed as u64);
+    }
+
+    /// Advance exactly one tick, ignoring the accumulator
+    /// Returns JSON array of game events
+    #[wasm_bindgen]
+    pub fn step(&mut self, delta_time: f32) -> String {
+        let events = self.system.step(delta_time);
+        serde_json::to_string(&events).unwrap_or_else(|_| "[]".to_string())
+    }
+
+    /// Start recording player inputs for a replay from the current state
+    #[wasm_bindgen(js_name = startRecording)]
+    pub fn start_recording(&mut self) {
+        self.system.start_recording();
+    }
+
+    /// Stop recording and return the replay file as JSON ("null" if not recording)
+    #[wasm_bindgen(js_name = stopRecording)]
+    pub fn stop_recording(&mut self) -> String {
+        let replay = self.system.stop_recording();
+        serde_json::to_string(&replay).unwrap_or_else(|_| "null".to_string())
+    }
+
+    /// Number of simulation ticks run so far
+    #[wasm_bindgen(js_name = getTick)]
+    pub fn get_tick(&self) -> f64 {
+        self.system.tick as f64
+    }
+
+    /// Fraction of a fixed tick banked since the last one, for render interpolation
+    #[wasm_bindgen(js_name = getInterpolationAlpha)]
+    pub fn get_interpolation_alpha(&self) -> f32 {
+        self.system.interpolation_alpha()
+    }
+
     /// Update game state
     /// Returns JSON array of game events
     #[wasm_bindgen]
@@ -80,15 +393,122 @@ pub fn get_state(&self) -> String {
         self.system.serialize_state().unwrap_or_else(|_| "{}".to_string())
     }
 
+    /// Get entity changes since the last acknowledged snapshot as JSON
+    /// (a full snapshot until one is acknowledged)
+    #[wasm_bindgen(js_name = getStateDelta)]
+    pub fn get_state_delta(&mut self) -> String {
+        let delta = self.encoder.encode(self.system.world_snapshot());
+        serde_json::to_string(&delta).unwrap_or_else(|_| "{}".to_string())
+    }
+
+    /// The peer has applied the snapshot for `tick`; later deltas build on it
+    #[wasm_bindgen(js_name = ackState)]
+    pub fn ack_state(&mut self, tick: f64) {
+        self.encoder.acknowledge(tick as u64);
+    }
+
+    /// Apply a delta from the server to the local world
+    /// Returns the tick to acknowledge, or -1 if the delta couldn't be applied
+    #[wasm_bindgen(js_name = applyStateDelta)]
+    pub fn apply_state_delta(&mut self, delta_json: &str) -> f64 {
+        let Ok(delta) = serde_json::from_str::<SnapshotDelta>(delta_json) else {
+            return -1.0;
+        };
+        match self.decoder.apply(&delta) {
+            Ok(snapshot) => {
+                snapshot.apply_to_world(&mut self.system.world);
+                snapshot.tick as f64
+            }
+            Err(_) => -1.0,
+        }
+    }
+
     /// Set game state from JSON (for network sync)
     #[wasm_bindgen(js_name = setState)]
     pub fn set_state(&mut self, state_json: &str) -> bool {
         self.system.deserialize_state(state_json).is_ok()
     }
 
+    /// Report that an object was tampered with, raising the alarm
+    #[wasm_bindgen(js_name = reportTampering)]
+    pub fn report_tampering(&mut self, entity_id: u32) -> bool {
+        self.system.report_tampering(entity_id).is_some()
+    }
+
+    /// Get the level-wide alarm state as JSON
+    #[wasm_bindgen(js_name = getAlarm)]
+    pub fn get_alarm(&self) -> String {
+        serde_json::to_string(&self.system.world.alarm).unwrap_or_else(|_| "{}".to_string())
+    }
+
+    /// Get every guard's suspicion of a player as JSON
+    #[wasm_bindgen(js_name = getSuspicion)]
+    pub fn get_suspicion(&self, target_id: u32) -> String {
+        let meters = self.system.world.suspicion_of(target_id);
+        serde_json::to_string(&meters).unwrap_or_else(|_| "[]".to_string())
+    }
+
+    /// Get the highest suspicion any guard holds for a player (HUD meter)
+    #[wasm_bindgen(js_name = getDetectionLevel)]
+    pub fn get_detection_level(&self, target_id: u32) -> f32 {
+        self.system.world.aggregate_detection(target_id)
+    }
+
     /// Get game time
     #[wasm_bindgen(js_name = getTime)]
     pub fn get_time(&self) -> f32 {
         self.system.time
     }
 }
+
+/// "null" on success, otherwise the error as a JSON string
+fn error_json(result: Result<(), String>) -> String {
+    match result {
+        Ok(()) => "null".to_string(),
+        Err(error) => serde_json::json!(error).to_string(),
+    }
+}
+
+/// What the browser needs to draw a puzzle in progress
+fn puzzle_json(run: Option<&PuzzleRun>) -> String {
+    let Some(run) = run else {
+        return "null".to_string();
+    };
+    serde_json::json!({
+        "name": run.puzzle.name,
+        "description": run.puzzle.description,
+        "registers": run.registers,
+        "goal": run.puzzle.goal_state,
+        "allowedInstructions": run.puzzle.allowed_instructions,
+        "history": run.history.iter().map(|i| i.to_string()).collect::<Vec<_>>(),
+        "moves": run.moves(),
+        "movesLeft": run.moves_left(),
+        "solved": run.is_solved(),
+        "hints": run.hints().map(|h| &h.text).collect::<Vec<_>>(),
+    })
+    .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_spawned_hacker_can_hack() {
+        let mut game = WasmGame::new(100.0, 100.0);
+        let hacker_id = game.spawn_hacker(0.0, 0.0);
+        let player_id = game.spawn_player(10.0, 10.0);
+        let terminal_id = game.spawn_terminal(50.0, 50.0);
+        let puzzle = r#"{"name": "lock", "initialState": {"x": 5, "y": 3}, "goalState": {"x": 8, "y": 3}}"#;
+        assert_eq!(game.set_device_puzzle(terminal_id, puzzle), "null");
+
+        let solve = format!(r#"{{"command": "execute", "device_id": {}, "instruction": "ADD x y"}}"#, terminal_id);
+        assert_eq!(game.hack(player_id, &solve), format!(r#"{{"NotAHacker":{}}}"#, player_id));
+        assert_eq!(game.hack(hacker_id, &solve), "null");
+
+        let network: serde_json::Value = serde_json::from_str(&game.get_network()).unwrap();
+        let terminal = network["nodes"].as_array().unwrap().iter().find(|n| n["device_id"] == terminal_id).unwrap();
+        assert_eq!(terminal["compromised"], true);
+        assert_ne!(game.get_trace(hacker_id), "null");
+    }
+}
@@ -149,8 +149,8 @@ let update = (game: t): unit => {
       Console.log(`Player ${Int.toString(player_id)} is now hidden`)
     | GuardAlerted({guard_id, position}) =>
       Console.log(`Guard ${Int.toString(guard_id)} alerted at (${Float.toString(position.x)}, ${Float.toString(position.y)})`)
-    | ObjectiveComplete({player_id}) =>
-      Console.log(`Player ${Int.toString(player_id)} completed objective!`)
+    | ObjectiveComplete({objective_id}) =>
+      Console.log(`Objective ${Int.toString(objective_id)} complete!`)
     }
   })
   
@@ -116,6 +116,7 @@ let render = (renderer: t, ~state: WasmEngine.gameState): unit => {
     let (bodyColor, outlineColor) = switch entity.entity_type {
     | "Player" => ("#4ecdc4", "#2a9d8f")
     | "Guard" => ("#ff6b6b", "#c92a2a")
+    | "Drone" => ("#ffa94d", "#e8590c")
     | _ => ("#888888", "#666666")
     }
     
@@ -27,6 +27,9 @@ and entity = {
   light_exposure: float,
   speed: float,
   health: float,
+  keycards: array<int>,
+  visible_to_hacker: bool,
+  visible_to_infiltrator: bool,
 }
 
 and position = {
@@ -53,22 +56,123 @@ type gameEvent =
   | PlayerDetected({player_id: int, guard_id: int, level: float})
   | PlayerHidden({player_id: int})
   | GuardAlerted({guard_id: int, position: position})
-  | ObjectiveComplete({player_id: int})
+  | GuardInvestigating({guard_id: int, position: position})
+  | GuardHunting({guard_id: int, target_id: int})
+  | GuardLostTarget({guard_id: int, last_known_position: position})
+  | GuardReturnedToPatrol({guard_id: int})
+  | NoiseHeard({guard_id: int, position: position, loudness: float})
+  | CameraAlarm({camera_id: int, player_id: int, position: position})
+  | AlarmTriggered({trigger: JSON.t, position: position, level: string})
+  | AlarmLevelChanged({previous: string, level: string})
+  | DoorInteraction({actor_id: int, door_id: int, result: JSON.t})
+  | DoorStateChanged({door_id: int, state: string})
+  | ObjectiveActivated({objective_id: int})
+  | ObjectiveComplete({objective_id: int, player_id: Nullable.t<int>})
+  | MissionComplete({bonus_completed: int})
+  | MissionFailed({reason: JSON.t})
+  | DeviceCompromised({device_id: int, kind: string})
+  | FeedLooped({camera_id: int, looped: bool})
+  | LightsCut({light_id: int, cut: bool})
+  | MarkerPlaced({marker_id: int, kind: JSON.t})
+  | MarkerRemoved({marker_id: int})
+  | TraceRaised({hacker_id: int, level: float})
+  | TraceStageChanged({hacker_id: int, previous: string, stage: string})
+  | DevicesRelocked({device_ids: array<int>})
+  | IceMoved({hacker_id: int, device_id: int})
+  | HackerTraced({hacker_id: int})
 
 @module("../engine/pkg/idaptik_engine.js")
 external make: (~width: float, ~height: float) => t = "WasmGame"
 
 @send external spawnPlayer: (t, ~x: float, ~y: float) => int = "spawnPlayer"
 @send external spawnGuard: (t, ~x: float, ~y: float) => int = "spawnGuard"
+@send external spawnHacker: (t, ~x: float, ~y: float) => int = "spawnHacker"
+@send
+external spawnCamera: (
+  t,
+  ~x: float,
+  ~y: float,
+  ~heading: float,
+  ~sweepArc: float,
+  ~sweepSpeed: float,
+  ~pauseTime: float,
+) => int = "spawnCamera"
+@send
+external spawnDoor: (t, ~x: float, ~y: float, ~radius: float, ~locked: bool) => int = "spawnDoor"
+@send external setDoorKeycard: (t, ~doorId: int, ~keycardId: int) => bool = "setDoorKeycard"
+@send external setDoorHackerOnly: (t, ~doorId: int) => bool = "setDoorHackerOnly"
+@send external giveKeycard: (t, ~entityId: int, ~keycardId: int) => bool = "giveKeycard"
+@send external interact: (t, ~actorId: int) => string = "interact"
+@send
+external spawnLight: (t, ~x: float, ~y: float, ~radius: float, ~intensity: float) => int =
+  "spawnLight"
+@send external connectDevices: (t, ~a: int, ~b: int) => bool = "connectDevices"
+@send external hack: (t, ~hackerId: int, ~commandJson: string) => string = "hack"
+@send external getNetwork: t => string = "getNetwork"
+@send external placeMarker: (t, ~markerJson: string) => option<int> = "placeMarker"
+@send external clearMarker: (t, ~markerId: int) => bool = "clearMarker"
+@send external getMarkers: t => string = "getMarkers"
+@send external getHackerIntel: t => string = "getHackerIntel"
+@send external getTrace: (t, ~hackerId: int) => string = "getTrace"
+@send
+external setDevicePuzzle: (t, ~deviceId: int, ~puzzleJson: string) => string = "setDevicePuzzle"
+@send external getDevicePuzzle: (t, ~deviceId: int) => string = "getDevicePuzzle"
+@send external loadPuzzle: (t, ~puzzleJson: string) => string = "loadPuzzle"
+@send external puzzleExecute: (t, ~instruction: string) => string = "puzzleExecute"
+@send external puzzleUndo: t => bool = "puzzleUndo"
+@send external puzzleReset: t => unit = "puzzleReset"
+@send external getPuzzle: t => string = "getPuzzle"
+@send external spawnItem: (t, ~x: float, ~y: float) => int = "spawnItem"
+@send external spawnTerminal: (t, ~x: float, ~y: float) => int = "spawnTerminal"
+@send
+external startMission: (t, ~ordered: bool, ~timeLimit: float, ~failOnCaught: bool) => unit =
+  "startMission"
+@send
+external addReachObjective: (t, ~x: float, ~y: float, ~radius: float, ~optional: bool) => option<int> =
+  "addReachObjective"
+@send
+external addStealObjective: (t, ~itemId: int, ~optional: bool) => option<int> = "addStealObjective"
+@send
+external addHackObjective: (t, ~terminalId: int, ~optional: bool) => option<int> = "addHackObjective"
+@send
+external addExtractObjective: (t, ~x: float, ~y: float, ~radius: float, ~optional: bool) => option<int> =
+  "addExtractObjective"
+@send external getMission: t => string = "getMission"
+@send
+external setPatrolRoute: (t, ~guardId: int, ~pingPong: bool) => bool = "setPatrolRoute"
+@send
+external addPatrolWaypoint: (
+  t,
+  ~guardId: int,
+  ~x: float,
+  ~y: float,
+  ~dwellTime: float,
+  ~lookAround: float,
+) => bool = "addPatrolWaypoint"
+@send external clearPatrolRoute: (t, ~guardId: int) => bool = "clearPatrolRoute"
 @send external addObstacle: (t, ~x: float, ~y: float, ~radius: float) => unit = "addObstacle"
 @send
 external addLight: (t, ~x: float, ~y: float, ~radius: float, ~intensity: float) => unit =
   "addLight"
 
 @send external update: (t, ~deltaTime: float) => string = "update"
+@send external step: (t, ~deltaTime: float) => string = "step"
+@send external setFixedTimestep: (t, ~timestep: float) => unit = "setFixedTimestep"
+@send external seedRng: (t, ~seed: float) => unit = "seedRng"
+@send external getTick: t => float = "getTick"
+@send external startRecording: t => unit = "startRecording"
+@send external stopRecording: t => string = "stopRecording"
+@send external getInterpolationAlpha: t => float = "getInterpolationAlpha"
 @send external getState: t => string = "getState"
 @send external setState: (t, ~stateJson: string) => bool = "setState"
+@send external getStateDelta: t => string = "getStateDelta"
+@send external ackState: (t, ~tick: float) => unit = "ackState"
+@send external applyStateDelta: (t, ~deltaJson: string) => float = "applyStateDelta"
 @send external getTime: t => float = "getTime"
+@send external reportTampering: (t, ~entityId: int) => bool = "reportTampering"
+@send external getAlarm: t => string = "getAlarm"
+@send external getSuspicion: (t, ~targetId: int) => string = "getSuspicion"
+@send external getDetectionLevel: (t, ~targetId: int) => float = "getDetectionLevel"
 
 @send
 external applyPlayerInput: (
@@ -105,6 +209,9 @@ let parseEvents = (json: string): array<gameEvent> => {
 // Create a new game instance with initial setup
 let createGame = (~width: float, ~height: float): t => {
   let game = make(~width, ~height)
+
+  // Simulate in fixed 60Hz ticks so every client stays in step regardless of frame rate
+  game->setFixedTimestep(~timestep=1.0 /. 60.0)
   
   // Set up initial world
   // Add some obstacles
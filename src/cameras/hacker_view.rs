@@ -1,20 +1,71 @@
 use bevy::prelude::*;
 use bevy::log::info;
 use bevy::gizmos::gizmos::Gizmos;
-use crate::game_state::{GameState, PlayerRole};
+use bevy::ecs::system::SystemParam;
+use bevy::window::PrimaryWindow;
+use std::f32::consts::{FRAC_PI_2, TAU};
+use idaptik_engine::hacking::{MarkerKind, TraceStage, ICE_THRESHOLD, MAX_ROUTE_POINTS, RELOCK_THRESHOLD, TRACE_MAX};
+use idaptik_engine::security::HackCommand;
+use idaptik_engine::Position;
+use crate::connection::ConnectionState;
+use crate::interpolation::Interpolation;
+use crate::game_state::{ClientMessage, EntityExt, EntityType, GameState, GameEntity, PlayerRole};
+use crate::port_communication::{reply, PortChannels};
+use super::{draw_markers, MARKER_COLOR};
+
+/// How close a click has to land to a device to select it
+const SELECT_RADIUS: f32 = 30.0;
+
+/// Device the hacker last clicked on
+#[derive(Resource, Debug, Default)]
+pub struct DeviceSelection(pub Option<u32>);
+
+fn is_device(entity: &GameEntity) -> bool {
+    matches!(
+        entity.entity_type,
+        EntityType::Camera | EntityType::Door | EntityType::LightSource | EntityType::Terminal
+    )
+}
+
+/// Waypoints the hacker is laying down before sharing them as a route
+#[derive(Resource, Debug, Default)]
+pub struct RouteDraft(pub Vec<Vec2>);
+
+fn is_taggable(entity: &GameEntity) -> bool {
+    matches!(entity.entity_type, EntityType::Guard | EntityType::Drone)
+}
 
 /// Tag component for the Hacker's camera
 #[derive(Component)]
 pub struct HackerCamera;
 
+/// Where the mouse points in the level, through the hacker's camera
+#[derive(SystemParam)]
+pub struct HackerCursor<'w, 's> {
+    windows: Query<'w, 's, &'static Window, With<PrimaryWindow>>,
+    cameras: Query<'w, 's, (&'static Camera, &'static GlobalTransform), With<HackerCamera>>,
+}
+
+impl HackerCursor<'_, '_> {
+    pub fn world_position(&self) -> Option<Vec2> {
+        let cursor = self.windows.single().ok().and_then(|w| w.cursor_position())?;
+        let (camera, transform) = self.cameras.single().ok()?;
+        camera.viewport_to_world_2d(transform, cursor).ok()
+    }
+}
+
 /// System to set up the Hacker's top-down orthographic camera
 pub fn setup_hacker_camera(mut commands: Commands, game_state: Res<GameState>) {
     if game_state.local_player_role != PlayerRole::Hacker {
         return;
     }
 
-    // Top-down orthographic camera
-    // Hacker sees the entire level from above
+    spawn_hacker_camera(&mut commands);
+}
+
+/// Top-down orthographic camera
+/// Hacker sees the entire level from above
+pub fn spawn_hacker_camera(commands: &mut Commands) {
     commands.spawn((
         Camera2d,
         Transform::from_xyz(640.0, 360.0, 999.0),
@@ -53,43 +104,316 @@ pub fn update_hacker_camera(
     }
 }
 
+/// Sensor coverage is drawn lit; what lies outside it is dimmed
+const COVERAGE_COLOR: Color = Color::srgba(1.0, 1.0, 0.7, 0.5);
+const DIMMED_ALPHA: f32 = 0.35;
+const GHOST_ALPHA: f32 = 0.2;
+
+fn entity_color(entity_type: EntityType) -> Color {
+    match entity_type {
+        EntityType::Player => Color::srgb(0.0, 0.8, 0.0),                          // Green
+        EntityType::Guard => Color::srgb(0.8, 0.0, 0.0),                           // Red
+        EntityType::Drone => Color::srgb(0.8, 0.4, 0.0),                           // Orange
+        EntityType::Door => Color::srgb(0.4, 0.4, 0.4),                            // Gray
+        EntityType::Wall | EntityType::CoverObject => Color::srgb(0.2, 0.2, 0.2),  // Dark gray
+        EntityType::Camera => Color::srgb(0.8, 0.8, 0.0),                          // Yellow
+        EntityType::Item | EntityType::Terminal => Color::srgb(0.0, 0.0, 0.8),     // Blue
+        EntityType::LightSource => Color::srgb(1.0, 0.9, 0.6),                     // Warm white
+        _ => Color::WHITE,
+    }
+}
+
+/// Area one compromised sensor covers, placed where its device stands now
+struct Coverage {
+    position: Vec2,
+    heading: f32,
+    range: f32,
+    field_of_view: f32,
+}
+
+impl Coverage {
+    fn contains(&self, point: Vec2) -> bool {
+        let offset = point - self.position;
+        if offset.length() > self.range {
+            return false;
+        }
+        self.field_of_view >= TAU
+            || offset.length() <= f32::EPSILON
+            || Vec2::from_angle(self.heading).angle_to(offset).abs() <= self.field_of_view / 2.0
+    }
+
+    fn draw(&self, gizmos: &mut Gizmos) {
+        if self.field_of_view >= TAU {
+            gizmos.circle_2d(self.position, self.range, COVERAGE_COLOR);
+            return;
+        }
+        // Arcs are centred on +Y, headings are measured from +X
+        let rotation = Rot2::radians(self.heading - FRAC_PI_2);
+        gizmos.arc_2d(Isometry2d::new(self.position, rotation), self.field_of_view, self.range, COVERAGE_COLOR);
+        for edge in [-0.5, 0.5] {
+            let direction = Vec2::from_angle(self.heading + edge * self.field_of_view);
+            gizmos.line_2d(self.position, self.position + direction * self.range, COVERAGE_COLOR);
+        }
+    }
+}
+
 /// System to render entities in top-down view
-/// Hacker sees ALL entities (strategic advantage)
+/// The hacker sees the map, the team and the devices, plus whatever compromised sensors pick up;
+/// everything outside their coverage is dimmed, and entities that left it linger as ghosts
 pub fn render_hacker_view(
     mut gizmos: Gizmos,
     game_state: Res<GameState>,
+    interpolation: Res<Interpolation>,
+    selection: Res<DeviceSelection>,
+    draft: Res<RouteDraft>,
 ) {
     if game_state.local_player_role != PlayerRole::Hacker {
         return;
     }
 
-    // Draw all entities from above
+    // What the infiltrator has been sent, and the route still being drawn
+    draw_markers(&mut gizmos, &game_state.markers);
+    gizmos.linestrip_2d(draft.0.iter().copied(), MARKER_COLOR.with_alpha(0.4));
+
+    // Light up what the hacked cameras and door sensors cover, following the devices as they turn
+    let coverage: Vec<Coverage> = game_state.intel.sensors.iter()
+        .filter_map(|sensor| {
+            let device = game_state.entities.iter().find(|e| e.id == sensor.sensor_id)?;
+            Some(Coverage {
+                position: interpolation.position(device),
+                heading: device.rotation,
+                range: sensor.range,
+                field_of_view: sensor.field_of_view,
+            })
+        })
+        .collect();
+    for area in &coverage {
+        area.draw(&mut gizmos);
+    }
+
+    // The ICE closing in on the hacker's connection
+    let ice = game_state.trace.as_ref().and_then(|t| t.ice);
+    if let Some(device) = ice.and_then(|id| game_state.entities.iter().find(|e| e.id == id)) {
+        gizmos.circle_2d(interpolation.position(device), 40.0, trace_color(TraceStage::Traced));
+    }
+
+    // Last known positions of what slipped out of coverage
+    for ghost in &game_state.intel.ghosts {
+        let position = Vec2::new(ghost.position.x, ghost.position.y);
+        gizmos.circle_2d(position, 20.0, entity_color(ghost.entity_type).with_alpha(GHOST_ALPHA));
+    }
+
+    // Draw what the hacker knows about from above
     for entity in &game_state.entities {
         if !entity.visible_to_hacker {
             continue;
         }
 
-        let color = match entity.entity_type {
-            crate::game_state::EntityType::Infiltrator => Color::srgb(0.0, 0.8, 0.0), // Green
-            crate::game_state::EntityType::Guard => Color::srgb(0.8, 0.0, 0.0),       // Red
-            crate::game_state::EntityType::Drone => Color::srgb(0.8, 0.4, 0.0),       // Orange
-            crate::game_state::EntityType::Door => Color::srgb(0.4, 0.4, 0.4),        // Gray
-            crate::game_state::EntityType::Wall => Color::srgb(0.2, 0.2, 0.2),        // Dark gray
-            crate::game_state::EntityType::Camera => Color::srgb(0.8, 0.8, 0.0),      // Yellow
-            crate::game_state::EntityType::Objective => Color::srgb(0.0, 0.0, 0.8),   // Blue
-            _ => Color::WHITE,
-        };
-
-        // Draw entity as circle from above
-        gizmos.circle_2d(entity.position, 20.0, color);
+        // Draw entity as circle from above, at its smoothed position
+        let position = interpolation.position(entity);
+        let lit = coverage.iter().any(|area| area.contains(position));
+        let color = entity_color(entity.entity_type);
+        let color = if lit { color } else { color.with_alpha(DIMMED_ALPHA) };
+        gizmos.circle_2d(position, 20.0, color);
+        if selection.0 == Some(entity.id) {
+            gizmos.circle_2d(position, 28.0, Color::srgb(0.0, 1.0, 1.0));
+        }
+        if game_state.is_tagged(entity.id) {
+            gizmos.circle_2d(position, 34.0, MARKER_COLOR);
+        }
 
         // Draw velocity vector for moving entities
-        if entity.velocity.length() > 0.1 {
+        if entity.vel().length() > 0.1 {
             gizmos.arrow_2d(
-                entity.position,
-                entity.position + entity.velocity.normalize() * 40.0,
+                position,
+                position + entity.vel().normalize() * 40.0,
                 color,
             );
         }
     }
 }
+
+/// Trace meter size and distance from the top-left corner of the screen, in pixels
+const TRACE_BAR_SIZE: Vec2 = Vec2::new(240.0, 14.0);
+const TRACE_BAR_MARGIN: f32 = 20.0;
+
+fn trace_color(stage: TraceStage) -> Color {
+    match stage {
+        TraceStage::Clear => Color::srgb(0.0, 0.8, 0.4),
+        TraceStage::Relock => Color::srgb(0.9, 0.8, 0.0),
+        TraceStage::Hunted => Color::srgb(1.0, 0.5, 0.0),
+        TraceStage::Traced => Color::srgb(1.0, 0.1, 0.1),
+    }
+}
+
+/// System to draw the hacker's trace meter in the corner of the screen
+/// Ticks mark where devices re-lock and where ICE starts hunting
+pub fn draw_trace_meter(
+    mut gizmos: Gizmos,
+    game_state: Res<GameState>,
+    cameras: Query<(&Camera, &GlobalTransform), With<HackerCamera>>,
+) {
+    if game_state.local_player_role != PlayerRole::Hacker {
+        return;
+    }
+    let (Some(trace), Ok((camera, transform))) = (&game_state.trace, cameras.single()) else {
+        return;
+    };
+    // Pin the bar to the screen rather than the level, whichever way the camera has panned
+    let corner = Vec2::new(TRACE_BAR_MARGIN, TRACE_BAR_MARGIN + TRACE_BAR_SIZE.y / 2.0);
+    let Ok(left) = camera.viewport_to_world_2d(transform, corner) else {
+        return;
+    };
+    // The orthographic camera draws one level unit per logical pixel
+    let size = TRACE_BAR_SIZE;
+    let at = |fraction: f32| left + Vec2::X * size.x * fraction;
+
+    let color = trace_color(trace.stage);
+    gizmos.rect_2d(Isometry2d::from_translation(at(0.5)), size, color);
+    let filled = (trace.level / TRACE_MAX).clamp(0.0, 1.0);
+    for row in 0..size.y as i32 {
+        let y = Vec2::Y * (row as f32 - size.y / 2.0);
+        gizmos.line_2d(at(0.0) + y, at(filled) + y, color.with_alpha(0.6));
+    }
+    for threshold in [RELOCK_THRESHOLD, ICE_THRESHOLD] {
+        let x = at(threshold / TRACE_MAX);
+        gizmos.line_2d(x - Vec2::Y * size.y, x + Vec2::Y * size.y, Color::WHITE);
+    }
+}
+
+/// System for the hacker to pick a device by clicking near it
+pub fn select_device(
+    mut selection: ResMut<DeviceSelection>,
+    game_state: Res<GameState>,
+    interpolation: Res<Interpolation>,
+    mouse: Res<ButtonInput<MouseButton>>,
+    cursor: HackerCursor,
+) {
+    if game_state.local_player_role != PlayerRole::Hacker || !mouse.just_pressed(MouseButton::Left) {
+        return;
+    }
+    let Some(point) = cursor.world_position() else {
+        return;
+    };
+
+    selection.0 = game_state.entities.iter()
+        .filter(|e| is_device(e) && e.visible_to_hacker)
+        .map(|e| (e, interpolation.position(e).distance(point)))
+        .filter(|(_, distance)| *distance <= SELECT_RADIUS)
+        .min_by(|a, b| a.1.total_cmp(&b.1))
+        .map(|(e, _)| e.id);
+}
+
+/// System to send hack commands for the selected device to the server
+/// C compromises, E loops/unlocks/cuts, R restores a feed or light
+pub fn hack_devices(
+    selection: Res<DeviceSelection>,
+    game_state: Res<GameState>,
+    connection: Res<ConnectionState>,
+    port_channels: Res<PortChannels>,
+    keyboard: Res<ButtonInput<KeyCode>>,
+) {
+    if game_state.local_player_role != PlayerRole::Hacker
+        || !connection.session().is_some_and(|s| s.supports("devices"))
+    {
+        return;
+    }
+    let Some(device) = selection.0.and_then(|id| game_state.entities.iter().find(|e| e.id == id)) else {
+        return;
+    };
+
+    let id = device.id;
+    let command = if keyboard.just_pressed(KeyCode::KeyC) {
+        Some(HackCommand::Compromise { device_id: id })
+    } else if keyboard.just_pressed(KeyCode::KeyE) {
+        match device.entity_type {
+            EntityType::Camera => Some(HackCommand::LoopFeed { camera_id: id }),
+            EntityType::Door => Some(HackCommand::UnlockDoor { door_id: id }),
+            EntityType::LightSource => Some(HackCommand::CutLights { light_id: id }),
+            _ => None,
+        }
+    } else if keyboard.just_pressed(KeyCode::KeyR) {
+        match device.entity_type {
+            EntityType::Camera => Some(HackCommand::RestoreFeed { camera_id: id }),
+            EntityType::LightSource => Some(HackCommand::RestoreLights { light_id: id }),
+            _ => None,
+        }
+    } else {
+        None
+    };
+
+    if let Some(command) = command {
+        info!("Hack: {:?}", command);
+        reply(&port_channels, ClientMessage::hack(game_state.local_player_id, command));
+    }
+}
+
+fn can_mark(game_state: &GameState, connection: &ConnectionState) -> bool {
+    game_state.local_player_role == PlayerRole::Hacker
+        && connection.session().is_some_and(|s| s.supports("markers"))
+}
+
+/// System for the hacker to right-click a ping, or tag the guard under the cursor
+/// Right-clicking an existing ping takes it down
+pub fn place_markers(
+    game_state: Res<GameState>,
+    interpolation: Res<Interpolation>,
+    connection: Res<ConnectionState>,
+    port_channels: Res<PortChannels>,
+    mouse: Res<ButtonInput<MouseButton>>,
+    cursor: HackerCursor,
+) {
+    if !can_mark(&game_state, &connection) || !mouse.just_pressed(MouseButton::Right) {
+        return;
+    }
+    let Some(point) = cursor.world_position() else {
+        return;
+    };
+
+    let ping = game_state.markers.iter().find(|m| match m.kind {
+        MarkerKind::Ping { position } => Vec2::new(position.x, position.y).distance(point) <= SELECT_RADIUS,
+        _ => false,
+    });
+    if let Some(ping) = ping {
+        reply(&port_channels, ClientMessage::unmark(game_state.local_player_id, ping.marker_id));
+        return;
+    }
+
+    let target = game_state.entities.iter()
+        .filter(|e| is_taggable(e) && e.visible_to_hacker)
+        .map(|e| (e, interpolation.position(e).distance(point)))
+        .filter(|(_, distance)| *distance <= SELECT_RADIUS)
+        .min_by(|a, b| a.1.total_cmp(&b.1))
+        .map(|(e, _)| e.id);
+    let marker = match target {
+        Some(entity_id) => MarkerKind::Tag { entity_id },
+        None => MarkerKind::Ping { position: Position::new(point.x, point.y) },
+    };
+    reply(&port_channels, ClientMessage::mark(game_state.local_player_id, marker));
+}
+
+/// System for the hacker to draw a route: Q adds a waypoint at the cursor,
+/// Enter shares it with the infiltrator, Backspace starts over
+pub fn draw_route(
+    mut draft: ResMut<RouteDraft>,
+    game_state: Res<GameState>,
+    connection: Res<ConnectionState>,
+    port_channels: Res<PortChannels>,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    cursor: HackerCursor,
+) {
+    if !can_mark(&game_state, &connection) {
+        return;
+    }
+
+    if keyboard.just_pressed(KeyCode::KeyQ) && draft.0.len() < MAX_ROUTE_POINTS {
+        if let Some(point) = cursor.world_position() {
+            draft.0.push(point);
+        }
+    } else if keyboard.just_pressed(KeyCode::Backspace) {
+        draft.0.clear();
+    } else if keyboard.just_pressed(KeyCode::Enter) && !draft.0.is_empty() {
+        let points = draft.0.drain(..).map(|p| Position::new(p.x, p.y)).collect();
+        reply(&port_channels, ClientMessage::mark(game_state.local_player_id, MarkerKind::Route { points }));
+    }
+}
@@ -1,7 +1,9 @@
 use bevy::prelude::*;
 use bevy::log::info;
 use bevy::gizmos::gizmos::Gizmos;
-use crate::game_state::{GameState, PlayerRole, EntityType};
+use crate::interpolation::Interpolation;
+use crate::game_state::{local_infiltrator, EntityExt, GameState, PlayerRole, EntityType};
+use super::{draw_markers, MARKER_COLOR};
 
 /// Tag component for the Infiltrator's camera
 #[derive(Component)]
@@ -13,11 +15,14 @@ pub fn setup_infiltrator_camera(mut commands: Commands, game_state: Res<GameStat
         return;
     }
 
+    spawn_infiltrator_camera(&mut commands, &game_state);
+}
+
+/// Side-scrolling camera, starting over the infiltrator
+pub fn spawn_infiltrator_camera(commands: &mut Commands, game_state: &GameState) {
     // Find infiltrator starting position by entity type, not player_id
-    let player_pos = game_state.entities
-        .iter()
-        .find(|e| matches!(e.entity_type, EntityType::Infiltrator))
-        .map(|e| e.position)
+    let player_pos = local_infiltrator(&game_state.entities)
+        .map(|e| e.pos())
         .unwrap_or(Vec2::new(400.0, 300.0));
 
     // Side-scrolling camera follows the player
@@ -42,7 +47,7 @@ pub fn update_infiltrator_camera(
     };
 
     // Find the local player (infiltrator) by entity type
-    let Some(player) = game_state.entities.iter().find(|e| matches!(e.entity_type, EntityType::Infiltrator)) else {
+    let Some(player) = local_infiltrator(&game_state.entities) else {
         return;
     };
 
@@ -60,67 +65,68 @@ pub fn update_infiltrator_camera(
 pub fn render_infiltrator_view(
     mut gizmos: Gizmos,
     game_state: Res<GameState>,
+    interpolation: Res<Interpolation>,
 ) {
     if game_state.local_player_role != PlayerRole::Infiltrator {
         return;
     }
 
     // Find the local player position by entity type to determine visibility
-    let player_pos = game_state.entities
-        .iter()
-        .find(|e| matches!(e.entity_type, EntityType::Infiltrator))
-        .map(|e| e.position)
+    let player_pos = local_infiltrator(&game_state.entities)
+        .map(|e| e.pos())
         .unwrap_or(Vec2::ZERO);
 
-    let visibility_radius = 300.0; // Infiltrator's sight range
+    let fade_radius = 300.0; // Distance at which entities are drawn faintest
+
+    // The hacker's pings and route
+    draw_markers(&mut gizmos, &game_state.markers);
 
-    // Draw entities that are visible to infiltrator
+    // The server only sends what the infiltrator may know about, so nothing is culled here
     for entity in &game_state.entities {
         if !entity.visible_to_infiltrator {
             continue;
         }
 
-        // Check if entity is within visibility range
-        let distance = player_pos.distance(entity.position);
-        if distance > visibility_radius {
-            continue; // Too far to see
-        }
-
-        // Fade out entities at edge of vision
-        let visibility_factor = 1.0 - (distance / visibility_radius).clamp(0.0, 1.0);
+        // Fade out entities towards the edge of vision, using the smoothed position
+        let position = interpolation.position(entity);
+        let distance = player_pos.distance(position);
+        let visibility_factor = (1.0 - distance / fade_radius).clamp(0.25, 1.0);
 
         let color = match entity.entity_type {
             EntityType::Guard => Color::srgba(0.8, 0.0, 0.0, visibility_factor),       // Red
             EntityType::Drone => Color::srgba(0.8, 0.4, 0.0, visibility_factor),       // Orange
             EntityType::Door => Color::srgba(0.4, 0.4, 0.4, visibility_factor),        // Gray
-            EntityType::Wall => Color::srgba(0.2, 0.2, 0.2, visibility_factor),        // Dark gray
+            EntityType::Wall | EntityType::CoverObject => Color::srgba(0.2, 0.2, 0.2, visibility_factor), // Dark gray
             EntityType::Camera => Color::srgba(0.8, 0.8, 0.0, visibility_factor),      // Yellow
-            EntityType::Objective => Color::srgba(0.0, 0.0, 0.8, visibility_factor),   // Blue
+            EntityType::Item | EntityType::Terminal => Color::srgba(0.0, 0.0, 0.8, visibility_factor), // Blue
             _ => Color::srgba(1.0, 1.0, 1.0, visibility_factor),
         };
 
+        // Tagged guards are sent even through walls; draw them as silhouettes
+        let color = if game_state.is_tagged(entity.id) { MARKER_COLOR } else { color };
+
         // Draw entity as sprite/shape from side view
         // For now using simple shapes - replace with proper sprites later
         match entity.entity_type {
             EntityType::Guard | EntityType::Drone => {
                 // Draw as rectangle (placeholder for character sprite)
-                gizmos.rect_2d(entity.position, Vec2::new(40.0, 60.0), color);
+                gizmos.rect_2d(position, Vec2::new(40.0, 60.0), color);
             }
             EntityType::Door => {
                 // Draw as tall rectangle
-                gizmos.rect_2d(entity.position, Vec2::new(20.0, 100.0), color);
+                gizmos.rect_2d(position, Vec2::new(20.0, 100.0), color);
             }
-            EntityType::Wall => {
+            EntityType::Wall | EntityType::CoverObject => {
                 // Draw as solid rectangle
-                gizmos.rect_2d(entity.position, Vec2::new(40.0, 40.0), color);
+                gizmos.rect_2d(position, Vec2::new(40.0, 40.0), color);
             }
             _ => {
                 // Draw as circle for other entities
-                gizmos.circle_2d(entity.position, 20.0, color);
+                gizmos.circle_2d(position, 20.0, color);
             }
         }
     }
 
     // Optional: Uncomment to see visibility radius (debug)
-    // gizmos.circle_2d(player_pos, visibility_radius, Color::srgba(1.0, 1.0, 1.0, 0.1));
+    // gizmos.circle_2d(player_pos, fade_radius, Color::srgba(1.0, 1.0, 1.0, 0.1));
 }
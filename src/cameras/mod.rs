@@ -3,3 +3,56 @@
 
 pub use hacker_view::*;
 pub use infiltrator_view::*;
+
+use bevy::prelude::*;
+use bevy::gizmos::gizmos::Gizmos;
+use idaptik_engine::hacking::{Marker, MarkerKind};
+use crate::game_state::{GameState, PlayerRole};
+
+/// Colour shared by everything the hacker marks for the infiltrator
+pub const MARKER_COLOR: Color = Color::srgb(1.0, 0.0, 1.0); // Magenta
+
+/// Draw pings and the shared route; tagged entities are drawn by each view
+pub fn draw_markers(gizmos: &mut Gizmos, markers: &[Marker]) {
+    for marker in markers {
+        match &marker.kind {
+            MarkerKind::Ping { position } => {
+                let position = Vec2::new(position.x, position.y);
+                gizmos.circle_2d(position, 12.0, MARKER_COLOR);
+                gizmos.circle_2d(position, 24.0, MARKER_COLOR.with_alpha(0.5));
+            }
+            MarkerKind::Route { points } => {
+                let points: Vec<Vec2> = points.iter().map(|p| Vec2::new(p.x, p.y)).collect();
+                gizmos.linestrip_2d(points.iter().copied(), MARKER_COLOR);
+                for point in points {
+                    gizmos.circle_2d(point, 6.0, MARKER_COLOR);
+                }
+            }
+            MarkerKind::Tag { .. } => {}
+        }
+    }
+}
+
+/// Swap cameras when the server changes the local player's role
+pub fn switch_camera_on_role_change(
+    mut commands: Commands,
+    game_state: Res<GameState>,
+    hacker_cameras: Query<Entity, With<HackerCamera>>,
+    infiltrator_cameras: Query<Entity, With<InfiltratorCamera>>,
+) {
+    match game_state.local_player_role {
+        PlayerRole::Hacker if hacker_cameras.is_empty() => {
+            for camera in &infiltrator_cameras {
+                commands.entity(camera).despawn();
+            }
+            spawn_hacker_camera(&mut commands);
+        }
+        PlayerRole::Infiltrator if infiltrator_cameras.is_empty() => {
+            for camera in &hacker_cameras {
+                commands.entity(camera).despawn();
+            }
+            spawn_infiltrator_camera(&mut commands, &game_state);
+        }
+        _ => {}
+    }
+}
@@ -0,0 +1,150 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use crate::game_state::{ClientMessage, PlayerRole};
+use crate::wire::Protocol;
+
+// Version and optional features come from the engine, so client and server can't drift apart
+pub use idaptik_engine::net::{CAPABILITIES, PROTOCOL_VERSION};
+
+/// Oldest server schema we can still work with (degraded)
+/// v4 switched entities to the engine's model, so older servers can't be understood
+pub const MIN_PROTOCOL_VERSION: u32 = 4;
+
+/// Port connection as seen by Bevy systems
+#[derive(Resource, Debug, Clone, PartialEq, Default)]
+pub enum ConnectionState {
+    #[default]
+    Connecting, // Hello sent, waiting for the welcome
+    Ready(Session),
+    Disconnected { reason: String },
+}
+
+impl ConnectionState {
+    pub fn is_ready(&self) -> bool {
+        matches!(self, ConnectionState::Ready(_))
+    }
+
+    pub fn session(&self) -> Option<&Session> {
+        match self {
+            ConnectionState::Ready(session) => Some(session),
+            _ => None,
+        }
+    }
+}
+
+/// What both sides agreed on
+#[derive(Debug, Clone, PartialEq)]
+pub struct Session {
+    pub version: u32,
+    pub capabilities: Vec<String>,
+}
+
+impl Session {
+    pub fn supports(&self, capability: &str) -> bool {
+        self.capabilities.iter().any(|c| c == capability)
+    }
+}
+
+/// Sent by the core as soon as the Port opens
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HelloPayload {
+    pub version: u32,
+    pub min_version: u32,
+    pub encoding: String,       // Encoding this process was started with
+    pub encodings: Vec<String>, // Every encoding this build understands
+    pub role: PlayerRole,
+    pub player_id: u32,
+    pub capabilities: Vec<String>,
+}
+
+/// Server's answer to the hello
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WelcomePayload {
+    pub version: u32,
+    #[serde(default)]
+    pub encoding: Option<String>,
+    #[serde(default)]
+    pub capabilities: Vec<String>,
+}
+
+pub fn hello(protocol: Protocol, role: PlayerRole, player_id: u32) -> ClientMessage {
+    let payload = HelloPayload {
+        version: PROTOCOL_VERSION,
+        min_version: MIN_PROTOCOL_VERSION,
+        encoding: protocol.name().to_string(),
+        encodings: Protocol::ALL.iter().map(|p| p.name().to_string()).collect(),
+        role,
+        player_id,
+        capabilities: CAPABILITIES.iter().map(|c| c.to_string()).collect(),
+    };
+    ClientMessage::new("hello", player_id, serde_json::to_value(payload).unwrap_or_default())
+}
+
+/// Check the server's welcome; Err carries why the session can't go ahead
+pub fn negotiate(welcome: &WelcomePayload, protocol: Protocol) -> Result<Session, String> {
+    if !(MIN_PROTOCOL_VERSION..=PROTOCOL_VERSION).contains(&welcome.version) {
+        return Err(format!(
+            "server speaks protocol v{}, this client supports v{}..=v{}",
+            welcome.version, MIN_PROTOCOL_VERSION, PROTOCOL_VERSION,
+        ));
+    }
+    if let Some(encoding) = welcome.encoding.as_deref() {
+        if encoding != protocol.name() {
+            return Err(format!("server expects {} but the Port was opened with {}", encoding, protocol.name()));
+        }
+    }
+
+    // Only what both sides offered; anything else is simply not used
+    let capabilities = welcome.capabilities.iter()
+        .filter(|c| CAPABILITIES.contains(&c.as_str()))
+        .cloned()
+        .collect();
+
+    Ok(Session { version: welcome.version, capabilities })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn welcome(version: u32, encoding: Option<&str>, capabilities: &[&str]) -> WelcomePayload {
+        WelcomePayload {
+            version,
+            encoding: encoding.map(str::to_string),
+            capabilities: capabilities.iter().map(|c| c.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn test_version_must_be_in_range() {
+        assert!(negotiate(&welcome(PROTOCOL_VERSION, None, &[]), Protocol::Json).is_ok());
+        assert!(negotiate(&welcome(MIN_PROTOCOL_VERSION, None, &[]), Protocol::Json).is_ok());
+
+        let too_old = negotiate(&welcome(MIN_PROTOCOL_VERSION - 1, None, &[]), Protocol::Json).unwrap_err();
+        assert!(too_old.contains(&format!("v{}", MIN_PROTOCOL_VERSION - 1)), "{}", too_old);
+        assert!(negotiate(&welcome(PROTOCOL_VERSION + 1, None, &[]), Protocol::Json).is_err());
+    }
+
+    #[test]
+    fn test_encoding_must_match_the_port() {
+        for protocol in Protocol::ALL {
+            assert!(negotiate(&welcome(PROTOCOL_VERSION, Some(protocol.name()), &[]), protocol).is_ok());
+        }
+
+        let mismatch = negotiate(&welcome(PROTOCOL_VERSION, Some("flatbuffers"), &[]), Protocol::Json).unwrap_err();
+        assert!(mismatch.contains("flatbuffers") && mismatch.contains("json"), "{}", mismatch);
+
+        // A server that doesn't say is taken at its word
+        assert!(negotiate(&welcome(PROTOCOL_VERSION, None, &[]), Protocol::FlatBuffers).is_ok());
+    }
+
+    #[test]
+    fn test_capabilities_are_what_both_sides_offered() {
+        let session = negotiate(&welcome(PROTOCOL_VERSION, Some("json"), &["chat", "teleport", "prediction"]), Protocol::Json).unwrap();
+        assert_eq!(session.version, PROTOCOL_VERSION);
+        assert_eq!(session.capabilities, vec!["chat".to_string(), "prediction".to_string()]);
+        assert!(session.supports("prediction"));
+        assert!(!session.supports("teleport"));
+        assert!(!session.supports("markers"), "not offered by the server");
+    }
+}
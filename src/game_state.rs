@@ -1,43 +1,55 @@
 use bevy::prelude::*;
 use serde::{Deserialize, Serialize};
+use crate::connection::WelcomePayload;
+use idaptik_engine::net::SnapshotDelta;
 
-/// Player role determines which camera view to use
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Component)]
-pub enum PlayerRole {
-    Hacker,      // Top-down strategic view
-    Infiltrator, // Side-scrolling platformer view
+// The engine's entity model is shared with the server and the WASM frontend
+// (aliased because Bevy has its own `Entity`)
+pub use idaptik_engine::{Entity as GameEntity, EntityType, PlayerRole, Position};
+use idaptik_engine::hacking::{Marker, MarkerKind, TraceStage};
+use idaptik_engine::net::{LevelGeometry, Sensor, Sighting};
+use idaptik_engine::security::{DoorState, HackCommand, InteractionResult};
+use idaptik_engine::glam::Vec2 as SimVec2;
+
+/// Bevy vectors for engine positions (the crates are on different glam versions)
+pub trait EntityExt {
+    fn pos(&self) -> Vec2;
+    fn vel(&self) -> Vec2;
 }
 
-/// Game entity that exists in the world
-#[derive(Debug, Clone, Component, Serialize, Deserialize)]
-pub struct GameEntity {
-    pub id: u32,
-    pub entity_type: EntityType,
-    pub position: Vec2,
-    pub velocity: Vec2,
-    pub visible_to_hacker: bool,    // Hacker sees everything
-    pub visible_to_infiltrator: bool, // Infiltrator only sees nearby
+impl EntityExt for GameEntity {
+    fn pos(&self) -> Vec2 {
+        Vec2::new(self.position.x, self.position.y)
+    }
+
+    fn vel(&self) -> Vec2 {
+        Vec2::new(self.velocity.x, self.velocity.y)
+    }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
-pub enum EntityType {
-    Infiltrator,
-    Hacker,
-    Guard,
-    Drone,
-    Door,
-    Wall,
-    Camera,
-    Objective,
+/// The infiltrator this client controls (found by type, as the camera does)
+pub fn local_infiltrator(entities: &[GameEntity]) -> Option<&GameEntity> {
+    entities.iter().find(|e| e.entity_type.role() == Some(PlayerRole::Infiltrator))
 }
 
+/// Chat lines kept for display
+pub const CHAT_HISTORY: usize = 50;
+
 /// Shared game state synchronized from Elixir server
 #[derive(Debug, Clone, Resource)]
 pub struct GameState {
     pub entities: Vec<GameEntity>,
     pub local_player_id: u32,
     pub local_player_role: PlayerRole,
-    pub _world_bounds: Vec2,
+    pub players: Vec<PlayerJoinedPayload>, // Everyone in the session, including us
+    pub mission: Option<MissionStatePayload>,
+    pub chat: Vec<ChatPayload>, // Oldest first, capped at CHAT_HISTORY
+    pub markers: Vec<Marker>,   // Hacker's pings, tags and route, as the server last sent them
+    pub intel: HackerIntelPayload, // Hacker's sensor coverage and ghosts
+    pub trace: Option<TracePayload>, // Hacker's trace meter, once they have hacked anything
+    pub doors: Vec<DoorStatePayload>, // Doors whose state the server has told us about
+    pub interaction: Option<InteractionPayload>, // How our last interact went
+    pub world_bounds: Vec2,
 }
 
 impl Default for GameState {
@@ -45,67 +57,34 @@ fn default() -> Self {
         // Create some test entities so we can see the asymmetric views
         let test_entities = vec![
             // Player 1 - Infiltrator
-            GameEntity {
-                id: 1,
-                entity_type: EntityType::Infiltrator,
-                position: Vec2::new(400.0, 300.0),
-                velocity: Vec2::ZERO,
-                visible_to_hacker: true,
-                visible_to_infiltrator: true,
-            },
+            GameEntity::new_player(1, Position::new(400.0, 300.0)),
             // Player 2 - Hacker (doesn't render in their own view)
             GameEntity {
-                id: 2,
-                entity_type: EntityType::Hacker,
-                position: Vec2::new(960.0, 540.0),
-                velocity: Vec2::ZERO,
-                visible_to_hacker: true,
                 visible_to_infiltrator: false,
+                ..GameEntity::new_static(2, EntityType::Hacker, Position::new(960.0, 540.0))
             },
             // Guard 1
             GameEntity {
-                id: 3,
-                entity_type: EntityType::Guard,
-                position: Vec2::new(600.0, 300.0),
-                velocity: Vec2::new(-50.0, 0.0),
-                visible_to_hacker: true,
-                visible_to_infiltrator: true,
+                velocity: SimVec2::new(-50.0, 0.0),
+                ..GameEntity::new_guard(3, Position::new(600.0, 300.0))
             },
             // Guard 2
             GameEntity {
-                id: 4,
-                entity_type: EntityType::Guard,
-                position: Vec2::new(800.0, 400.0),
-                velocity: Vec2::new(50.0, 0.0),
-                visible_to_hacker: true,
+                velocity: SimVec2::new(50.0, 0.0),
                 visible_to_infiltrator: false, // Too far for infiltrator to see
+                ..GameEntity::new_guard(4, Position::new(800.0, 400.0))
             },
             // Door
-            GameEntity {
-                id: 5,
-                entity_type: EntityType::Door,
-                position: Vec2::new(500.0, 300.0),
-                velocity: Vec2::ZERO,
-                visible_to_hacker: true,
-                visible_to_infiltrator: true,
-            },
+            GameEntity::new_door(5, Position::new(500.0, 300.0)),
             // Camera (security)
             GameEntity {
-                id: 6,
-                entity_type: EntityType::Camera,
-                position: Vec2::new(700.0, 200.0),
-                velocity: Vec2::ZERO,
-                visible_to_hacker: true,
                 visible_to_infiltrator: false,
+                ..GameEntity::new_camera(6, Position::new(700.0, 200.0), 0.0)
             },
             // Objective
             GameEntity {
-                id: 7,
-                entity_type: EntityType::Objective,
-                position: Vec2::new(1200.0, 500.0),
-                velocity: Vec2::ZERO,
-                visible_to_hacker: true,
                 visible_to_infiltrator: false, // Far away
+                ..GameEntity::new_static(7, EntityType::Item, Position::new(1200.0, 500.0))
             },
         ];
 
@@ -113,7 +92,15 @@ fn default() -> Self {
             entities: test_entities,
             local_player_id: 0,
             local_player_role: PlayerRole::Infiltrator,
-            _world_bounds: Vec2::new(1920.0, 1080.0),
+            players: Vec::new(),
+            mission: None,
+            chat: Vec::new(),
+            markers: Vec::new(),
+            intel: HackerIntelPayload::default(),
+            trace: None,
+            doors: Vec::new(),
+            interaction: None,
+            world_bounds: Vec2::new(1920.0, 1080.0),
         }
     }
 }
@@ -128,11 +115,201 @@ pub struct ServerMessage {
 #[derive(Debug, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum MessageType {
+    Welcome, // Handshake reply to our hello
     StateUpdate,
+    StateDelta, // Delta-compressed entities against an acknowledged snapshot
     PlayerJoined,
     PlayerLeft,
     EntitySpawned,
     EntityRemoved,
+    RoleChanged,
+    MissionState,
+    Chat,
+    Ping,
+    InputAck, // Last input sequence the server has applied
+    Markers,  // Every live hacker marker, sent whenever they change
+    HackerIntel, // Hacker only: compromised sensors and ghosts, sent whenever they change
+    Trace,       // Hacker only: trace meter and hunting ICE, sent whenever they move
+    Interaction, // Outcome of our own interact
+    DoorState,   // A door was opened, shut, locked down or hacked
+    Level,       // Blocking geometry and tick length, for prediction
+}
+
+/// Typed body of a ServerMessage, one variant per MessageType
+#[derive(Debug, Clone)]
+pub enum ServerPayload {
+    Welcome(WelcomePayload),
+    StateUpdate(Vec<GameEntity>),
+    StateDelta(SnapshotDelta),
+    PlayerJoined(PlayerJoinedPayload),
+    PlayerLeft(PlayerLeftPayload),
+    EntitySpawned(GameEntity),
+    EntityRemoved(EntityRemovedPayload),
+    RoleChanged(RoleChangedPayload),
+    MissionState(MissionStatePayload),
+    Chat(ChatPayload),
+    Ping(PingPayload),
+    InputAck(InputAckPayload),
+    Markers(MarkersPayload),
+    HackerIntel(HackerIntelPayload),
+    Trace(TracePayload),
+    Interaction(InteractionPayload),
+    DoorState(DoorStatePayload),
+    Level(LevelGeometry),
+}
+
+impl ServerMessage {
+    /// Parse `data` into the payload its msg_type calls for
+    pub fn payload(&self) -> Result<ServerPayload, serde_json::Error> {
+        let data = self.data.clone();
+        Ok(match self.msg_type {
+            MessageType::Welcome => ServerPayload::Welcome(serde_json::from_value(data)?),
+            MessageType::StateUpdate => ServerPayload::StateUpdate(serde_json::from_value(data)?),
+            MessageType::StateDelta => ServerPayload::StateDelta(serde_json::from_value(data)?),
+            MessageType::PlayerJoined => ServerPayload::PlayerJoined(serde_json::from_value(data)?),
+            MessageType::PlayerLeft => ServerPayload::PlayerLeft(serde_json::from_value(data)?),
+            MessageType::EntitySpawned => ServerPayload::EntitySpawned(serde_json::from_value(data)?),
+            MessageType::EntityRemoved => ServerPayload::EntityRemoved(serde_json::from_value(data)?),
+            MessageType::RoleChanged => ServerPayload::RoleChanged(serde_json::from_value(data)?),
+            MessageType::MissionState => ServerPayload::MissionState(serde_json::from_value(data)?),
+            MessageType::Chat => ServerPayload::Chat(serde_json::from_value(data)?),
+            MessageType::Ping => ServerPayload::Ping(serde_json::from_value(data)?),
+            MessageType::InputAck => ServerPayload::InputAck(serde_json::from_value(data)?),
+            MessageType::Markers => ServerPayload::Markers(serde_json::from_value(data)?),
+            MessageType::HackerIntel => ServerPayload::HackerIntel(serde_json::from_value(data)?),
+            MessageType::Trace => ServerPayload::Trace(serde_json::from_value(data)?),
+            MessageType::Interaction => ServerPayload::Interaction(serde_json::from_value(data)?),
+            MessageType::DoorState => ServerPayload::DoorState(serde_json::from_value(data)?),
+            MessageType::Level => ServerPayload::Level(serde_json::from_value(data)?),
+        })
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlayerJoinedPayload {
+    pub player_id: u32,
+    pub role: PlayerRole,
+    #[serde(default)]
+    pub name: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlayerLeftPayload {
+    pub player_id: u32,
+}
+
+/// Accepts `{"entity_id": 5}` as well as the older bare `5`
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(from = "EntityRemovedWire")]
+pub struct EntityRemovedPayload {
+    pub entity_id: u32,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum EntityRemovedWire {
+    Id(u32),
+    Object { entity_id: u32 },
+}
+
+impl From<EntityRemovedWire> for EntityRemovedPayload {
+    fn from(wire: EntityRemovedWire) -> Self {
+        match wire {
+            EntityRemovedWire::Id(entity_id) | EntityRemovedWire::Object { entity_id } => Self { entity_id },
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RoleChangedPayload {
+    pub player_id: u32,
+    pub role: PlayerRole,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MissionStatus {
+    InProgress,
+    Succeeded,
+    Failed,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ObjectiveStatus {
+    pub objective_id: u32,
+    pub description: String,
+    pub complete: bool,
+    #[serde(default)]
+    pub optional: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MissionStatePayload {
+    pub status: MissionStatus,
+    #[serde(default)]
+    pub objectives: Vec<ObjectiveStatus>,
+    #[serde(default)]
+    pub time_remaining: Option<f32>, // Seconds, None if untimed
+    #[serde(default)]
+    pub failure_reason: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChatPayload {
+    pub player_id: u32,
+    pub text: String,
+}
+
+/// Liveness check; answered with a `pong` carrying the same nonce
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct PingPayload {
+    pub nonce: u64,
+}
+
+/// Sent before the state that reflects the acknowledged inputs
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct InputAckPayload {
+    pub sequence: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MarkersPayload {
+    pub markers: Vec<Marker>,
+}
+
+/// Sensors the hacker watches through, and where entities were last seen
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct HackerIntelPayload {
+    pub sensors: Vec<Sensor>,
+    pub ghosts: Vec<Sighting>,
+}
+
+/// How close security is to tracing the hacker, and where its ICE is
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TracePayload {
+    pub level: f32,
+    pub stage: TraceStage,
+    pub ice: Option<u32>, // Device the ICE sits on while it hunts
+}
+
+/// Reply to our interact; `door_id` is None when there was no door to use
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct InteractionPayload {
+    pub door_id: Option<u32>,
+    pub result: InteractionResult,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct DoorStatePayload {
+    pub door_id: u32,
+    pub state: DoorState,
+}
+
+impl GameState {
+    /// Whether the hacker has tagged this entity for the infiltrator
+    pub fn is_tagged(&self, entity_id: u32) -> bool {
+        self.markers.iter().any(|m| matches!(m.kind, MarkerKind::Tag { entity_id: id } if id == entity_id))
+    }
 }
 
 /// Message to Elixir server via stdout (Port communication)
@@ -142,3 +319,33 @@ pub struct ClientMessage {
     pub player_id: u32,
     pub data: serde_json::Value,
 }
+
+impl ClientMessage {
+    pub fn new(msg_type: &str, player_id: u32, data: serde_json::Value) -> Self {
+        Self { msg_type: msg_type.to_string(), player_id, data }
+    }
+
+    /// Hacker command against the device network
+    pub fn hack(player_id: u32, command: HackCommand) -> Self {
+        Self::new("hack", player_id, serde_json::to_value(command).unwrap_or_default())
+    }
+
+    /// Hacker marker for the infiltrator: a ping, a tagged guard or a route
+    pub fn mark(player_id: u32, marker: MarkerKind) -> Self {
+        Self::new("mark", player_id, serde_json::to_value(marker).unwrap_or_default())
+    }
+
+    /// Take down a marker before it runs out
+    pub fn unmark(player_id: u32, marker_id: u32) -> Self {
+        Self::new("unmark", player_id, serde_json::json!({ "marker_id": marker_id }))
+    }
+
+    /// Report a message from Elixir that couldn't be understood
+    /// `msg_type` is the offending message's type, if it got that far
+    pub fn error(player_id: u32, msg_type: Option<&str>, reason: impl ToString) -> Self {
+        Self::new("error", player_id, serde_json::json!({
+            "msg_type": msg_type,
+            "reason": reason.to_string(),
+        }))
+    }
+}
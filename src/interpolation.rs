@@ -0,0 +1,223 @@
+use bevy::prelude::*;
+use std::collections::{HashMap, VecDeque};
+use crate::game_state::{EntityExt, GameEntity, GameState};
+
+/// How far behind the newest state remote entities are drawn (seconds)
+pub const DEFAULT_INTERPOLATION_DELAY: f64 = 0.1;
+
+/// Longest gap bridged by extrapolating along the last known velocity (seconds)
+pub const MAX_EXTRAPOLATION: f64 = 0.25;
+
+/// Samples kept per entity
+pub const SAMPLE_HISTORY: usize = 32;
+
+/// How far each received state moves the server clock estimate towards what it implies
+pub const CLOCK_SMOOTHING: f64 = 0.1;
+
+/// A server clock estimate further off than this is reset rather than smoothed (seconds)
+pub const CLOCK_RESYNC: f64 = 1.0;
+
+/// Server position of one entity at the server time of its state
+#[derive(Debug, Clone, Copy)]
+pub struct EntitySample {
+    pub time: f64,
+    pub position: Vec2,
+    pub velocity: Vec2,
+}
+
+/// Smooths remote entities by drawing them slightly in the past between received states
+/// States are placed on the server's clock (tick × timestep), so network jitter doesn't become motion jitter
+#[derive(Resource, Debug)]
+pub struct Interpolation {
+    pub delay: f64,
+    pub max_extrapolation: f64,
+    timestep: Option<f64>,     // Server tick length, once the level has told us
+    clock_offset: Option<f64>, // Estimated server time minus local time
+    samples: HashMap<u32, VecDeque<EntitySample>>,
+    positions: HashMap<u32, Vec2>, // Where to draw each remote entity this frame
+}
+
+impl Default for Interpolation {
+    fn default() -> Self {
+        Self {
+            delay: DEFAULT_INTERPOLATION_DELAY,
+            max_extrapolation: MAX_EXTRAPOLATION,
+            timestep: None,
+            clock_offset: None,
+            samples: HashMap::new(),
+            positions: HashMap::new(),
+        }
+    }
+}
+
+impl Interpolation {
+    /// Place states on the server's fixed tick from now on
+    /// What was buffered on the local clock is dropped, as the two don't line up
+    pub fn set_timestep(&mut self, timestep: f32) {
+        self.timestep = Some(timestep as f64).filter(|t| *t > 0.0);
+        self.clock_offset = None;
+        self.samples.clear();
+    }
+
+    /// Buffer the state of server `tick`, received at local time `received`
+    /// Without a tick or a timestep the state is placed at the time it was received
+    /// `local_id` is predicted instead and skipped
+    pub fn record(&mut self, entities: &[GameEntity], tick: Option<u64>, received: f64, local_id: Option<u32>) {
+        let time = match (tick, self.timestep) {
+            (Some(tick), Some(timestep)) => tick as f64 * timestep,
+            _ => received,
+        };
+        self.sync_clock(time - received);
+
+        self.samples.retain(|id, _| entities.iter().any(|e| e.id == *id));
+        self.positions.retain(|id, _| entities.iter().any(|e| e.id == *id));
+
+        for entity in entities.iter().filter(|e| Some(e.id) != local_id) {
+            let buffer = self.samples.entry(entity.id).or_default();
+            buffer.push_back(EntitySample { time, position: entity.pos(), velocity: entity.vel() });
+            while buffer.len() > SAMPLE_HISTORY {
+                buffer.pop_front();
+            }
+        }
+
+        if let Some(id) = local_id {
+            self.samples.remove(&id);
+            self.positions.remove(&id);
+        }
+    }
+
+    /// Follow the server clock smoothly, so one late or early state barely moves it
+    fn sync_clock(&mut self, offset: f64) {
+        self.clock_offset = Some(match self.clock_offset {
+            Some(current) if (offset - current).abs() <= CLOCK_RESYNC => current + (offset - current) * CLOCK_SMOOTHING,
+            _ => offset,
+        });
+    }
+
+    /// Server time to draw remote entities at, `delay` behind the estimated server clock
+    pub fn render_time(&self, now: f64) -> f64 {
+        now + self.clock_offset.unwrap_or(0.0) - self.delay
+    }
+
+    /// Position of an entity at server time `time`, if it has been sampled
+    pub fn sample(&self, id: u32, time: f64) -> Option<Vec2> {
+        let buffer = self.samples.get(&id)?;
+        let oldest = buffer.front()?;
+        let newest = buffer.back()?;
+
+        if time <= oldest.time {
+            return Some(oldest.position);
+        }
+        if time >= newest.time {
+            // Nothing newer yet: carry on along the last velocity for a short while
+            let ahead = (time - newest.time).min(self.max_extrapolation) as f32;
+            return Some(newest.position + newest.velocity * ahead);
+        }
+
+        let (from, to) = buffer.iter()
+            .zip(buffer.iter().skip(1))
+            .find(|(_, to)| to.time >= time)?;
+        let span = to.time - from.time;
+        let t = if span > 0.0 { ((time - from.time) / span) as f32 } else { 1.0 };
+        Some(from.position.lerp(to.position, t))
+    }
+
+    /// Where to draw an entity this frame (its latest position if it isn't interpolated)
+    pub fn position(&self, entity: &GameEntity) -> Vec2 {
+        self.positions.get(&entity.id).copied().unwrap_or(entity.pos())
+    }
+}
+
+/// System to work out this frame's draw positions for remote entities
+pub fn update_interpolation(
+    mut interpolation: ResMut<Interpolation>,
+    game_state: Res<GameState>,
+    time: Res<Time>,
+) {
+    let render_time = interpolation.render_time(time.elapsed_secs_f64());
+    let positions: HashMap<u32, Vec2> = game_state.entities.iter()
+        .filter_map(|e| Some((e.id, interpolation.sample(e.id, render_time)?)))
+        .collect();
+    interpolation.positions = positions;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game_state::Position;
+    use idaptik_engine::glam::Vec2 as SimVec2;
+
+    fn guard(x: f32, velocity_x: f32) -> GameEntity {
+        let mut guard = GameEntity::new_guard(2, Position::new(x, 20.0));
+        guard.velocity = SimVec2::new(velocity_x, 0.0);
+        guard
+    }
+
+    /// Guard 2 received at x = 0 (t = 1) and x = 10 (t = 2), still moving at 4 per second
+    fn recorded() -> Interpolation {
+        let mut interpolation = Interpolation::default();
+        interpolation.record(&[guard(0.0, 10.0)], None, 1.0, None);
+        interpolation.record(&[guard(10.0, 4.0)], None, 2.0, None);
+        interpolation
+    }
+
+    #[test]
+    fn test_sample_interpolates_between_states() {
+        let interpolation = recorded();
+        assert_eq!(interpolation.sample(2, 1.5), Some(Vec2::new(5.0, 20.0)));
+        assert_eq!(interpolation.sample(2, 1.75), Some(Vec2::new(7.5, 20.0)));
+        assert_eq!(interpolation.sample(3, 1.5), None, "never received");
+    }
+
+    #[test]
+    fn test_sample_clamps_to_the_oldest_state() {
+        let interpolation = recorded();
+        assert_eq!(interpolation.sample(2, 0.5), Some(Vec2::new(0.0, 20.0)));
+        assert_eq!(interpolation.sample(2, 1.0), Some(Vec2::new(0.0, 20.0)));
+    }
+
+    #[test]
+    fn test_sample_caps_extrapolation() {
+        let interpolation = recorded();
+        assert_eq!(interpolation.sample(2, 2.0), Some(Vec2::new(10.0, 20.0)));
+        assert_eq!(interpolation.sample(2, 2.125), Some(Vec2::new(10.5, 20.0)));
+
+        // Past the cap the guard waits where it would have got to rather than wandering off
+        let capped = Vec2::new(10.0 + 4.0 * MAX_EXTRAPOLATION as f32, 20.0);
+        assert_eq!(interpolation.sample(2, 2.0 + MAX_EXTRAPOLATION), Some(capped));
+        assert_eq!(interpolation.sample(2, 10.0), Some(capped));
+    }
+
+    #[test]
+    fn test_predicted_and_departed_entities_are_not_sampled() {
+        let mut interpolation = recorded();
+        interpolation.record(&[guard(12.0, 0.0)], None, 3.0, Some(2));
+        assert_eq!(interpolation.sample(2, 2.5), None, "predicted locally instead");
+
+        let mut interpolation = recorded();
+        interpolation.record(&[], None, 3.0, None);
+        assert_eq!(interpolation.sample(2, 1.5), None);
+    }
+
+    #[test]
+    fn test_states_are_placed_on_the_server_clock() {
+        let mut interpolation = Interpolation::default();
+        interpolation.set_timestep(0.5);
+
+        // Ticks 2, 3 and 4 are half a second apart on the server; tick 3 arrives 0.4 seconds late
+        let server_clock = |interpolation: &Interpolation, now: f64| interpolation.render_time(now) + interpolation.delay;
+        interpolation.record(&[guard(0.0, 10.0)], Some(2), 10.0, None);
+        assert_eq!(server_clock(&interpolation, 10.0), 1.0);
+        interpolation.record(&[guard(5.0, 10.0)], Some(3), 10.9, None);
+        assert!((server_clock(&interpolation, 10.9) - (1.9 - 0.4 * CLOCK_SMOOTHING)).abs() < 1e-9, "only nudged");
+        interpolation.record(&[guard(10.0, 10.0)], Some(4), 11.0, None);
+
+        // Positions are where the server had them, however they arrived
+        assert_eq!(interpolation.sample(2, 1.25), Some(Vec2::new(2.5, 20.0)));
+        assert_eq!(interpolation.sample(2, 1.75), Some(Vec2::new(7.5, 20.0)));
+
+        // A server restart is too far off to smooth, so the clock starts over
+        interpolation.record(&[guard(0.0, 0.0)], Some(0), 12.0, None);
+        assert_eq!(server_clock(&interpolation, 12.0), 0.0);
+    }
+}
@@ -2,24 +2,42 @@
 use bevy::log::info;
 
 mod cameras;
+mod connection;
 mod game_state;
+mod interpolation;
 mod port_communication;
+mod prediction;
+mod snapshot;
+mod wire;
 
 use cameras::*;
+use connection::ConnectionState;
 use game_state::*;
+use interpolation::{Interpolation, update_interpolation};
 use port_communication::*;
+use prediction::Prediction;
+use snapshot::SnapshotReceiver;
+use wire::Protocol;
 
 fn main() {
     // Read player role from command line args
-    // Elixir will spawn with: ./idaptik-core --role hacker --player-id 1
+    // Elixir will spawn with: ./idaptik-core --role hacker --player-id 1 --protocol flatbuffers
     let args: Vec<String> = std::env::args().collect();
     let role = parse_role(&args);
     let player_id = parse_player_id(&args);
+    let protocol = parse_protocol(&args);
 
-    info!("Starting IDApTIK Core - Role: {:?}, Player ID: {}", role, player_id);
+    info!("Starting IDApTIK Core - Role: {:?}, Player ID: {}, Protocol: {:?}", role, player_id, protocol);
 
     // Initialize Port communication
-    let port_channels = init_port_communication();
+    let port_channels = init_port_communication(protocol, role, player_id);
+
+    let game_state = GameState {
+        local_player_role: role,
+        local_player_id: player_id,
+        ..default()
+    };
+    let prediction = Prediction::new(game_state.world_bounds);
 
     App::new()
         .add_plugins(DefaultPlugins.set(WindowPlugin {
@@ -31,13 +49,15 @@ fn main() {
             ..default()
         }))
         // Insert game state resource
-        .insert_resource(GameState {
-            local_player_role: role,
-            local_player_id: player_id,
-            ..default()
-        })
+        .insert_resource(game_state)
+        .insert_resource(prediction)
         // Insert Port communication channels
         .insert_resource(port_channels)
+        .init_resource::<ConnectionState>()
+        .init_resource::<SnapshotReceiver>()
+        .init_resource::<Interpolation>()
+        .init_resource::<DeviceSelection>()
+        .init_resource::<RouteDraft>()
         // Setup systems - run once at startup
         .add_systems(Startup, (
             setup_hacker_camera,
@@ -49,14 +69,20 @@ fn main() {
             receive_from_elixir,
             send_to_elixir,
             // Camera updates
+            switch_camera_on_role_change,
             update_hacker_camera,
             update_infiltrator_camera,
+            // Hacker actions on the device network
+            (select_device, hack_devices).chain(),
+            // Hacker pings, tags and routes for the infiltrator
+            place_markers,
+            draw_route,
         ))
         .add_systems(Update, (
-            // Rendering
-            render_hacker_view,
-            render_infiltrator_view,
-        ))
+            // Smooth remote entities, then draw them
+            update_interpolation.after(receive_from_elixir),
+            (render_hacker_view, draw_trace_meter, render_infiltrator_view),
+        ).chain())
         .run();
 }
 
@@ -93,3 +119,22 @@ fn parse_player_id(args: &[String]) -> u32 {
     eprintln!("No --player-id specified, defaulting to 0");
     0
 }
+
+/// Parse the Port wire protocol from command line arguments
+fn parse_protocol(args: &[String]) -> Protocol {
+    for i in 0..args.len() {
+        if args[i] == "--protocol" && i + 1 < args.len() {
+            return match args[i + 1].to_lowercase().as_str() {
+                "flatbuffers" => Protocol::FlatBuffers,
+                "json" => Protocol::Json,
+                _ => {
+                    eprintln!("Unknown protocol '{}', defaulting to json", args[i + 1]);
+                    Protocol::Json
+                }
+            };
+        }
+    }
+
+    // JSON stays the default so the binary can be driven by hand
+    Protocol::Json
+}
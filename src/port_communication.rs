@@ -1,10 +1,17 @@
 use bevy::prelude::*;
-use bevy::log::{warn, error};
-use std::io::{self, BufRead, Write};
+use idaptik_engine::PlayerInput;
+use bevy::log::{info, warn, error};
+use std::io;
 use std::sync::{Arc, Mutex};
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::mpsc::{channel, Receiver, Sender};
 use std::thread;
-use crate::game_state::{ServerMessage, ClientMessage, GameState, GameEntity};
+use crate::connection::{self, ConnectionState};
+use crate::game_state::{local_infiltrator, ServerMessage, ServerPayload, ClientMessage, GameState, PlayerRole, CHAT_HISTORY};
+use crate::interpolation::Interpolation;
+use crate::prediction::Prediction;
+use crate::snapshot::{snapshot_entities, SnapshotReceiver};
+use crate::wire::{self, Protocol};
 
 /// Resource that holds channels for Port communication
 /// Wrapped in Arc<Mutex<>> to make them thread-safe for Bevy
@@ -12,34 +19,41 @@
 pub struct PortChannels {
     pub to_elixir: Arc<Mutex<Sender<ClientMessage>>>,
     pub from_elixir: Arc<Mutex<Receiver<ServerMessage>>>,
+    pub closed: Arc<AtomicBool>, // Set once stdin or stdout is gone
+    pub protocol: Protocol,
 }
 
 /// Initialize Port communication channels
-/// Spawns background thread to handle stdin/stdout
-pub fn init_port_communication() -> PortChannels {
+/// Spawns background threads to handle stdin/stdout in the chosen wire protocol
+/// The hello is queued first; nothing else is sent until the server's welcome arrives
+pub fn init_port_communication(protocol: Protocol, role: PlayerRole, player_id: u32) -> PortChannels {
     let (tx_to_elixir, rx_to_elixir) = channel::<ClientMessage>();
     let (tx_from_elixir, rx_from_elixir) = channel::<ServerMessage>();
+    let tx_errors = tx_to_elixir.clone();
+    let closed = Arc::new(AtomicBool::new(false));
+    let reader_closed = closed.clone();
+    let writer_closed = closed.clone();
+
+    let _ = tx_to_elixir.send(connection::hello(protocol, role, player_id));
 
     // Spawn thread to read from stdin (messages FROM Elixir)
     thread::spawn(move || {
         let stdin = io::stdin();
         let mut reader = stdin.lock();
-        let mut buffer = String::new();
 
         loop {
-            buffer.clear();
-            match reader.read_line(&mut buffer) {
-                Ok(0) => break, // EOF - Elixir process closed
-                Ok(_) => {
-                    // Parse JSON message from Elixir
-                    match serde_json::from_str::<ServerMessage>(buffer.trim()) {
+            match wire::read_frame(&mut reader, protocol) {
+                Ok(None) => break, // EOF - Elixir process closed
+                Ok(Some(frame)) => {
+                    match wire::decode_server_message(&frame, protocol) {
                         Ok(msg) => {
                             if tx_from_elixir.send(msg).is_err() {
                                 break; // Game closed
                             }
                         }
                         Err(e) => {
-                            eprintln!("Failed to parse message from Elixir: {}", e);
+                            // Tell Elixir rather than dropping the message silently
+                            let _ = tx_errors.send(ClientMessage::error(player_id, None, e));
                         }
                     }
                 }
@@ -49,6 +63,7 @@ pub fn init_port_communication() -> PortChannels {
                 }
             }
         }
+        reader_closed.store(true, Ordering::Relaxed);
     });
 
     // Spawn thread to write to stdout (messages TO Elixir)
@@ -57,25 +72,25 @@ pub fn init_port_communication() -> PortChannels {
         let mut writer = stdout.lock();
 
         while let Ok(msg) = rx_to_elixir.recv() {
-            match serde_json::to_string(&msg) {
-                Ok(json) => {
-                    if writeln!(writer, "{}", json).is_err() {
+            match wire::encode_client_message(&msg, protocol) {
+                Ok(frame) => {
+                    if wire::write_frame(&mut writer, protocol, &frame).is_err() {
                         break; // Elixir process closed
                     }
-                    if writer.flush().is_err() {
-                        break;
-                    }
                 }
                 Err(e) => {
                     eprintln!("Failed to serialize message to Elixir: {}", e);
                 }
             }
         }
+        writer_closed.store(true, Ordering::Relaxed);
     });
 
     PortChannels {
         to_elixir: Arc::new(Mutex::new(tx_to_elixir)),
         from_elixir: Arc::new(Mutex::new(rx_from_elixir)),
+        closed,
+        protocol,
     }
 }
 
@@ -83,46 +98,226 @@ pub fn init_port_communication() -> PortChannels {
 pub fn receive_from_elixir(
     port_channels: Res<PortChannels>,
     mut game_state: ResMut<GameState>,
+    mut snapshots: ResMut<SnapshotReceiver>,
+    mut connection: ResMut<ConnectionState>,
+    mut prediction: ResMut<Prediction>,
+    mut interpolation: ResMut<Interpolation>,
+    time: Res<Time>,
 ) {
+    if matches!(*connection, ConnectionState::Disconnected { .. }) {
+        return;
+    }
+    if port_channels.closed.load(Ordering::Relaxed) {
+        *connection = ConnectionState::Disconnected { reason: "Port closed".to_string() };
+        return;
+    }
+
     // Lock the receiver
     let Ok(receiver) = port_channels.from_elixir.lock() else {
         return;
     };
 
+    // Set when the server sent positions our prediction has to be checked against
+    let mut authoritative = false;
+    let mut tick = None; // Server tick of those positions, when it said
+
     // Process all available messages from Elixir
     while let Ok(msg) = receiver.try_recv() {
-        match msg.msg_type {
-            crate::game_state::MessageType::StateUpdate => {
-                // Full state update from server
-                if let Ok(entities) = serde_json::from_value::<Vec<GameEntity>>(msg.data) {
-                    game_state.entities = entities;
+        let msg_type = serde_json::to_value(&msg.msg_type).ok();
+        let msg_type = msg_type.as_ref().and_then(|t| t.as_str());
+        let payload = match msg.payload() {
+            Ok(payload) => payload,
+            Err(e) => {
+                reply(&port_channels, ClientMessage::error(game_state.local_player_id, msg_type, e));
+                continue;
+            }
+        };
+
+        // Until the handshake completes only the welcome and pings are meaningful
+        if !connection.is_ready() && !matches!(payload, ServerPayload::Welcome(_) | ServerPayload::Ping(_)) {
+            reply(&port_channels, ClientMessage::error(
+                game_state.local_player_id,
+                msg_type,
+                "handshake not complete",
+            ));
+            continue;
+        }
+
+        // Optional messages need their capability to have been agreed on
+        let capability = match &payload {
+            ServerPayload::StateDelta(_) => Some("state_delta"),
+            ServerPayload::MissionState(_) => Some("mission_state"),
+            ServerPayload::Chat(_) => Some("chat"),
+            ServerPayload::InputAck(_) | ServerPayload::Level(_) => Some("prediction"),
+            ServerPayload::Markers(_) => Some("markers"),
+            ServerPayload::HackerIntel(_) => Some("intel"),
+            ServerPayload::Trace(_) => Some("trace"),
+            ServerPayload::Interaction(_) | ServerPayload::DoorState(_) => Some("doors"),
+            _ => None,
+        };
+        if let (Some(session), Some(capability)) = (connection.session(), capability) {
+            if !session.supports(capability) {
+                reply(&port_channels, ClientMessage::error(
+                    game_state.local_player_id,
+                    msg_type,
+                    format!("capability {} was not negotiated", capability),
+                ));
+                continue;
+            }
+        }
+
+        match payload {
+            ServerPayload::Welcome(welcome) => {
+                match connection::negotiate(&welcome, port_channels.protocol) {
+                    Ok(session) => {
+                        info!("Connected: protocol v{}, capabilities {:?}", session.version, session.capabilities);
+                        *connection = ConnectionState::Ready(session);
+                    }
+                    Err(reason) => {
+                        // Refuse: tell the server why and stop talking
+                        error!("Handshake failed: {}", reason);
+                        reply(&port_channels, ClientMessage::error(game_state.local_player_id, msg_type, &reason));
+                        *connection = ConnectionState::Disconnected { reason };
+                        return;
+                    }
                 }
             }
-            crate::game_state::MessageType::EntitySpawned => {
-                // New entity added
-                if let Ok(entity) = serde_json::from_value::<GameEntity>(msg.data) {
-                    game_state.entities.push(entity);
+            ServerPayload::StateUpdate(entities) => {
+                // Full state update from server
+                game_state.entities = entities;
+                authoritative = true;
+                tick = None;
+            }
+            ServerPayload::StateDelta(delta) => {
+                // Changes since a snapshot we acknowledged
+                match snapshots.apply(&delta) {
+                    Ok(snapshot) => {
+                        game_state.entities = snapshot_entities(snapshot);
+                        authoritative = true;
+                        tick = Some(snapshot.tick);
+                        let ack = ClientMessage::new(
+                            "state_ack",
+                            game_state.local_player_id,
+                            serde_json::json!({ "tick": snapshot.tick }),
+                        );
+                        reply(&port_channels, ack);
+                    }
+                    Err(e) => {
+                        // Without an ack the server falls back to an older baseline or a full snapshot
+                        warn!("Dropped state delta for tick {}: {:?}", delta.tick, e);
+                    }
                 }
             }
-            crate::game_state::MessageType::EntityRemoved => {
-                // Entity removed
-                if let Some(entity_id) = msg.data.as_u64() {
-                    game_state.entities.retain(|e| e.id != entity_id as u32);
+            ServerPayload::PlayerJoined(player) => {
+                info!("Player {} joined as {:?}", player.player_id, player.role);
+                game_state.players.retain(|p| p.player_id != player.player_id);
+                game_state.players.push(player);
+            }
+            ServerPayload::PlayerLeft(left) => {
+                info!("Player {} left", left.player_id);
+                game_state.players.retain(|p| p.player_id != left.player_id);
+            }
+            ServerPayload::EntitySpawned(entity) => {
+                // New entity added (replacing any stale copy)
+                game_state.entities.retain(|e| e.id != entity.id);
+                game_state.entities.push(entity);
+            }
+            ServerPayload::EntityRemoved(removed) => {
+                game_state.entities.retain(|e| e.id != removed.entity_id);
+            }
+            ServerPayload::RoleChanged(change) => {
+                if let Some(player) = game_state.players.iter_mut().find(|p| p.player_id == change.player_id) {
+                    player.role = change.role;
                 }
+                if change.player_id == game_state.local_player_id {
+                    info!("Local role changed to {:?}", change.role);
+                    game_state.local_player_role = change.role;
+                }
+            }
+            ServerPayload::MissionState(mission) => {
+                game_state.mission = Some(mission);
             }
-            _ => {
-                warn!("Unhandled message type: {:?}", msg.msg_type);
+            ServerPayload::Chat(line) => {
+                game_state.chat.push(line);
+                let overflow = game_state.chat.len().saturating_sub(CHAT_HISTORY);
+                game_state.chat.drain(..overflow);
+            }
+            ServerPayload::Ping(ping) => {
+                let pong = ClientMessage::new(
+                    "pong",
+                    game_state.local_player_id,
+                    serde_json::json!({ "nonce": ping.nonce }),
+                );
+                reply(&port_channels, pong);
+            }
+            ServerPayload::InputAck(ack) => {
+                prediction.acknowledge(ack.sequence);
+            }
+            ServerPayload::Markers(markers) => {
+                game_state.markers = markers.markers;
+            }
+            ServerPayload::HackerIntel(intel) => {
+                game_state.intel = intel;
+            }
+            ServerPayload::Trace(trace) => {
+                game_state.trace = Some(trace);
+            }
+            ServerPayload::Interaction(interaction) => {
+                info!("Interact: {:?}", interaction.result);
+                game_state.interaction = Some(interaction);
+            }
+            ServerPayload::Level(level) => {
+                prediction.load_level(&level);
+                interpolation.set_timestep(level.timestep);
+            }
+            ServerPayload::DoorState(door) => {
+                prediction.set_door_state(door.door_id, door.state);
+                game_state.doors.retain(|d| d.door_id != door.door_id);
+                game_state.doors.push(door);
             }
         }
     }
+
+    if !authoritative {
+        return;
+    }
+
+    // Our own infiltrator is predicted; everything else is buffered for interpolation
+    let predicted = game_state.local_player_role == PlayerRole::Infiltrator;
+    let local_id = local_infiltrator(&game_state.entities).filter(|_| predicted).map(|e| e.id);
+    interpolation.record(&game_state.entities, tick, time.elapsed_secs_f64(), local_id);
+
+    // Rewind to the server's positions and replay inputs it hasn't seen yet
+    if predicted {
+        prediction.reconcile(&mut game_state.entities);
+    }
+}
+
+/// Queue a message for Elixir from inside a system
+pub fn reply(port_channels: &PortChannels, msg: ClientMessage) {
+    if let Ok(sender) = port_channels.to_elixir.lock() {
+        if sender.send(msg).is_err() {
+            error!("Failed to send message to Elixir - Port closed");
+        }
+    }
 }
 
 /// System to send player input to Elixir
+/// The infiltrator's movement is predicted locally and tagged with a sequence number
 pub fn send_to_elixir(
     port_channels: Res<PortChannels>,
-    game_state: Res<GameState>,
+    mut game_state: ResMut<GameState>,
+    connection: Res<ConnectionState>,
+    mut prediction: ResMut<Prediction>,
     keyboard: Res<ButtonInput<KeyCode>>,
+    time: Res<Time>,
+    mut was_moving: Local<bool>,
 ) {
+    // Input waits for the handshake
+    if !connection.is_ready() {
+        return;
+    }
+
     let mut movement = Vec2::ZERO;
 
     // Capture player input
@@ -139,38 +334,126 @@ pub fn send_to_elixir(
         movement.y -= 1.0;
     }
 
-    // Only send if there's actual input
-    if movement.length() > 0.0 {
-        let msg = ClientMessage {
-            msg_type: "player_input".to_string(),
-            player_id: game_state.local_player_id,
-            data: serde_json::json!({
-                "movement": {
-                    "x": movement.x,
-                    "y": movement.y
-                }
-            }),
-        };
+    // A predicted input is sampled once per server tick, since the server simulates each for one tick
+    let predicting = game_state.local_player_role == PlayerRole::Infiltrator
+        && connection.session().is_some_and(|s| s.supports("prediction"));
+    let samples = if predicting { prediction.due_ticks(time.delta_secs()) } else { 1 };
 
-        if let Ok(sender) = port_channels.to_elixir.lock() {
-            if sender.send(msg).is_err() {
-                error!("Failed to send message to Elixir - Port closed");
+    // Send while moving, plus once more when the player stops
+    let moving = movement.length() > 0.0;
+    for _ in 0..samples {
+        if !moving && !*was_moving {
+            break;
+        }
+
+        let mut data = serde_json::json!({
+            "movement": {
+                "x": movement.x,
+                "y": movement.y
             }
+        });
+
+        if predicting {
+            let input = PlayerInput { move_x: movement.x, move_y: movement.y, ..default() };
+            let step = prediction.step_size(time.delta_secs());
+            let sequence = prediction.predict(&mut game_state.entities, input, step);
+            data["sequence"] = serde_json::json!(sequence);
         }
+
+        reply(&port_channels, ClientMessage::new("player_input", game_state.local_player_id, data));
+        *was_moving = moving;
     }
 
     // Handle action keys (space for infiltrator actions, etc.)
     if keyboard.just_pressed(KeyCode::Space) {
-        let msg = ClientMessage {
-            msg_type: "player_action".to_string(),
-            player_id: game_state.local_player_id,
-            data: serde_json::json!({
+        let msg = ClientMessage::new(
+            "player_action",
+            game_state.local_player_id,
+            serde_json::json!({
                 "action": "interact"
             }),
-        };
+        );
+        reply(&port_channels, msg);
+    }
+}
 
-        if let Ok(sender) = port_channels.to_elixir.lock() {
-            let _ = sender.send(msg);
-        }
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game_state::MessageType;
+    use bevy::ecs::system::RunSystemOnce;
+    use serde_json::json;
+
+    /// A world with everything receive_from_elixir needs, fed by `to_core` and answering on `from_core`
+    fn port() -> (World, Sender<ServerMessage>, Receiver<ClientMessage>) {
+        let (to_core, from_elixir) = channel();
+        let (to_elixir, from_core) = channel();
+        let mut world = World::new();
+        world.insert_resource(PortChannels {
+            to_elixir: Arc::new(Mutex::new(to_elixir)),
+            from_elixir: Arc::new(Mutex::new(from_elixir)),
+            closed: Arc::new(AtomicBool::new(false)),
+            protocol: Protocol::Json,
+        });
+        let game_state = GameState { local_player_id: 7, ..default() };
+        world.insert_resource(Prediction::new(game_state.world_bounds));
+        world.insert_resource(game_state);
+        world.init_resource::<SnapshotReceiver>();
+        world.init_resource::<ConnectionState>();
+        world.init_resource::<Interpolation>();
+        world.init_resource::<Time>();
+        (world, to_core, from_core)
+    }
+
+    fn deliver(world: &mut World, to_core: &Sender<ServerMessage>, msg_type: MessageType, data: serde_json::Value) {
+        to_core.send(ServerMessage { msg_type, data }).unwrap();
+        world.run_system_once(receive_from_elixir).unwrap();
+    }
+
+    fn errors(from_core: &Receiver<ClientMessage>) -> Vec<serde_json::Value> {
+        from_core.try_iter().filter(|m| m.msg_type == "error").map(|m| m.data).collect()
+    }
+
+    fn welcome(capabilities: &[&str]) -> serde_json::Value {
+        json!({ "version": connection::PROTOCOL_VERSION, "encoding": "json", "capabilities": capabilities })
+    }
+
+    #[test]
+    fn test_mismatched_payloads_are_answered_with_an_error() {
+        let (mut world, to_core, from_core) = port();
+        deliver(&mut world, &to_core, MessageType::Welcome, welcome(&["chat"]));
+        deliver(&mut world, &to_core, MessageType::Chat, json!({ "oops": 1 }));
+
+        let errors = errors(&from_core);
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0]["msg_type"], "chat");
+        assert!(world.resource::<GameState>().chat.is_empty());
+    }
+
+    #[test]
+    fn test_only_the_welcome_gets_through_before_the_handshake() {
+        let (mut world, to_core, from_core) = port();
+        deliver(&mut world, &to_core, MessageType::Chat, json!({ "player_id": 2, "text": "early" }));
+        let refused = errors(&from_core);
+        assert_eq!(refused[0]["reason"], "handshake not complete");
+
+        deliver(&mut world, &to_core, MessageType::Welcome, welcome(&["chat"]));
+        assert!(world.resource::<ConnectionState>().is_ready());
+        deliver(&mut world, &to_core, MessageType::Chat, json!({ "player_id": 2, "text": "hi" }));
+        assert!(errors(&from_core).is_empty());
+        assert_eq!(world.resource::<GameState>().chat.len(), 1);
+    }
+
+    #[test]
+    fn test_messages_need_their_capability() {
+        let (mut world, to_core, from_core) = port();
+        deliver(&mut world, &to_core, MessageType::Welcome, welcome(&["trace"]));
+        deliver(&mut world, &to_core, MessageType::Chat, json!({ "player_id": 2, "text": "hi" }));
+        assert_eq!(errors(&from_core)[0]["reason"], "capability chat was not negotiated");
+        assert!(world.resource::<GameState>().chat.is_empty());
+
+        // Messages every server sends need nothing
+        deliver(&mut world, &to_core, MessageType::PlayerLeft, json!({ "player_id": 2 }));
+        assert!(errors(&from_core).is_empty());
     }
 }
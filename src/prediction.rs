@@ -0,0 +1,230 @@
+use bevy::prelude::*;
+use idaptik_engine::net::LevelGeometry;
+use idaptik_engine::security::DoorState;
+use idaptik_engine::{GameSystem, PlayerInput};
+use std::collections::VecDeque;
+use crate::game_state::{local_infiltrator, GameEntity};
+
+/// Unacknowledged inputs kept for replay; older ones are dropped
+pub const MAX_PENDING_INPUTS: usize = 256;
+
+/// Input applied locally and sent to the server, waiting for acknowledgement
+#[derive(Debug, Clone, Copy)]
+pub struct PredictedInput {
+    pub sequence: u32,
+    pub input: PlayerInput,
+    pub delta_time: f32,
+}
+
+/// Runs the engine simulation locally so the infiltrator moves without waiting on the server
+#[derive(Resource)]
+pub struct Prediction {
+    system: GameSystem,
+    local_id: Option<u32>,
+    next_sequence: u32,
+    acked_sequence: u32,
+    pending: VecDeque<PredictedInput>,
+}
+
+impl Prediction {
+    pub fn new(world_bounds: Vec2) -> Self {
+        Self {
+            system: GameSystem::new(world_bounds.x, world_bounds.y),
+            local_id: None,
+            next_sequence: 1,
+            acked_sequence: 0,
+            pending: VecDeque::new(),
+        }
+    }
+
+    /// Block movement with the server's obstacles, doors and bounds, and tick at its rate
+    pub fn load_level(&mut self, level: &LevelGeometry) {
+        level.apply_to(&mut self.system);
+    }
+
+    /// Keep the local copy of a door in step with the server's
+    pub fn set_door_state(&mut self, door_id: u32, state: DoorState) {
+        if let Some(door) = self.system.world.get_door_mut(door_id) {
+            door.state = state;
+        }
+    }
+
+    /// Inputs to sample this frame: one per server tick now due, or one per frame before the level arrives
+    pub fn due_ticks(&mut self, frame_delta: f32) -> u32 {
+        self.system.due_ticks(frame_delta)
+    }
+
+    /// How long each sampled input is simulated for
+    pub fn step_size(&self, frame_delta: f32) -> f32 {
+        self.system.fixed_timestep().unwrap_or(frame_delta)
+    }
+
+    /// Apply an input locally and return the sequence number to send with it
+    pub fn predict(&mut self, entities: &mut [GameEntity], input: PlayerInput, delta_time: f32) -> u32 {
+        let sequence = self.next_sequence;
+        self.next_sequence = self.next_sequence.wrapping_add(1);
+
+        self.pending.push_back(PredictedInput { sequence, input, delta_time });
+        while self.pending.len() > MAX_PENDING_INPUTS {
+            self.pending.pop_front();
+        }
+
+        if self.track(entities) {
+            self.simulate(input, delta_time);
+            self.write_back(entities);
+        }
+        sequence
+    }
+
+    /// The server has processed every input up to `sequence`
+    pub fn acknowledge(&mut self, sequence: u32) {
+        self.acked_sequence = self.acked_sequence.max(sequence);
+        let acked = self.acked_sequence;
+        self.pending.retain(|p| p.sequence > acked);
+    }
+
+    /// Rewind to the authoritative state just received and replay unacknowledged inputs
+    pub fn reconcile(&mut self, entities: &mut [GameEntity]) {
+        if !self.track(entities) {
+            return;
+        }
+
+        let inputs: Vec<PredictedInput> = self.pending.iter().copied().collect();
+        for predicted in inputs {
+            self.simulate(predicted.input, predicted.delta_time);
+        }
+        self.write_back(entities);
+    }
+
+    /// Copy the authoritative local infiltrator into the simulation
+    /// Returns false if there is no infiltrator to predict
+    fn track(&mut self, entities: &[GameEntity]) -> bool {
+        let Some(authoritative) = local_infiltrator(entities) else {
+            return false;
+        };
+
+        if self.local_id != Some(authoritative.id) {
+            if let Some(old) = self.local_id {
+                self.system.world.remove_entity(old);
+            }
+            self.system.world.add_entity(GameEntity::new_player(authoritative.id, authoritative.position));
+            self.local_id = Some(authoritative.id);
+        }
+
+        let Some(player) = self.system.world.get_entity_mut(authoritative.id) else {
+            return false;
+        };
+        // Velocity comes from each replayed input, so only the position is rewound
+        player.position = authoritative.position;
+        true
+    }
+
+    fn simulate(&mut self, input: PlayerInput, delta_time: f32) {
+        let Some(id) = self.local_id else {
+            return;
+        };
+        self.system.apply_player_input(id, input);
+        self.system.step(delta_time);
+    }
+
+    /// Show the predicted position instead of the last one the server sent
+    fn write_back(&self, entities: &mut [GameEntity]) {
+        let Some(predicted) = self.local_id.and_then(|id| self.system.world.get_entity(id)) else {
+            return;
+        };
+        if let Some(entity) = entities.iter_mut().find(|e| e.id == predicted.id) {
+            entity.position = predicted.position;
+            entity.velocity = predicted.velocity;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game_state::Position;
+    use idaptik_engine::game::Obstacle;
+
+    const RIGHT: PlayerInput = PlayerInput { move_x: 1.0, move_y: 0.0, sprint: false, crouch: false, prone: false };
+
+    fn infiltrator_at(x: f32) -> Vec<GameEntity> {
+        vec![GameEntity::new_player(1, Position::new(x, 50.0))]
+    }
+
+    #[test]
+    fn test_acknowledge_drops_only_what_the_server_has_seen() {
+        let mut prediction = Prediction::new(Vec2::new(100.0, 100.0));
+        let mut entities = infiltrator_at(10.0);
+
+        let sequences: Vec<u32> = (0..3).map(|_| prediction.predict(&mut entities, RIGHT, 1.0)).collect();
+        assert_eq!(sequences, vec![1, 2, 3]);
+        assert!(entities[0].position.x > 10.0, "moved without waiting on the server");
+
+        prediction.acknowledge(2);
+        assert_eq!(prediction.pending.iter().map(|p| p.sequence).collect::<Vec<_>>(), vec![3]);
+
+        // A late, older ack doesn't bring anything back or move the ack backwards
+        prediction.acknowledge(1);
+        assert_eq!(prediction.acked_sequence, 2);
+        assert_eq!(prediction.pending.len(), 1);
+    }
+
+    #[test]
+    fn test_reconcile_replays_unacknowledged_inputs() {
+        let mut prediction = Prediction::new(Vec2::new(100.0, 100.0));
+        let mut entities = infiltrator_at(10.0);
+        for _ in 0..3 {
+            prediction.predict(&mut entities, RIGHT, 0.5);
+        }
+        let predicted = entities[0].position.x;
+        let per_input = (predicted - 10.0) / 3.0;
+
+        // The server has applied two of them, and agrees on where that put us
+        prediction.acknowledge(2);
+        let mut authoritative = infiltrator_at(10.0 + 2.0 * per_input);
+        prediction.reconcile(&mut authoritative);
+        assert!((authoritative[0].position.x - predicted).abs() < 1e-3);
+
+        // Where the server disagrees, its position wins and the last input is replayed on top
+        let mut corrected = infiltrator_at(30.0);
+        prediction.reconcile(&mut corrected);
+        assert!((corrected[0].position.x - (30.0 + per_input)).abs() < 1e-3);
+
+        // Nothing pending: the server's position stands
+        prediction.acknowledge(3);
+        let mut settled = infiltrator_at(40.0);
+        prediction.reconcile(&mut settled);
+        assert_eq!(settled[0].position.x, 40.0);
+    }
+
+    #[test]
+    fn test_level_blocks_and_sets_the_tick() {
+        let mut prediction = Prediction::new(Vec2::new(100.0, 100.0));
+        assert_eq!(prediction.due_ticks(0.016), 1, "one sample per frame until the level arrives");
+        assert_eq!(prediction.step_size(0.016), 0.016);
+
+        let level = LevelGeometry {
+            timestep: 0.05,
+            width: 100.0,
+            height: 100.0,
+            obstacles: vec![Obstacle {
+                position: Position::new(20.0, 50.0),
+                radius: 5.0,
+                blocks_vision: true,
+                blocks_sound: false,
+                provides_cover: true,
+            }],
+            doors: Vec::new(),
+        };
+        prediction.load_level(&level);
+        assert_eq!(prediction.step_size(0.016), 0.05);
+        assert_eq!(prediction.due_ticks(0.03), 0);
+        assert_eq!(prediction.due_ticks(0.03), 1);
+
+        let mut entities = infiltrator_at(10.0);
+        for _ in 0..40 {
+            prediction.predict(&mut entities, RIGHT, 0.05);
+        }
+        assert!(entities[0].position.x < 15.0, "stopped at the obstacle, got {}", entities[0].position.x);
+    }
+}
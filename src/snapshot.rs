@@ -0,0 +1,22 @@
+use bevy::prelude::*;
+use idaptik_engine::net::{DeltaDecoder, DeltaError, SnapshotDelta, WorldSnapshot};
+use crate::game_state::GameEntity;
+
+/// Rebuilds world snapshots from deltas sent by the server
+/// Same decoder the WASM frontend uses, so both clients read one schema
+#[derive(Resource, Default)]
+pub struct SnapshotReceiver {
+    decoder: DeltaDecoder,
+}
+
+impl SnapshotReceiver {
+    /// Apply a delta; the caller acknowledges the returned snapshot's tick
+    pub fn apply(&mut self, delta: &SnapshotDelta) -> Result<&WorldSnapshot, DeltaError> {
+        self.decoder.apply(delta)
+    }
+}
+
+/// Entities described by a snapshot
+pub fn snapshot_entities(snapshot: &WorldSnapshot) -> Vec<GameEntity> {
+    snapshot.entities.iter().map(|e| e.to_entity()).collect()
+}
@@ -0,0 +1,124 @@
+use idaptik_engine::net::wire as engine;
+use crate::game_state::{ServerMessage, ClientMessage};
+
+// Framing and the FlatBuffers tables (schemas/port_protocol.fbs) are shared with the server
+pub use idaptik_engine::net::wire::{read_frame, write_frame, Protocol};
+
+pub fn decode_server_message(frame: &[u8], protocol: Protocol) -> Result<ServerMessage, String> {
+    let message = engine::decode_server_message(frame, protocol).map_err(|e| e.to_string())?;
+    let msg_type = serde_json::from_value(serde_json::Value::String(message.msg_type))
+        .map_err(|e| e.to_string())?;
+    Ok(ServerMessage { msg_type, data: message.data })
+}
+
+pub fn encode_client_message(msg: &ClientMessage, protocol: Protocol) -> Result<Vec<u8>, String> {
+    let message = idaptik_engine::net::ClientMessage {
+        msg_type: msg.msg_type.clone(),
+        player_id: msg.player_id,
+        data: msg.data.clone(),
+    };
+    engine::encode_client_message(&message, protocol).map_err(|e| e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game_state::{EntityType, MessageType, ServerPayload};
+    use idaptik_engine::net::{decode_client_message, encode_server_message};
+    use idaptik_engine::GameSystem;
+    use serde_json::json;
+
+    fn from_server(msg_type: &str, data: serde_json::Value, protocol: Protocol) -> ServerMessage {
+        let message = idaptik_engine::net::ServerMessage { msg_type: msg_type.to_string(), data, to: Some(1) };
+        let frame = encode_server_message(&message, protocol).unwrap();
+        decode_server_message(&frame, protocol).unwrap()
+    }
+
+    #[test]
+    fn test_server_messages_reach_their_payloads() {
+        for protocol in Protocol::ALL {
+            let welcome = from_server("welcome", json!({ "version": 4, "encoding": protocol.name(), "capabilities": ["chat"] }), protocol);
+            assert!(matches!(welcome.msg_type, MessageType::Welcome));
+            match welcome.payload().unwrap() {
+                ServerPayload::Welcome(w) => {
+                    assert_eq!(w.version, 4);
+                    assert_eq!(w.encoding.as_deref(), Some(protocol.name()));
+                    assert_eq!(w.capabilities, vec!["chat".to_string()]);
+                }
+                other => panic!("expected a welcome, got {:?}", other),
+            }
+
+            let trace = from_server("trace", json!({ "level": 40.0, "stage": "Hunted", "ice": 4 }), protocol);
+            match trace.payload().unwrap() {
+                ServerPayload::Trace(t) => assert_eq!((t.level, t.ice), (40.0, Some(4))),
+                other => panic!("expected a trace, got {:?}", other),
+            }
+
+            let door = from_server("door_state", json!({ "door_id": 6, "state": "Locked" }), protocol);
+            assert!(matches!(door.msg_type, MessageType::DoorState));
+            assert!(matches!(door.payload().unwrap(), ServerPayload::DoorState(d) if d.door_id == 6));
+
+            let level = json!({ "timestep": 0.05, "width": 200.0, "height": 100.0, "obstacles": [], "doors": [] });
+            let level = from_server("level", level, protocol);
+            assert!(matches!(level.payload().unwrap(), ServerPayload::Level(l) if l.timestep == 0.05 && l.width == 200.0));
+        }
+    }
+
+    #[test]
+    fn test_game_entities_round_trip() {
+        let mut game = GameSystem::new(100.0, 100.0);
+        let player_id = game.spawn_player(12.5, 30.25);
+        game.spawn_guard(50.0, 50.0);
+        {
+            let player = game.world.get_entity_mut(player_id).unwrap();
+            player.keycards = vec![2, 7];
+            player.visible_to_hacker = false;
+            player.rotation = 1.25;
+        }
+
+        let data = serde_json::to_value(&game.world.entities).unwrap();
+        let message = from_server("state_update", data, Protocol::FlatBuffers);
+        let ServerPayload::StateUpdate(entities) = message.payload().unwrap() else {
+            panic!("expected a state update");
+        };
+
+        assert_eq!(entities.len(), 2);
+        let player = entities.iter().find(|e| e.id == player_id).unwrap();
+        let original = game.world.get_entity(player_id).unwrap();
+        assert_eq!(player.entity_type, EntityType::Player);
+        assert_eq!(player.position, original.position);
+        assert_eq!(player.rotation, 1.25);
+        assert_eq!(player.keycards, vec![2, 7]);
+        assert!(!player.visible_to_hacker);
+        assert!(player.visible_to_infiltrator);
+        assert_eq!(player.speed, original.speed);
+        assert!(entities.iter().any(|e| e.entity_type == EntityType::Guard));
+    }
+
+    #[test]
+    fn test_client_messages_reach_the_server() {
+        let messages = [
+            ClientMessage::new("player_input", 3, json!({ "movement": { "x": 1.0, "y": 0.0 }, "sequence": 9, "sprint": false, "crouch": false, "prone": false })),
+            ClientMessage::new("state_ack", 3, json!({ "tick": 41 })),
+            ClientMessage::unmark(3, 2),
+            ClientMessage::error(3, Some("trace"), "bad stage"),
+        ];
+
+        for protocol in Protocol::ALL {
+            for msg in &messages {
+                let frame = encode_client_message(msg, protocol).unwrap();
+                let decoded = decode_client_message(&frame, protocol).unwrap();
+                assert_eq!(decoded.msg_type, msg.msg_type);
+                assert_eq!(decoded.player_id, 3);
+                assert_eq!(decoded.data, msg.data, "{} over {:?}", msg.msg_type, protocol);
+            }
+        }
+    }
+
+    #[test]
+    fn test_untyped_payloads_are_refused() {
+        let msg = ClientMessage::new("teleport", 3, json!({ "x": 1 }));
+        assert!(encode_client_message(&msg, Protocol::FlatBuffers).is_err());
+        assert!(decode_server_message(b"not a buffer", Protocol::FlatBuffers).is_err());
+    }
+}